@@ -74,6 +74,7 @@ use crate::{
 
 #[cfg(test)]
 mod tests;
+pub mod tsconfig;
 
 #[cfg(feature = "plugin")]
 /// A shared instance to plugin's module bytecode cache.
@@ -160,6 +161,13 @@ pub struct Options {
     #[serde(default = "default_swcrc")]
     pub swcrc: bool,
 
+    /// Path to a `tsconfig.json` to read compiler options from, relative to
+    /// `cwd`.
+    ///
+    /// Options from `.swcrc` take precedence over the ones from tsconfig.
+    #[serde(default)]
+    pub tsconfig: Option<PathBuf>,
+
     #[cfg(not(all(target_arch = "wasm32", not(target_os = "wasi"))))]
     #[serde(default)]
     pub swcrc_roots: Option<PathBuf>,
@@ -522,6 +530,7 @@ impl Options {
         };
 
         let verbatim_module_syntax = transform.verbatim_module_syntax.into_bool();
+        let isolated_modules = transform.isolated_modules.into_bool();
 
         let charset = cfg.jsc.output.charset.or_else(|| {
             if js_minify.as_ref()?.format.ascii_only {
@@ -713,6 +722,7 @@ impl Options {
                         typescript::Config {
                             import_export_assign_config,
                             verbatim_module_syntax,
+                            isolated_modules,
                             ..Default::default()
                         },
                         typescript::TsxConfig {
@@ -730,6 +740,7 @@ impl Options {
                                     .clone()
                                     .unwrap_or_else(default_pragma_frag)
                             ),
+                            runtime: transform.react.runtime,
                         },
                         comments.map(|v| v as _),
                         unresolved_mark,
@@ -1440,6 +1451,10 @@ pub struct TransformConfig {
     #[serde(default)]
     pub verbatim_module_syntax: BoolConfig<false>,
 
+    /// https://www.typescriptlang.org/tsconfig#isolatedModules
+    #[serde(default)]
+    pub isolated_modules: BoolConfig<false>,
+
     #[serde(default)]
     pub decorator_version: Option<DecoratorVersion>,
 }
@@ -0,0 +1,366 @@
+//! Support for reading compiler options from `tsconfig.json`.
+
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Error};
+use jsonc_parser::{parse_to_serde_value, ParseOptions};
+use serde::Deserialize;
+use swc_config::merge::Merge;
+use swc_ecma_ast::EsVersion;
+use swc_ecma_parser::{EsSyntax, Syntax, TsSyntax};
+use swc_ecma_transforms::{
+    modules::{self, EsModuleConfig},
+    react::{self, Runtime},
+};
+
+use super::{Config, JscConfig, ModuleConfig, Paths, TransformConfig};
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTsConfig {
+    #[serde(default)]
+    extends: Option<Extends>,
+
+    #[serde(default)]
+    compiler_options: CompilerOptions,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum Extends {
+    Single(String),
+    Multi(Vec<String>),
+}
+
+impl Extends {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            Extends::Single(s) => vec![s],
+            Extends::Multi(v) => v,
+        }
+    }
+}
+
+/// `compilerOptions` of a `tsconfig.json` file.
+///
+/// Only the options swc can honor are read, and all of them are optional so
+/// that a config can be merged with the configs it `extends`.
+#[derive(Debug, Default, Clone, Deserialize, Merge)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilerOptions {
+    #[serde(default)]
+    pub target: Option<String>,
+
+    #[serde(default)]
+    pub module: Option<String>,
+
+    #[serde(default)]
+    pub jsx: Option<String>,
+
+    #[serde(default)]
+    pub jsx_factory: Option<String>,
+
+    #[serde(default)]
+    pub jsx_fragment_factory: Option<String>,
+
+    #[serde(default)]
+    pub jsx_import_source: Option<String>,
+
+    #[serde(default)]
+    pub experimental_decorators: Option<bool>,
+
+    #[serde(default)]
+    pub emit_decorator_metadata: Option<bool>,
+
+    #[serde(default)]
+    pub use_define_for_class_fields: Option<bool>,
+
+    /// Resolved against the directory of the config file which defines it.
+    #[serde(default)]
+    pub base_url: Option<PathBuf>,
+
+    #[serde(default)]
+    pub paths: Option<Paths>,
+
+    /// Directory of the config file which defines `paths`, which is used as
+    /// the base of `paths` if `baseUrl` is not set.
+    #[serde(skip)]
+    pub paths_base: Option<PathBuf>,
+
+    #[serde(default)]
+    pub import_helpers: Option<bool>,
+
+    #[serde(default)]
+    pub verbatim_module_syntax: Option<bool>,
+}
+
+/// Loads `compilerOptions` of the tsconfig file at `path`, following its
+/// `extends` chain.
+pub fn load_tsconfig(path: &Path) -> Result<CompilerOptions, Error> {
+    let path = path
+        .canonicalize()
+        .with_context(|| format!("failed to find tsconfig file at `{}`", path.display()))?;
+
+    load(&path, &mut vec![])
+}
+
+fn load(path: &Path, stack: &mut Vec<PathBuf>) -> Result<CompilerOptions, Error> {
+    if stack.iter().any(|p| p == path) {
+        bail!(
+            "circularity detected while resolving `extends` of tsconfig file at `{}`",
+            path.display()
+        )
+    }
+
+    let content = read_to_string(path)
+        .with_context(|| format!("failed to read tsconfig file at `{}`", path.display()))?;
+    let raw = parse_tsconfig(&content)
+        .with_context(|| format!("failed to parse tsconfig file at `{}`", path.display()))?;
+
+    let dir = path.parent().expect("tsconfig path should have parent dir");
+
+    let mut options = raw.compiler_options;
+    if let Some(base_url) = &mut options.base_url {
+        let joined = dir.join(&*base_url);
+        *base_url = joined.canonicalize().with_context(|| {
+            format!(
+                "failed to canonicalize base url using the path of tsconfig\nPath: {}\nDir: \
+                 {}\nbaseUrl: {}",
+                joined.display(),
+                dir.display(),
+                base_url.display()
+            )
+        })?;
+    }
+    if options.paths.is_some() {
+        options.paths_base = Some(dir.to_path_buf());
+    }
+
+    let extends = raw.extends.map(Extends::into_vec).unwrap_or_default();
+
+    stack.push(path.to_path_buf());
+    // Later entries of `extends` override earlier ones, and the config itself
+    // overrides all of them.
+    for spec in extends.iter().rev() {
+        let base = resolve_extends(dir, spec).with_context(|| {
+            format!(
+                "failed to resolve `extends: {:?}` of tsconfig file at `{}`",
+                spec,
+                path.display()
+            )
+        })?;
+
+        options.merge(load(&base, stack)?);
+    }
+    stack.pop();
+
+    Ok(options)
+}
+
+fn parse_tsconfig(s: &str) -> Result<RawTsConfig, Error> {
+    let v = parse_to_serde_value(
+        s.trim_start_matches('\u{feff}'),
+        &ParseOptions {
+            allow_comments: true,
+            allow_trailing_commas: true,
+            allow_loose_object_property_names: false,
+        },
+    )?;
+
+    match v {
+        Some(v) => Ok(serde_json::from_value(v)?),
+        None => Ok(Default::default()),
+    }
+}
+
+/// Resolves `spec` of `extends` like tsc does, either relative to `dir` or
+/// as a file in a package from `node_modules`.
+fn resolve_extends(dir: &Path, spec: &str) -> Result<PathBuf, Error> {
+    let path = Path::new(spec);
+
+    if path.is_absolute() || spec.starts_with("./") || spec.starts_with("../") {
+        if let Some(file) = resolve_as_file(&dir.join(path)) {
+            return Ok(file);
+        }
+
+        bail!("file does not exist")
+    }
+
+    for dir in dir.ancestors() {
+        let path = dir.join("node_modules").join(spec);
+
+        if let Some(file) = resolve_as_file(&path) {
+            return Ok(file);
+        }
+
+        if path.is_dir() {
+            if let Some(file) = resolve_as_package(&path) {
+                return Ok(file);
+            }
+        }
+    }
+
+    bail!("failed to find the config in node_modules")
+}
+
+fn resolve_as_file(path: &Path) -> Option<PathBuf> {
+    if path.is_file() {
+        return Some(path.to_path_buf());
+    }
+
+    if path.extension() != Some("json".as_ref()) {
+        let mut with_ext = path.as_os_str().to_os_string();
+        with_ext.push(".json");
+        let with_ext = PathBuf::from(with_ext);
+
+        if with_ext.is_file() {
+            return Some(with_ext);
+        }
+    }
+
+    None
+}
+
+/// Uses the `tsconfig` field of `package.json`, falling back to
+/// `tsconfig.json` of the package.
+fn resolve_as_package(dir: &Path) -> Option<PathBuf> {
+    #[derive(Deserialize)]
+    struct PackageJson {
+        #[serde(default)]
+        tsconfig: Option<String>,
+    }
+
+    let tsconfig = read_to_string(dir.join("package.json"))
+        .ok()
+        .and_then(|s| serde_json::from_str::<PackageJson>(&s).ok())
+        .and_then(|pkg| pkg.tsconfig);
+
+    if let Some(file) = tsconfig.and_then(|f| resolve_as_file(&dir.join(f))) {
+        return Some(file);
+    }
+
+    resolve_as_file(&dir.join("tsconfig.json"))
+}
+
+impl CompilerOptions {
+    /// Maps the compiler options onto [Config] for `filename`.
+    ///
+    /// Options missing from the tsconfig file are left empty so that they can
+    /// be filled by the `.swcrc` file or the defaults of swc.
+    pub fn into_config(self, filename: Option<&Path>) -> Result<Config, Error> {
+        let target = self.target.as_deref().map(parse_target).transpose()?;
+        let module = self.module.as_deref().map(parse_module).transpose()?;
+
+        let mut react = react::Options {
+            pragma: self.jsx_factory,
+            pragma_frag: self.jsx_fragment_factory,
+            import_source: self.jsx_import_source,
+            ..Default::default()
+        };
+        if let Some(jsx) = &self.jsx {
+            match &*jsx.to_ascii_lowercase() {
+                "react" => react.runtime = Some(Runtime::Classic),
+                "react-jsx" => react.runtime = Some(Runtime::Automatic),
+                "react-jsxdev" => {
+                    react.runtime = Some(Runtime::Automatic);
+                    react.development = Some(true);
+                }
+                "preserve" | "react-native" => react.runtime = Some(Runtime::Preserve),
+                _ => bail!("unknown `jsx` in tsconfig: `{}`", jsx),
+            }
+        }
+
+        // tsc defines class fields by default only if the target supports them,
+        // and its default target doesn't.
+        let use_define_for_class_fields = self
+            .use_define_for_class_fields
+            .unwrap_or_else(|| target.map_or(false, |target| target >= EsVersion::Es2022));
+
+        let mut config = Config {
+            jsc: JscConfig {
+                syntax: Some(syntax_for(filename)),
+                transform: Some(TransformConfig {
+                    react,
+                    legacy_decorator: self.experimental_decorators.into(),
+                    decorator_metadata: self.emit_decorator_metadata.into(),
+                    use_define_for_class_fields: Some(use_define_for_class_fields).into(),
+                    verbatim_module_syntax: self.verbatim_module_syntax.into(),
+                    ..Default::default()
+                })
+                .into(),
+                external_helpers: self.import_helpers.into(),
+                target,
+                base_url: self.base_url.or(self.paths_base).unwrap_or_default(),
+                paths: self.paths.unwrap_or_default(),
+                ..Default::default()
+            },
+            module: module.flatten(),
+            ..Default::default()
+        };
+
+        if let Some(filename) = filename {
+            config.adjust(filename);
+        }
+
+        Ok(config)
+    }
+}
+
+/// tsc always parses decorators, so they are enabled regardless of
+/// `experimentalDecorators`.
+fn syntax_for(filename: Option<&Path>) -> Syntax {
+    let ext = filename
+        .and_then(|f| f.extension())
+        .and_then(|ext| ext.to_str());
+
+    match ext {
+        Some("js" | "jsx" | "mjs" | "cjs") => Syntax::Es(EsSyntax {
+            jsx: true,
+            decorators: true,
+            ..Default::default()
+        }),
+        _ => Syntax::Typescript(TsSyntax {
+            tsx: ext == Some("tsx"),
+            decorators: true,
+            disallow_ambiguous_jsx_like: matches!(ext, Some("mts" | "cts")),
+            ..Default::default()
+        }),
+    }
+}
+
+fn parse_target(target: &str) -> Result<EsVersion, Error> {
+    Ok(match &*target.to_ascii_lowercase() {
+        "es3" => EsVersion::Es3,
+        "es5" => EsVersion::Es5,
+        "es6" | "es2015" => EsVersion::Es2015,
+        "es2016" => EsVersion::Es2016,
+        "es2017" => EsVersion::Es2017,
+        "es2018" => EsVersion::Es2018,
+        "es2019" => EsVersion::Es2019,
+        "es2020" => EsVersion::Es2020,
+        "es2021" => EsVersion::Es2021,
+        // swc does not distinguish versions after es2022.
+        "es2022" | "es2023" | "es2024" => EsVersion::Es2022,
+        "esnext" => EsVersion::EsNext,
+        _ => bail!("unknown `target` in tsconfig: `{}`", target),
+    })
+}
+
+/// Returns `None` for module kinds which should not be transformed.
+fn parse_module(module: &str) -> Result<Option<ModuleConfig>, Error> {
+    Ok(Some(match &*module.to_ascii_lowercase() {
+        "none" | "preserve" => return Ok(None),
+        "commonjs" => ModuleConfig::CommonJs(modules::common_js::Config::default()),
+        "amd" => ModuleConfig::Amd(modules::amd::Config::default()),
+        "umd" => ModuleConfig::Umd(modules::umd::Config::default()),
+        "system" => ModuleConfig::SystemJs(modules::system_js::Config::default()),
+        "es6" | "es2015" | "es2020" | "es2022" | "esnext" => {
+            ModuleConfig::Es6(EsModuleConfig::default())
+        }
+        "node16" | "node18" | "nodenext" => ModuleConfig::NodeNext(EsModuleConfig::default()),
+        _ => bail!("unknown `module` in tsconfig: `{}`", module),
+    }))
+}
@@ -3,7 +3,7 @@ use swc_common::{
     BytePos, Span, DUMMY_SP,
 };
 use swc_ecma_ast::{Module, Script};
-use swc_ecma_visit::{as_folder, standard_only_visit_mut, Fold, VisitMut, VisitMutWith};
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
 
 /// Preserves comments that would otherwise be dropped.
 ///
@@ -36,7 +36,7 @@ struct DroppedCommentsPreserver {
 type CommentEntries = Vec<(BytePos, Vec<Comment>)>;
 
 impl VisitMut for DroppedCommentsPreserver {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     fn visit_mut_module(&mut self, module: &mut Module) {
         module.visit_mut_children_with(self);
@@ -122,9 +122,11 @@ use std::{
 use anyhow::{bail, Context, Error};
 use base64::prelude::{Engine, BASE64_STANDARD};
 use common::{
+    collections::ARandomState,
     comments::{Comment, SingleThreadedComments},
     errors::HANDLER,
 };
+use dashmap::DashMap;
 use jsonc_parser::{parse_to_serde_value, ParseOptions};
 use once_cell::sync::Lazy;
 use serde_json::error::Category;
@@ -135,8 +137,9 @@ use swc_common::{
 };
 pub use swc_compiler_base::{PrintArgs, TransformOutput};
 pub use swc_config::config_types::{BoolConfig, BoolOr, BoolOrDataConfig};
+use swc_config::merge::Merge;
 use swc_ecma_ast::{EsVersion, Program};
-use swc_ecma_codegen::{to_code, Node};
+use swc_ecma_codegen::Node;
 use swc_ecma_loader::resolvers::{
     lru::CachingResolver, node::NodeModulesResolver, tsc::TsConfigResolver,
 };
@@ -162,6 +165,7 @@ use url::Url;
 
 pub use crate::builder::PassBuilder;
 use crate::config::{
+    tsconfig::{load_tsconfig, CompilerOptions},
     BuiltInput, Config, ConfigFile, InputSourceMap, IsModule, JsMinifyCommentOption,
     JsMinifyOptions, Options, OutputCharset, Rc, RootMode, SourceMapsConfig,
 };
@@ -492,6 +496,8 @@ impl Compiler {
                 root_mode,
                 swcrc,
                 config_file,
+                tsconfig,
+                cwd,
                 ..
             } = opts;
 
@@ -521,6 +527,31 @@ impl Compiler {
                 _ => None,
             };
 
+            // Options from .swcrc take precedence over the ones from tsconfig.
+            let tsconfig = match tsconfig {
+                Some(path) => {
+                    static CACHE: Lazy<DashMap<PathBuf, CompilerOptions, ARandomState>> =
+                        Lazy::new(Default::default);
+
+                    let path = cwd.join(path);
+                    let options = match CACHE.get(&path) {
+                        Some(cached) => cached.clone(),
+                        None => {
+                            let options = load_tsconfig(&path)?;
+                            CACHE.insert(path, options.clone());
+                            options
+                        }
+                    };
+
+                    Some(
+                        options
+                            .into_config(filename_path)
+                            .context("failed to process tsconfig file")?,
+                    )
+                }
+                None => None,
+            };
+
             if let Some(filename_path) = filename_path {
                 if let Some(config) = config_file {
                     let dir = swcrc_path
@@ -557,20 +588,35 @@ impl Compiler {
                                 })?
                             };
                         }
+
+                        if let Some(tsconfig) = tsconfig {
+                            c.merge(tsconfig);
+                        }
                     }
 
                     return Ok(config);
                 }
 
+                if let Some(tsconfig) = tsconfig {
+                    return Ok(Some(tsconfig));
+                }
+
                 let config_file = config_file.unwrap_or_default();
                 let config = config_file.into_config(Some(filename_path))?;
 
                 return Ok(config);
             }
 
-            let config = match config_file {
-                Some(config_file) => config_file.into_config(None)?,
-                None => Rc::default().into_config(None)?,
+            let config = match (config_file, tsconfig) {
+                (Some(config_file), tsconfig) => {
+                    let mut config = config_file.into_config(None)?;
+                    if let (Some(c), Some(tsconfig)) = (&mut config, tsconfig) {
+                        c.merge(tsconfig);
+                    }
+                    config
+                }
+                (None, Some(tsconfig)) => Some(tsconfig),
+                (None, None) => Rc::default().into_config(None)?,
             };
 
             match config {
@@ -977,7 +1023,7 @@ impl Compiler {
                 Default::default()
             };
 
-            let dts_code = if emit_dts && program.is_module() {
+            let dts_output = if emit_dts && program.is_module() {
                 let mut checker = FastDts::new(fm.name.clone());
                 let mut module = program.clone().expect_module();
 
@@ -990,19 +1036,46 @@ impl Compiler {
                         .struct_span_err(range.span, &issue.to_string())
                         .emit();
                 }
-                let dts_code = to_code(&module);
-                Some(dts_code)
+
+                // Spans of the declarations point to the input, so the declaration map is
+                // generated like the source map of the output. It's always emitted as a
+                // separate file, because `"inline"` only applies to the output.
+                let dts_output = self.print(
+                    &module,
+                    PrintArgs {
+                        source_root: config.source_root.as_deref(),
+                        source_file_name: config.source_file_name.as_deref(),
+                        output_path: config
+                            .output_path
+                            .as_ref()
+                            .map(|output_path| output_path.with_extension("d.ts")),
+                        inline_sources_content: config.inline_sources_content,
+                        source_map: SourceMapsConfig::Bool(config.source_maps.enabled()),
+                        source_map_names: &source_map_names,
+                        orig: orig.clone(),
+                        emit_source_map_columns: config.emit_source_map_columns,
+                        ..Default::default()
+                    },
+                )?;
+                Some(dts_output)
             } else {
                 None
             };
 
             let mut pass = config.pass;
             let (program, output) = swc_transform_common::output::capture(|| {
-                if let Some(dts_code) = dts_code {
+                if let Some(dts_output) = dts_output {
                     emit(
                         "__swc_isolated_declarations__".into(),
-                        serde_json::Value::String(dts_code),
+                        serde_json::Value::String(dts_output.code),
                     );
+
+                    if let Some(dts_map) = dts_output.map {
+                        emit(
+                            "__swc_isolated_declarations_map__".into(),
+                            serde_json::Value::String(dts_map),
+                        );
+                    }
                 }
 
                 helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
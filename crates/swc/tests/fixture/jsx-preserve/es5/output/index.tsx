@@ -0,0 +1,77 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "Toolbar", {
+    enumerable: true,
+    get: function() {
+        return Toolbar;
+    }
+});
+var _async_to_generator = require("@swc/helpers/_/_async_to_generator");
+var _class_call_check = require("@swc/helpers/_/_class_call_check");
+var _create_class = require("@swc/helpers/_/_create_class");
+var _define_property = require("@swc/helpers/_/_define_property");
+var _object_without_properties = require("@swc/helpers/_/_object_without_properties");
+var _to_consumable_array = require("@swc/helpers/_/_to_consumable_array");
+var _ts_generator = require("@swc/helpers/_/_ts_generator");
+var _button = require("./button");
+var Toolbar = /*#__PURE__*/ function() {
+    "use strict";
+    function Toolbar() {
+        _class_call_check._(this, Toolbar);
+        _define_property._(this, "label", "Save");
+    }
+    _create_class._(Toolbar, [
+        {
+            key: "render",
+            value: function render(props) {
+                var _this = this;
+                var _props_icon = props.icon, icon = _props_icon === void 0 ? Toolbar.defaultIcon : _props_icon, rest = _object_without_properties._(props, [
+                    "icon"
+                ]);
+                var _props_items;
+                var items = _to_consumable_array._((_props_items = props.items) !== null && _props_items !== void 0 ? _props_items : []).map(function(item) {
+                    return <li key={item}>{_this.label}: {item}</li>;
+                });
+                var _this1 = this;
+                return <>
+                <_button.Button {...rest} onClick={/*#__PURE__*/ _async_to_generator._(function() {
+                    return _ts_generator._(this, function(_state) {
+                        switch(_state.label){
+                            case 0:
+                                return [
+                                    4,
+                                    _this1.save()
+                                ];
+                            case 1:
+                                return [
+                                    2,
+                                    _state.sent()
+                                ];
+                        }
+                    });
+                })}>
+                    {icon}
+                    {"".concat(this.label, "!")}
+                </_button.Button>
+                <ul>{items}</ul>
+            </>;
+            }
+        },
+        {
+            key: "save",
+            value: function save() {
+                return _async_to_generator._(function() {
+                    return _ts_generator._(this, function(_state) {
+                        return [
+                            2
+                        ];
+                    });
+                })();
+            }
+        }
+    ]);
+    return Toolbar;
+}();
+_define_property._(Toolbar, "defaultIcon", <span className="icon"/>);
@@ -0,0 +1,19 @@
+import { Button } from "./button";
+export class Toolbar {
+    static defaultIcon = <span className="icon"/>;
+    label = "Save";
+    render(props) {
+        const { icon = Toolbar.defaultIcon, ...rest } = props;
+        const items = [
+            ...props.items ?? []
+        ].map((item)=><li key={item}>{this.label}: {item}</li>);
+        return <>
+                <Button {...rest} onClick={async ()=>await this.save()}>
+                    {icon}
+                    {`${this.label}!`}
+                </Button>
+                <ul>{items}</ul>
+            </>;
+    }
+    async save() {}
+}
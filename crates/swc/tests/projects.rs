@@ -784,6 +784,53 @@ fn ts_id(input_dir: PathBuf) {
     tests(input_dir, Some(IsModule::Bool(true)));
 }
 
+#[testing::fixture("tests/tsconfig/**/input/")]
+fn tsconfig(input_dir: PathBuf) {
+    let output_dir = input_dir.parent().unwrap().join("output");
+
+    for entry in WalkDir::new(&input_dir)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != "node_modules")
+    {
+        let entry = entry.unwrap();
+        let file_name = entry.file_name().to_string_lossy();
+        if !file_name.ends_with(".ts") && !file_name.ends_with(".tsx") {
+            continue;
+        }
+        println!("File: {}", entry.path().to_string_lossy());
+
+        let rel_path = entry
+            .path()
+            .strip_prefix(&input_dir)
+            .expect("failed to strip prefix");
+
+        Tester::new()
+            .print_errors(|cm, handler| {
+                let c = Compiler::new(cm.clone());
+                let fm = cm.load_file(entry.path()).expect("failed to load file");
+
+                let output = c
+                    .process_js_file(
+                        fm,
+                        &handler,
+                        &Options {
+                            swcrc: true,
+                            tsconfig: Some(input_dir.join("tsconfig.json")),
+                            ..Default::default()
+                        },
+                    )
+                    .expect("failed to process file");
+
+                NormalizedOutput::from(output.code)
+                    .compare_to_file(output_dir.join(rel_path).with_extension("js"))
+                    .unwrap();
+
+                Ok(())
+            })
+            .unwrap();
+    }
+}
+
 fn tests(input_dir: PathBuf, is_module: Option<IsModule>) {
     let output_dir = input_dir.parent().unwrap().join("output");
 
@@ -862,6 +909,20 @@ fn tests(input_dir: PathBuf, is_module: Option<IsModule>) {
                                 .unwrap();
                         }
 
+                        if let Some(v) = value.remove("__swc_isolated_declarations_map__") {
+                            let map: serde_json::Value = serde_json::from_str(
+                                v.as_str()
+                                    .expect("isolated declaration pass should emit string"),
+                            )
+                            .unwrap();
+
+                            NormalizedOutput::from(serde_json::to_string_pretty(&map).unwrap())
+                                .compare_to_file(
+                                    output_dir.join(rel_path).with_extension("d.ts.map"),
+                                )
+                                .unwrap();
+                        }
+
                         if !value.is_empty() {
                             let extra = serde_json::to_string_pretty(&value).unwrap();
 
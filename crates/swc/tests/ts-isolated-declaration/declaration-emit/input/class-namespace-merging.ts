@@ -0,0 +1,32 @@
+export class Point {
+    x: number = 0;
+    y: number = 0;
+
+    static zero(): Point {
+        return new Point();
+    }
+}
+
+export namespace Point {
+    export interface Like {
+        x: number;
+        y: number;
+    }
+
+    export function from(like: Like): Point {
+        const point = new Point();
+        point.x = like.x;
+        point.y = like.y;
+        return point;
+    }
+}
+
+export enum Color {
+    Red,
+}
+
+export namespace Color {
+    export function parse(name: string): Color {
+        return Color.Red;
+    }
+}
@@ -0,0 +1,22 @@
+export interface Config {
+    debug: boolean;
+}
+
+declare global {
+    interface Window {
+        appConfig: Config;
+    }
+
+    function getConfig(): Config;
+    var configVersion: string;
+
+    namespace NodeJS {
+        interface ProcessEnv {
+            APP_ENV: string;
+        }
+    }
+}
+
+export function load(): Config {
+    return window.appConfig;
+}
@@ -0,0 +1,22 @@
+export function format(value: string): string;
+export function format(value: number): string;
+export function format(value: unknown): string {
+    return String(value);
+}
+
+export namespace format {
+    export const separator: string = ",";
+    export function join(values: string[]): string {
+        return values.join(separator);
+    }
+}
+
+function helper(value: string): string;
+function helper(value: any): string {
+    return value;
+}
+namespace helper {
+    export const version: string = "1";
+}
+
+export { helper };
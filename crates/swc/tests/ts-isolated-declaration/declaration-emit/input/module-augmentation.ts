@@ -0,0 +1,40 @@
+import { Observable } from "./observable";
+
+declare module "./observable" {
+    interface Observable<T> {
+        map<U>(project: (value: T) => U): Observable<U>;
+    }
+
+    function merge<T>(...sources: Observable<T>[]): Observable<T>;
+    const VERSION: string;
+    export function fromArray<T>(values: T[]): Observable<T>;
+}
+
+declare module "express" {
+    export interface Request {
+        user?: string;
+    }
+
+    export function json(): void;
+    export let maxBodySize: number;
+}
+
+declare module "scoped" {
+    interface Internal {}
+
+    export interface Public extends Internal {}
+    export {};
+}
+
+declare namespace Ambient {
+    function overloaded(value: string): void;
+    function overloaded(value: number): void;
+
+    export namespace Inner {
+        export const value: number;
+    }
+}
+
+export function create<T>(value: T): Observable<T> {
+    return Observable.of(value);
+}
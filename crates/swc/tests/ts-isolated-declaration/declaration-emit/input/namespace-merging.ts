@@ -0,0 +1,35 @@
+export namespace Shapes {
+    export const origin: number = 0;
+}
+
+export namespace Shapes {
+    export function area(width: number, height: number): number {
+        return width * height;
+    }
+}
+
+export namespace Outer.Inner.Deep {
+    export type Id = string;
+}
+
+export namespace Internal {
+    export interface Options {
+        verbose: boolean;
+    }
+}
+
+export namespace Internal {
+    export const defaults: Options = { verbose: false };
+
+    export namespace Nested {
+        export let counter: number = 0;
+    }
+}
+
+export namespace WithLocals {
+    interface Secret {
+        value: string;
+    }
+
+    export const secret: Secret = { value: "" };
+}
@@ -0,0 +1,18 @@
+export declare class Point {
+    x: number;
+    y: number;
+    static zero(): Point;
+}
+export declare namespace Point {
+    interface Like {
+        x: number;
+        y: number;
+    }
+    function from(like: Like): Point;
+}
+export declare enum Color {
+    Red
+}
+export declare namespace Color {
+    function parse(name: string): Color;
+}
@@ -0,0 +1,39 @@
+import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
+import { _ as _create_class } from "@swc/helpers/_/_create_class";
+import { _ as _define_property } from "@swc/helpers/_/_define_property";
+export var Point = /*#__PURE__*/ function() {
+    "use strict";
+    function Point() {
+        _class_call_check(this, Point);
+        _define_property(this, "x", 0);
+        _define_property(this, "y", 0);
+    }
+    _create_class(Point, null, [
+        {
+            key: "zero",
+            value: function zero() {
+                return new Point();
+            }
+        }
+    ]);
+    return Point;
+}();
+(function(Point) {
+    function from(like) {
+        var point = new Point();
+        point.x = like.x;
+        point.y = like.y;
+        return point;
+    }
+    Point.from = from;
+})(Point || (Point = {}));
+export var Color;
+(function(Color) {
+    Color[Color["Red"] = 0] = "Red";
+})(Color || (Color = {}));
+(function(Color) {
+    function parse(name) {
+        return 0;
+    }
+    Color.parse = parse;
+})(Color || (Color = {}));
@@ -0,0 +1,16 @@
+export interface Config {
+    debug: boolean;
+}
+declare global {
+    interface Window {
+        appConfig: Config;
+    }
+    function getConfig(): Config;
+    var configVersion: string;
+    namespace NodeJS {
+        interface ProcessEnv {
+            APP_ENV: string;
+        }
+    }
+}
+export declare function load(): Config;
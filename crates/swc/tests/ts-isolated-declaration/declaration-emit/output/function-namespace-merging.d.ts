@@ -0,0 +1,11 @@
+export declare function format(value: string): string;
+export declare function format(value: number): string;
+export declare namespace format {
+    const separator: string;
+    function join(values: string[]): string;
+}
+declare function helper(value: string): string;
+declare namespace helper {
+    const version: string;
+}
+export { helper };
@@ -0,0 +1,17 @@
+export function format(value) {
+    return String(value);
+}
+(function(format) {
+    format.separator = ",";
+    function join(values) {
+        return values.join(format.separator);
+    }
+    format.join = join;
+})(format || (format = {}));
+function helper(value) {
+    return value;
+}
+(function(helper) {
+    helper.version = "1";
+})(helper || (helper = {}));
+export { helper };
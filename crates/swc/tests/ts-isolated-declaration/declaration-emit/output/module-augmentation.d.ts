@@ -0,0 +1,31 @@
+import { Observable } from "./observable";
+declare module "./observable" {
+    interface Observable<T> {
+        map<U>(project: (value: T) => U): Observable<U>;
+    }
+    function merge<T>(...sources: Observable<T>[]): Observable<T>;
+    const VERSION: string;
+    function fromArray<T>(values: T[]): Observable<T>;
+}
+declare module "express" {
+    interface Request {
+        user?: string;
+    }
+    function json(): void;
+    let maxBodySize: number;
+}
+declare module "scoped" {
+    interface Internal {
+    }
+    export interface Public extends Internal {
+    }
+    export { };
+}
+declare namespace Ambient {
+    function overloaded(value: string): void;
+    function overloaded(value: number): void;
+    namespace Inner {
+        const value: number;
+    }
+}
+export declare function create<T>(value: T): Observable<T>;
@@ -0,0 +1,4 @@
+import { Observable } from "./observable";
+export function create(value) {
+    return Observable.of(value);
+}
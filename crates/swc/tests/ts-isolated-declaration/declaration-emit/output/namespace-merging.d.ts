@@ -0,0 +1,27 @@
+export declare namespace Shapes {
+    const origin: number;
+}
+export declare namespace Shapes {
+    function area(width: number, height: number): number;
+}
+export declare namespace Outer.Inner.Deep {
+    type Id = string;
+}
+export declare namespace Internal {
+    interface Options {
+        verbose: boolean;
+    }
+}
+export declare namespace Internal {
+    const defaults: Options;
+    namespace Nested {
+        let counter: number;
+    }
+}
+export declare namespace WithLocals {
+    interface Secret {
+        value: string;
+    }
+    export const secret: Secret;
+    export { };
+}
@@ -0,0 +1,26 @@
+export var Shapes;
+(function(Shapes) {
+    Shapes.origin = 0;
+})(Shapes || (Shapes = {}));
+(function(Shapes) {
+    function area(width, height) {
+        return width * height;
+    }
+    Shapes.area = area;
+})(Shapes || (Shapes = {}));
+export var Internal;
+(function(Internal) {
+    Internal.defaults = {
+        verbose: false
+    };
+    var Nested;
+    (function(Nested) {
+        Nested.counter = 0;
+    })(Nested = Internal.Nested || (Internal.Nested = {}));
+})(Internal || (Internal = {}));
+export var WithLocals;
+(function(WithLocals) {
+    WithLocals.secret = {
+        value: ""
+    };
+})(WithLocals || (WithLocals = {}));
@@ -1,5 +1,4 @@
 declare function a(a: number): number;
 declare function a(a: string): string;
-declare function a(a: any): any;
 declare function b(a: number): number;
 declare function b(a: string): string;
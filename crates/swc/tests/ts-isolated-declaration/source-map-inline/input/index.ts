@@ -0,0 +1,22 @@
+export interface Options {
+    name: string;
+}
+
+export function create(options: Options): Options {
+    return { ...options };
+}
+
+export const version = "1.0.0";
+
+export const defaults = {
+    name: "default",
+    retries: 3,
+} as const;
+
+export class Client {
+    constructor(private options: Options) {}
+
+    get name(): string {
+        return this.options.name;
+    }
+}
@@ -0,0 +1,13 @@
+export interface Options {
+    name: string;
+}
+export declare function create(options: Options): Options;
+export declare const version: string;
+export declare const defaults: {
+    readonly name: "default";
+    readonly retries: 3;
+};
+export declare class Client {
+    constructor(private options: Options);
+    get name(): string;
+}
@@ -0,0 +1,31 @@
+import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
+import { _ as _create_class } from "@swc/helpers/_/_create_class";
+import { _ as _define_property } from "@swc/helpers/_/_define_property";
+import { _ as _object_spread } from "@swc/helpers/_/_object_spread";
+export function create(options) {
+    return _object_spread({}, options);
+}
+export var version = "1.0.0";
+export var defaults = {
+    name: "default",
+    retries: 3
+};
+export var Client = /*#__PURE__*/ function() {
+    "use strict";
+    function Client(options) {
+        _class_call_check(this, Client);
+        _define_property(this, "options", void 0);
+        this.options = options;
+    }
+    _create_class(Client, [
+        {
+            key: "name",
+            get: function get() {
+                return this.options.name;
+            }
+        }
+    ]);
+    return Client;
+}();
+
+//# sourceMappingURL=data:application/json;base64,eyJ2ZXJzaW9uIjozLCJzb3VyY2VzIjpbIi4uLy4uL2lucHV0L2luZGV4LnRzIl0sInNvdXJjZXNDb250ZW50IjpbImV4cG9ydCBpbnRlcmZhY2UgT3B0aW9ucyB7XG4gICAgbmFtZTogc3RyaW5nO1xufVxuXG5leHBvcnQgZnVuY3Rpb24gY3JlYXRlKG9wdGlvbnM6IE9wdGlvbnMpOiBPcHRpb25zIHtcbiAgICByZXR1cm4geyAuLi5vcHRpb25zIH07XG59XG5cbmV4cG9ydCBjb25zdCB2ZXJzaW9uID0gXCIxLjAuMFwiO1xuXG5leHBvcnQgY29uc3QgZGVmYXVsdHMgPSB7XG4gICAgbmFtZTogXCJkZWZhdWx0XCIsXG4gICAgcmV0cmllczogMyxcbn0gYXMgY29uc3Q7XG5cbmV4cG9ydCBjbGFzcyBDbGllbnQge1xuICAgIGNvbnN0cnVjdG9yKHByaXZhdGUgb3B0aW9uczogT3B0aW9ucykge31cblxuICAgIGdldCBuYW1lKCk6IHN0cmluZyB7XG4gICAgICAgIHJldHVybiB0aGlzLm9wdGlvbnMubmFtZTtcbiAgICB9XG59XG4iXSwibmFtZXMiOlsiY3JlYXRlIiwib3B0aW9ucyIsInZlcnNpb24iLCJkZWZhdWx0cyIsIm5hbWUiLCJyZXRyaWVzIiwiQ2xpZW50Il0sIm1hcHBpbmdzIjoiOzs7O0FBSUEsT0FBTyxTQUFTQSxPQUFPQyxPQUFnQjtJQUNuQyxPQUFPLG1CQUFLQTtBQUNoQjtBQUVBLE9BQU8sSUFBTUMsVUFBVSxRQUFRO0FBRS9CLE9BQU8sSUFBTUMsV0FBVztJQUNwQkMsTUFBTTtJQUNOQyxTQUFTO0FBQ2IsRUFBVztBQUVYLE9BQU8sSUFBQSxBQUFNQyx1QkFBRCxBQUFMOzthQUFNQSxPQUNHLEFBQVFMLE9BQWdCO2dDQUQzQks7O2FBQ1dMLFVBQUFBOztrQkFEWEs7O1lBR0xGLEtBQUFBO2lCQUFKO2dCQUNJLE9BQU8sSUFBSSxDQUFDSCxPQUFPLENBQUNHLElBQUk7WUFDNUI7OztXQUxTRTtJQU1aIn0=
@@ -0,0 +1,29 @@
+import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
+import { _ as _create_class } from "@swc/helpers/_/_create_class";
+import { _ as _define_property } from "@swc/helpers/_/_define_property";
+import { _ as _object_spread } from "@swc/helpers/_/_object_spread";
+export function create(options) {
+    return _object_spread({}, options);
+}
+export var version = "1.0.0";
+export var defaults = {
+    name: "default",
+    retries: 3
+};
+export var Client = /*#__PURE__*/ function() {
+    "use strict";
+    function Client(options) {
+        _class_call_check(this, Client);
+        _define_property(this, "options", void 0);
+        this.options = options;
+    }
+    _create_class(Client, [
+        {
+            key: "name",
+            get: function get() {
+                return this.options.name;
+            }
+        }
+    ]);
+    return Client;
+}();
@@ -0,0 +1,26 @@
+module X.Y {
+    export class Point {
+        constructor(x: number, y: number) {
+            this.x = x;
+            this.y = y;
+        }
+        x: number;
+        y: number;
+    }
+}
+
+module X.Y {
+    export module Point {
+        export var Origin: Point = new Point(0, 0);
+    }
+}
+
+class A {
+    id: string;
+}
+
+module A {
+    export var Instance: A = new A();
+}
+
+var a: { id: string };
@@ -0,0 +1,14 @@
+module B {
+
+    export function Point(): { x: number; y: number } {
+        return { x: 0, y: 0 };
+    }
+
+    export module Point {
+        export var Origin: { x: number; y: number } = { x: 0, y: 0 };
+    }
+}
+
+var fn: () => { x: number; y: number };
+
+var cl: { x: number; y: number; }
@@ -0,0 +1,8 @@
+declare module "M" {
+    export var x: string;
+}
+
+// Merge
+declare module "M" {
+    export var y: string;
+}
@@ -0,0 +1,5 @@
+export { };
+
+declare global {
+  var x: number;
+}
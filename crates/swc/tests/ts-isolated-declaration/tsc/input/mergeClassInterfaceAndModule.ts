@@ -0,0 +1,11 @@
+interface C1 {}
+declare class C1 {}
+module C1 {}
+
+declare class C2 {}
+interface C2 {}
+module C2 {}
+
+declare class C3 {}
+module C3 {}
+interface C3 {}
@@ -0,0 +1,21 @@
+declare namespace X.Y {
+    class Point {
+        constructor(x: number, y: number);
+        x: number;
+        y: number;
+    }
+}
+declare namespace X.Y {
+    namespace Point {
+        var Origin: Point;
+    }
+}
+declare class A {
+    id: string;
+}
+declare namespace A {
+    var Instance: A;
+}
+declare var a: {
+    id: string;
+};
@@ -0,0 +1,33 @@
+import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
+import { _ as _define_property } from "@swc/helpers/_/_define_property";
+var X;
+(function(X) {
+    (function(Y) {
+        var Point = function Point(x, y) {
+            "use strict";
+            _class_call_check(this, Point);
+            _define_property(this, "x", void 0);
+            _define_property(this, "y", void 0);
+            this.x = x;
+            this.y = y;
+        };
+        Y.Point = Point;
+    })(X.Y || (X.Y = {}));
+})(X || (X = {}));
+(function(X) {
+    (function(Y) {
+        var Point;
+        (function(Point) {
+            Point.Origin = new Point(0, 0);
+        })(Point = Y.Point || (Y.Point = {}));
+    })(X.Y || (X.Y = {}));
+})(X || (X = {}));
+var A = function A() {
+    "use strict";
+    _class_call_check(this, A);
+    _define_property(this, "id", void 0);
+};
+(function(A) {
+    A.Instance = new A();
+})(A || (A = {}));
+var a;
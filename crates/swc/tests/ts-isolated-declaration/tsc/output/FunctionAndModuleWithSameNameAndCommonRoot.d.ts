@@ -0,0 +1,20 @@
+declare namespace B {
+    function Point(): {
+        x: number;
+        y: number;
+    };
+    namespace Point {
+        var Origin: {
+            x: number;
+            y: number;
+        };
+    }
+}
+declare var fn: () => {
+    x: number;
+    y: number;
+};
+declare var cl: {
+    x: number;
+    y: number;
+};
@@ -0,0 +1,18 @@
+var B;
+(function(B) {
+    function Point() {
+        return {
+            x: 0,
+            y: 0
+        };
+    }
+    B.Point = Point;
+    (function(Point) {
+        Point.Origin = {
+            x: 0,
+            y: 0
+        };
+    })(Point = B.Point || (B.Point = {}));
+})(B || (B = {}));
+var fn;
+var cl;
@@ -0,0 +1,38 @@
+declare namespace A {
+    interface Point {
+        x: number;
+        y: number;
+        toCarth(): Point;
+    }
+}
+declare namespace A {
+    interface Point {
+        fromCarth(): Point;
+    }
+}
+declare var p: {
+    x: number;
+    y: number;
+    toCarth(): A.Point;
+    fromCarth(): A.Point;
+};
+declare var p: A.Point;
+declare namespace X.Y.Z {
+    interface Line {
+        new(start: A.Point, end: A.Point): any;
+    }
+}
+declare namespace X {
+    namespace Y.Z {
+        interface Line {
+            start: A.Point;
+            end: A.Point;
+        }
+    }
+}
+declare var l: {
+    start: A.Point;
+    end: A.Point;
+    new(s: A.Point, e: A.Point): any;
+};
+declare var l: X.Y.Z.Line;
@@ -0,0 +1,6 @@
+// ensure merges as expected
+var p;
+var p;
+// ensure merges as expected
+var l;
+var l;
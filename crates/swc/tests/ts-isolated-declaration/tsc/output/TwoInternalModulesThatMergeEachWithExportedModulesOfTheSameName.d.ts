@@ -0,0 +1,28 @@
+declare namespace A.B {
+    var x: number;
+}
+declare namespace A {
+    namespace B {
+        var x: string;
+    }
+    export { };
+}
+declare namespace X.Y.Z {
+    class Line {
+        length: number;
+    }
+}
+declare namespace X {
+    namespace Y {
+        namespace Z {
+            class Line {
+                name: string;
+            }
+        }
+        export { };
+    }
+}
+declare var l: {
+    length: number;
+};
+declare var l: X.Y.Z.Line;
@@ -0,0 +1,40 @@
+import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
+import { _ as _define_property } from "@swc/helpers/_/_define_property";
+var A;
+(function(A) {
+    (function(B) {})(A.B || (A.B = {}));
+})(A || (A = {}));
+(function(A) {
+    var B;
+    (function(B) {})(B || (B = {}));
+})(A || (A = {}));
+var X;
+(function(X) {
+    (function(Y) {
+        (function(Z) {
+            var Line = function Line() {
+                "use strict";
+                _class_call_check(this, Line);
+                _define_property(this, "length", void 0);
+            };
+            Z.Line = Line;
+        })(Y.Z || (Y.Z = {}));
+    })(X.Y || (X.Y = {}));
+})(X || (X = {}));
+(function(X) {
+    var Y;
+    (function(Y) {
+        var Z;
+        (function(Z) {
+            var Line = function Line() {
+                "use strict";
+                _class_call_check(this, Line);
+                _define_property(this, "name", void 0);
+            };
+            Z.Line = Line;
+        })(Z || (Z = {}));
+    })(Y = X.Y || (X.Y = {}));
+})(X || (X = {}));
+// make sure merging works as expected
+var l;
+var l;
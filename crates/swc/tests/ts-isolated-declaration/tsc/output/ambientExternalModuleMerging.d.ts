@@ -0,0 +1,6 @@
+declare module "M" {
+    var x: string;
+}
+declare module "M" {
+    var y: string;
+}
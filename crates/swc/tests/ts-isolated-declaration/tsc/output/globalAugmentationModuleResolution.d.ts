@@ -0,0 +1,4 @@
+export { };
+declare global {
+    var x: number;
+}
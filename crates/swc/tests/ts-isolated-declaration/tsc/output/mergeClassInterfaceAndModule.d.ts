@@ -0,0 +1,18 @@
+interface C1 {
+}
+declare class C1 {
+}
+declare namespace C1 {
+}
+declare class C2 {
+}
+interface C2 {
+}
+declare namespace C2 {
+}
+declare class C3 {
+}
+declare namespace C3 {
+}
+interface C3 {
+}
@@ -24,9 +24,9 @@ var _ts_decorate = require("@swc/helpers/_/_ts_decorate");
 var _ts_metadata = require("@swc/helpers/_/_ts_metadata");
 var MyComponent = /*#__PURE__*/ function() {
     "use strict";
-    function MyComponent(Service1) {
+    function MyComponent(Service) {
         _class_call_check._(this, MyComponent);
-        this.Service = Service1;
+        this.Service = Service;
     }
     var _proto = MyComponent.prototype;
     _proto.method = function method(x) {};
@@ -44,6 +44,6 @@ MyComponent = _ts_decorate._([
     decorator,
     _ts_metadata._("design:type", Function),
     _ts_metadata._("design:paramtypes", [
-        typeof Service === "undefined" ? Object : Service
+        Object
     ])
 ], MyComponent);
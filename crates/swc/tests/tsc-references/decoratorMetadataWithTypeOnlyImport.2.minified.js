@@ -15,8 +15,8 @@ Object.defineProperty(exports, "__esModule", {
     value: !0
 });
 var _class_call_check = require("@swc/helpers/_/_class_call_check"), _ts_decorate = require("@swc/helpers/_/_ts_decorate"), _ts_metadata = require("@swc/helpers/_/_ts_metadata"), MyComponent = function() {
-    function MyComponent(Service1) {
-        _class_call_check._(this, MyComponent), this.Service = Service1;
+    function MyComponent(Service) {
+        _class_call_check._(this, MyComponent), this.Service = Service;
     }
     return MyComponent.prototype.method = function(x) {}, MyComponent;
 }();
@@ -31,6 +31,6 @@ _ts_decorate._([
     decorator,
     _ts_metadata._("design:type", Function),
     _ts_metadata._("design:paramtypes", [
-        "undefined" == typeof Service ? Object : Service
+        Object
     ])
 ], MyComponent);
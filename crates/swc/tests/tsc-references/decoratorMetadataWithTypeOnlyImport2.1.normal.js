@@ -19,5 +19,5 @@ export var Main = function Main() {
 };
 _ts_decorate([
     decorator(),
-    _ts_metadata("design:type", typeof Services === "undefined" || typeof Services.Service === "undefined" ? Object : Services.Service)
+    _ts_metadata("design:type", Object)
 ], Main.prototype, "field", void 0);
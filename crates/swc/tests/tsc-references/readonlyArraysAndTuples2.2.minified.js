@@ -10,8 +10,8 @@ var A = function A() {
 };
 _ts_decorate([
     someDec,
-    _ts_metadata("design:type", Object)
+    _ts_metadata("design:type", Array)
 ], A.prototype, "j", void 0), _ts_decorate([
     someDec,
-    _ts_metadata("design:type", Object)
+    _ts_metadata("design:type", Array)
 ], A.prototype, "k", void 0);
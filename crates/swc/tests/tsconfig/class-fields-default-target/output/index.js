@@ -0,0 +1,11 @@
+function _class_call_check(instance, Constructor) {
+    if (!(instance instanceof Constructor)) {
+        throw new TypeError("Cannot call a class as a function");
+    }
+}
+export var Counter = function Counter(step) {
+    "use strict";
+    _class_call_check(this, Counter);
+    this.step = step;
+    this.count = 0;
+};
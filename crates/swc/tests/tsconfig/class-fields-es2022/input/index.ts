@@ -0,0 +1,6 @@
+export class Counter {
+    count = 0;
+    label?: string;
+
+    constructor(private readonly step: number) {}
+}
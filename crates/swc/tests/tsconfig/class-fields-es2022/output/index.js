@@ -0,0 +1,9 @@
+export class Counter {
+    step;
+    count;
+    label;
+    constructor(step){
+        this.step = step;
+        this.count = 0;
+    }
+}
@@ -0,0 +1,3 @@
+export function App({ name }: { name: string }) {
+    return <div className="app">Hello {name}</div>;
+}
@@ -0,0 +1,13 @@
+import { Injectable } from "./di";
+
+@Injectable()
+export class Service {
+    count = 0;
+
+    constructor(private readonly name: string) {}
+
+    async load() {
+        const { data, ...rest } = await fetch(this.name).then((r) => r.json());
+        return { data, rest };
+    }
+}
@@ -0,0 +1,20 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "App", {
+    enumerable: true,
+    get: function() {
+        return App;
+    }
+});
+const _jsxruntime = require("react/jsx-runtime");
+function App({ name }) {
+    return /*#__PURE__*/ (0, _jsxruntime.jsxs)("div", {
+        className: "app",
+        children: [
+            "Hello ",
+            name
+        ]
+    });
+}
@@ -0,0 +1,36 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "Service", {
+    enumerable: true,
+    get: function() {
+        return Service;
+    }
+});
+const _object_without_properties = require("@swc/helpers/_/_object_without_properties");
+const _ts_decorate = require("@swc/helpers/_/_ts_decorate");
+const _ts_metadata = require("@swc/helpers/_/_ts_metadata");
+const _di = require("./di");
+class Service {
+    async load() {
+        const _ref = await fetch(this.name).then((r)=>r.json()), { data } = _ref, rest = _object_without_properties._(_ref, [
+            "data"
+        ]);
+        return {
+            data,
+            rest
+        };
+    }
+    constructor(name){
+        this.name = name;
+        this.count = 0;
+    }
+}
+Service = _ts_decorate._([
+    (0, _di.Injectable)(),
+    _ts_metadata._("design:type", Function),
+    _ts_metadata._("design:paramtypes", [
+        String
+    ])
+], Service);
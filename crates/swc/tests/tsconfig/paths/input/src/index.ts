@@ -0,0 +1,5 @@
+import { add } from "@utils/math";
+
+export class Counter {
+    value = add(1, 2);
+}
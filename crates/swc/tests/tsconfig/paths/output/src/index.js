@@ -0,0 +1,4 @@
+import { add } from "./utils/math";
+export class Counter {
+    value = add(1, 2);
+}
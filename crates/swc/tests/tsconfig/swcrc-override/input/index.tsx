@@ -0,0 +1,10 @@
+import { type Props, render } from "./render";
+import type { Theme } from "./theme";
+
+export class Button {
+    theme?: Theme;
+
+    render(props: Props) {
+        return render(<button {...props} />);
+    }
+}
@@ -0,0 +1,6 @@
+import { render } from "./render";
+export class Button {
+    render(props) {
+        return render(<button {...props}/>);
+    }
+}
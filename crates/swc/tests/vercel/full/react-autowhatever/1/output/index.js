@@ -4,16 +4,16 @@ Object.defineProperty(exports, "__esModule", {
 }), Object.defineProperty(exports, "default", {
     enumerable: !0,
     get: function() {
-        return p;
+        return _;
     }
 });
-var e = require("@swc/helpers/_/_assert_this_initialized"), t = require("@swc/helpers/_/_class_call_check"), r = require("@swc/helpers/_/_create_class"), i = require("@swc/helpers/_/_define_property"), n = require("@swc/helpers/_/_inherits"), s = require("@swc/helpers/_/_interop_require_default"), u = require("@swc/helpers/_/_interop_require_wildcard"), d = require("@swc/helpers/_/_object_spread"), c = require("@swc/helpers/_/_object_spread_props"), l = require("@swc/helpers/_/_create_super"), a = require("react/jsx-runtime"), o = u._(require("react")), _ = s._(require("prop-types")), p = function(s) {
+var e = require("@swc/helpers/_/_assert_this_initialized"), t = require("@swc/helpers/_/_class_call_check"), r = require("@swc/helpers/_/_create_class"), i = require("@swc/helpers/_/_define_property"), n = require("@swc/helpers/_/_inherits"), s = require("@swc/helpers/_/_interop_require_default"), u = require("@swc/helpers/_/_object_spread"), d = require("@swc/helpers/_/_object_spread_props"), c = require("@swc/helpers/_/_create_super"), l = require("react/jsx-runtime"), a = require("react"), o = s._(require("prop-types")), _ = function(s) {
     "use strict";
     n._(o, s);
-    var u = l._(o);
+    var a = c._(o);
     function o() {
         var r;
-        return t._(this, o), r = u.apply(this, arguments), i._(e._(r), "storeHighlightedItemReference", function(e) {
+        return t._(this, o), r = a.apply(this, arguments), i._(e._(r), "storeHighlightedItemReference", function(e) {
             r.props.onHighlightedItemChange(null === e ? null : e.item);
         }), r;
     }
@@ -27,20 +27,20 @@ var e = require("@swc/helpers/_/_assert_this_initialized"), t = require("@swc/he
         {
             key: "render",
             value: function() {
-                var e = this, t = this.props, r = t.items, i = t.itemProps, n = t.renderItem, s = t.renderItemData, u = t.sectionIndex, l = t.highlightedItemIndex, o = t.getItemId, _ = t.theme, p = t.keyPrefix, h = null === u ? p : "".concat(p, "section-").concat(u, "-"), f = "function" == typeof i;
-                return (0, a.jsx)("ul", c._(d._({
+                var e = this, t = this.props, r = t.items, i = t.itemProps, n = t.renderItem, s = t.renderItemData, c = t.sectionIndex, a = t.highlightedItemIndex, o = t.getItemId, _ = t.theme, p = t.keyPrefix, h = null === c ? p : "".concat(p, "section-").concat(c, "-"), f = "function" == typeof i;
+                return (0, l.jsx)("ul", d._(u._({
                     role: "listbox"
                 }, _("".concat(h, "items-list"), "itemsList")), {
                     children: r.map(function(t, r) {
-                        var p = 0 === r, m = r === l, I = "".concat(h, "item-").concat(r), g = f ? i({
-                            sectionIndex: u,
+                        var p = 0 === r, m = r === a, I = "".concat(h, "item-").concat(r), g = f ? i({
+                            sectionIndex: c,
                             itemIndex: r
-                        }) : i, q = d._({
-                            id: o(u, r),
+                        }) : i, q = u._({
+                            id: o(c, r),
                             "aria-selected": m
                         }, _(I, "item", p && "itemFirst", m && "itemHighlighted"), g);
-                        return m && (q.ref = e.storeHighlightedItemReference), (0, a.jsx)(Item, c._(d._({}, q), {
-                            sectionIndex: u,
+                        return m && (q.ref = e.storeHighlightedItemReference), (0, l.jsx)(Item, d._(u._({}, q), {
+                            sectionIndex: c,
                             isHighlighted: m,
                             itemIndex: r,
                             item: t,
@@ -52,21 +52,21 @@ var e = require("@swc/helpers/_/_assert_this_initialized"), t = require("@swc/he
             }
         }
     ]), o;
-}(o.Component);
-i._(p, "propTypes", {
-    items: _.default.array.isRequired,
-    itemProps: _.default.oneOfType([
-        _.default.object,
-        _.default.func
+}(a.Component);
+i._(_, "propTypes", {
+    items: o.default.array.isRequired,
+    itemProps: o.default.oneOfType([
+        o.default.object,
+        o.default.func
     ]),
-    renderItem: _.default.func.isRequired,
-    renderItemData: _.default.object.isRequired,
-    sectionIndex: _.default.number,
-    highlightedItemIndex: _.default.number,
-    onHighlightedItemChange: _.default.func.isRequired,
-    getItemId: _.default.func.isRequired,
-    theme: _.default.func.isRequired,
-    keyPrefix: _.default.string.isRequired
-}), i._(p, "defaultProps", {
+    renderItem: o.default.func.isRequired,
+    renderItemData: o.default.object.isRequired,
+    sectionIndex: o.default.number,
+    highlightedItemIndex: o.default.number,
+    onHighlightedItemChange: o.default.func.isRequired,
+    getItemId: o.default.func.isRequired,
+    theme: o.default.func.isRequired,
+    keyPrefix: o.default.string.isRequired
+}), i._(_, "defaultProps", {
     sectionIndex: null
-}), new p();
+}), new _();
@@ -7,17 +7,17 @@ Object.defineProperty(exports, "__esModule", {
         return u;
     }
 });
-var e = require("@swc/helpers/_/_assert_this_initialized"), r = require("@swc/helpers/_/_class_call_check"), t = require("@swc/helpers/_/_create_class"), i = require("@swc/helpers/_/_define_property"), s = require("@swc/helpers/_/_inherits"), n = require("@swc/helpers/_/_interop_require_wildcard"), c = require("@swc/helpers/_/_object_spread"), o = require("@swc/helpers/_/_object_spread_props"), _ = require("@swc/helpers/_/_create_super"), l = require("react/jsx-runtime"), u = function(n) {
+var e = require("@swc/helpers/_/_assert_this_initialized"), t = require("@swc/helpers/_/_class_call_check"), r = require("@swc/helpers/_/_create_class"), i = require("@swc/helpers/_/_define_property"), s = require("@swc/helpers/_/_inherits"), n = require("@swc/helpers/_/_object_spread"), c = require("@swc/helpers/_/_object_spread_props"), o = require("@swc/helpers/_/_create_super"), l = require("react/jsx-runtime"), u = function(u) {
     "use strict";
-    s._(p, n);
-    var u = _._(p);
+    s._(p, u);
+    var _ = o._(p);
     function p() {
-        var t;
-        return r._(this, p), t = u.apply(this, arguments), i._(e._(t), "storeHighlightedItemReference", function(e) {
-            t.props.onHighlightedItemChange(null === e ? null : e.item);
-        }), t;
+        var r;
+        return t._(this, p), r = _.apply(this, arguments), i._(e._(r), "storeHighlightedItemReference", function(e) {
+            r.props.onHighlightedItemChange(null === e ? null : e.item);
+        }), r;
     }
-    return t._(p, [
+    return r._(p, [
         {
             key: "shouldComponentUpdate",
             value: function(e) {
@@ -29,32 +29,32 @@ var e = require("@swc/helpers/_/_assert_this_initialized"), r = require("@swc/he
         {
             key: "render",
             value: function() {
-                var e = this, r = this.props, t = r.items, i = r.itemProps, s = r.renderItem, n = r.renderItemData, _ = r.sectionIndex, u = r.highlightedItemIndex, p = r.getItemId, a = r.theme, d = r.keyPrefix, h = null === _ ? d : "".concat(d, "section-").concat(_, "-"), m = "function" == typeof i;
-                return (0, l.jsx)("ul", o._(c._({
+                var e = this, t = this.props, r = t.items, i = t.itemProps, s = t.renderItem, o = t.renderItemData, u = t.sectionIndex, _ = t.highlightedItemIndex, p = t.getItemId, a = t.theme, d = t.keyPrefix, h = null === u ? d : "".concat(d, "section-").concat(u, "-"), m = "function" == typeof i;
+                return (0, l.jsx)("ul", c._(n._({
                     role: "listbox"
                 }, a("".concat(h, "items-list"), "itemsList")), {
-                    children: t.map(function(r, t) {
-                        var d = 0 === t, f = t === u, I = "".concat(h, "item-").concat(t), g = m ? i({
-                            sectionIndex: _,
-                            itemIndex: t
-                        }) : i, x = c._({
-                            id: p(_, t),
+                    children: r.map(function(t, r) {
+                        var d = 0 === r, f = r === _, I = "".concat(h, "item-").concat(r), g = m ? i({
+                            sectionIndex: u,
+                            itemIndex: r
+                        }) : i, x = n._({
+                            id: p(u, r),
                             "aria-selected": f
                         }, a(I, "item", d && "itemFirst", f && "itemHighlighted"), g);
-                        return f && (x.ref = e.storeHighlightedItemReference), (0, l.jsx)(Item, o._(c._({}, x), {
-                            sectionIndex: _,
+                        return f && (x.ref = e.storeHighlightedItemReference), (0, l.jsx)(Item, c._(n._({}, x), {
+                            sectionIndex: u,
                             isHighlighted: f,
-                            itemIndex: t,
-                            item: r,
+                            itemIndex: r,
+                            item: t,
                             renderItem: s,
-                            renderItemData: n
+                            renderItemData: o
                         }));
                     })
                 }));
             }
         }
     ]), p;
-}(n._(require("react")).Component);
+}(require("react").Component);
 i._(u, "propTypes", {
     items: 500
 }), i._(u, "defaultProps", {
@@ -1,5 +1,4 @@
 import { jsx as r, Fragment as t } from "react/jsx-runtime";
-import "react";
 import { useRouter as e } from "next/router";
 import { useProject as o } from "@swr/use-project";
 import a from "@swr/use-team";
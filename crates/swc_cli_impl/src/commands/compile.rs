@@ -245,7 +245,18 @@ fn emit_output(
 
             if let Some(dts_code) = extra.remove("__swc_isolated_declarations__") {
                 let dts_file_path = output_file_path.with_extension("d.ts");
-                fs::write(dts_file_path, dts_code.as_str().unwrap())?;
+                let mut dts_code = dts_code.as_str().unwrap().to_string();
+
+                if let Some(dts_map) = extra.remove("__swc_isolated_declarations_map__") {
+                    let dts_map_path = output_file_path.with_extension("d.ts.map");
+
+                    dts_code.push_str("\n//# sourceMappingURL=");
+                    dts_code.push_str(&dts_map_path.file_name().unwrap().to_string_lossy());
+
+                    fs::write(dts_map_path, dts_map.as_str().unwrap())?;
+                }
+
+                fs::write(dts_file_path, dts_code)?;
             }
         }
     } else {
@@ -6,7 +6,8 @@ use swc_common::{ast_node, util::take::Take, EqIgnoreSpan, Span};
 use crate::{
     CustomIdent, CustomPropertyName, DashedIdent, Declaration, Dimension, FamilyName,
     ForgivingSelectorList, Function, Ident, ListOfComponentValues, Number, Percentage, Ratio,
-    SelectorList, SimpleBlock, Str, Url,
+    ScssForwardPrelude, ScssIncludePrelude, ScssMixinPrelude, ScssUsePrelude, SelectorList,
+    SimpleBlock, Str, Url,
 };
 
 #[ast_node("AtRule")]
@@ -87,6 +88,14 @@ pub enum AtRulePrelude {
     CustomMediaPrelude(CustomMediaQuery),
     #[tag("ScopeRange")]
     ScopePrelude(ScopeRange),
+    #[tag("ScssMixinPrelude")]
+    ScssMixinPrelude(ScssMixinPrelude),
+    #[tag("ScssIncludePrelude")]
+    ScssIncludePrelude(ScssIncludePrelude),
+    #[tag("ScssUsePrelude")]
+    ScssUsePrelude(ScssUsePrelude),
+    #[tag("ScssForwardPrelude")]
+    ScssForwardPrelude(ScssForwardPrelude),
 }
 
 #[ast_node("ScopeRange")]
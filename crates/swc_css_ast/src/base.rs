@@ -6,7 +6,8 @@ use crate::{
     AlphaValue, AnglePercentage, AtRule, CalcSum, CmykComponent, Color, ComplexSelector,
     DashedIdent, Delimiter, Dimension, FrequencyPercentage, Hue, IdSelector, Ident, Integer,
     KeyframeBlock, LayerName, LengthPercentage, Number, Percentage, Ratio, RelativeSelectorList,
-    SelectorList, Str, SupportsCondition, TimePercentage, TokenAndSpan, UnicodeRange, Url,
+    ScssInterpolation, ScssVariable, ScssVariableDeclaration, SelectorList, Str, SupportsCondition,
+    TimePercentage, TokenAndSpan, UnicodeRange, Url,
 };
 
 #[ast_node("Stylesheet")]
@@ -27,6 +28,9 @@ pub enum Rule {
 
     #[tag("ListOfComponentValues")]
     ListOfComponentValues(Box<ListOfComponentValues>),
+
+    #[tag("ScssVariableDeclaration")]
+    ScssVariableDeclaration(Box<ScssVariableDeclaration>),
 }
 
 impl Take for Rule {
@@ -81,6 +85,8 @@ pub enum StyleBlock {
     QualifiedRule(Box<QualifiedRule>),
     #[tag("ListOfComponentValues")]
     ListOfComponentValues(Box<ListOfComponentValues>),
+    #[tag("ScssVariableDeclaration")]
+    ScssVariableDeclaration(Box<ScssVariableDeclaration>),
 }
 
 #[ast_node("SimpleBlock")]
@@ -238,6 +244,14 @@ pub enum ComponentValue {
     Declaration(Box<Declaration>),
     #[tag("IdSelector")]
     IdSelector(Box<IdSelector>),
+
+    // SCSS
+    #[tag("ScssVariable")]
+    ScssVariable(Box<ScssVariable>),
+    #[tag("ScssInterpolation")]
+    ScssInterpolation(Box<ScssInterpolation>),
+    #[tag("ScssVariableDeclaration")]
+    ScssVariableDeclaration(Box<ScssVariableDeclaration>),
 }
 
 impl From<StyleBlock> for ComponentValue {
@@ -252,6 +266,9 @@ impl From<StyleBlock> for ComponentValue {
             StyleBlock::ListOfComponentValues(list_of_component_values) => {
                 ComponentValue::ListOfComponentValues(list_of_component_values)
             }
+            StyleBlock::ScssVariableDeclaration(scss_variable_declaration) => {
+                ComponentValue::ScssVariableDeclaration(scss_variable_declaration)
+            }
         }
     }
 }
@@ -280,6 +297,9 @@ impl From<Rule> for ComponentValue {
             Rule::ListOfComponentValues(list_of_component_values) => {
                 ComponentValue::ListOfComponentValues(list_of_component_values)
             }
+            Rule::ScssVariableDeclaration(scss_variable_declaration) => {
+                ComponentValue::ScssVariableDeclaration(scss_variable_declaration)
+            }
         }
     }
 }
@@ -2,10 +2,11 @@
 #![allow(clippy::large_enum_variant)]
 
 //! AST definitions for CSS.
-pub use self::{at_rule::*, base::*, selector::*, token::*, value::*};
+pub use self::{at_rule::*, base::*, scss::*, selector::*, token::*, value::*};
 
 mod at_rule;
 mod base;
+mod scss;
 mod selector;
 mod token;
 mod value;
@@ -0,0 +1,160 @@
+//! Nodes of the [SCSS](https://sass-lang.com/documentation/syntax) syntax.
+//!
+//! These nodes are only created by the parser when the SCSS syntax is enabled.
+
+use is_macro::Is;
+use string_enum::StringEnum;
+use swc_common::{ast_node, EqIgnoreSpan, Span};
+
+use crate::{ComponentValue, Ident, Str};
+
+/// `$name`
+#[ast_node("ScssVariable")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+pub struct ScssVariable {
+    pub span: Span,
+    /// Name of the variable, without `$`.
+    pub name: Ident,
+}
+
+/// `$name: value !default;`
+#[ast_node("ScssVariableDeclaration")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+pub struct ScssVariableDeclaration {
+    pub span: Span,
+    pub name: ScssVariable,
+    pub value: Vec<ComponentValue>,
+    pub flags: Vec<ScssFlag>,
+}
+
+/// `!default` or `!global`
+#[ast_node("ScssFlag")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+pub struct ScssFlag {
+    pub span: Span,
+    pub value: Ident,
+}
+
+/// `#{...}`
+#[ast_node("ScssInterpolation")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+pub struct ScssInterpolation {
+    pub span: Span,
+    pub value: Vec<ComponentValue>,
+}
+
+/// `%name`
+#[ast_node("ScssPlaceholderSelector")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+pub struct ScssPlaceholderSelector {
+    pub span: Span,
+    pub name: Ident,
+}
+
+/// Parameters of a mixin, arguments of an `@include` or the configuration of
+/// a module, i.e. `($a, $b: 10px)`.
+#[ast_node("ScssArgumentList")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+pub struct ScssArgumentList {
+    /// Span including parens.
+    pub span: Span,
+    pub value: Vec<ComponentValue>,
+}
+
+/// `@mixin name($a, $b: 10px)`
+#[ast_node("ScssMixinPrelude")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+pub struct ScssMixinPrelude {
+    pub span: Span,
+    pub name: Ident,
+    pub parameters: Option<ScssArgumentList>,
+}
+
+/// `@include namespace.name($a, $b)`
+#[ast_node("ScssIncludePrelude")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+pub struct ScssIncludePrelude {
+    pub span: Span,
+    pub namespace: Option<Ident>,
+    pub name: Ident,
+    pub arguments: Option<ScssArgumentList>,
+}
+
+/// `@use "url" as namespace with ($a: 10px)`
+#[ast_node("ScssUsePrelude")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+pub struct ScssUsePrelude {
+    pub span: Span,
+    pub url: Str,
+    pub namespace: Option<ScssNamespace>,
+    pub configuration: Option<ScssArgumentList>,
+}
+
+#[ast_node]
+#[derive(Eq, Hash, Is, EqIgnoreSpan)]
+pub enum ScssNamespace {
+    #[tag("Ident")]
+    Ident(Ident),
+    #[tag("ScssWildcard")]
+    Wildcard(ScssWildcard),
+}
+
+/// `*` in `@use "url" as *`
+#[ast_node("ScssWildcard")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+pub struct ScssWildcard {
+    pub span: Span,
+}
+
+/// `@forward "url" as prefix-* show a, $b with ($c: 10px)`
+#[ast_node("ScssForwardPrelude")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+pub struct ScssForwardPrelude {
+    pub span: Span,
+    pub url: Str,
+    /// Prefix without `*`, i.e. `prefix-`.
+    pub prefix: Option<Ident>,
+    pub visibility: Option<ScssForwardVisibility>,
+    pub configuration: Option<ScssArgumentList>,
+}
+
+/// `show a, $b` or `hide a, $b`
+#[ast_node("ScssForwardVisibility")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+pub struct ScssForwardVisibility {
+    pub span: Span,
+    pub kind: ScssForwardVisibilityKind,
+    pub members: Vec<ScssForwardMember>,
+}
+
+#[derive(StringEnum, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Is, EqIgnoreSpan)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "rkyv", archive(check_bytes))]
+#[cfg_attr(feature = "rkyv", archive_attr(repr(u32)))]
+#[cfg_attr(
+    feature = "rkyv",
+    archive(bound(
+        serialize = "__S: rkyv::ser::Serializer + rkyv::ser::ScratchSpace + \
+                     rkyv::ser::SharedSerializeRegistry",
+        deserialize = "__D: rkyv::de::SharedDeserializeRegistry"
+    ))
+)]
+pub enum ScssForwardVisibilityKind {
+    /// `show`
+    Show,
+
+    /// `hide`
+    Hide,
+}
+
+#[ast_node]
+#[derive(Eq, Hash, Is, EqIgnoreSpan)]
+pub enum ScssForwardMember {
+    #[tag("Ident")]
+    Ident(Ident),
+    #[tag("ScssVariable")]
+    Variable(ScssVariable),
+}
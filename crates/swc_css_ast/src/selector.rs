@@ -3,7 +3,7 @@ use string_enum::StringEnum;
 use swc_atoms::{Atom, JsWord};
 use swc_common::{ast_node, util::take::Take, EqIgnoreSpan, Span};
 
-use crate::{Delimiter, Ident, ListOfComponentValues, Str, TokenAndSpan};
+use crate::{Delimiter, Ident, ListOfComponentValues, ScssPlaceholderSelector, Str, TokenAndSpan};
 
 #[ast_node("SelectorList")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
@@ -233,6 +233,9 @@ pub enum SubclassSelector {
 
     #[tag("PseudoElementSelector")]
     PseudoElement(PseudoElementSelector),
+
+    #[tag("ScssPlaceholderSelector")]
+    ScssPlaceholder(ScssPlaceholderSelector),
 }
 
 #[ast_node("IdSelector")]
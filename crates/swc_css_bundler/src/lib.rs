@@ -0,0 +1,631 @@
+//! CSS bundler.
+//!
+//! Inlines stylesheets imported with `@import`, so a stylesheet and all its
+//! local dependencies can be served as a single file.
+#![deny(clippy::all)]
+
+use std::iter::once;
+
+use anyhow::{bail, Context, Error};
+use swc_common::{
+    sync::Lrc, util::take::Take, BytePos, EqIgnoreSpan, FileName, LineCol, SourceMap, DUMMY_SP,
+};
+use swc_css_ast::*;
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_css_visit::VisitMutWith;
+
+pub use self::load::{Load, Resolve};
+use self::rebase::UrlRebaser;
+
+mod load;
+mod rebase;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    /// Rewrite relative `url()`s in imported stylesheets, so they are relative
+    /// to the entry stylesheet.
+    ///
+    /// Defaults to `true`.
+    pub rebase_urls: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { rebase_urls: true }
+    }
+}
+
+pub struct Bundler<L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    loader: L,
+    resolver: R,
+    config: Config,
+}
+
+/// Conditions of an `@import` rule, i.e. `layer()`, `supports()` and media
+/// queries.
+#[derive(Debug, Clone)]
+struct ImportConditionsData {
+    layer: Option<Option<LayerName>>,
+    supports: Option<SupportsCondition>,
+    media: Option<MediaQueryList>,
+}
+
+impl ImportConditionsData {
+    fn is_empty(&self) -> bool {
+        self.layer.is_none() && self.supports.is_none() && self.media.is_none()
+    }
+}
+
+impl EqIgnoreSpan for ImportConditionsData {
+    fn eq_ignore_span(&self, other: &Self) -> bool {
+        self.layer.eq_ignore_span(&other.layer)
+            && self.supports.eq_ignore_span(&other.supports)
+            && self.media.eq_ignore_span(&other.media)
+    }
+}
+
+/// State of a single [Bundler::bundle] call.
+#[derive(Default)]
+struct BundleContext {
+    /// Files which are currently being inlined, used to detect cycles.
+    stack: Vec<FileName>,
+    /// Inlined files, with the conditions of all ancestor imports.
+    inlined: Vec<(FileName, Vec<ImportConditionsData>)>,
+    /// `@import` rules which are not inlined, like `https://` urls.
+    external_imports: Vec<Rule>,
+    charset: Option<Rule>,
+}
+
+impl<L, R> Bundler<L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    pub fn new(loader: L, resolver: R, config: Config) -> Self {
+        Bundler {
+            loader,
+            resolver,
+            config,
+        }
+    }
+
+    /// Bundles `entry` and all stylesheets imported from it into a single
+    /// stylesheet.
+    ///
+    /// - Imported stylesheets are wrapped in `@layer`, `@supports` and `@media`
+    ///   rules according to the conditions of the `@import` rule.
+    ///
+    /// - A stylesheet imported multiple times with the same conditions is
+    ///   inlined only once, at the position of the first import.
+    ///
+    /// - `@import` rules with absolute urls (e.g. `https://` or `//`) are not
+    ///   inlined and are hoisted to the top of the bundle. The conditions of
+    ///   the `@import` rules they are nested in are merged into them, and an
+    ///   error is returned if the conditions can't be expressed in a single
+    ///   `@import` rule.
+    ///
+    /// - Circular imports are ignored.
+    ///
+    /// Spans of the returned stylesheet point to the original files, so
+    /// [print] can be used to generate a source map for the bundle.
+    pub fn bundle(&self, entry: &FileName) -> Result<Stylesheet, Error> {
+        let mut ctx = BundleContext::default();
+
+        let rules = self.load_rules(&mut ctx, entry, entry, &[])?;
+
+        let mut bundle = Stylesheet {
+            span: DUMMY_SP,
+            rules: Vec::with_capacity(rules.len() + ctx.external_imports.len() + 1),
+        };
+        bundle.rules.extend(ctx.charset.take());
+        bundle.rules.extend(ctx.external_imports.take());
+        bundle.rules.extend(rules);
+
+        Ok(bundle)
+    }
+
+    fn load_rules(
+        &self,
+        ctx: &mut BundleContext,
+        entry: &FileName,
+        file: &FileName,
+        conditions: &[ImportConditionsData],
+    ) -> Result<Vec<Rule>, Error> {
+        let mut stylesheet = self
+            .loader
+            .load(file)
+            .with_context(|| format!("failed to load `{}`", file))?;
+
+        if self.config.rebase_urls && file != entry {
+            stylesheet.visit_mut_with(&mut UrlRebaser { entry, file });
+        }
+
+        ctx.stack.push(file.clone());
+
+        let mut rules = Vec::with_capacity(stylesheet.rules.len());
+
+        for rule in stylesheet.rules {
+            let Rule::AtRule(at_rule) = rule else {
+                rules.push(rule);
+                continue;
+            };
+
+            match at_rule.prelude.as_deref() {
+                Some(AtRulePrelude::CharsetPrelude(..)) => {
+                    // Only the `@charset` of the entry is preserved, as it's the only one
+                    // respected by browsers.
+                    if ctx.stack.len() == 1 && ctx.charset.is_none() {
+                        ctx.charset = Some(Rule::AtRule(at_rule));
+                    }
+                }
+                Some(AtRulePrelude::ImportPrelude(import_prelude)) => {
+                    let href = import_href(import_prelude);
+
+                    if href.map(is_external_url).unwrap_or(true) {
+                        let at_rule =
+                            hoist_external_import(at_rule, conditions).with_context(|| {
+                                format!("failed to hoist `@import` from `{}`", file)
+                            })?;
+                        ctx.external_imports.push(Rule::AtRule(at_rule));
+                        continue;
+                    }
+
+                    let href = href.unwrap();
+                    let resolved = self
+                        .resolver
+                        .resolve(file, href)
+                        .with_context(|| format!("failed to resolve `{}` from `{}`", href, file))?;
+
+                    if ctx.stack.contains(&resolved) {
+                        continue;
+                    }
+
+                    let current = import_conditions_data(import_prelude);
+                    let mut import_conditions = conditions.to_vec();
+                    if !current.is_empty() {
+                        import_conditions.push(current.clone());
+                    }
+
+                    if ctx.inlined.iter().any(|(inlined, inlined_conditions)| {
+                        *inlined == resolved
+                            && inlined_conditions.eq_ignore_span(&import_conditions)
+                    }) {
+                        continue;
+                    }
+                    ctx.inlined
+                        .push((resolved.clone(), import_conditions.clone()));
+
+                    let imported_rules =
+                        self.load_rules(ctx, entry, &resolved, &import_conditions)?;
+
+                    if current.is_empty() {
+                        rules.extend(imported_rules);
+                    } else {
+                        rules.extend(wrap_rules(imported_rules, &current));
+                    }
+                }
+                _ => rules.push(Rule::AtRule(at_rule)),
+            }
+        }
+
+        ctx.stack.pop();
+
+        Ok(rules)
+    }
+}
+
+/// Prints the bundle, optionally with a source map pointing to the original
+/// stylesheets.
+pub fn print(
+    cm: Lrc<SourceMap>,
+    stylesheet: &Stylesheet,
+    minify: bool,
+    source_map: bool,
+) -> Result<(String, Option<sourcemap::SourceMap>), Error> {
+    let mut code = String::new();
+    let mut src_map_buf: Vec<(BytePos, LineCol)> = vec![];
+
+    {
+        let wr = BasicCssWriter::new(
+            &mut code,
+            if source_map {
+                Some(&mut src_map_buf)
+            } else {
+                None
+            },
+            BasicCssWriterConfig::default(),
+        );
+        let mut gen = CodeGenerator::new(wr, CodegenConfig { minify });
+
+        gen.emit(stylesheet).context("failed to print the bundle")?;
+    }
+
+    let map = if source_map {
+        Some(cm.build_source_map(&src_map_buf))
+    } else {
+        None
+    };
+
+    Ok((code, map))
+}
+
+fn import_href(import_prelude: &ImportPrelude) -> Option<&str> {
+    match &*import_prelude.href {
+        ImportHref::Str(Str { value, .. }) => Some(value),
+        ImportHref::Url(Url {
+            value: Some(value), ..
+        }) => match &**value {
+            UrlValue::Str(Str { value, .. }) => Some(value),
+            UrlValue::Raw(UrlValueRaw { value, .. }) => Some(value),
+        },
+        ImportHref::Url(..) => None,
+    }
+}
+
+/// Returns `true` for urls which can't be resolved to a local stylesheet, like
+/// `https://example.com/style.css` or `//example.com/style.css`.
+pub(crate) fn is_external_url(url: &str) -> bool {
+    if url.starts_with("//") {
+        return true;
+    }
+
+    match url.find(':') {
+        Some(index) => {
+            let scheme = &url[..index];
+
+            // Windows paths like `C:\foo` are not urls.
+            scheme.len() > 1
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+        }
+        None => false,
+    }
+}
+
+fn import_conditions_data(import_prelude: &ImportPrelude) -> ImportConditionsData {
+    let layer = import_prelude
+        .layer_name
+        .as_deref()
+        .map(|layer_name| match layer_name {
+            ImportLayerName::Ident(..) => None,
+            ImportLayerName::Function(function) => {
+                function.value.iter().find_map(|value| match value {
+                    ComponentValue::LayerName(layer_name) => Some((**layer_name).clone()),
+                    _ => None,
+                })
+            }
+        });
+
+    let supports = import_prelude
+        .import_conditions
+        .as_ref()
+        .and_then(|import_conditions| import_conditions.supports.as_deref())
+        .and_then(|function| {
+            function.value.iter().find_map(|value| match value {
+                ComponentValue::SupportsCondition(supports_condition) => {
+                    Some((**supports_condition).clone())
+                }
+                ComponentValue::Declaration(declaration) => Some(SupportsCondition {
+                    span: declaration.span,
+                    conditions: vec![SupportsConditionType::SupportsInParens(
+                        SupportsInParens::Feature(SupportsFeature::Declaration(
+                            declaration.clone(),
+                        )),
+                    )],
+                }),
+                _ => None,
+            })
+        });
+
+    let media = import_prelude
+        .import_conditions
+        .as_ref()
+        .and_then(|import_conditions| import_conditions.media.as_deref())
+        .filter(|media| !media.queries.is_empty())
+        .cloned();
+
+    ImportConditionsData {
+        layer,
+        supports,
+        media,
+    }
+}
+
+/// Merges the conditions of the ancestor `@import` rules into an external
+/// `@import` rule, so it keeps applying only when they match after hoisting.
+fn hoist_external_import(
+    mut at_rule: Box<AtRule>,
+    conditions: &[ImportConditionsData],
+) -> Result<Box<AtRule>, Error> {
+    let Some((outermost, rest)) = conditions.split_first() else {
+        return Ok(at_rule);
+    };
+
+    let Some(AtRulePrelude::ImportPrelude(import_prelude)) = at_rule.prelude.as_deref_mut() else {
+        unreachable!("hoisted at-rule should be an `@import` rule")
+    };
+
+    let current = import_conditions_data(import_prelude);
+    let mut merged = outermost.clone();
+    for inner in rest.iter().chain(once(&current)) {
+        merged = merge_conditions(merged, inner)?;
+    }
+
+    import_prelude.layer_name = merged.layer.map(|layer| {
+        Box::new(match layer {
+            Some(layer) => ImportLayerName::Function(Function {
+                span: DUMMY_SP,
+                name: FunctionName::Ident(ident("layer")),
+                value: vec![ComponentValue::LayerName(Box::new(layer))],
+            }),
+            None => ImportLayerName::Ident(ident("layer")),
+        })
+    });
+
+    import_prelude.import_conditions = if merged.supports.is_none() && merged.media.is_none() {
+        None
+    } else {
+        Some(Box::new(ImportConditions {
+            span: DUMMY_SP,
+            supports: merged.supports.map(|supports| {
+                let value = match supports_in_parens(supports) {
+                    SupportsInParens::Feature(SupportsFeature::Declaration(declaration)) => {
+                        ComponentValue::Declaration(declaration)
+                    }
+                    SupportsInParens::SupportsCondition(supports) => {
+                        ComponentValue::SupportsCondition(Box::new(supports))
+                    }
+                    supports => ComponentValue::SupportsCondition(Box::new(SupportsCondition {
+                        span: DUMMY_SP,
+                        conditions: vec![SupportsConditionType::SupportsInParens(supports)],
+                    })),
+                };
+
+                Box::new(Function {
+                    span: DUMMY_SP,
+                    name: FunctionName::Ident(ident("supports")),
+                    value: vec![value],
+                })
+            }),
+            media: merged.media.map(Box::new),
+        }))
+    };
+
+    Ok(at_rule)
+}
+
+/// Merges the conditions of a nested `@import` rule into the conditions of
+/// the outer one.
+fn merge_conditions(
+    outer: ImportConditionsData,
+    inner: &ImportConditionsData,
+) -> Result<ImportConditionsData, Error> {
+    let layer = match (outer.layer, &inner.layer) {
+        (layer, None) => layer,
+        (None, layer) => layer.clone(),
+        (Some(Some(mut outer)), Some(Some(inner))) => {
+            outer.name.extend(inner.name.iter().cloned());
+            Some(Some(outer))
+        }
+        _ => bail!("anonymous layers can't be nested in a single `@import` rule"),
+    };
+
+    let supports = match (outer.supports, &inner.supports) {
+        (supports, None) => supports,
+        (None, supports) => supports.clone(),
+        (Some(outer), Some(inner)) => Some(SupportsCondition {
+            span: DUMMY_SP,
+            conditions: vec![
+                SupportsConditionType::SupportsInParens(supports_in_parens(outer)),
+                SupportsConditionType::And(SupportsAnd {
+                    span: DUMMY_SP,
+                    keyword: None,
+                    condition: Box::new(supports_in_parens(inner.clone())),
+                }),
+            ],
+        }),
+    };
+
+    let media = match (outer.media, &inner.media) {
+        (media, None) => media,
+        (None, media) => media.clone(),
+        (Some(outer), Some(inner)) => {
+            let mut queries = Vec::with_capacity(outer.queries.len() * inner.queries.len());
+            for outer in &outer.queries {
+                for inner in &inner.queries {
+                    queries.push(merge_media_queries(outer, inner)?);
+                }
+            }
+
+            Some(MediaQueryList {
+                span: DUMMY_SP,
+                queries,
+            })
+        }
+    };
+
+    Ok(ImportConditionsData {
+        layer,
+        supports,
+        media,
+    })
+}
+
+/// Creates a media query matching only if both `outer` and `inner` match.
+fn merge_media_queries(outer: &MediaQuery, inner: &MediaQuery) -> Result<MediaQuery, Error> {
+    let is_not = |query: &MediaQuery| {
+        query
+            .modifier
+            .as_ref()
+            .is_some_and(|modifier| modifier.value.eq_ignore_ascii_case("not"))
+    };
+    let is_all = |media_type: &MediaType| match media_type {
+        MediaType::Ident(ident) => ident.value.eq_ignore_ascii_case("all"),
+    };
+
+    if is_not(outer) || is_not(inner) {
+        bail!("media queries with `not` can't be merged")
+    }
+
+    let media_type = match (&outer.media_type, &inner.media_type) {
+        (None, media_type) | (media_type, None) => media_type.clone(),
+        (Some(outer), Some(inner)) if is_all(outer) => Some(inner.clone()),
+        (Some(outer), Some(inner)) if is_all(inner) || outer.eq_ignore_span(inner) => {
+            Some(outer.clone())
+        }
+        (Some(MediaType::Ident(outer)), Some(MediaType::Ident(inner))) => bail!(
+            "media types `{}` and `{}` can't be merged",
+            outer.value,
+            inner.value
+        ),
+    };
+
+    let condition = match (&outer.condition, &inner.condition) {
+        (None, condition) | (condition, None) => condition.as_deref().cloned(),
+        (Some(outer), Some(inner)) => {
+            let first = media_in_parens(outer);
+            let and = MediaAnd {
+                span: DUMMY_SP,
+                keyword: None,
+                condition: media_in_parens(inner),
+            };
+
+            // Media types can only be followed by conditions without `or`.
+            Some(if media_type.is_some() {
+                MediaConditionType::WithoutOr(MediaConditionWithoutOr {
+                    span: DUMMY_SP,
+                    conditions: vec![
+                        MediaConditionWithoutOrType::MediaInParens(first),
+                        MediaConditionWithoutOrType::And(and),
+                    ],
+                })
+            } else {
+                MediaConditionType::All(MediaCondition {
+                    span: DUMMY_SP,
+                    conditions: vec![
+                        MediaConditionAllType::MediaInParens(first),
+                        MediaConditionAllType::And(and),
+                    ],
+                })
+            })
+        }
+    };
+
+    Ok(MediaQuery {
+        span: DUMMY_SP,
+        // `only` is meaningless without a media type.
+        modifier: media_type
+            .as_ref()
+            .and(outer.modifier.clone().or_else(|| inner.modifier.clone())),
+        keyword: None,
+        media_type,
+        condition: condition.map(Box::new),
+    })
+}
+
+/// Avoids redundant parens around conditions which are already in parens.
+fn supports_in_parens(mut condition: SupportsCondition) -> SupportsInParens {
+    if let [SupportsConditionType::SupportsInParens(..)] = &*condition.conditions {
+        match condition.conditions.pop() {
+            Some(SupportsConditionType::SupportsInParens(in_parens)) => return in_parens,
+            _ => unreachable!(),
+        }
+    }
+
+    SupportsInParens::SupportsCondition(condition)
+}
+
+fn media_in_parens(condition: &MediaConditionType) -> MediaInParens {
+    let conditions = match condition {
+        MediaConditionType::All(condition) => condition.conditions.clone(),
+        MediaConditionType::WithoutOr(condition) => condition
+            .conditions
+            .iter()
+            .cloned()
+            .map(|condition| match condition {
+                MediaConditionWithoutOrType::Not(not) => MediaConditionAllType::Not(not),
+                MediaConditionWithoutOrType::And(and) => MediaConditionAllType::And(and),
+                MediaConditionWithoutOrType::MediaInParens(in_parens) => {
+                    MediaConditionAllType::MediaInParens(in_parens)
+                }
+            })
+            .collect(),
+    };
+
+    match <[_; 1]>::try_from(conditions) {
+        Ok([MediaConditionAllType::MediaInParens(in_parens)]) => in_parens,
+        Ok([condition]) => MediaInParens::MediaCondition(MediaCondition {
+            span: DUMMY_SP,
+            conditions: vec![condition],
+        }),
+        Err(conditions) => MediaInParens::MediaCondition(MediaCondition {
+            span: DUMMY_SP,
+            conditions,
+        }),
+    }
+}
+
+fn ident(value: &str) -> Ident {
+    Ident {
+        span: DUMMY_SP,
+        value: value.into(),
+        raw: None,
+    }
+}
+
+/// Wraps rules as `@media { @supports { @layer { ... } } }`.
+fn wrap_rules(rules: Vec<Rule>, conditions: &ImportConditionsData) -> Vec<Rule> {
+    let mut rules = rules;
+
+    if let Some(layer) = &conditions.layer {
+        rules = vec![wrap_in_at_rule(
+            "layer",
+            layer
+                .clone()
+                .map(|layer| AtRulePrelude::LayerPrelude(LayerPrelude::Name(layer))),
+            rules,
+        )];
+    }
+
+    if let Some(supports) = &conditions.supports {
+        rules = vec![wrap_in_at_rule(
+            "supports",
+            Some(AtRulePrelude::SupportsPrelude(supports.clone())),
+            rules,
+        )];
+    }
+
+    if let Some(media) = &conditions.media {
+        rules = vec![wrap_in_at_rule(
+            "media",
+            Some(AtRulePrelude::MediaPrelude(media.clone())),
+            rules,
+        )];
+    }
+
+    rules
+}
+
+fn wrap_in_at_rule(name: &str, prelude: Option<AtRulePrelude>, rules: Vec<Rule>) -> Rule {
+    Rule::AtRule(Box::new(AtRule {
+        span: DUMMY_SP,
+        name: AtRuleName::Ident(ident(name)),
+        prelude: prelude.map(Box::new),
+        block: Some(SimpleBlock {
+            span: DUMMY_SP,
+            name: TokenAndSpan {
+                span: DUMMY_SP,
+                token: Token::LBrace,
+            },
+            value: rules.into_iter().map(ComponentValue::from).collect(),
+        }),
+    }))
+}
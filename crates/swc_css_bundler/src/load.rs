@@ -0,0 +1,40 @@
+use anyhow::Error;
+use swc_common::FileName;
+use swc_css_ast::Stylesheet;
+
+/// Responsible for providing stylesheets to the bundler.
+///
+/// This trait is designed to allow passing pre-parsed or preprocessed
+/// stylesheets, e.g. the output of a Sass compiler.
+pub trait Load {
+    fn load(&self, file: &FileName) -> Result<Stylesheet, Error>;
+}
+
+impl<T: ?Sized + Load> Load for Box<T> {
+    fn load(&self, file: &FileName) -> Result<Stylesheet, Error> {
+        (**self).load(file)
+    }
+}
+
+impl<'a, T: ?Sized + Load> Load for &'a T {
+    fn load(&self, file: &FileName) -> Result<Stylesheet, Error> {
+        (**self).load(file)
+    }
+}
+
+/// Resolves the specifier of an `@import` rule.
+pub trait Resolve {
+    fn resolve(&self, base: &FileName, specifier: &str) -> Result<FileName, Error>;
+}
+
+impl<T: ?Sized + Resolve> Resolve for Box<T> {
+    fn resolve(&self, base: &FileName, specifier: &str) -> Result<FileName, Error> {
+        (**self).resolve(base, specifier)
+    }
+}
+
+impl<'a, T: ?Sized + Resolve> Resolve for &'a T {
+    fn resolve(&self, base: &FileName, specifier: &str) -> Result<FileName, Error> {
+        (**self).resolve(base, specifier)
+    }
+}
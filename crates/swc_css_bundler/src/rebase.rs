@@ -0,0 +1,92 @@
+use std::path::{Component, Path, PathBuf};
+
+use swc_common::FileName;
+use swc_css_ast::*;
+use swc_css_visit::{VisitMut, VisitMutWith};
+
+use crate::is_external_url;
+
+/// Rewrites relative urls in `file` to be relative to `entry`.
+pub(crate) struct UrlRebaser<'a> {
+    pub entry: &'a FileName,
+    pub file: &'a FileName,
+}
+
+impl UrlRebaser<'_> {
+    fn rebase(&self, url: &str) -> Option<String> {
+        if url.is_empty() || url.starts_with('#') || url.starts_with('/') || is_external_url(url) {
+            return None;
+        }
+
+        let (FileName::Real(entry), FileName::Real(file)) = (self.entry, self.file) else {
+            return None;
+        };
+
+        let entry_dir = entry.parent()?;
+        let file_dir = file.parent()?;
+
+        // Keep query and fragment, e.g. `font.svg#iefix` or `image.png?v=1`.
+        let path_end = url.find(['?', '#']).unwrap_or(url.len());
+        let (path, suffix) = url.split_at(path_end);
+
+        let target = normalize(&file_dir.join(path));
+        let relative = pathdiff::diff_paths(target, normalize(entry_dir))?;
+
+        let mut rebased = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        rebased.push_str(suffix);
+
+        Some(rebased)
+    }
+}
+
+impl VisitMut for UrlRebaser<'_> {
+    fn visit_mut_import_prelude(&mut self, _: &mut ImportPrelude) {
+        // `@import` rules are resolved relative to the importing file.
+    }
+
+    fn visit_mut_url(&mut self, n: &mut Url) {
+        n.visit_mut_children_with(self);
+
+        let Some(value) = &mut n.value else {
+            return;
+        };
+
+        match &mut **value {
+            UrlValue::Str(s) => {
+                if let Some(rebased) = self.rebase(&s.value) {
+                    s.value = rebased.into();
+                    s.raw = None;
+                }
+            }
+            UrlValue::Raw(raw) => {
+                if let Some(rebased) = self.rebase(&raw.value) {
+                    raw.value = rebased.into();
+                    raw.raw = None;
+                }
+            }
+        }
+    }
+}
+
+/// Removes `.` and `..` from the path without accessing the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+
+    normalized
+}
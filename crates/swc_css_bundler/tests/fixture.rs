@@ -0,0 +1,94 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Error};
+use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_css_ast::Stylesheet;
+use swc_css_bundler::{Bundler, Config, Load, Resolve};
+use swc_css_parser::parser::ParserConfig;
+use testing::NormalizedOutput;
+
+struct Loader {
+    cm: Lrc<SourceMap>,
+}
+
+impl Load for Loader {
+    fn load(&self, file: &FileName) -> Result<Stylesheet, Error> {
+        let path = match file {
+            FileName::Real(path) => path,
+            _ => unreachable!(),
+        };
+        let fm = self.cm.load_file(path)?;
+
+        let mut errors = vec![];
+        let stylesheet =
+            swc_css_parser::parse_file(&fm, None, ParserConfig::default(), &mut errors)
+                .map_err(|err| anyhow!("failed to parse: {:?}", err))?;
+
+        Ok(stylesheet)
+    }
+}
+
+struct Resolver;
+
+impl Resolve for Resolver {
+    fn resolve(&self, base: &FileName, specifier: &str) -> Result<FileName, Error> {
+        let base = match base {
+            FileName::Real(path) => path,
+            _ => unreachable!(),
+        };
+
+        Ok(FileName::Real(
+            base.parent().unwrap().join(specifier).canonicalize()?,
+        ))
+    }
+}
+
+fn bundle(cm: Lrc<SourceMap>, entry: &Path) -> Stylesheet {
+    let bundler = Bundler::new(Loader { cm }, Resolver, Config::default());
+
+    bundler
+        .bundle(&FileName::Real(entry.canonicalize().unwrap()))
+        .unwrap()
+}
+
+#[testing::fixture("tests/fixture/**/input/index.css")]
+fn fixture(input: PathBuf) {
+    let output = input.parent().unwrap().parent().unwrap().join("output.css");
+
+    testing::run_test(false, |cm, _| {
+        let stylesheet = bundle(cm.clone(), &input);
+
+        let (code, _) = swc_css_bundler::print(cm, &stylesheet, false, false).unwrap();
+
+        NormalizedOutput::from(code)
+            .compare_to_file(output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn source_map() {
+    let input = PathBuf::from("tests/fixture/basic/input/index.css");
+
+    testing::run_test(false, |cm, _| {
+        let stylesheet = bundle(cm.clone(), &input);
+
+        let (_, map) = swc_css_bundler::print(cm, &stylesheet, true, true).unwrap();
+        let map = map.unwrap();
+
+        let sources = map
+            .sources()
+            .map(|source| Path::new(source).file_name().unwrap().to_owned())
+            .collect::<Vec<_>>();
+
+        assert!(sources.contains(&"index.css".into()));
+        assert!(sources.contains(&"a.css".into()));
+        assert!(sources.contains(&"b.css".into()));
+
+        Ok(())
+    })
+    .unwrap();
+}
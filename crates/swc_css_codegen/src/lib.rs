@@ -77,6 +77,10 @@ where
                     n
                 )
             }
+            Rule::ScssVariableDeclaration(n) => {
+                emit!(self, n);
+                semi!(self);
+            }
         }
     }
 
@@ -326,6 +330,22 @@ where
             AtRulePrelude::ScopePrelude(n) => {
                 emit!(self, n);
             }
+            AtRulePrelude::ScssMixinPrelude(n) => {
+                space!(self);
+                emit!(self, n);
+            }
+            AtRulePrelude::ScssIncludePrelude(n) => {
+                space!(self);
+                emit!(self, n);
+            }
+            AtRulePrelude::ScssUsePrelude(n) => {
+                formatting_space!(self);
+                emit!(self, n);
+            }
+            AtRulePrelude::ScssForwardPrelude(n) => {
+                formatting_space!(self);
+                emit!(self, n);
+            }
         }
     }
 
@@ -599,9 +619,10 @@ where
     fn emit_media_in_parens(&mut self, n: &MediaInParens) -> Result {
         match n {
             MediaInParens::MediaCondition(n) => {
-                write_raw!(self, lo_span_offset!(n.span, 1), "(");
+                // Parens are not part of the span of the condition
+                write_raw!(self, "(");
                 emit!(self, n);
-                write_raw!(self, hi_span_offset!(n.span, 1), ")");
+                write_raw!(self, ")");
             }
             MediaInParens::Feature(n) => emit!(self, n),
             MediaInParens::GeneralEnclosed(n) => emit!(self, n),
@@ -665,7 +686,7 @@ where
     fn emit_media_feature_range(&mut self, n: &MediaFeatureRange) -> Result {
         emit!(self, n.left);
         formatting_space!(self);
-        write_raw!(self, n.span, n.comparison.as_str());
+        write_raw!(self, n.comparison.as_str());
         formatting_space!(self);
         emit!(self, n.right);
     }
@@ -674,11 +695,11 @@ where
     fn emit_media_feature_range_interval(&mut self, n: &MediaFeatureRangeInterval) -> Result {
         emit!(self, n.left);
         formatting_space!(self);
-        write_raw!(self, n.span, n.left_comparison.as_str());
+        write_raw!(self, n.left_comparison.as_str());
         formatting_space!(self);
         emit!(self, n.name);
         formatting_space!(self);
-        write_raw!(self, n.span, n.right_comparison.as_str());
+        write_raw!(self, n.right_comparison.as_str());
         formatting_space!(self);
         emit!(self, n.right);
     }
@@ -730,9 +751,10 @@ where
     fn emit_supports_in_parens(&mut self, n: &SupportsInParens) -> Result {
         match n {
             SupportsInParens::SupportsCondition(n) => {
-                write_raw!(self, lo_span_offset!(n.span, 1), "(");
+                // Parens are not part of the span of the condition
+                write_raw!(self, "(");
                 emit!(self, n);
-                write_raw!(self, hi_span_offset!(n.span, 1), ")");
+                write_raw!(self, ")");
             }
             SupportsInParens::Feature(n) => emit!(self, n),
             SupportsInParens::GeneralEnclosed(n) => emit!(self, n),
@@ -743,9 +765,10 @@ where
     fn emit_supports_feature(&mut self, n: &SupportsFeature) -> Result {
         match n {
             SupportsFeature::Declaration(n) => {
-                write_raw!(self, lo_span_offset!(n.span, 1), "(");
+                // Parens are not part of the span of the declaration
+                write_raw!(self, "(");
                 emit!(self, n);
-                write_raw!(self, hi_span_offset!(n.span, 1), ")");
+                write_raw!(self, ")");
             }
             SupportsFeature::Function(n) => emit!(self, n),
         }
@@ -907,9 +930,10 @@ where
     fn emit_query_in_parens(&mut self, n: &QueryInParens) -> Result {
         match n {
             QueryInParens::ContainerQuery(n) => {
-                write_raw!(self, lo_span_offset!(n.span, 1), "(");
+                // Parens are not part of the span of the condition
+                write_raw!(self, "(");
                 emit!(self, n);
-                write_raw!(self, hi_span_offset!(n.span, 1), ")");
+                write_raw!(self, ")");
             }
             QueryInParens::SizeFeature(n) => emit!(self, n),
             QueryInParens::GeneralEnclosed(n) => emit!(self, n),
@@ -984,7 +1008,7 @@ where
     fn emit_size_feature_range(&mut self, n: &SizeFeatureRange) -> Result {
         emit!(self, n.left);
         formatting_space!(self);
-        write_raw!(self, n.span, n.comparison.as_str());
+        write_raw!(self, n.comparison.as_str());
         formatting_space!(self);
         emit!(self, n.right);
     }
@@ -993,7 +1017,7 @@ where
     fn emit_size_feature_range_interval(&mut self, n: &SizeFeatureRangeInterval) -> Result {
         emit!(self, n.left);
         formatting_space!(self);
-        write_raw!(self, n.span, n.left_comparison.as_str());
+        write_raw!(self, n.left_comparison.as_str());
         formatting_space!(self);
         emit!(
             &mut *self.with_ctx(Ctx {
@@ -1003,7 +1027,7 @@ where
             n.name
         );
         formatting_space!(self);
-        write_raw!(self, n.span, n.right_comparison.as_str());
+        write_raw!(self, n.right_comparison.as_str());
         formatting_space!(self);
         emit!(self, n.right);
     }
@@ -1038,9 +1062,23 @@ where
                 continue;
             }
 
-            let is_current_preserved_token = matches!(node, ComponentValue::PreservedToken(_));
+            // SCSS nodes are only used in values which were not canonicalized, so
+            // whitespaces are preserved around them
+            let is_current_preserved_token = matches!(
+                node,
+                ComponentValue::PreservedToken(_)
+                    | ComponentValue::ScssVariable(_)
+                    | ComponentValue::ScssInterpolation(_)
+            );
             let next = nodes.get(idx + 1);
-            let is_next_preserved_token = matches!(next, Some(ComponentValue::PreservedToken(_)));
+            let is_next_preserved_token = matches!(
+                next,
+                Some(
+                    ComponentValue::PreservedToken(_)
+                        | ComponentValue::ScssVariable(_)
+                        | ComponentValue::ScssInterpolation(_)
+                )
+            );
 
             if idx != len - 1 && !is_current_preserved_token && !is_next_preserved_token {
                 let need_delim = match node {
@@ -1249,7 +1287,9 @@ where
 
         for (idx, node) in n.value.iter().enumerate() {
             match node {
-                ComponentValue::ListOfComponentValues(_) | ComponentValue::Declaration(_) => {
+                ComponentValue::ListOfComponentValues(_)
+                | ComponentValue::Declaration(_)
+                | ComponentValue::ScssVariableDeclaration(_) => {
                     if idx == 0 {
                         formatting_newline!(self);
                     }
@@ -1286,7 +1326,7 @@ where
                     formatting_newline!(self);
                     decrease_indent!(self);
                 }
-                ComponentValue::Declaration(_) => {
+                ComponentValue::Declaration(_) | ComponentValue::ScssVariableDeclaration(_) => {
                     if idx != len - 1 {
                         semi!(self);
                     } else {
@@ -1357,6 +1397,10 @@ where
             ComponentValue::Declaration(n) => emit!(self, n),
             ComponentValue::SupportsCondition(n) => emit!(self, n),
             ComponentValue::IdSelector(n) => emit!(self, n),
+
+            ComponentValue::ScssVariable(n) => emit!(self, n),
+            ComponentValue::ScssInterpolation(n) => emit!(self, n),
+            ComponentValue::ScssVariableDeclaration(n) => emit!(self, n),
         }
     }
 
@@ -1375,6 +1419,7 @@ where
             StyleBlock::AtRule(n) => emit!(self, n),
             StyleBlock::Declaration(n) => emit!(self, n),
             StyleBlock::QualifiedRule(n) => emit!(self, n),
+            StyleBlock::ScssVariableDeclaration(n) => emit!(self, n),
         }
     }
 
@@ -1507,7 +1552,15 @@ where
 
         let serialized = serialize_ident(&n.value, self.config.minify);
 
-        write_raw!(self, n.span, &serialized);
+        write_raw!(
+            self,
+            if n.span.is_dummy() {
+                DUMMY_SP
+            } else {
+                Span::new(n.span.lo + BytePos(2), n.span.hi)
+            },
+            &serialized
+        );
     }
 
     #[emitter]
@@ -2183,6 +2236,7 @@ where
             SubclassSelector::Attribute(n) => emit!(self, n),
             SubclassSelector::PseudoClass(n) => emit!(self, n),
             SubclassSelector::PseudoElement(n) => emit!(self, n),
+            SubclassSelector::ScssPlaceholder(n) => emit!(self, n),
         }
     }
 
@@ -2253,7 +2307,19 @@ where
     #[emitter]
     fn emit_id_selector(&mut self, n: &IdSelector) -> Result {
         write_raw!(self, lo_span_offset!(n.span, 1), "#");
-        emit!(self, n.text);
+
+        // The span of the name includes `#`
+        if !n.text.span.is_dummy() && n.text.span.lo == n.span.lo {
+            emit!(
+                self,
+                Ident {
+                    span: Span::new(n.text.span.lo + BytePos(1), n.text.span.hi),
+                    ..n.text.clone()
+                }
+            );
+        } else {
+            emit!(self, n.text);
+        }
     }
 
     #[emitter]
@@ -2432,7 +2498,15 @@ where
     #[emitter]
     fn emit_pseudo_element_selector(&mut self, n: &PseudoElementSelector) -> Result {
         write_raw!(self, lo_span_offset!(n.span, 1), ":");
-        write_raw!(self, lo_span_offset!(n.span, 2), ":");
+        write_raw!(
+            self,
+            if n.span.is_dummy() {
+                DUMMY_SP
+            } else {
+                Span::new(n.span.lo + BytePos(1), n.span.lo + BytePos(2))
+            },
+            ":"
+        );
         emit!(
             &mut *self.with_ctx(Ctx {
                 allow_to_lowercase: true,
@@ -2478,6 +2552,155 @@ where
         }
     }
 
+    #[emitter]
+    fn emit_scss_variable(&mut self, n: &ScssVariable) -> Result {
+        write_raw!(self, lo_span_offset!(n.span, 1), "$");
+        emit!(self, n.name);
+    }
+
+    #[emitter]
+    fn emit_scss_variable_declaration(&mut self, n: &ScssVariableDeclaration) -> Result {
+        emit!(self, n.name);
+        write_raw!(self, ":");
+        formatting_space!(self);
+        self.emit_list_of_component_values_inner(
+            &n.value,
+            ListFormat::SpaceDelimited | ListFormat::SingleLine,
+        )?;
+
+        for flag in &n.flags {
+            space!(self);
+            emit!(self, flag);
+        }
+    }
+
+    #[emitter]
+    fn emit_scss_flag(&mut self, n: &ScssFlag) -> Result {
+        write_raw!(self, lo_span_offset!(n.span, 1), "!");
+        emit!(self, n.value);
+    }
+
+    #[emitter]
+    fn emit_scss_interpolation(&mut self, n: &ScssInterpolation) -> Result {
+        write_raw!(self, lo_span_offset!(n.span, 2), "#{");
+        self.with_ctx(Ctx {
+            in_list_of_component_values: true,
+            ..self.ctx
+        })
+        .emit_list(&n.value, ListFormat::NotDelimited)?;
+        write_raw!(self, hi_span_offset!(n.span, 1), "}");
+    }
+
+    #[emitter]
+    fn emit_scss_placeholder_selector(&mut self, n: &ScssPlaceholderSelector) -> Result {
+        write_raw!(self, lo_span_offset!(n.span, 1), "%");
+        emit!(self, n.name);
+    }
+
+    #[emitter]
+    fn emit_scss_argument_list(&mut self, n: &ScssArgumentList) -> Result {
+        write_raw!(self, lo_span_offset!(n.span, 1), "(");
+        self.with_ctx(Ctx {
+            in_list_of_component_values: true,
+            ..self.ctx
+        })
+        .emit_list(&n.value, ListFormat::NotDelimited)?;
+        write_raw!(self, hi_span_offset!(n.span, 1), ")");
+    }
+
+    #[emitter]
+    fn emit_scss_mixin_prelude(&mut self, n: &ScssMixinPrelude) -> Result {
+        emit!(self, n.name);
+        emit!(self, n.parameters);
+    }
+
+    #[emitter]
+    fn emit_scss_include_prelude(&mut self, n: &ScssIncludePrelude) -> Result {
+        if let Some(namespace) = &n.namespace {
+            emit!(self, namespace);
+            write_raw!(self, ".");
+        }
+
+        emit!(self, n.name);
+        emit!(self, n.arguments);
+    }
+
+    #[emitter]
+    fn emit_scss_use_prelude(&mut self, n: &ScssUsePrelude) -> Result {
+        emit!(self, n.url);
+
+        if let Some(namespace) = &n.namespace {
+            space!(self);
+            write_raw!(self, "as");
+            space!(self);
+            emit!(self, namespace);
+        }
+
+        if let Some(configuration) = &n.configuration {
+            space!(self);
+            write_raw!(self, "with");
+            space!(self);
+            emit!(self, configuration);
+        }
+    }
+
+    #[emitter]
+    fn emit_scss_namespace(&mut self, n: &ScssNamespace) -> Result {
+        match n {
+            ScssNamespace::Ident(n) => emit!(self, n),
+            ScssNamespace::Wildcard(n) => emit!(self, n),
+        }
+    }
+
+    #[emitter]
+    fn emit_scss_wildcard(&mut self, n: &ScssWildcard) -> Result {
+        write_raw!(self, n.span, "*");
+    }
+
+    #[emitter]
+    fn emit_scss_forward_prelude(&mut self, n: &ScssForwardPrelude) -> Result {
+        emit!(self, n.url);
+
+        if let Some(prefix) = &n.prefix {
+            space!(self);
+            write_raw!(self, "as");
+            space!(self);
+            emit!(self, prefix);
+            write_raw!(self, "*");
+        }
+
+        if let Some(visibility) = &n.visibility {
+            space!(self);
+            emit!(self, visibility);
+        }
+
+        if let Some(configuration) = &n.configuration {
+            space!(self);
+            write_raw!(self, "with");
+            space!(self);
+            emit!(self, configuration);
+        }
+    }
+
+    #[emitter]
+    fn emit_scss_forward_visibility(&mut self, n: &ScssForwardVisibility) -> Result {
+        match n.kind {
+            ScssForwardVisibilityKind::Show => write_raw!(self, "show"),
+            ScssForwardVisibilityKind::Hide => write_raw!(self, "hide"),
+        }
+
+        space!(self);
+        self.emit_list(&n.members, ListFormat::CommaDelimited)?;
+    }
+
+    #[emitter]
+    fn emit_scss_forward_member(&mut self, n: &ScssForwardMember) -> Result {
+        match n {
+            ScssForwardMember::Ident(n) => emit!(self, n),
+            ScssForwardMember::Variable(n) => emit!(self, n),
+        }
+    }
+
     fn emit_list_pseudo_element_selector_children(
         &mut self,
         nodes: &[PseudoElementSelectorChildren],
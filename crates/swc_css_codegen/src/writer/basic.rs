@@ -61,6 +61,13 @@ impl<'a, W> BasicCssWriter<'a, W>
 where
     W: Write,
 {
+    /// If `srcmap` is [Some], mappings between positions in the input and the
+    /// output are recorded into it.
+    ///
+    /// The mappings can be converted to a source map using
+    /// `SourceMap::build_source_map` of `swc_common`, or
+    /// `SourceMap::build_source_map_from` to chain it with the input source
+    /// map (e.g. one generated by Sass or PostCSS).
     pub fn new(
         writer: W,
         srcmap: Option<&'a mut Vec<(BytePos, LineCol)>>,
@@ -110,16 +117,22 @@ where
         Ok(())
     }
 
-    fn write(&mut self, span: Option<Span>, data: &str) -> Result {
-        if !data.is_empty() {
-            if self.line_start {
-                self.write_indent_string()?;
-                self.line_start = false;
+    fn write_line_start(&mut self) -> Result {
+        if self.line_start {
+            self.write_indent_string()?;
+            self.line_start = false;
 
-                if let Some(pending) = self.pending_srcmap.take() {
-                    self.srcmap(pending);
-                }
+            if let Some(pending) = self.pending_srcmap.take() {
+                self.srcmap(pending);
             }
+        }
+
+        Ok(())
+    }
+
+    fn write(&mut self, span: Option<Span>, data: &str) -> Result {
+        if !data.is_empty() {
+            self.write_line_start()?;
 
             if let Some(span) = span {
                 if !span.is_dummy() {
@@ -199,6 +212,9 @@ where
 
     fn write_str(&mut self, span: Span, s: &str) -> Result {
         if !s.is_empty() {
+            // Indentation should not be included in the mapping
+            self.write_line_start()?;
+
             if !span.is_dummy() {
                 self.srcmap(span.lo())
             }
@@ -9,11 +9,11 @@ use swc_css_codegen::{
     writer::basic::{BasicCssWriter, BasicCssWriterConfig, IndentType, LineFeed},
     CodeGenerator, CodegenConfig, Emit,
 };
-use swc_css_parser::parse_file;
+use swc_css_parser::{parse_file, parser::ParserConfig};
 use swc_css_visit::{VisitMut, VisitMutWith};
 use testing::{run_test2, NormalizedOutput};
 
-fn run(input: &Path, minify: bool) {
+fn run(input: &Path, minify: bool, config: ParserConfig) {
     let dir = input.parent().unwrap();
     // let map = if minify {
     //     dir.join(format!(
@@ -47,7 +47,7 @@ fn run(input: &Path, minify: bool) {
 
         let mut errors = vec![];
         let mut stylesheet: Stylesheet =
-            parse_file(&fm, Some(&comments), Default::default(), &mut errors).unwrap();
+            parse_file(&fm, Some(&comments), config, &mut errors).unwrap();
 
         for err in take(&mut errors) {
             err.to_diagnostics(&handler).emit();
@@ -87,10 +87,10 @@ fn run(input: &Path, minify: bool) {
         }
 
         let mut errors = vec![];
-        let mut stylesheet_output: Stylesheet =
-            parse_file(&fm_output, None, Default::default(), &mut errors).map_err(|err| {
-                err.to_diagnostics(&handler).emit();
-            })?;
+        let mut stylesheet_output: Stylesheet = parse_file(&fm_output, None, config, &mut errors)
+            .map_err(|err| {
+            err.to_diagnostics(&handler).emit();
+        })?;
 
         for err in take(&mut errors) {
             err.to_diagnostics(&handler).emit();
@@ -334,8 +334,19 @@ impl VisitMut for NormalizeTest {
 
 #[testing::fixture("tests/fixture/**/input.css")]
 fn css(input: PathBuf) {
-    run(&input, false);
-    run(&input, true);
+    run(&input, false, Default::default());
+    run(&input, true, Default::default());
+}
+
+#[testing::fixture("tests/scss/**/input.scss")]
+fn scss(input: PathBuf) {
+    let config = ParserConfig {
+        scss: true,
+        ..Default::default()
+    };
+
+    run(&input, false, config);
+    run(&input, true, config);
 }
 
 #[testing::fixture("tests/options/indent_type/**/input.css")]
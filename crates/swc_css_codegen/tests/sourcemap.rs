@@ -0,0 +1,117 @@
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use sourcemap::SourceMap;
+use swc_common::{BytePos, LineCol};
+use swc_css_ast::Stylesheet;
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_css_parser::parse_file;
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/sourcemap/**/input.css")]
+fn sourcemap(input: PathBuf) {
+    run(&input, false);
+    run(&input, true);
+}
+
+fn run(input: &Path, minify: bool) {
+    let dir = input.parent().unwrap();
+    let output = if minify {
+        dir.join("output.min.mappings")
+    } else {
+        dir.join("output.mappings")
+    };
+    // An input source map, e.g. generated by Sass, to chain
+    let input_map = dir.join("input.css.map");
+
+    testing::run_test2(false, |cm, handler| {
+        let fm = cm.load_file(input).unwrap();
+
+        let mut errors = vec![];
+        let stylesheet: Stylesheet =
+            parse_file(&fm, None, Default::default(), &mut errors).unwrap();
+
+        for err in errors {
+            err.to_diagnostics(&handler).emit();
+        }
+
+        let mut css_str = String::new();
+        let mut src_map_buf: Vec<(BytePos, LineCol)> = vec![];
+
+        {
+            let wr = BasicCssWriter::new(
+                &mut css_str,
+                Some(&mut src_map_buf),
+                BasicCssWriterConfig::default(),
+            );
+            let mut gen = CodeGenerator::new(wr, CodegenConfig { minify });
+
+            gen.emit(&stylesheet).unwrap();
+        }
+
+        let orig = if input_map.exists() {
+            Some(SourceMap::from_slice(&fs::read(&input_map).unwrap()).unwrap())
+        } else {
+            None
+        };
+        let source_map = cm.build_source_map_from(&src_map_buf, orig);
+
+        NormalizedOutput::from(print_mappings(&css_str, &source_map))
+            .compare_to_file(&output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Prints the code and the original position of each mapped output segment.
+fn print_mappings(code: &str, map: &SourceMap) -> String {
+    let lines = code.lines().collect::<Vec<_>>();
+    let tokens = map.tokens().collect::<Vec<_>>();
+
+    let mut buf = String::new();
+
+    writeln!(buf, "{}\n", code).unwrap();
+
+    for (idx, token) in tokens.iter().enumerate() {
+        let line = lines
+            .get(token.get_dst_line() as usize)
+            .copied()
+            .unwrap_or_default();
+        let start = token.get_dst_col() as usize;
+        let end = tokens
+            .get(idx + 1)
+            .filter(|next| next.get_dst_line() == token.get_dst_line())
+            .map(|next| next.get_dst_col() as usize)
+            .unwrap_or_else(|| line.chars().count());
+        let segment = line
+            .chars()
+            .skip(start)
+            .take(end.saturating_sub(start))
+            .collect::<String>();
+
+        writeln!(
+            buf,
+            "{:?} {}:{} -> {}:{}:{}",
+            segment,
+            token.get_dst_line() + 1,
+            token.get_dst_col(),
+            token
+                .get_source()
+                .and_then(|source| source.rsplit('/').next())
+                .unwrap_or_default(),
+            token.get_src_line() + 1,
+            token.get_src_col(),
+        )
+        .unwrap();
+    }
+
+    buf
+}
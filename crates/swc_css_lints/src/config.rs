@@ -5,10 +5,17 @@ use serde::{Deserialize, Serialize};
 use crate::rules::{
     at_rule_no_unknown::AtRuleNoUnknownConfig, color_hex_alpha::ColorHexAlphaConfig,
     color_hex_length::ColorHexLengthConfig,
+    declaration_block_no_duplicate_properties::DeclarationBlockNoDuplicatePropertiesConfig,
     font_family_no_duplicate_names::FontFamilyNoDuplicateNamesConfig,
+    font_family_no_missing_generic_family_keyword::FontFamilyNoMissingGenericFamilyKeywordConfig,
+    media_feature_name_no_unknown::MediaFeatureNameNoUnknownConfig,
+    no_descending_specificity::NoDescendingSpecificityConfig,
+    no_duplicate_selectors::NoDuplicateSelectorsConfig,
     no_invalid_position_at_import_rule::NoInvalidPositionAtImportRuleConfig,
-    selector_max_class::SelectorMaxClassConfig,
-    selector_max_combinators::SelectorMaxCombinatorsConfig, unit_no_unknown::UnitNoUnknownConfig,
+    property_no_unknown::PropertyNoUnknownConfig, selector_max_class::SelectorMaxClassConfig,
+    selector_max_combinators::SelectorMaxCombinatorsConfig,
+    selector_pseudo_class_no_unknown::SelectorPseudoClassNoUnknownConfig,
+    unit_no_unknown::UnitNoUnknownConfig,
 };
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
@@ -117,6 +124,32 @@ pub struct RulesConfig {
 
     #[serde(default, alias = "customPropertyNoMissingVarFunction")]
     pub custom_property_no_missing_var_function: RuleConfig<()>,
+
+    #[serde(default, alias = "declarationBlockNoDuplicateProperties")]
+    pub declaration_block_no_duplicate_properties:
+        RuleConfig<DeclarationBlockNoDuplicatePropertiesConfig>,
+
+    #[serde(default, alias = "noDescendingSpecificity")]
+    pub no_descending_specificity: RuleConfig<NoDescendingSpecificityConfig>,
+
+    #[serde(default, alias = "selectorPseudoClassNoUnknown")]
+    pub selector_pseudo_class_no_unknown: RuleConfig<SelectorPseudoClassNoUnknownConfig>,
+
+    #[serde(default, alias = "propertyNoUnknown")]
+    pub property_no_unknown: RuleConfig<PropertyNoUnknownConfig>,
+
+    #[serde(default, alias = "functionCalcNoUnspacedOperator")]
+    pub function_calc_no_unspaced_operator: RuleConfig<()>,
+
+    #[serde(default, alias = "fontFamilyNoMissingGenericFamilyKeyword")]
+    pub font_family_no_missing_generic_family_keyword:
+        RuleConfig<FontFamilyNoMissingGenericFamilyKeywordConfig>,
+
+    #[serde(default, alias = "mediaFeatureNameNoUnknown")]
+    pub media_feature_name_no_unknown: RuleConfig<MediaFeatureNameNoUnknownConfig>,
+
+    #[serde(default, alias = "noDuplicateSelectors")]
+    pub no_duplicate_selectors: RuleConfig<NoDuplicateSelectorsConfig>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
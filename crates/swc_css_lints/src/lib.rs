@@ -6,6 +6,7 @@ mod error;
 mod pattern;
 mod rule;
 mod rules;
+mod selector;
 
 pub use config::LintConfig;
 pub(crate) use error::ConfigError;
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use swc_common::EqIgnoreSpan;
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::{
+    pattern::NamePattern,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    ConfigError,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeclarationBlockNoDuplicatePropertiesConfig {
+    ignore: Option<Vec<DuplicatePropertiesIgnore>>,
+    ignore_properties: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DuplicatePropertiesIgnore {
+    /// Ignore consecutive duplicated properties.
+    ConsecutiveDuplicates,
+    /// Ignore consecutive duplicated properties with different values.
+    ConsecutiveDuplicatesWithDifferentValues,
+}
+
+pub fn declaration_block_no_duplicate_properties(
+    ctx: LintRuleContext<DeclarationBlockNoDuplicatePropertiesConfig>,
+) -> Result<Box<dyn LintRule>, ConfigError> {
+    let ignore = ctx.config().ignore.clone().unwrap_or_default();
+    let ignored_properties = ctx
+        .config()
+        .ignore_properties
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(NamePattern::try_from)
+        .collect::<Result<_, _>>()?;
+    Ok(visitor_rule(
+        ctx.reaction(),
+        DeclarationBlockNoDuplicateProperties {
+            ctx,
+            ignore,
+            ignored_properties,
+        },
+    ))
+}
+
+#[derive(Debug, Default)]
+struct DeclarationBlockNoDuplicateProperties {
+    ctx: LintRuleContext<DeclarationBlockNoDuplicatePropertiesConfig>,
+    ignore: Vec<DuplicatePropertiesIgnore>,
+    ignored_properties: Vec<NamePattern>,
+}
+
+impl DeclarationBlockNoDuplicateProperties {
+    fn is_ignored(&self, current: &Declaration, previous: &Declaration, consecutive: bool) -> bool {
+        if !consecutive {
+            return false;
+        }
+
+        self.ignore.iter().any(|ignore| match ignore {
+            DuplicatePropertiesIgnore::ConsecutiveDuplicates => true,
+            DuplicatePropertiesIgnore::ConsecutiveDuplicatesWithDifferentValues => {
+                !current.value.eq_ignore_span(&previous.value)
+                    || !current.important.eq_ignore_span(&previous.important)
+            }
+        })
+    }
+}
+
+impl Visit for DeclarationBlockNoDuplicateProperties {
+    fn visit_simple_block(&mut self, simple_block: &SimpleBlock) {
+        let mut seen: Vec<(String, &Declaration)> = vec![];
+
+        for declaration in simple_block
+            .value
+            .iter()
+            .filter_map(|component_value| component_value.as_declaration())
+        {
+            let name = match &declaration.name {
+                DeclarationName::Ident(Ident { value, .. }) => {
+                    value.to_ascii_lowercase().to_string()
+                }
+                // Custom properties are case-sensitive.
+                DeclarationName::DashedIdent(DashedIdent { value, .. }) => format!("--{}", value),
+            };
+
+            let previous = seen
+                .iter()
+                .enumerate()
+                .rev()
+                .find(|(_, (seen_name, _))| *seen_name == name)
+                .filter(|_| {
+                    self.ignored_properties
+                        .iter()
+                        .all(|item| !item.is_match(&name))
+                });
+
+            if let Some((index, (_, previous))) = previous {
+                let consecutive = index == seen.len() - 1;
+
+                if !self.is_ignored(declaration, previous, consecutive) {
+                    let message = format!("Unexpected duplicate property \"{}\".", name);
+                    self.ctx.report(&declaration.name, message);
+                }
+            }
+
+            seen.push((name, declaration));
+        }
+
+        simple_block.visit_children_with(self);
+    }
+}
@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use swc_common::{Span, Spanned};
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::{
+    dataset::{is_css_wide_keyword, is_generic_font_keyword, is_system_font_keyword},
+    pattern::NamePattern,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    ConfigError,
+};
+
+const MESSAGE: &str = "Unexpected missing generic font family.";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FontFamilyNoMissingGenericFamilyKeywordConfig {
+    ignore_font_families: Option<Vec<String>>,
+}
+
+pub fn font_family_no_missing_generic_family_keyword(
+    ctx: LintRuleContext<FontFamilyNoMissingGenericFamilyKeywordConfig>,
+) -> Result<Box<dyn LintRule>, ConfigError> {
+    let ignored = ctx
+        .config()
+        .ignore_font_families
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(NamePattern::try_from)
+        .collect::<Result<_, _>>()?;
+    Ok(visitor_rule(
+        ctx.reaction(),
+        FontFamilyNoMissingGenericFamilyKeyword { ctx, ignored },
+    ))
+}
+
+#[derive(Debug, Default)]
+struct FontFamilyNoMissingGenericFamilyKeyword {
+    ctx: LintRuleContext<FontFamilyNoMissingGenericFamilyKeywordConfig>,
+    ignored: Vec<NamePattern>,
+}
+
+struct FontFamily {
+    name: String,
+    quoted: bool,
+    span: Span,
+}
+
+impl FontFamilyNoMissingGenericFamilyKeyword {
+    fn check_component_values(&self, values: &[ComponentValue]) {
+        // Values like `var(--font)` can't be checked statically.
+        if values.iter().any(|value| value.is_function()) {
+            return;
+        }
+
+        let families = collect_font_families(values);
+
+        let Some(last) = families.last() else {
+            return;
+        };
+
+        if families.len() == 1 && !last.quoted && is_css_wide_keyword(&last.name) {
+            return;
+        }
+
+        let has_generic_family = families.iter().any(|family| {
+            (!family.quoted && is_generic_font_keyword(&family.name))
+                || self.ignored.iter().any(|item| item.is_match(&family.name))
+        });
+
+        if !has_generic_family {
+            self.ctx.report(last.span, MESSAGE);
+        }
+    }
+}
+
+impl Visit for FontFamilyNoMissingGenericFamilyKeyword {
+    fn visit_declaration(&mut self, declaration: &Declaration) {
+        match &declaration.name {
+            DeclarationName::Ident(Ident { value, .. })
+                if value.eq_ignore_ascii_case("font-family") =>
+            {
+                self.check_component_values(&declaration.value);
+            }
+            DeclarationName::Ident(Ident { value, .. }) if value.eq_ignore_ascii_case("font") => {
+                if let [ComponentValue::Ident(ident)] = &*declaration.value {
+                    if is_system_font_keyword(&ident.value) || is_css_wide_keyword(&ident.value) {
+                        return;
+                    }
+                }
+
+                // Font families always come after the font size.
+                let index = declaration
+                    .value
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .find(|(_, item)| {
+                        matches!(
+                            item,
+                            ComponentValue::Integer(..)
+                                | ComponentValue::Number(..)
+                                | ComponentValue::Percentage(..)
+                                | ComponentValue::Dimension(..)
+                                | ComponentValue::LengthPercentage(..)
+                        )
+                    })
+                    .map(|(i, _)| i);
+                if let Some(index) = index {
+                    self.check_component_values(&declaration.value[(index + 1)..]);
+                }
+            }
+            _ => {}
+        }
+
+        declaration.visit_children_with(self);
+    }
+}
+
+fn collect_font_families(values: &[ComponentValue]) -> Vec<FontFamily> {
+    let mut families: Vec<FontFamily> = vec![];
+    let mut last_is_ident = false;
+
+    for value in values {
+        match value {
+            ComponentValue::Ident(ident) => {
+                match families.last_mut() {
+                    // Unquoted font family names can contain multiple identifiers.
+                    Some(family) if last_is_ident => {
+                        family.name.push(' ');
+                        family.name.push_str(&ident.value);
+                        family.span = family.span.with_hi(ident.span_hi());
+                    }
+                    _ => families.push(FontFamily {
+                        name: ident.value.to_string(),
+                        quoted: false,
+                        span: ident.span,
+                    }),
+                }
+                last_is_ident = true;
+            }
+            ComponentValue::Str(s) => {
+                families.push(FontFamily {
+                    name: s.value.to_string(),
+                    quoted: true,
+                    span: s.span,
+                });
+                last_is_ident = false;
+            }
+            _ => {
+                last_is_ident = false;
+            }
+        }
+    }
+
+    families
+}
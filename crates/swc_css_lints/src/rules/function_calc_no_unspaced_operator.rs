@@ -0,0 +1,178 @@
+use swc_common::{BytePos, Span, Spanned};
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::rule::{visitor_rule, LintRule, LintRuleContext};
+
+pub fn function_calc_no_unspaced_operator(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(
+        ctx.reaction(),
+        FunctionCalcNoUnspacedOperator {
+            ctx,
+            in_math_function: false,
+        },
+    )
+}
+
+#[derive(Debug, Default)]
+struct FunctionCalcNoUnspacedOperator {
+    ctx: LintRuleContext<()>,
+    in_math_function: bool,
+}
+
+impl FunctionCalcNoUnspacedOperator {
+    fn report_before(&self, span: Span, operator: char) {
+        let message = format!("Expected single space before \"{}\" operator.", operator);
+        self.ctx.report(span, message);
+    }
+
+    fn report_after(&self, span: Span, operator: char) {
+        let message = format!("Expected single space after \"{}\" operator.", operator);
+        self.ctx.report(span, message);
+    }
+
+    /// Checks component values which the parser could not parse as a math
+    /// expression, e.g. `calc(1px+2px)` is tokenized as `1px` and `+2px`.
+    fn check_component_values(&self, values: &[ComponentValue]) {
+        for (index, value) in values.iter().enumerate() {
+            let Some(token_and_span) = value.as_preserved_token() else {
+                continue;
+            };
+
+            let (operator, is_signed_number) = match &token_and_span.token {
+                Token::Delim {
+                    value: value @ ('+' | '-'),
+                } => (*value, false),
+                Token::Number { raw, .. } | Token::Percentage { raw, .. } => {
+                    match leading_sign(raw) {
+                        Some(sign) => (sign, true),
+                        None => continue,
+                    }
+                }
+                Token::Dimension(dimension) => match leading_sign(&dimension.raw_value) {
+                    Some(sign) => (sign, true),
+                    None => continue,
+                },
+                _ => continue,
+            };
+
+            let span = token_and_span.span;
+            let operator_span = span.with_hi(span.lo + BytePos(1));
+
+            match index.checked_sub(1).map(|index| &values[index]) {
+                Some(prev) if is_operand(prev) => {
+                    self.report_before(operator_span, operator);
+                }
+                Some(prev) if is_signed_number && is_whitespace(prev) => {
+                    if index >= 2 && is_operand(&values[index - 2]) {
+                        self.report_after(operator_span, operator);
+                    }
+                }
+                _ => {}
+            }
+
+            if !is_signed_number {
+                if let Some(next) = values.get(index + 1) {
+                    if is_operand(next) {
+                        self.report_after(operator_span, operator);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Visit for FunctionCalcNoUnspacedOperator {
+    fn visit_function(&mut self, function: &Function) {
+        let old_in_math_function = self.in_math_function;
+
+        self.in_math_function = match &function.name {
+            FunctionName::Ident(Ident { value, .. }) => is_math_function(value),
+            FunctionName::DashedIdent(..) => false,
+        };
+
+        if self.in_math_function {
+            self.check_component_values(&function.value);
+        }
+
+        function.visit_children_with(self);
+
+        self.in_math_function = old_in_math_function;
+    }
+
+    fn visit_simple_block(&mut self, simple_block: &SimpleBlock) {
+        if self.in_math_function {
+            self.check_component_values(&simple_block.value);
+        }
+
+        simple_block.visit_children_with(self);
+    }
+
+    fn visit_calc_sum(&mut self, calc_sum: &CalcSum) {
+        for (index, expression) in calc_sum.expressions.iter().enumerate() {
+            let CalcProductOrOperator::Operator(operator) = expression else {
+                continue;
+            };
+
+            let operator_char = match operator.value {
+                CalcOperatorType::Add => '+',
+                CalcOperatorType::Sub => '-',
+                _ => continue,
+            };
+
+            if let Some(prev) = index
+                .checked_sub(1)
+                .and_then(|index| calc_sum.expressions.get(index))
+            {
+                if prev.span_hi() == operator.span.lo {
+                    self.report_before(operator.span, operator_char);
+                }
+            }
+
+            if let Some(next) = calc_sum.expressions.get(index + 1) {
+                if operator.span.hi == next.span_lo() {
+                    self.report_after(operator.span, operator_char);
+                }
+            }
+        }
+
+        calc_sum.visit_children_with(self);
+    }
+}
+
+fn is_math_function(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+
+    matches!(
+        &*name,
+        "calc" | "-webkit-calc" | "-moz-calc" | "min" | "max" | "clamp"
+    )
+}
+
+fn leading_sign(raw: &str) -> Option<char> {
+    raw.chars().next().filter(|c| *c == '+' || *c == '-')
+}
+
+fn is_whitespace(value: &ComponentValue) -> bool {
+    matches!(
+        value
+            .as_preserved_token()
+            .map(|token_and_span| &**token_and_span),
+        Some(TokenAndSpan {
+            token: Token::WhiteSpace { .. },
+            ..
+        })
+    )
+}
+
+fn is_operand(value: &ComponentValue) -> bool {
+    !matches!(
+        value
+            .as_preserved_token()
+            .map(|token_and_span| &**token_and_span),
+        Some(TokenAndSpan {
+            token: Token::WhiteSpace { .. } | Token::Comma | Token::Delim { .. },
+            ..
+        })
+    )
+}
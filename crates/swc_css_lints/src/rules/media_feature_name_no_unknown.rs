@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::{
+    dataset::{is_known_media_feature_name, is_vendor_prefixed},
+    pattern::NamePattern,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    ConfigError,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaFeatureNameNoUnknownConfig {
+    ignore_media_feature_names: Option<Vec<String>>,
+}
+
+pub fn media_feature_name_no_unknown(
+    ctx: LintRuleContext<MediaFeatureNameNoUnknownConfig>,
+) -> Result<Box<dyn LintRule>, ConfigError> {
+    let ignored = ctx
+        .config()
+        .ignore_media_feature_names
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(NamePattern::try_from)
+        .collect::<Result<_, _>>()?;
+    Ok(visitor_rule(
+        ctx.reaction(),
+        MediaFeatureNameNoUnknown { ctx, ignored },
+    ))
+}
+
+#[derive(Debug, Default)]
+struct MediaFeatureNameNoUnknown {
+    ctx: LintRuleContext<MediaFeatureNameNoUnknownConfig>,
+    ignored: Vec<NamePattern>,
+}
+
+impl Visit for MediaFeatureNameNoUnknown {
+    fn visit_media_feature_name(&mut self, media_feature_name: &MediaFeatureName) {
+        if let MediaFeatureName::Ident(Ident { value, .. }) = media_feature_name {
+            if !is_vendor_prefixed(value)
+                && !is_known_media_feature_name(value)
+                && self.ignored.iter().all(|item| !item.is_match(value))
+            {
+                let message = format!("Unexpected unknown media feature name \"{}\".", value);
+                self.ctx.report(media_feature_name, message);
+            }
+        }
+
+        media_feature_name.visit_children_with(self);
+    }
+}
@@ -7,13 +7,20 @@ use crate::{
         color_hex_alpha::color_hex_alpha, color_hex_length::color_hex_length,
         color_no_invalid_hex::color_no_invalid_hex,
         custom_property_no_missing_var_function::custom_property_no_missing_var_function,
+        declaration_block_no_duplicate_properties::declaration_block_no_duplicate_properties,
         declaration_no_important::declaration_no_important,
         font_family_no_duplicate_names::font_family_no_duplicate_names,
+        font_family_no_missing_generic_family_keyword::font_family_no_missing_generic_family_keyword,
+        function_calc_no_unspaced_operator::function_calc_no_unspaced_operator,
         keyframe_declaration_no_important::keyframe_declaration_no_important,
+        media_feature_name_no_unknown::media_feature_name_no_unknown,
+        no_descending_specificity::no_descending_specificity,
         no_duplicate_at_import_rules::no_duplicate_at_import_rules,
-        no_empty_source::no_empty_source,
+        no_duplicate_selectors::no_duplicate_selectors, no_empty_source::no_empty_source,
         no_invalid_position_at_import_rule::no_invalid_position_at_import_rule,
-        selector_max_class::selector_max_class, selector_max_combinators::selector_max_combinators,
+        property_no_unknown::property_no_unknown, selector_max_class::selector_max_class,
+        selector_max_combinators::selector_max_combinators,
+        selector_pseudo_class_no_unknown::selector_pseudo_class_no_unknown,
         unit_no_unknown::unit_no_unknown,
     },
 };
@@ -24,14 +31,22 @@ pub mod color_hex_alpha;
 pub mod color_hex_length;
 pub mod color_no_invalid_hex;
 pub mod custom_property_no_missing_var_function;
+pub mod declaration_block_no_duplicate_properties;
 pub mod declaration_no_important;
 pub mod font_family_no_duplicate_names;
+pub mod font_family_no_missing_generic_family_keyword;
+pub mod function_calc_no_unspaced_operator;
 pub mod keyframe_declaration_no_important;
+pub mod media_feature_name_no_unknown;
+pub mod no_descending_specificity;
 pub mod no_duplicate_at_import_rules;
+pub mod no_duplicate_selectors;
 pub mod no_empty_source;
 pub mod no_invalid_position_at_import_rule;
+pub mod property_no_unknown;
 pub mod selector_max_class;
 pub mod selector_max_combinators;
+pub mod selector_pseudo_class_no_unknown;
 pub mod unit_no_unknown;
 
 pub struct LintParams<'a> {
@@ -63,6 +78,20 @@ pub fn get_rules(
         custom_property_no_missing_var_function(
             (&rules_config.custom_property_no_missing_var_function).into(),
         ),
+        declaration_block_no_duplicate_properties(
+            (&rules_config.declaration_block_no_duplicate_properties).into(),
+        )?,
+        no_descending_specificity((&rules_config.no_descending_specificity).into()),
+        selector_pseudo_class_no_unknown((&rules_config.selector_pseudo_class_no_unknown).into())?,
+        property_no_unknown((&rules_config.property_no_unknown).into())?,
+        function_calc_no_unspaced_operator(
+            (&rules_config.function_calc_no_unspaced_operator).into(),
+        ),
+        font_family_no_missing_generic_family_keyword(
+            (&rules_config.font_family_no_missing_generic_family_keyword).into(),
+        )?,
+        media_feature_name_no_unknown((&rules_config.media_feature_name_no_unknown).into())?,
+        no_duplicate_selectors((&rules_config.no_duplicate_selectors).into()),
     ];
 
     Ok(rules)
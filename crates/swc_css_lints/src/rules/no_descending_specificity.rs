@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use swc_common::collections::AHashMap;
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::{
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    selector::{complex_selector_specificity, print_selector, Specificity},
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoDescendingSpecificityConfig {
+    ignore: Option<Vec<NoDescendingSpecificityIgnore>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NoDescendingSpecificityIgnore {
+    /// Only compare selectors which are not part of a selector list.
+    SelectorsWithinList,
+}
+
+pub fn no_descending_specificity(
+    ctx: LintRuleContext<NoDescendingSpecificityConfig>,
+) -> Box<dyn LintRule> {
+    let ignore_selectors_within_list = ctx
+        .config()
+        .ignore
+        .as_ref()
+        .map(|ignore| ignore.contains(&NoDescendingSpecificityIgnore::SelectorsWithinList))
+        .unwrap_or_default();
+    visitor_rule(
+        ctx.reaction(),
+        NoDescendingSpecificity {
+            ctx,
+            ignore_selectors_within_list,
+            contexts: vec![Default::default()],
+        },
+    )
+}
+
+#[derive(Debug, Default)]
+struct NoDescendingSpecificity {
+    ctx: LintRuleContext<NoDescendingSpecificityConfig>,
+    ignore_selectors_within_list: bool,
+    /// Selectors are only compared to selectors in the same at-rule.
+    ///
+    /// Maps the last compound selector (without pseudo-classes) to the
+    /// selectors seen so far.
+    contexts: Vec<AHashMap<String, Vec<(String, Specificity)>>>,
+}
+
+impl NoDescendingSpecificity {
+    fn check_complex_selector(&mut self, complex_selector: &ComplexSelector) {
+        let Some(reference) = reference_selector(complex_selector) else {
+            return;
+        };
+
+        let selector = print_selector(complex_selector);
+        let specificity = complex_selector_specificity(complex_selector);
+        let seen = self
+            .contexts
+            .last_mut()
+            .unwrap()
+            .entry(reference)
+            .or_default();
+
+        for (prior_selector, prior_specificity) in seen.iter() {
+            if specificity < *prior_specificity {
+                let message = format!(
+                    "Expected selector \"{}\" to come before selector \"{}\".",
+                    selector, prior_selector
+                );
+                self.ctx.report(complex_selector, message);
+            }
+        }
+
+        seen.push((selector, specificity));
+    }
+}
+
+impl Visit for NoDescendingSpecificity {
+    fn visit_at_rule(&mut self, at_rule: &AtRule) {
+        self.contexts.push(Default::default());
+
+        at_rule.visit_children_with(self);
+
+        self.contexts.pop();
+    }
+
+    fn visit_qualified_rule(&mut self, qualified_rule: &QualifiedRule) {
+        if let QualifiedRulePrelude::SelectorList(selector_list) = &qualified_rule.prelude {
+            if !(self.ignore_selectors_within_list && selector_list.children.len() > 1) {
+                for complex_selector in &selector_list.children {
+                    self.check_complex_selector(complex_selector);
+                }
+            }
+        }
+
+        qualified_rule.visit_children_with(self);
+    }
+}
+
+/// Returns the last compound selector without pseudo-classes, as a string.
+fn reference_selector(complex_selector: &ComplexSelector) -> Option<String> {
+    let mut compound_selector = complex_selector
+        .children
+        .iter()
+        .rev()
+        .find_map(|child| child.as_compound_selector())?
+        .clone();
+
+    compound_selector
+        .subclass_selectors
+        .retain(|subclass_selector| !subclass_selector.is_pseudo_class());
+
+    if compound_selector.nesting_selector.is_none()
+        && compound_selector.type_selector.is_none()
+        && compound_selector.subclass_selectors.is_empty()
+    {
+        return None;
+    }
+
+    Some(print_selector(&compound_selector))
+}
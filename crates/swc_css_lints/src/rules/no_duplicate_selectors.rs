@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use swc_common::collections::AHashSet;
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::{
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    selector::print_selector,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoDuplicateSelectorsConfig {
+    /// Also disallow selectors which are used in another selector list.
+    #[serde(default)]
+    disallow_in_list: bool,
+}
+
+pub fn no_duplicate_selectors(
+    ctx: LintRuleContext<NoDuplicateSelectorsConfig>,
+) -> Box<dyn LintRule> {
+    let disallow_in_list = ctx.config().disallow_in_list;
+    visitor_rule(
+        ctx.reaction(),
+        NoDuplicateSelectors {
+            ctx,
+            disallow_in_list,
+            contexts: vec![Default::default()],
+        },
+    )
+}
+
+fn build_message(selector: &str) -> String {
+    format!("Unexpected duplicate selector \"{}\".", selector)
+}
+
+#[derive(Debug, Default)]
+struct SelectorContext {
+    selector_lists: AHashSet<String>,
+    selectors: AHashSet<String>,
+}
+
+#[derive(Debug, Default)]
+struct NoDuplicateSelectors {
+    ctx: LintRuleContext<NoDuplicateSelectorsConfig>,
+    disallow_in_list: bool,
+    /// Selectors are only compared to selectors in the same at-rule or the
+    /// same parent rule.
+    contexts: Vec<SelectorContext>,
+}
+
+impl NoDuplicateSelectors {
+    fn check_selector_list(&mut self, selector_list: &SelectorList) {
+        let mut selectors = Vec::with_capacity(selector_list.children.len());
+
+        for complex_selector in &selector_list.children {
+            let selector = print_selector(complex_selector);
+
+            if selectors.contains(&selector) {
+                self.ctx.report(complex_selector, build_message(&selector));
+            }
+
+            selectors.push(selector);
+        }
+
+        let context = self.contexts.last_mut().unwrap();
+
+        let mut key = selectors.clone();
+        key.sort();
+        key.dedup();
+        let key = key.join(",");
+
+        if context.selector_lists.contains(&key) {
+            self.ctx
+                .report(selector_list, build_message(&print_selector(selector_list)));
+        } else if self.disallow_in_list {
+            for (complex_selector, selector) in selector_list.children.iter().zip(&selectors) {
+                if context.selectors.contains(selector) {
+                    self.ctx.report(complex_selector, build_message(selector));
+                }
+            }
+        }
+
+        context.selector_lists.insert(key);
+        if self.disallow_in_list {
+            context.selectors.extend(selectors);
+        }
+    }
+}
+
+impl Visit for NoDuplicateSelectors {
+    fn visit_at_rule(&mut self, at_rule: &AtRule) {
+        self.contexts.push(Default::default());
+
+        at_rule.visit_children_with(self);
+
+        self.contexts.pop();
+    }
+
+    fn visit_qualified_rule(&mut self, qualified_rule: &QualifiedRule) {
+        if let QualifiedRulePrelude::SelectorList(selector_list) = &qualified_rule.prelude {
+            self.check_selector_list(selector_list);
+        }
+
+        self.contexts.push(Default::default());
+
+        qualified_rule.visit_children_with(self);
+
+        self.contexts.pop();
+    }
+}
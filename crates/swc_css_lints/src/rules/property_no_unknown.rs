@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::{
+    dataset::{is_known_property, is_vendor_prefixed, unprefixed},
+    pattern::NamePattern,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    selector::print_selector,
+    ConfigError,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyNoUnknownConfig {
+    ignore_properties: Option<Vec<String>>,
+    ignore_selectors: Option<Vec<String>>,
+    /// Also check vendor-prefixed properties, with the prefix stripped.
+    #[serde(default)]
+    check_prefixed: bool,
+}
+
+pub fn property_no_unknown(
+    ctx: LintRuleContext<PropertyNoUnknownConfig>,
+) -> Result<Box<dyn LintRule>, ConfigError> {
+    let ignored_properties = ctx
+        .config()
+        .ignore_properties
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(NamePattern::try_from)
+        .collect::<Result<_, _>>()?;
+    let ignored_selectors = ctx
+        .config()
+        .ignore_selectors
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(NamePattern::try_from)
+        .collect::<Result<_, _>>()?;
+    let check_prefixed = ctx.config().check_prefixed;
+    Ok(visitor_rule(
+        ctx.reaction(),
+        PropertyNoUnknown {
+            ctx,
+            ignored_properties,
+            ignored_selectors,
+            check_prefixed,
+            in_ignored_selector: false,
+        },
+    ))
+}
+
+#[derive(Debug, Default)]
+struct PropertyNoUnknown {
+    ctx: LintRuleContext<PropertyNoUnknownConfig>,
+    ignored_properties: Vec<NamePattern>,
+    ignored_selectors: Vec<NamePattern>,
+    check_prefixed: bool,
+    in_ignored_selector: bool,
+}
+
+impl Visit for PropertyNoUnknown {
+    fn visit_qualified_rule(&mut self, qualified_rule: &QualifiedRule) {
+        let old_in_ignored_selector = self.in_ignored_selector;
+
+        if !self.ignored_selectors.is_empty() {
+            if let QualifiedRulePrelude::SelectorList(selector_list) = &qualified_rule.prelude {
+                let selector = print_selector(selector_list);
+
+                if self
+                    .ignored_selectors
+                    .iter()
+                    .any(|item| item.is_match(&selector))
+                {
+                    self.in_ignored_selector = true;
+                }
+            }
+        }
+
+        qualified_rule.visit_children_with(self);
+
+        self.in_ignored_selector = old_in_ignored_selector;
+    }
+
+    fn visit_declaration(&mut self, declaration: &Declaration) {
+        if let DeclarationName::Ident(Ident { value, .. }) = &declaration.name {
+            let name = if is_vendor_prefixed(value) {
+                if self.check_prefixed {
+                    Some(unprefixed(value))
+                } else {
+                    None
+                }
+            } else {
+                Some(&**value)
+            };
+
+            if let Some(name) = name {
+                if !self.in_ignored_selector
+                    && !is_known_property(name)
+                    && self
+                        .ignored_properties
+                        .iter()
+                        .all(|item| !item.is_match(value))
+                {
+                    let message = format!("Unexpected unknown property \"{}\".", value);
+                    self.ctx.report(&declaration.name, message);
+                }
+            }
+        }
+
+        declaration.visit_children_with(self);
+    }
+}
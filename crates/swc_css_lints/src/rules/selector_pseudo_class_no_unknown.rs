@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::{
+    dataset::{is_known_pseudo_class, is_vendor_prefixed},
+    pattern::NamePattern,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    ConfigError,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectorPseudoClassNoUnknownConfig {
+    ignore_pseudo_classes: Option<Vec<String>>,
+}
+
+pub fn selector_pseudo_class_no_unknown(
+    ctx: LintRuleContext<SelectorPseudoClassNoUnknownConfig>,
+) -> Result<Box<dyn LintRule>, ConfigError> {
+    let ignored = ctx
+        .config()
+        .ignore_pseudo_classes
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(NamePattern::try_from)
+        .collect::<Result<_, _>>()?;
+    Ok(visitor_rule(
+        ctx.reaction(),
+        SelectorPseudoClassNoUnknown { ctx, ignored },
+    ))
+}
+
+#[derive(Debug, Default)]
+struct SelectorPseudoClassNoUnknown {
+    ctx: LintRuleContext<SelectorPseudoClassNoUnknownConfig>,
+    ignored: Vec<NamePattern>,
+}
+
+impl Visit for SelectorPseudoClassNoUnknown {
+    fn visit_pseudo_class_selector(&mut self, pseudo_class_selector: &PseudoClassSelector) {
+        let name = &pseudo_class_selector.name.value;
+
+        if !is_vendor_prefixed(name)
+            && !is_known_pseudo_class(name)
+            && self.ignored.iter().all(|item| !item.is_match(name))
+        {
+            let message = format!("Unexpected unknown pseudo-class selector \":{}\".", name);
+            self.ctx.report(&pseudo_class_selector.name, message);
+        }
+
+        pseudo_class_selector.visit_children_with(self);
+    }
+}
@@ -0,0 +1,151 @@
+use std::ops::Add;
+
+use swc_common::Spanned;
+use swc_css_ast::*;
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+
+/// Prints a selector in minified form, which is used both for messages and as
+/// a normalized key for comparison.
+pub(crate) fn print_selector<N>(node: &N) -> String
+where
+    N: Spanned,
+    for<'a> CodeGenerator<BasicCssWriter<'a, &'a mut String>>: Emit<N>,
+{
+    let mut buf = String::new();
+    {
+        let wr = BasicCssWriter::new(&mut buf, None, BasicCssWriterConfig::default());
+        let mut gen = CodeGenerator::new(wr, CodegenConfig { minify: true });
+
+        gen.emit(node).unwrap();
+    }
+
+    buf
+}
+
+/// Specificity of a selector, in the form of `(ids, classes, types)`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Specificity(pub u32, pub u32, pub u32);
+
+impl Add for Specificity {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Specificity(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+pub(crate) fn complex_selector_specificity(complex_selector: &ComplexSelector) -> Specificity {
+    complex_selector
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            ComplexSelectorChildren::CompoundSelector(compound_selector) => {
+                Some(compound_selector_specificity(compound_selector))
+            }
+            _ => None,
+        })
+        .fold(Specificity::default(), Add::add)
+}
+
+pub(crate) fn compound_selector_specificity(compound_selector: &CompoundSelector) -> Specificity {
+    let type_selector = match compound_selector.type_selector.as_deref() {
+        Some(TypeSelector::TagName(..)) => Specificity(0, 0, 1),
+        _ => Specificity::default(),
+    };
+
+    compound_selector
+        .subclass_selectors
+        .iter()
+        .map(|subclass_selector| match subclass_selector {
+            SubclassSelector::Id(..) => Specificity(1, 0, 0),
+            SubclassSelector::Class(..)
+            | SubclassSelector::Attribute(..)
+            | SubclassSelector::ScssPlaceholder(..) => Specificity(0, 1, 0),
+            SubclassSelector::PseudoClass(pseudo_class) => pseudo_class_specificity(pseudo_class),
+            SubclassSelector::PseudoElement(..) => Specificity(0, 0, 1),
+        })
+        .fold(type_selector, Add::add)
+}
+
+fn pseudo_class_specificity(pseudo_class: &PseudoClassSelector) -> Specificity {
+    let name = pseudo_class.name.value.to_ascii_lowercase();
+    let children = pseudo_class.children.as_deref().unwrap_or_default();
+
+    match &*name {
+        "where" => Specificity::default(),
+        // The specificity of these pseudo-classes is replaced by the specificity of
+        // the most specific selector in its argument.
+        "is" | "not" | "has" | "matches" | "-webkit-any" | "-moz-any" => {
+            max_specificity_of_children(children)
+        }
+        "nth-child" | "nth-last-child" => {
+            Specificity(0, 1, 0) + max_specificity_of_children(children)
+        }
+        _ => Specificity(0, 1, 0),
+    }
+}
+
+fn max_specificity_of_children(children: &[PseudoClassSelectorChildren]) -> Specificity {
+    let mut max = Specificity::default();
+
+    for child in children {
+        let specificity = match child {
+            PseudoClassSelectorChildren::ComplexSelector(complex_selector) => {
+                complex_selector_specificity(complex_selector)
+            }
+            PseudoClassSelectorChildren::CompoundSelector(compound_selector) => {
+                compound_selector_specificity(compound_selector)
+            }
+            PseudoClassSelectorChildren::SelectorList(selector_list) => selector_list
+                .children
+                .iter()
+                .map(complex_selector_specificity)
+                .max()
+                .unwrap_or_default(),
+            PseudoClassSelectorChildren::ForgivingSelectorList(selector_list) => selector_list
+                .children
+                .iter()
+                .filter_map(|selector| match selector {
+                    ForgivingComplexSelector::ComplexSelector(complex_selector) => {
+                        Some(complex_selector_specificity(complex_selector))
+                    }
+                    _ => None,
+                })
+                .max()
+                .unwrap_or_default(),
+            PseudoClassSelectorChildren::CompoundSelectorList(selector_list) => selector_list
+                .children
+                .iter()
+                .map(compound_selector_specificity)
+                .max()
+                .unwrap_or_default(),
+            PseudoClassSelectorChildren::RelativeSelectorList(selector_list) => selector_list
+                .children
+                .iter()
+                .map(|relative_selector| complex_selector_specificity(&relative_selector.selector))
+                .max()
+                .unwrap_or_default(),
+            PseudoClassSelectorChildren::ForgivingRelativeSelectorList(selector_list) => {
+                selector_list
+                    .children
+                    .iter()
+                    .filter_map(|selector| match selector {
+                        ForgivingRelativeSelector::RelativeSelector(relative_selector) => {
+                            Some(complex_selector_specificity(&relative_selector.selector))
+                        }
+                        _ => None,
+                    })
+                    .max()
+                    .unwrap_or_default()
+            }
+            _ => continue,
+        };
+
+        max = max.max(specificity);
+    }
+
+    max
+}
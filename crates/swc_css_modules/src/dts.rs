@@ -0,0 +1,227 @@
+//! TypeScript declaration generator for css modules.
+//!
+//! Turns the class name map of [TransformResult] into the content of a
+//! `.module.css.d.ts` file, so that typed imports of class names can be used
+//! without a separate `typed-css-modules` step.
+
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+use swc_atoms::JsWord;
+
+use crate::TransformResult;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DtsConfig {
+    #[serde(default)]
+    pub export_style: DtsExportStyle,
+
+    #[serde(default)]
+    pub locals_convention: LocalsConvention,
+}
+
+/// How the class names are exported from the generated declaration file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DtsExportStyle {
+    /// `export const foo: string;`
+    ///
+    /// Names which are not valid identifiers are skipped, because they cannot
+    /// be imported by name.
+    Named,
+
+    /// `declare const styles: { readonly "foo": string }; export default
+    /// styles;`
+    #[default]
+    Default,
+}
+
+/// Naming convention of exported names, compatible with
+/// `exportLocalsConvention` of `css-loader`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LocalsConvention {
+    /// Class names are exported as-is.
+    #[default]
+    AsIs,
+    /// Class names are exported as-is and in camel case.
+    CamelCase,
+    /// Only camel cased class names are exported.
+    CamelCaseOnly,
+    /// Only dashes in class names are camelized, and the original name is
+    /// also exported.
+    Dashes,
+    /// Only dashes in class names are camelized.
+    DashesOnly,
+}
+
+impl LocalsConvention {
+    /// Returns the exported names for a local name.
+    fn names_for(self, local: &str) -> Vec<String> {
+        let mut names = match self {
+            LocalsConvention::AsIs => vec![local.to_string()],
+            LocalsConvention::CamelCase => vec![local.to_string(), camel_case(local)],
+            LocalsConvention::CamelCaseOnly => vec![camel_case(local)],
+            LocalsConvention::Dashes => vec![local.to_string(), dashes_camel_case(local)],
+            LocalsConvention::DashesOnly => vec![dashes_camel_case(local)],
+        };
+        names.dedup();
+        names
+    }
+}
+
+/// Generates the content of a `.d.ts` file for `result`.
+///
+/// The output is sorted by exported name, so it's stable across runs.
+pub fn generate_dts(result: &TransformResult, config: &DtsConfig) -> String {
+    let mut names = result
+        .renamed
+        .keys()
+        .flat_map(|local: &JsWord| config.locals_convention.names_for(local))
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+
+    let mut buf = String::new();
+
+    match config.export_style {
+        DtsExportStyle::Named => {
+            for name in names.iter().filter(|name| is_valid_export_name(name)) {
+                writeln!(buf, "export declare const {}: string;", name).unwrap();
+            }
+        }
+        DtsExportStyle::Default => {
+            buf.push_str("declare const styles: {\n");
+            for name in &names {
+                writeln!(buf, "  readonly {}: string;", quote_str(name)).unwrap();
+            }
+            buf.push_str("};\nexport default styles;\n");
+        }
+    }
+
+    buf
+}
+
+/// `foo-bar_baz` => `fooBarBaz`
+fn camel_case(s: &str) -> String {
+    let mut buf = String::with_capacity(s.len());
+    let mut upper_next = false;
+
+    for c in s.chars() {
+        if c == '-' || c == '_' {
+            upper_next = !buf.is_empty();
+            continue;
+        }
+
+        if upper_next {
+            buf.extend(c.to_uppercase());
+            upper_next = false;
+        } else if buf.is_empty() {
+            buf.extend(c.to_lowercase());
+        } else {
+            buf.push(c);
+        }
+    }
+
+    buf
+}
+
+/// `foo-bar_baz` => `fooBar_baz`
+fn dashes_camel_case(s: &str) -> String {
+    let mut buf = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('-', Some(next)) if next.is_alphanumeric() => {
+                buf.extend(next.to_uppercase());
+                chars.next();
+            }
+            _ => buf.push(c),
+        }
+    }
+
+    buf
+}
+
+fn is_valid_export_name(s: &str) -> bool {
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some(c) if c == '$' || c == '_' || c.is_alphabetic() => {}
+        _ => return false,
+    }
+
+    chars.all(|c| c == '$' || c == '_' || c.is_alphanumeric()) && !is_reserved_word(s)
+}
+
+fn is_reserved_word(s: &str) -> bool {
+    matches!(
+        s,
+        "break"
+            | "case"
+            | "catch"
+            | "class"
+            | "const"
+            | "continue"
+            | "debugger"
+            | "default"
+            | "delete"
+            | "do"
+            | "else"
+            | "enum"
+            | "export"
+            | "extends"
+            | "false"
+            | "finally"
+            | "for"
+            | "function"
+            | "if"
+            | "import"
+            | "in"
+            | "instanceof"
+            | "new"
+            | "null"
+            | "return"
+            | "super"
+            | "switch"
+            | "this"
+            | "throw"
+            | "true"
+            | "try"
+            | "typeof"
+            | "var"
+            | "void"
+            | "while"
+            | "with"
+            | "yield"
+            | "let"
+            | "static"
+            | "implements"
+            | "interface"
+            | "package"
+            | "private"
+            | "protected"
+            | "public"
+            | "await"
+    )
+}
+
+fn quote_str(s: &str) -> String {
+    let mut buf = String::with_capacity(s.len() + 2);
+    buf.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\u{2028}' => buf.push_str("\\u2028"),
+            '\u{2029}' => buf.push_str("\\u2029"),
+            _ => buf.push(c),
+        }
+    }
+    buf.push('"');
+    buf
+}
@@ -8,6 +8,7 @@ use swc_css_ast::{
 };
 use swc_css_visit::{VisitMut, VisitMutWith};
 
+pub mod dts;
 pub mod imports;
 
 /// Various configurations for the css modules.
@@ -688,6 +689,7 @@ fn process_local<C>(
         SubclassSelector::Attribute(_) => {}
         SubclassSelector::PseudoClass(_) => {}
         SubclassSelector::PseudoElement(_) => {}
+        SubclassSelector::ScssPlaceholder(_) => {}
     }
 }
 
@@ -0,0 +1,49 @@
+use std::{fs, path::PathBuf};
+
+use swc_atoms::JsWord;
+use swc_css_modules::dts::{generate_dts, DtsConfig};
+use swc_css_parser::parser::ParserConfig;
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/dts/**/input.css")]
+fn dts(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let config_path = dir.join("config.json");
+    let config: DtsConfig = if config_path.exists() {
+        serde_json::from_str(&fs::read_to_string(&config_path).unwrap()).unwrap()
+    } else {
+        Default::default()
+    };
+
+    testing::run_test(false, |cm, _| {
+        let fm = cm.load_file(&input).unwrap();
+        let mut errors = vec![];
+        let mut ss = swc_css_parser::parse_file(
+            &fm,
+            None,
+            ParserConfig {
+                css_modules: true,
+                ..Default::default()
+            },
+            &mut errors,
+        )
+        .unwrap();
+
+        let result = swc_css_modules::compile(&mut ss, TestConfig {});
+
+        NormalizedOutput::from(generate_dts(&result, &config))
+            .compare_to_file(dir.join("output.d.ts"))
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+struct TestConfig {}
+
+impl swc_css_modules::TransformConfig for TestConfig {
+    fn new_name_for(&self, local: &JsWord) -> JsWord {
+        format!("__local__{}", local).into()
+    }
+}
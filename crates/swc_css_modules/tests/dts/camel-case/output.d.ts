@@ -0,0 +1,6 @@
+export declare const bazQux: string;
+export declare const baz_qux: string;
+export declare const fadeIn: string;
+export declare const foo: string;
+export declare const fooBar: string;
+export declare const main: string;
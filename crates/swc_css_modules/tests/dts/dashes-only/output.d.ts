@@ -0,0 +1,9 @@
+declare const styles: {
+  readonly "baz_qux": string;
+  readonly "fadeIn": string;
+  readonly "foo": string;
+  readonly "fooBar": string;
+  readonly "main": string;
+  readonly "uM+": string;
+};
+export default styles;
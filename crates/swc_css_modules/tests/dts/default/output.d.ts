@@ -0,0 +1,9 @@
+declare const styles: {
+  readonly "baz_qux": string;
+  readonly "fade-in": string;
+  readonly "foo": string;
+  readonly "foo-bar": string;
+  readonly "main": string;
+  readonly "u-m+": string;
+};
+export default styles;
@@ -0,0 +1,3 @@
+export declare const baz_qux: string;
+export declare const foo: string;
+export declare const main: string;
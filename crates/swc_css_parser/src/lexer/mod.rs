@@ -531,7 +531,7 @@ where
                     }
                 }
             }
-        } else if self.config.allow_wrong_line_comments
+        } else if (self.config.allow_wrong_line_comments || self.config.scss)
             && self.next() == Some('/')
             && self.next_next() == Some('/')
         {
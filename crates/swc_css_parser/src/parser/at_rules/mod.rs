@@ -420,6 +420,42 @@ where
                 Some(prelude)
             }
 
+            "mixin" if self.config.scss => {
+                self.input.skip_ws();
+
+                let prelude = AtRulePrelude::ScssMixinPrelude(self.parse()?);
+
+                self.input.skip_ws();
+
+                Some(prelude)
+            }
+            "include" if self.config.scss => {
+                self.input.skip_ws();
+
+                let prelude = AtRulePrelude::ScssIncludePrelude(self.parse()?);
+
+                self.input.skip_ws();
+
+                Some(prelude)
+            }
+            "use" if self.config.scss => {
+                self.input.skip_ws();
+
+                let prelude = AtRulePrelude::ScssUsePrelude(self.parse()?);
+
+                self.input.skip_ws();
+
+                Some(prelude)
+            }
+            "forward" if self.config.scss => {
+                self.input.skip_ws();
+
+                let prelude = AtRulePrelude::ScssForwardPrelude(self.parse()?);
+
+                self.input.skip_ws();
+
+                Some(prelude)
+            }
             "value" => {
                 if self.config.css_modules {
                     let span = self.input.cur_span();
@@ -634,6 +670,9 @@ where
                         Rule::ListOfComponentValues(locv) => {
                             ComponentValue::ListOfComponentValues(locv)
                         }
+                        Rule::ScssVariableDeclaration(declaration) => {
+                            ComponentValue::ScssVariableDeclaration(declaration)
+                        }
                     })
                     .collect();
 
@@ -674,6 +713,16 @@ where
 
                 style_blocks
             }
+            "mixin" | "include" | "function" | "if" | "else" | "each" | "for" | "while"
+            | "at-root"
+                if self.config.scss =>
+            {
+                let style_blocks: Vec<StyleBlock> = self.parse()?;
+                let style_blocks: Vec<ComponentValue> =
+                    style_blocks.into_iter().map(ComponentValue::from).collect();
+
+                style_blocks
+            }
             "page" => {
                 let declaration_list = self
                     .with_ctx(Ctx {
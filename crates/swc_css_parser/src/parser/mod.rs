@@ -10,6 +10,7 @@ use crate::{error::Error, Parse};
 mod macros;
 mod at_rules;
 pub mod input;
+mod scss;
 mod selectors;
 mod syntax;
 #[cfg(test)]
@@ -51,6 +52,15 @@ pub struct ParserConfig {
     /// If this is `true`, the legacy syntax for IE will be parsed.
     #[serde(default)]
     pub legacy_ie: bool,
+
+    /// If this is `true`, the [SCSS](https://sass-lang.com/documentation/syntax)
+    /// syntax will be parsed, i.e. variables, `@mixin`/`@include`,
+    /// `@use`/`@forward`, placeholder selectors, interpolation and `//`
+    /// comments. Nested rules are parsed like [ParserConfig::legacy_nesting].
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub scss: bool,
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,651 @@
+use swc_common::{Span, Spanned};
+use swc_css_ast::*;
+
+use super::{
+    input::{Input, InputType, ParserInput},
+    Ctx, PResult, Parser,
+};
+use crate::{
+    error::{Error, ErrorKind},
+    Parse,
+};
+
+impl<I> Parser<I>
+where
+    I: ParserInput,
+{
+    /// Canonicalize a SCSS value.
+    ///
+    /// Values using the SCSS syntax (variables, interpolation or module
+    /// members) can't be validated against a CSS grammar, so they are kept as
+    /// is and only SCSS constructions are replaced with the corresponding
+    /// nodes. Other values are canonicalized on a best-effort basis, because
+    /// SCSS has values which are not valid in CSS (i.e. maps).
+    pub(super) fn canonicalize_scss_value(
+        &mut self,
+        values: Vec<ComponentValue>,
+    ) -> Vec<ComponentValue> {
+        if is_scss_value(&values) {
+            return scss_component_values(values);
+        }
+
+        let locv = self.create_locv(values);
+        let lexer = Input::new(InputType::ListOfComponentValues(&locv));
+        let mut parser = Parser::new(lexer, self.config);
+        let res = parser
+            .with_ctx(Ctx {
+                need_canonicalize: true,
+                ..self.ctx
+            })
+            .parse_generic_values();
+
+        match res {
+            Ok(values) if parser.take_errors().is_empty() => values,
+            _ => scss_component_values(locv.children),
+        }
+    }
+
+    /// Returns `true` if the current `{` token starts an interpolation, i.e.
+    /// `#{...}` in a prelude of a rule.
+    pub(super) fn is_scss_interpolation_start(&mut self, prelude: &[ComponentValue]) -> bool {
+        if !self.config.scss || !is!(self, "{") {
+            return false;
+        }
+
+        let lo = self.input.cur_span().lo;
+
+        matches!(
+            prelude.last(),
+            Some(ComponentValue::PreservedToken(token_and_span))
+                if matches!(token_and_span.token, Token::Delim { value: '#' })
+                    && token_and_span.span.hi == lo
+        )
+    }
+
+    /// Parse the name and arguments of `@mixin` and `@include`, i.e.
+    /// `name($a, $b)`.
+    fn parse_scss_callable(&mut self) -> PResult<(Ident, Option<ScssArgumentList>)> {
+        match cur!(self) {
+            tok!("function") => {
+                let function = self
+                    .with_ctx(Ctx {
+                        need_canonicalize: false,
+                        ..self.ctx
+                    })
+                    .parse_as::<Function>()?;
+                let name = match function.name {
+                    FunctionName::Ident(name) => name,
+                    FunctionName::DashedIdent(name) => {
+                        return Err(Error::new(name.span, ErrorKind::Expected("ident")));
+                    }
+                };
+                let arguments = ScssArgumentList {
+                    span: Span::new(name.span.hi, function.span.hi),
+                    value: scss_component_values(function.value),
+                };
+
+                Ok((name, Some(arguments)))
+            }
+            tok!("ident") => {
+                let name = self.parse()?;
+                let arguments = if is!(self, "(") {
+                    Some(self.parse()?)
+                } else {
+                    None
+                };
+
+                Ok((name, arguments))
+            }
+            _ => {
+                let span = self.input.cur_span();
+
+                Err(Error::new(
+                    span,
+                    ErrorKind::Expected("ident or function token"),
+                ))
+            }
+        }
+    }
+
+    /// Parse `with ($a: 10px)` of `@use` and `@forward`.
+    fn parse_scss_configuration(&mut self) -> PResult<Option<ScssArgumentList>> {
+        if !is_case_insensitive_ident!(self, "with") {
+            return Ok(None);
+        }
+
+        bump!(self);
+
+        self.input.skip_ws();
+
+        Ok(Some(self.parse()?))
+    }
+}
+
+impl<I> Parse<ScssVariable> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<ScssVariable> {
+        let span = self.input.cur_span();
+
+        expect!(self, "$");
+
+        if !is!(self, Ident) || self.input.cur_span().lo != span.hi {
+            let span = self.input.cur_span();
+
+            return Err(Error::new(span, ErrorKind::Expected("ident")));
+        }
+
+        let name = self.parse()?;
+
+        Ok(ScssVariable {
+            span: span!(self, span.lo),
+            name,
+        })
+    }
+}
+
+impl<I> Parse<ScssVariableDeclaration> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<ScssVariableDeclaration> {
+        let span = self.input.cur_span();
+        let name = self.parse()?;
+
+        self.input.skip_ws();
+
+        expect!(self, ":");
+
+        self.input.skip_ws();
+
+        let mut value = vec![];
+
+        loop {
+            if is!(self, EOF) {
+                break;
+            }
+
+            value.push(self.parse_as::<ComponentValue>()?);
+        }
+
+        let flags = take_scss_flags(&mut value);
+
+        if value.is_empty() {
+            return Err(Error::new(
+                span!(self, span.lo),
+                ErrorKind::Expected("value of variable"),
+            ));
+        }
+
+        let value = self.canonicalize_scss_value(value);
+
+        Ok(ScssVariableDeclaration {
+            span: span!(self, span.lo),
+            name,
+            value,
+            flags,
+        })
+    }
+}
+
+impl<I> Parse<ScssPlaceholderSelector> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<ScssPlaceholderSelector> {
+        let span = self.input.cur_span();
+
+        expect!(self, "%");
+
+        if !is!(self, Ident) || self.input.cur_span().lo != span.hi {
+            let span = self.input.cur_span();
+
+            return Err(Error::new(span, ErrorKind::Expected("ident")));
+        }
+
+        let name = self.parse()?;
+
+        Ok(ScssPlaceholderSelector {
+            span: span!(self, span.lo),
+            name,
+        })
+    }
+}
+
+impl<I> Parse<ScssArgumentList> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<ScssArgumentList> {
+        if !is!(self, "(") {
+            let span = self.input.cur_span();
+
+            return Err(Error::new(span, ErrorKind::Expected("'(' token")));
+        }
+
+        let block: SimpleBlock = self.parse()?;
+
+        Ok(ScssArgumentList {
+            span: block.span,
+            value: scss_component_values(block.value),
+        })
+    }
+}
+
+impl<I> Parse<ScssMixinPrelude> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<ScssMixinPrelude> {
+        let span = self.input.cur_span();
+        let (name, parameters) = self.parse_scss_callable()?;
+
+        Ok(ScssMixinPrelude {
+            span: span!(self, span.lo),
+            name,
+            parameters,
+        })
+    }
+}
+
+impl<I> Parse<ScssIncludePrelude> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<ScssIncludePrelude> {
+        let span = self.input.cur_span();
+        let namespace = if is!(self, Ident) && peeked_is!(self, ".") {
+            let namespace = self.parse()?;
+
+            bump!(self);
+
+            Some(namespace)
+        } else {
+            None
+        };
+        let (name, arguments) = self.parse_scss_callable()?;
+
+        Ok(ScssIncludePrelude {
+            span: span!(self, span.lo),
+            namespace,
+            name,
+            arguments,
+        })
+    }
+}
+
+impl<I> Parse<ScssUsePrelude> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<ScssUsePrelude> {
+        let span = self.input.cur_span();
+        let url: Str = self.parse()?;
+        let mut last_pos = self.input.last_pos();
+
+        self.input.skip_ws();
+
+        let namespace = if is_case_insensitive_ident!(self, "as") {
+            bump!(self);
+
+            self.input.skip_ws();
+
+            let namespace = self.parse()?;
+
+            last_pos = self.input.last_pos();
+
+            self.input.skip_ws();
+
+            Some(namespace)
+        } else {
+            None
+        };
+        let configuration = self.parse_scss_configuration()?;
+
+        if configuration.is_some() {
+            last_pos = self.input.last_pos();
+        }
+
+        Ok(ScssUsePrelude {
+            span: Span::new(span.lo, last_pos),
+            url,
+            namespace,
+            configuration,
+        })
+    }
+}
+
+impl<I> Parse<ScssNamespace> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<ScssNamespace> {
+        match cur!(self) {
+            tok!("*") => {
+                let span = self.input.cur_span();
+
+                bump!(self);
+
+                Ok(ScssNamespace::Wildcard(ScssWildcard { span }))
+            }
+            _ => Ok(ScssNamespace::Ident(self.parse()?)),
+        }
+    }
+}
+
+impl<I> Parse<ScssForwardPrelude> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<ScssForwardPrelude> {
+        let span = self.input.cur_span();
+        let url: Str = self.parse()?;
+        let mut last_pos = self.input.last_pos();
+
+        self.input.skip_ws();
+
+        let prefix = if is_case_insensitive_ident!(self, "as") {
+            bump!(self);
+
+            self.input.skip_ws();
+
+            let prefix: Ident = self.parse()?;
+
+            if !is!(self, "*") || self.input.cur_span().lo != prefix.span.hi {
+                let span = self.input.cur_span();
+
+                return Err(Error::new(span, ErrorKind::Expected("'*' delim token")));
+            }
+
+            bump!(self);
+
+            last_pos = self.input.last_pos();
+
+            self.input.skip_ws();
+
+            Some(prefix)
+        } else {
+            None
+        };
+        let visibility = if is_one_of_case_insensitive_ident!(self, "show", "hide") {
+            let visibility: ScssForwardVisibility = self.parse()?;
+
+            last_pos = visibility.span.hi;
+
+            self.input.skip_ws();
+
+            Some(visibility)
+        } else {
+            None
+        };
+        let configuration = self.parse_scss_configuration()?;
+
+        if configuration.is_some() {
+            last_pos = self.input.last_pos();
+        }
+
+        Ok(ScssForwardPrelude {
+            span: Span::new(span.lo, last_pos),
+            url,
+            prefix,
+            visibility,
+            configuration,
+        })
+    }
+}
+
+impl<I> Parse<ScssForwardVisibility> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<ScssForwardVisibility> {
+        let span = self.input.cur_span();
+        let kind = if is_case_insensitive_ident!(self, "show") {
+            ScssForwardVisibilityKind::Show
+        } else if is_case_insensitive_ident!(self, "hide") {
+            ScssForwardVisibilityKind::Hide
+        } else {
+            return Err(Error::new(
+                span,
+                ErrorKind::Expected("'show' or 'hide' ident"),
+            ));
+        };
+
+        bump!(self);
+
+        self.input.skip_ws();
+
+        let mut members = vec![self.parse()?];
+        let mut last_pos = self.input.last_pos();
+
+        loop {
+            self.input.skip_ws();
+
+            if !eat!(self, ",") {
+                break;
+            }
+
+            self.input.skip_ws();
+
+            members.push(self.parse()?);
+
+            last_pos = self.input.last_pos();
+        }
+
+        Ok(ScssForwardVisibility {
+            span: Span::new(span.lo, last_pos),
+            kind,
+            members,
+        })
+    }
+}
+
+impl<I> Parse<ScssForwardMember> for Parser<I>
+where
+    I: ParserInput,
+{
+    fn parse(&mut self) -> PResult<ScssForwardMember> {
+        match cur!(self) {
+            tok!("$") => Ok(ScssForwardMember::Variable(self.parse()?)),
+            _ => Ok(ScssForwardMember::Ident(self.parse()?)),
+        }
+    }
+}
+
+/// Returns `true` for at-rules with a prelude defined by SCSS.
+pub(crate) fn is_scss_at_rule(name: &str) -> bool {
+    matches!(name, "mixin" | "include" | "use" | "forward")
+}
+
+/// Remove trailing `!default` and `!global` flags (and whitespaces) from the
+/// value of a variable declaration.
+fn take_scss_flags(value: &mut Vec<ComponentValue>) -> Vec<ScssFlag> {
+    let mut flags = vec![];
+
+    loop {
+        while matches!(
+            value.last(),
+            Some(ComponentValue::PreservedToken(token_and_span))
+                if matches!(token_and_span.token, Token::WhiteSpace { .. })
+        ) {
+            value.pop();
+        }
+
+        let len = value.len();
+
+        if len < 2 {
+            break;
+        }
+
+        let flag = match (&value[len - 2], &value[len - 1]) {
+            (
+                ComponentValue::PreservedToken(exclamation),
+                ComponentValue::PreservedToken(ident),
+            ) => match (&exclamation.token, &ident.token) {
+                (Token::Delim { value: '!' }, Token::Ident { value, raw })
+                    if &**value == "default" || &**value == "global" =>
+                {
+                    ScssFlag {
+                        span: Span::new(exclamation.span.lo, ident.span.hi),
+                        value: Ident {
+                            span: ident.span,
+                            value: value.clone(),
+                            raw: Some(raw.clone()),
+                        },
+                    }
+                }
+                _ => break,
+            },
+            _ => break,
+        };
+
+        value.truncate(len - 2);
+        flags.insert(0, flag);
+    }
+
+    flags
+}
+
+/// Replace `$name` and `#{...}` in a list of component values with
+/// [ScssVariable] and [ScssInterpolation].
+///
+/// The result can't be used as an input of the parser anymore, so it should
+/// only be applied to values which will not be parsed again.
+pub(crate) fn scss_component_values(values: Vec<ComponentValue>) -> Vec<ComponentValue> {
+    let mut result = Vec::with_capacity(values.len());
+    let mut iter = values.into_iter().peekable();
+
+    while let Some(value) = iter.next() {
+        match value {
+            ComponentValue::PreservedToken(token_and_span)
+                if matches!(token_and_span.token, Token::Delim { value: '$' })
+                    && matches!(
+                        iter.peek(),
+                        Some(ComponentValue::PreservedToken(next))
+                            if matches!(next.token, Token::Ident { .. })
+                                && next.span.lo == token_and_span.span.hi
+                    ) =>
+            {
+                let Some(ComponentValue::PreservedToken(next)) = iter.next() else {
+                    unreachable!();
+                };
+                let TokenAndSpan {
+                    span,
+                    token: Token::Ident { value, raw },
+                } = *next
+                else {
+                    unreachable!();
+                };
+
+                result.push(ComponentValue::ScssVariable(Box::new(ScssVariable {
+                    span: Span::new(token_and_span.span.lo, span.hi),
+                    name: Ident {
+                        span,
+                        value,
+                        raw: Some(raw),
+                    },
+                })));
+            }
+            ComponentValue::PreservedToken(token_and_span)
+                if matches!(token_and_span.token, Token::Delim { value: '#' })
+                    && matches!(
+                        iter.peek(),
+                        Some(ComponentValue::SimpleBlock(next))
+                            if next.name.token == Token::LBrace
+                                && next.span.lo == token_and_span.span.hi
+                    ) =>
+            {
+                let Some(ComponentValue::SimpleBlock(block)) = iter.next() else {
+                    unreachable!();
+                };
+
+                result.push(ComponentValue::ScssInterpolation(Box::new(
+                    ScssInterpolation {
+                        span: Span::new(token_and_span.span.lo, block.span.hi),
+                        value: scss_component_values(block.value),
+                    },
+                )));
+            }
+            ComponentValue::Function(mut function) => {
+                function.value = scss_component_values(function.value);
+
+                result.push(ComponentValue::Function(function));
+            }
+            ComponentValue::SimpleBlock(mut simple_block) => {
+                simple_block.value = scss_component_values(simple_block.value);
+
+                result.push(ComponentValue::SimpleBlock(simple_block));
+            }
+            _ => {
+                result.push(value);
+            }
+        }
+    }
+
+    result
+}
+
+/// Returns `true` if a list of component values contains a variable or
+/// interpolation.
+pub(crate) fn contains_scss_syntax(values: &[ComponentValue]) -> bool {
+    contains_scss_syntax_inner(values, false)
+}
+
+/// Returns `true` if a value contains a variable, interpolation or a member of
+/// a module (i.e. `math.div(10px, 2)`).
+///
+/// Module members are not checked in preludes, because `a.b` is valid there
+/// (i.e. selectors and layer names).
+pub(crate) fn is_scss_value(values: &[ComponentValue]) -> bool {
+    contains_scss_syntax_inner(values, true)
+}
+
+fn contains_scss_syntax_inner(values: &[ComponentValue], module_members: bool) -> bool {
+    values.iter().enumerate().any(|(idx, value)| {
+        let next = values.get(idx + 1);
+        let is_adjacent = |next: &ComponentValue| next.span_lo() == value.span_hi();
+
+        match value {
+            ComponentValue::PreservedToken(token_and_span) => match &token_and_span.token {
+                Token::Delim { value: '$' } => matches!(
+                    next,
+                    Some(next @ ComponentValue::PreservedToken(next_token))
+                        if matches!(next_token.token, Token::Ident { .. }) && is_adjacent(next)
+                ),
+                Token::Delim { value: '#' } => matches!(
+                    next,
+                    Some(next @ ComponentValue::SimpleBlock(simple_block))
+                        if simple_block.name.token == Token::LBrace && is_adjacent(next)
+                ),
+                Token::Ident { .. } if module_members => match next {
+                    Some(dot @ ComponentValue::PreservedToken(dot_token))
+                        if matches!(dot_token.token, Token::Delim { value: '.' })
+                            && is_adjacent(dot) =>
+                    {
+                        matches!(
+                            values.get(idx + 2),
+                            Some(member @ (ComponentValue::Function(_) | ComponentValue::PreservedToken(_)))
+                                if member.span_lo() == dot.span_hi() && match member {
+                                    ComponentValue::PreservedToken(member) => matches!(
+                                        member.token,
+                                        Token::Ident { .. } | Token::Delim { value: '$' }
+                                    ),
+                                    _ => true,
+                                }
+                        )
+                    }
+                    _ => false,
+                },
+                _ => false,
+            },
+            ComponentValue::Function(function) => {
+                contains_scss_syntax_inner(&function.value, module_members)
+            }
+            ComponentValue::SimpleBlock(simple_block) => {
+                contains_scss_syntax_inner(&simple_block.value, module_members)
+            }
+            _ => false,
+        }
+    })
+}
@@ -426,7 +426,8 @@ where
             if !(is!(self, "#")
                 || is!(self, ".")
                 || is!(self, "[")
-                || (is!(self, ":") && !peeked_is!(self, ":")))
+                || (is!(self, ":") && !peeked_is!(self, ":"))
+                || (self.config.scss && is!(self, "%")))
             {
                 break;
             }
@@ -605,6 +606,7 @@ where
             tok!(".") => Ok(SubclassSelector::Class(self.parse()?)),
             tok!("[") => Ok(SubclassSelector::Attribute(self.parse()?)),
             tok!(":") => Ok(SubclassSelector::PseudoClass(self.parse()?)),
+            tok!("%") if self.config.scss => Ok(SubclassSelector::ScssPlaceholder(self.parse()?)),
             _ => {
                 let span = self.input.cur_span();
 
@@ -4,7 +4,10 @@ use swc_css_ast::*;
 use super::{input::ParserInput, PResult, Parser};
 use crate::{
     error::{Error, ErrorKind},
-    parser::{BlockContentsGrammar, Ctx},
+    parser::{
+        scss::{is_scss_value, scss_component_values},
+        BlockContentsGrammar, Ctx,
+    },
     Parse,
 };
 
@@ -88,6 +91,44 @@ where
 
                     rules.push(Rule::AtRule(at_rule));
                 }
+                // SCSS variable declaration, i.e. `$name: value;`
+                //
+                // Consume a variable declaration from the temporary list, like declarations in
+                // blocks, and consume the following <semicolon-token>.
+                tok!("$") if self.config.scss => {
+                    let span = self.input.cur_span();
+                    let mut temporary_list = ListOfComponentValues {
+                        span: Default::default(),
+                        children: vec![],
+                    };
+
+                    while !is_one_of!(self, ";", EOF) {
+                        let component_value = self.with_ctx(ctx).parse_as::<ComponentValue>()?;
+
+                        temporary_list.children.push(component_value);
+                    }
+
+                    temporary_list.span = span!(self, span.lo);
+
+                    eat!(self, ";");
+
+                    match self.parse_according_to_grammar::<ScssVariableDeclaration>(
+                        &temporary_list,
+                        |parser| parser.parse(),
+                    ) {
+                        Ok(declaration) => {
+                            rules.push(Rule::ScssVariableDeclaration(Box::new(declaration)))
+                        }
+                        Err(err) => {
+                            self.errors.push(err);
+
+                            temporary_list.children =
+                                scss_component_values(temporary_list.children);
+
+                            rules.push(Rule::ListOfComponentValues(Box::new(temporary_list)));
+                        }
+                    }
+                }
                 // anything else
                 // Reconsume the current input token. Consume a qualified rule. If anything is
                 // returned, append it to the list of rules.
@@ -197,6 +238,8 @@ where
                 return Ok(at_rule);
             }
 
+            let is_scss_interpolation = self.is_scss_interpolation_start(&prelude);
+
             match cur!(self) {
                 // <semicolon-token>
                 // Return the at-rule.
@@ -217,7 +260,9 @@ where
                 }
                 // <{-token>
                 // Consume a simple block and assign it to the at-rule’s block. Return the at-rule.
-                tok!("{") => {
+                //
+                // SCSS interpolation is a part of the prelude.
+                tok!("{") if !is_scss_interpolation => {
                     let block = self.parse_as::<SimpleBlock>()?;
 
                     at_rule.prelude = Some(Box::new(AtRulePrelude::ListOfComponentValues(
@@ -269,6 +314,8 @@ where
                 ));
             }
 
+            let is_scss_interpolation = self.is_scss_interpolation_start(&prelude);
+
             match cur!(self) {
                 // <semicolon-token>
                 // If mixed with declarations is true, this is a parse error; return nothing.
@@ -287,7 +334,9 @@ where
                 // <{-token>
                 // Consume a simple block and assign it to the qualified rule’s block. Return the
                 // qualified rule.
-                tok!("{") => {
+                //
+                // SCSS interpolation is a part of the prelude.
+                tok!("{") if !is_scss_interpolation => {
                     let block = self.parse_as::<SimpleBlock>()?;
                     let mut qualified_rule = QualifiedRule {
                         span: span!(self, span.lo),
@@ -363,7 +412,47 @@ where
                 tok!("@") => {
                     let at_rule = self.parse()?;
 
-                    rules.push(StyleBlock::AtRule(Box::new(at_rule)));
+                    // SCSS is order-sensitive, i.e. variables can be reassigned between rules
+                    if self.config.scss {
+                        declarations.push(StyleBlock::AtRule(Box::new(at_rule)));
+                    } else {
+                        rules.push(StyleBlock::AtRule(Box::new(at_rule)));
+                    }
+                }
+                // SCSS variable declaration, i.e. `$name: value;`
+                tok!("$") if self.config.scss => {
+                    let span = self.input.cur_span();
+                    let mut temporary_list = ListOfComponentValues {
+                        span: Default::default(),
+                        children: vec![],
+                    };
+
+                    while !is_one_of!(self, ";", EOF) {
+                        let component_value = self.parse_as::<ComponentValue>()?;
+
+                        temporary_list.children.push(component_value);
+                    }
+
+                    let declaration_or_list_of_component_values = match self
+                        .parse_according_to_grammar::<ScssVariableDeclaration>(
+                            &temporary_list,
+                            |parser| parser.parse(),
+                        ) {
+                        Ok(declaration) => {
+                            StyleBlock::ScssVariableDeclaration(Box::new(declaration))
+                        }
+                        Err(err) => {
+                            self.errors.push(err);
+
+                            temporary_list.span = span!(self, span.lo);
+                            temporary_list.children =
+                                scss_component_values(temporary_list.children);
+
+                            StyleBlock::ListOfComponentValues(Box::new(temporary_list))
+                        }
+                    };
+
+                    declarations.push(declaration_or_list_of_component_values);
                 }
                 // <ident-token>
                 // <function-token>
@@ -380,11 +469,16 @@ where
                     //
                     // Constructions like `a { prop: {value}; }` still affected this problem, but
                     // `{`/`}` doesn't used in declarations
-                    if self.config.legacy_nesting
+                    if (self.config.legacy_nesting || self.config.scss)
                         && matches!(self.input.cur(), Some(Token::Ident { value, .. }) if !value.starts_with("--"))
                     {
                         if let Some(legacy_nested) = self.try_to_parse_legacy_nesting() {
-                            rules.push(StyleBlock::QualifiedRule(Box::new(legacy_nested)));
+                            if self.config.scss {
+                                declarations
+                                    .push(StyleBlock::QualifiedRule(Box::new(legacy_nested)));
+                            } else {
+                                rules.push(StyleBlock::QualifiedRule(Box::new(legacy_nested)));
+                            }
 
                             continue;
                         }
@@ -430,6 +524,9 @@ where
                         .parse_as::<Box<QualifiedRule>>();
 
                     match qualified_rule {
+                        Ok(i) if self.config.scss => {
+                            declarations.push(StyleBlock::QualifiedRule(i))
+                        }
                         Ok(i) => rules.push(StyleBlock::QualifiedRule(i)),
                         Err(err) => {
                             self.errors.push(err);
@@ -770,6 +867,13 @@ where
         // Update span
         declaration.span = span!(self, span.lo);
 
+        // Values using the SCSS syntax can't be canonicalized
+        if self.config.scss && is_scss_value(&declaration.value) {
+            declaration.value = scss_component_values(declaration.value);
+
+            return Ok(declaration);
+        }
+
         if is_dashed_ident {
             // Don't parse custom properties
             //
@@ -7,7 +7,13 @@ use super::{
     input::{Input, InputType, ParserInput},
     Ctx, Error, PResult, Parse, Parser,
 };
-use crate::{error::ErrorKind, parser::BlockContentsGrammar};
+use crate::{
+    error::ErrorKind,
+    parser::{
+        scss::{contains_scss_syntax, is_scss_at_rule, scss_component_values},
+        BlockContentsGrammar,
+    },
+};
 
 impl<I> Parser<I>
 where
@@ -92,6 +98,24 @@ where
             }
         };
 
+        // Preludes using the SCSS syntax can't be canonicalized, except for SCSS
+        // at-rules
+        if self.config.scss
+            && !is_scss_at_rule(&normalized_at_rule_name)
+            && contains_scss_syntax(&list_of_component_values.children)
+        {
+            let mut list_of_component_values = list_of_component_values;
+
+            list_of_component_values.children =
+                scss_component_values(list_of_component_values.children);
+
+            at_rule.prelude = Some(Box::new(AtRulePrelude::ListOfComponentValues(
+                list_of_component_values,
+            )));
+
+            return Ok(at_rule);
+        }
+
         at_rule.prelude = match self
             .parse_according_to_grammar(&list_of_component_values, |parser| {
                 parser.parse_at_rule_prelude(&normalized_at_rule_name)
@@ -127,6 +151,13 @@ where
                 }
 
                 if !list_of_component_values.children.is_empty() {
+                    let mut list_of_component_values = list_of_component_values;
+
+                    if self.config.scss {
+                        list_of_component_values.children =
+                            scss_component_values(list_of_component_values.children);
+                    }
+
                     Some(Box::new(AtRulePrelude::ListOfComponentValues(
                         list_of_component_values,
                     )))
@@ -186,6 +217,14 @@ where
         };
 
         qualified_rule.prelude = if self.ctx.in_keyframes_at_rule {
+            QualifiedRulePrelude::ListOfComponentValues(list_of_component_values)
+        } else if self.config.scss && contains_scss_syntax(&list_of_component_values.children) {
+            // Selectors with interpolation can't be canonicalized
+            let mut list_of_component_values = list_of_component_values;
+
+            list_of_component_values.children =
+                scss_component_values(list_of_component_values.children);
+
             QualifiedRulePrelude::ListOfComponentValues(list_of_component_values)
         } else if self.ctx.mixed_with_declarations {
             match self.parse_according_to_grammar::<RelativeSelectorList>(
@@ -521,6 +521,32 @@ impl Visit for SpanVisualizer<'_> {
 
     mtd!(PageSelectorPseudo, visit_page_selector_pseudo);
 
+    mtd!(ScssVariable, visit_scss_variable);
+
+    mtd!(ScssVariableDeclaration, visit_scss_variable_declaration);
+
+    mtd!(ScssFlag, visit_scss_flag);
+
+    mtd!(ScssInterpolation, visit_scss_interpolation);
+
+    mtd!(ScssPlaceholderSelector, visit_scss_placeholder_selector);
+
+    mtd!(ScssArgumentList, visit_scss_argument_list);
+
+    mtd!(ScssMixinPrelude, visit_scss_mixin_prelude);
+
+    mtd!(ScssIncludePrelude, visit_scss_include_prelude);
+
+    mtd!(ScssUsePrelude, visit_scss_use_prelude);
+
+    mtd!(ScssNamespace, visit_scss_namespace);
+
+    mtd!(ScssForwardPrelude, visit_scss_forward_prelude);
+
+    mtd!(ScssForwardVisibility, visit_scss_forward_visibility);
+
+    mtd!(ScssForwardMember, visit_scss_forward_member);
+
     fn visit_token_and_span(&mut self, n: &TokenAndSpan) {
         self.handler
             .struct_span_err(n.span, &format!("{:?}", n.token))
@@ -612,6 +638,28 @@ fn span_visualizer_line_comment(input: PathBuf) {
     )
 }
 
+#[testing::fixture("tests/scss/**/input.scss")]
+fn scss_pass(input: PathBuf) {
+    stylesheet_test(
+        input,
+        ParserConfig {
+            scss: true,
+            ..Default::default()
+        },
+    )
+}
+
+#[testing::fixture("tests/scss/**/input.scss")]
+fn span_visualizer_scss(input: PathBuf) {
+    stylesheet_span_visualizer(
+        input,
+        Some(ParserConfig {
+            scss: true,
+            ..Default::default()
+        }),
+    )
+}
+
 // TODO fix exclude
 #[testing::fixture(
     "tests/recovery/**/input.css",
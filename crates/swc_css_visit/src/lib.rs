@@ -58,6 +58,10 @@ define!({
         SupportsCondition(Box<SupportsCondition>),
         Declaration(Box<Declaration>),
         IdSelector(Box<IdSelector>),
+
+        ScssVariable(Box<ScssVariable>),
+        ScssInterpolation(Box<ScssInterpolation>),
+        ScssVariableDeclaration(Box<ScssVariableDeclaration>),
     }
 
     pub struct Ident {
@@ -136,6 +140,7 @@ define!({
         Declaration(Box<Declaration>),
         QualifiedRule(Box<QualifiedRule>),
         ListOfComponentValues(Box<ListOfComponentValues>),
+        ScssVariableDeclaration(Box<ScssVariableDeclaration>),
     }
 
     pub enum DeclarationOrAtRule {
@@ -477,6 +482,7 @@ define!({
         Attribute(Box<AttributeSelector>),
         PseudoClass(PseudoClassSelector),
         PseudoElement(PseudoElementSelector),
+        ScssPlaceholder(ScssPlaceholderSelector),
     }
 
     pub struct AttributeSelector {
@@ -574,6 +580,7 @@ define!({
         QualifiedRule(Box<QualifiedRule>),
         AtRule(Box<AtRule>),
         ListOfComponentValues(Box<ListOfComponentValues>),
+        ScssVariableDeclaration(Box<ScssVariableDeclaration>),
     }
 
     pub struct ImportPrelude {
@@ -642,6 +649,10 @@ define!({
         ContainerPrelude(ContainerCondition),
         CustomMediaPrelude(CustomMediaQuery),
         ScopePrelude(ScopeRange),
+        ScssMixinPrelude(ScssMixinPrelude),
+        ScssIncludePrelude(ScssIncludePrelude),
+        ScssUsePrelude(ScssUsePrelude),
+        ScssForwardPrelude(ScssForwardPrelude),
     }
 
     pub struct ScopeRange {
@@ -1029,6 +1040,86 @@ define!({
         Function(Function),
         SimpleBlock(SimpleBlock),
     }
+
+    pub struct ScssVariable {
+        pub span: Span,
+        pub name: Ident,
+    }
+
+    pub struct ScssVariableDeclaration {
+        pub span: Span,
+        pub name: ScssVariable,
+        pub value: Vec<ComponentValue>,
+        pub flags: Vec<ScssFlag>,
+    }
+
+    pub struct ScssFlag {
+        pub span: Span,
+        pub value: Ident,
+    }
+
+    pub struct ScssInterpolation {
+        pub span: Span,
+        pub value: Vec<ComponentValue>,
+    }
+
+    pub struct ScssPlaceholderSelector {
+        pub span: Span,
+        pub name: Ident,
+    }
+
+    pub struct ScssArgumentList {
+        pub span: Span,
+        pub value: Vec<ComponentValue>,
+    }
+
+    pub struct ScssMixinPrelude {
+        pub span: Span,
+        pub name: Ident,
+        pub parameters: Option<ScssArgumentList>,
+    }
+
+    pub struct ScssIncludePrelude {
+        pub span: Span,
+        pub namespace: Option<Ident>,
+        pub name: Ident,
+        pub arguments: Option<ScssArgumentList>,
+    }
+
+    pub struct ScssUsePrelude {
+        pub span: Span,
+        pub url: Str,
+        pub namespace: Option<ScssNamespace>,
+        pub configuration: Option<ScssArgumentList>,
+    }
+
+    pub enum ScssNamespace {
+        Ident(Ident),
+        Wildcard(ScssWildcard),
+    }
+
+    pub struct ScssWildcard {
+        pub span: Span,
+    }
+
+    pub struct ScssForwardPrelude {
+        pub span: Span,
+        pub url: Str,
+        pub prefix: Option<Ident>,
+        pub visibility: Option<ScssForwardVisibility>,
+        pub configuration: Option<ScssArgumentList>,
+    }
+
+    pub struct ScssForwardVisibility {
+        pub span: Span,
+        pub kind: ScssForwardVisibilityKind,
+        pub members: Vec<ScssForwardMember>,
+    }
+
+    pub enum ScssForwardMember {
+        Ident(Ident),
+        Variable(ScssVariable),
+    }
 });
 
 impl<T: ?Sized> Node for T {}
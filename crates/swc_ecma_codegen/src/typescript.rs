@@ -605,6 +605,8 @@ where
             }
             formatting_space!();
             emit!(body);
+        } else {
+            formatting_semi!();
         }
     }
 
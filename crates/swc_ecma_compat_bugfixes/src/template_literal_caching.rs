@@ -1,7 +1,7 @@
 use swc_common::DUMMY_SP;
 use swc_ecma_ast::*;
 use swc_ecma_utils::{prepend_stmt, private_ident, ExprFactory};
-use swc_ecma_visit::{standard_only_fold, Fold, FoldWith};
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
 use swc_trace_macro::swc_trace;
 
 // Converts destructured parameters with default values to non-shorthand syntax.
@@ -59,7 +59,7 @@ impl TemplateLiteralCaching {
 /// TODO: VisitMut
 #[swc_trace]
 impl Fold for TemplateLiteralCaching {
-    standard_only_fold!();
+    noop_fold_type!();
 
     fn fold_expr(&mut self, n: Expr) -> Expr {
         let n = n.fold_children_with(self);
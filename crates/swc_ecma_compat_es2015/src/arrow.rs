@@ -6,9 +6,7 @@ use swc_ecma_utils::{
     function::{init_this, FnEnvHoister},
     prepend_stmt,
 };
-use swc_ecma_visit::{
-    as_folder, standard_only_visit_mut, Fold, InjectVars, VisitMut, VisitMutWith,
-};
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, InjectVars, VisitMut, VisitMutWith};
 use swc_trace_macro::swc_trace;
 
 /// Compile ES2015 arrow functions to ES5
@@ -74,7 +72,7 @@ struct Arrow {
 
 #[swc_trace]
 impl VisitMut for Arrow {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     fn visit_mut_class(&mut self, c: &mut Class) {
         if c.super_class.is_some() {
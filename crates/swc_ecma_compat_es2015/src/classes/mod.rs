@@ -15,8 +15,7 @@ use swc_ecma_utils::{
     quote_str, replace_ident, ExprFactory, ModuleItemLike, StmtLike,
 };
 use swc_ecma_visit::{
-    as_folder, standard_only_visit, standard_only_visit_mut, Fold, Visit, VisitMut, VisitMutWith,
-    VisitWith,
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Visit, VisitMut, VisitMutWith, VisitWith,
 };
 use swc_trace_macro::swc_trace;
 use tracing::debug;
@@ -224,7 +223,7 @@ impl<C> VisitMut for Classes<C>
 where
     C: Comments,
 {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
         self.visit_mut_stmt_like(items)
@@ -1262,7 +1261,7 @@ fn is_always_initialized(body: &[Stmt]) -> bool {
     }
 
     impl Visit for SuperFinder {
-        standard_only_visit!();
+        noop_visit_type!();
 
         fn visit_callee(&mut self, node: &Callee) {
             match *node {
@@ -1317,7 +1316,7 @@ struct ClassFinder {
 }
 
 impl Visit for ClassFinder {
-    standard_only_visit!();
+    noop_visit_type!();
 
     fn visit_class(&mut self, _: &Class) {
         self.found = true
@@ -4,8 +4,7 @@ use swc_ecma_ast::*;
 use swc_ecma_transforms_base::helper;
 use swc_ecma_utils::{quote_ident, ExprFactory, StmtLike};
 use swc_ecma_visit::{
-    as_folder, standard_only_visit, standard_only_visit_mut, Fold, Visit, VisitMut, VisitMutWith,
-    VisitWith,
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Visit, VisitMut, VisitMutWith, VisitWith,
 };
 use swc_trace_macro::swc_trace;
 
@@ -63,7 +62,7 @@ struct ComputedProps {
 
 #[swc_trace]
 impl VisitMut for ComputedProps {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     fn visit_mut_expr(&mut self, expr: &mut Expr) {
         expr.visit_mut_children_with(self);
@@ -356,7 +355,7 @@ struct ComplexVisitor {
 }
 
 impl Visit for ComplexVisitor {
-    standard_only_visit!();
+    noop_visit_type!();
 
     fn visit_prop_name(&mut self, pn: &PropName) {
         if let PropName::Computed(..) = *pn {
@@ -443,7 +442,7 @@ struct ShouldWork {
 }
 
 impl Visit for ShouldWork {
-    standard_only_visit!();
+    noop_visit_type!();
 
     fn visit_prop_name(&mut self, node: &PropName) {
         if let PropName::Computed(_) = *node {
@@ -11,8 +11,7 @@ use swc_ecma_utils::{
     prop_name_to_expr, quote_ident, ExprFactory, StmtLike,
 };
 use swc_ecma_visit::{
-    as_folder, standard_only_visit, standard_only_visit_mut, Fold, Visit, VisitMut, VisitMutWith,
-    VisitWith,
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Visit, VisitMut, VisitMutWith, VisitWith,
 };
 use swc_trace_macro::swc_trace;
 
@@ -471,7 +470,7 @@ impl AssignFolder {
 #[swc_trace]
 #[fast_path(DestructuringVisitor)]
 impl VisitMut for Destructuring {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     impl_for_for_stmt!(visit_mut_for_in_stmt, ForInStmt);
 
@@ -598,7 +597,7 @@ impl AssignFolder {
 #[swc_trace]
 #[fast_path(DestructuringVisitor)]
 impl VisitMut for AssignFolder {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     fn visit_mut_export_decl(&mut self, decl: &mut ExportDecl) {
         let old = self.exporting;
@@ -1303,7 +1302,7 @@ struct DestructuringVisitor {
 }
 
 impl Visit for DestructuringVisitor {
-    standard_only_visit!();
+    noop_visit_type!();
 
     fn visit_assign_target_pat(&mut self, _: &AssignTargetPat) {
         self.found = true;
@@ -2,7 +2,7 @@ use swc_common::{util::take::Take, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::{helper, perf::Parallel};
 use swc_ecma_utils::{quote_str, ExprFactory};
-use swc_ecma_visit::{as_folder, standard_only_visit_mut, Fold, VisitMut, VisitMutWith};
+use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
 use swc_trace_macro::swc_trace;
 
 pub fn typeof_symbol() -> impl VisitMut + Fold {
@@ -23,7 +23,7 @@ impl Parallel for TypeOfSymbol {
 
 #[swc_trace]
 impl VisitMut for TypeOfSymbol {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     fn visit_mut_bin_expr(&mut self, expr: &mut BinExpr) {
         match expr.op {
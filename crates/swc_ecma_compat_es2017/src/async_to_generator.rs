@@ -13,8 +13,7 @@ use swc_ecma_utils::{
     private_ident, quote_ident, ExprFactory, Remapper, StmtLike,
 };
 use swc_ecma_visit::{
-    as_folder, standard_only_visit, standard_only_visit_mut, Fold, Visit, VisitMut, VisitMutWith,
-    VisitWith,
+    as_folder, noop_visit_mut_type, noop_visit_type, Fold, Visit, VisitMut, VisitMutWith, VisitWith,
 };
 use swc_trace_macro::swc_trace;
 
@@ -78,7 +77,7 @@ struct Actual<C: Comments> {
 #[swc_trace]
 #[fast_path(ShouldWork)]
 impl<C: Comments + Clone> VisitMut for AsyncToGenerator<C> {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     fn visit_mut_module_items(&mut self, n: &mut Vec<ModuleItem>) {
         self.visit_mut_stmt_like(n);
@@ -121,7 +120,7 @@ impl<C: Comments + Clone> AsyncToGenerator<C> {
 #[swc_trace]
 #[fast_path(ShouldWork)]
 impl<C: Comments> VisitMut for Actual<C> {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     fn visit_mut_class_method(&mut self, m: &mut ClassMethod) {
         if m.function.body.is_none() {
@@ -450,7 +449,7 @@ macro_rules! noop {
 
 #[swc_trace]
 impl VisitMut for AsyncFnBodyHandler {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     noop!(visit_mut_fn_expr, FnExpr);
 
@@ -537,7 +536,7 @@ struct ShouldWork {
 
 #[swc_trace]
 impl Visit for ShouldWork {
-    standard_only_visit!();
+    noop_visit_type!();
 
     fn visit_function(&mut self, f: &Function) {
         if f.is_async {
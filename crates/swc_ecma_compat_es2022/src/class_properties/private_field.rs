@@ -8,7 +8,7 @@ use swc_common::{
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::helper;
 use swc_ecma_utils::{alias_ident_for, alias_if_required, prepend_stmt, quote_ident, ExprFactory};
-use swc_ecma_visit::{standard_only_visit_mut, VisitMut, VisitMutWith};
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
 use swc_trace_macro::swc_trace;
 
 use super::Config;
@@ -86,7 +86,7 @@ pub(super) struct BrandCheckHandler<'a> {
 
 #[swc_trace]
 impl VisitMut for BrandCheckHandler<'_> {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     fn visit_mut_expr(&mut self, e: &mut Expr) {
         e.visit_mut_children_with(self);
@@ -205,7 +205,7 @@ macro_rules! take_vars {
 // super.#sdsa is invalid
 #[swc_trace]
 impl<'a> VisitMut for PrivateAccessVisitor<'a> {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     take_vars!(visit_mut_function, Function);
 
@@ -1,6 +1,6 @@
 use swc_ecma_ast::*;
 use swc_ecma_utils::is_valid_ident;
-use swc_ecma_visit::{standard_only_fold, Fold, FoldWith};
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith};
 use swc_trace_macro::swc_trace;
 
 /// babel: `transform-member-expression-literals`
@@ -28,7 +28,7 @@ struct MemberExprLit;
 
 #[swc_trace]
 impl Fold for MemberExprLit {
-    standard_only_fold!();
+    noop_fold_type!();
 
     fn fold_member_expr(&mut self, e: MemberExpr) -> MemberExpr {
         let e = e.fold_children_with(self);
@@ -264,6 +264,7 @@ pub enum SyntaxError {
     TS1267,
     TS1273(JsWord),
     TS1274(JsWord),
+    TS1276,
     TS1277(JsWord),
     TS2206,
     TS2207,
@@ -684,6 +685,7 @@ impl SyntaxError {
                 word
             )
             .into(),
+            SyntaxError::TS1276 => "An 'accessor' property cannot be declared optional.".into(),
             SyntaxError::TS1277(word) => format!(
                 "'{}' modifier can only appear on a type parameter of a function, method or class",
                 word
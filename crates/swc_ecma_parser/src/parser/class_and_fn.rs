@@ -681,7 +681,15 @@ impl<I: Tokens> Parser<I> {
         }
 
         let accessor_token = accessor_token.or_else(|| {
-            if self.syntax().auto_accessors() && readonly.is_none() {
+            // `readonly accessor;` is a property named `accessor`
+            let is_accessor_modifier = readonly.is_none()
+                || (is!(self, "accessor")
+                    && !self.input.has_linebreak_between_cur_and_peeked()
+                    && (peeked_is!(self, IdentName)
+                        || peeked_is!(self, '#')
+                        || peeked_is!(self, '[')));
+
+            if self.syntax().auto_accessors() && is_accessor_modifier {
                 let start = cur_pos!(self);
                 if eat!(self, "accessor") {
                     Some(span!(self, start))
@@ -1091,7 +1099,25 @@ impl<I: Tokens> Parser<I> {
                 p.emit_err(p.input.cur_span(), SyntaxError::TS1005);
             }
 
-            if accessor_token.is_some() {
+            if let Some(accessor_token) = accessor_token {
+                if p.input.syntax().typescript() {
+                    if declare {
+                        p.emit_err(
+                            accessor_token,
+                            SyntaxError::TS1243("accessor".into(), "declare".into()),
+                        );
+                    }
+                    if readonly {
+                        p.emit_err(
+                            accessor_token,
+                            SyntaxError::TS1243("accessor".into(), "readonly".into()),
+                        );
+                    }
+                    if is_optional {
+                        p.emit_err(key.span(), SyntaxError::TS1276);
+                    }
+                }
+
                 return Ok(ClassMember::AutoAccessor(AutoAccessor {
                     span: span!(p, start),
                     key,
@@ -0,0 +1,8 @@
+class C extends B {
+  declare accessor a: number;
+  readonly accessor b = 1;
+  accessor c?: string;
+  static readonly accessor d = 2;
+  readonly accessor;
+  override accessor e = 3;
+}
@@ -7,7 +7,7 @@ MyClass = _ts_decorate([
     _ts_param(0, inject()),
     _ts_metadata("design:type", Function),
     _ts_metadata("design:paramtypes", [
-        typeof Injected === "undefined" ? Object : Injected
+        Injected
     ])
 ], MyClass);
 class MyOtherClass {
@@ -20,7 +20,7 @@ _ts_decorate([
     _ts_metadata("design:type", Function),
     _ts_metadata("design:paramtypes", [
         String,
-        void 0
+        Object
     ]),
     _ts_metadata("design:returntype", void 0)
 ], MyOtherClass.prototype, "methodUndecorated", null);
@@ -30,7 +30,7 @@ _ts_decorate([
     _ts_param(1, arg()),
     _ts_metadata("design:type", Function),
     _ts_metadata("design:paramtypes", [
-        typeof Injected === "undefined" ? Object : Injected,
+        Injected,
         typeof Schema === "undefined" ? Object : Schema
     ]),
     _ts_metadata("design:returntype", void 0)
@@ -40,8 +40,8 @@ MyOtherClass = _ts_decorate([
     _ts_param(1, inject("KIND")),
     _ts_metadata("design:type", Function),
     _ts_metadata("design:paramtypes", [
-        typeof Injected === "undefined" ? Object : Injected,
-        typeof Injected === "undefined" ? Object : Injected
+        Injected,
+        Injected
     ])
 ], MyOtherClass);
 class DecoratedClass {
@@ -63,7 +63,7 @@ DecoratedClass = _ts_decorate([
     _ts_param(1, inject()),
     _ts_metadata("design:type", Function),
     _ts_metadata("design:paramtypes", [
-        typeof Injected === "undefined" ? Object : Injected,
-        typeof Injected === "undefined" ? Object : Injected
+        Injected,
+        Injected
     ])
 ], DecoratedClass);
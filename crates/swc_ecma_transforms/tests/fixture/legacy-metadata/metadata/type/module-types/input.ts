@@ -0,0 +1,82 @@
+import type { Logger } from "./logger";
+import { type Config, Service } from "./service";
+import * as models from "./models";
+import type * as types from "./types";
+
+enum Direction {
+    Up,
+    Down = 1 << 1,
+}
+
+enum Color {
+    Red = "RED",
+    Green = `GREEN`,
+}
+
+enum Mixed {
+    A = 1,
+    B = "b",
+}
+
+interface Options {
+    debug: boolean;
+}
+
+class Repository {}
+
+type Id = string | `${number}`;
+type Ids = readonly Id[];
+type Handler = () => void;
+type Maybe<T> = T | null;
+type Repo = Repository;
+type Status = Direction | Direction.Up;
+type Entry<T> = T extends string ? Repository : Options;
+
+@Injectable()
+class UserService<T> {
+    constructor(
+        private readonly repository: Repository,
+        private readonly logger: Logger,
+        private readonly service: Service,
+        config: Config,
+        options: Options,
+        direction: Direction,
+        color: Color,
+        mixed: Mixed,
+        model: models.User,
+        type: types.User,
+        untyped,
+        ...rest: Repository[]
+    ) {}
+
+    @Get()
+    find(
+        this: UserService<T>,
+        id: Id,
+        ids: Ids,
+        handler: Handler,
+        maybe: Maybe<string>,
+        repo: Repo,
+        status: Status,
+        entry: Entry<T>,
+        value: T,
+        red: Color.Red,
+        mixed: Mixed.B,
+        both: Repository | Repository,
+        union: string | never,
+        never: never | never,
+        intersection: Options & never,
+        unknown: unknown & string,
+        conditional: T extends string ? Service : Service,
+        big: 10n,
+        list: readonly string[],
+        imported: import("./models").User,
+        ...args: Array<Direction>
+    ): Direction {}
+
+    @Column()
+    color: Color;
+
+    @Column()
+    repository: Repository;
+}
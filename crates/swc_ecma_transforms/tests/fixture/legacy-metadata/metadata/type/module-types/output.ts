@@ -0,0 +1,87 @@
+import type { Logger } from "./logger";
+import { type Config, Service } from "./service";
+import * as models from "./models";
+import type * as types from "./types";
+enum Direction {
+    Up,
+    Down = 1 << 1
+}
+enum Color {
+    Red = "RED",
+    Green = `GREEN`
+}
+enum Mixed {
+    A = 1,
+    B = "b"
+}
+interface Options {
+    debug: boolean;
+}
+class Repository {
+}
+type Id = string | `${number}`;
+type Ids = readonly Id[];
+type Handler = () => void;
+type Maybe<T> = T | null;
+type Repo = Repository;
+type Status = Direction | Direction.Up;
+type Entry<T> = T extends string ? Repository : Options;
+class UserService<T> {
+    constructor(private readonly repository: Repository, private readonly logger: Logger, private readonly service: Service, config: Config, options: Options, direction: Direction, color: Color, mixed: Mixed, model: models.User, type: types.User, untyped, ...rest: Repository[]){}
+    find(this: UserService<T>, id: Id, ids: Ids, handler: Handler, maybe: Maybe<string>, repo: Repo, status: Status, entry: Entry<T>, value: T, red: Color.Red, mixed: Mixed.B, both: Repository | Repository, union: string | never, never: never | never, intersection: Options & never, unknown: unknown & string, conditional: T extends string ? Service : Service, big: 10n, list: readonly string[], imported: import("./models").User, ...args: Array<Direction>): Direction {}
+    color: Color;
+    repository: Repository;
+}
+_ts_decorate([
+    Get(),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        String,
+        Array,
+        Function,
+        Object,
+        Object,
+        Number,
+        Object,
+        Object,
+        String,
+        String,
+        Repository,
+        String,
+        void 0,
+        Object,
+        String,
+        Object,
+        typeof BigInt === "undefined" ? Object : BigInt,
+        Array,
+        Object,
+        Number
+    ]),
+    _ts_metadata("design:returntype", Number)
+], UserService.prototype, "find", null);
+_ts_decorate([
+    Column(),
+    _ts_metadata("design:type", String)
+], UserService.prototype, "color", void 0);
+_ts_decorate([
+    Column(),
+    _ts_metadata("design:type", Repository)
+], UserService.prototype, "repository", void 0);
+UserService = _ts_decorate([
+    Injectable(),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        Repository,
+        Object,
+        typeof Service === "undefined" ? Object : Service,
+        Object,
+        Object,
+        Number,
+        String,
+        Object,
+        typeof models === "undefined" || typeof models.User === "undefined" ? Object : models.User,
+        Object,
+        Object,
+        Repository
+    ])
+], UserService);
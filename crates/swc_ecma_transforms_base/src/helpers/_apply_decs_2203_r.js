@@ -15,6 +15,8 @@
 
   STATIC = 5;
 
+  DECORATORS_HAVE_THIS = 16;
+
   CLASS = 10; // only used in assertValidReturnValue
 */
 
@@ -36,7 +38,8 @@ function applyDecs2203RFactory() {
         isStatic,
         isPrivate,
         metadata,
-        value
+        value,
+        decThis
     ) {
         var kindStr;
 
@@ -107,7 +110,7 @@ function applyDecs2203RFactory() {
             get && set ? { get: get, set: set } : get ? { get: get } : { set: set };
 
         try {
-            return dec(value, ctx);
+            return dec.call(decThis, value, ctx);
         } finally {
             decoratorFinishedRef.v = true;
         }
@@ -169,7 +172,8 @@ function applyDecs2203RFactory() {
         isStatic,
         isPrivate,
         initializers,
-        metadata
+        metadata,
+        decoratorsHaveThis
     ) {
         var decs = decInfo[0];
 
@@ -242,8 +246,9 @@ function applyDecs2203RFactory() {
                 }
             }
         } else {
-            for (var i = decs.length - 1; i >= 0; i--) {
+            for (var i = decs.length - 1; i >= 0; i -= decoratorsHaveThis ? 2 : 1) {
                 var dec = decs[i];
+                var decThis = decoratorsHaveThis ? decs[i - 1] : void 0;
 
                 newValue = memberDec(
                     dec,
@@ -254,7 +259,8 @@ function applyDecs2203RFactory() {
                     isStatic,
                     isPrivate,
                     metadata,
-                    value
+                    value,
+                    decThis
                 );
 
                 if (newValue !== void 0) {
@@ -364,6 +370,8 @@ function applyDecs2203RFactory() {
 
             var kind = decInfo[1];
             var name = decInfo[2];
+            var decoratorsHaveThis = kind & 16; /* DECORATORS_HAVE_THIS */
+            kind &= ~16; /* DECORATORS_HAVE_THIS */
             var isPrivate = decInfo.length > 3;
 
             var isStatic = kind >= 5; /* STATIC */
@@ -415,7 +423,8 @@ function applyDecs2203RFactory() {
                 isStatic,
                 isPrivate,
                 initializers,
-                metadata
+                metadata,
+                decoratorsHaveThis
             );
         }
 
@@ -435,17 +444,20 @@ function applyDecs2203RFactory() {
         }
     }
 
-    function applyClassDecs(targetClass, classDecs, metadata) {
+    function applyClassDecs(targetClass, classDecs, metadata, classDecsHaveThis) {
         if (classDecs.length > 0) {
             var initializers = [];
             var newClass = targetClass;
             var name = targetClass.name;
 
-            for (var i = classDecs.length - 1; i >= 0; i--) {
+            for (var i = classDecs.length - 1; i >= 0; i -= classDecsHaveThis ? 2 : 1) {
                 var decoratorFinishedRef = { v: false };
 
                 try {
-                    var nextNewClass = classDecs[i](newClass, {
+                    var nextNewClass = classDecs[i].call(
+                        classDecsHaveThis ? classDecs[i - 1] : void 0,
+                        newClass,
+                        {
                         kind: "class",
                         name: name,
                         addInitializer: createAddInitializerMethod(
@@ -453,7 +465,8 @@ function applyDecs2203RFactory() {
                             decoratorFinishedRef
                         ),
                         metadata,
-                    });
+                        }
+                    );
                 } finally {
                     decoratorFinishedRef.v = true;
                 }
@@ -631,7 +644,13 @@ function applyDecs2203RFactory() {
     initializeClass(Class);
    */
 
-    return function applyDecs2203R(targetClass, memberDecs, classDecs, parentClass) {
+    return function applyDecs2203R(
+        targetClass,
+        memberDecs,
+        classDecs,
+        parentClass,
+        classDecsHaveThis
+    ) {
         if (parentClass !== void 0) {
             var parentMetadata =
                 parentClass[Symbol.metadata || Symbol.for("Symbol.metadata")];
@@ -645,17 +664,24 @@ function applyDecs2203RFactory() {
             e: e,
             // Lazily apply class decorations so that member init locals can be properly bound.
             get c() {
-                return applyClassDecs(targetClass, classDecs, metadata);
+                return applyClassDecs(targetClass, classDecs, metadata, classDecsHaveThis);
             },
         };
     };
 }
 
-function _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentClass) {
+function _apply_decs_2203_r(
+    targetClass,
+    memberDecs,
+    classDecs,
+    parentClass,
+    classDecsHaveThis
+) {
     return (_apply_decs_2203_r = applyDecs2203RFactory())(
         targetClass,
         memberDecs,
         classDecs,
-        parentClass
+        parentClass,
+        classDecsHaveThis
     );
 }
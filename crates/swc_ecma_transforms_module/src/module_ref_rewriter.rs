@@ -8,7 +8,7 @@ use swc_ecma_transforms_base::helpers::HELPERS;
 use swc_ecma_utils::{ExprFactory, QueryRef, RefRewriter};
 use swc_ecma_visit::VisitMutWith;
 
-use crate::util::prop_name;
+use crate::util::{prop_name, IdentOrStr};
 
 pub type ImportMap = AHashMap<Id, (Ident, Option<JsWord>)>;
 
@@ -71,9 +71,36 @@ impl QueryRef for ImportQuery {
         None
     }
 
-    fn query_jsx(&self, _: &Ident) -> Option<JSXElementName> {
-        // We do not need to handle JSX since there is no jsx preserve option in swc
-        None
+    fn query_jsx(&self, ident: &Ident) -> Option<JSXElementName> {
+        // Only reachable if jsx is preserved
+        let (mod_ident, mod_prop) = self.import_map.get(&ident.to_id())?;
+
+        // `_mod()` is not allowed as a jsx element name
+        if self.lazy_record.contains(&mod_ident.to_id()) {
+            return None;
+        }
+
+        let mut mod_ident = mod_ident.clone();
+        mod_ident.span = ident.span;
+
+        match mod_prop {
+            Some(imported_name) => {
+                let IdentOrStr::Ident(prop) = prop_name(imported_name, (ident.span, ident.ctxt))
+                else {
+                    return None;
+                };
+
+                Some(
+                    JSXMemberExpr {
+                        span: ident.span,
+                        obj: mod_ident.into(),
+                        prop,
+                    }
+                    .into(),
+                )
+            }
+            None => Some(mod_ident.into()),
+        }
     }
 
     fn should_fix_this(&self, ident: &Ident) -> bool {
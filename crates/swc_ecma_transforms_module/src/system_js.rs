@@ -6,7 +6,7 @@ use swc_ecma_ast::*;
 use swc_ecma_utils::{
     member_expr, private_ident, quote_ident, quote_str, var::VarCollector, ExprFactory,
 };
-use swc_ecma_visit::{standard_only_fold, Fold, FoldWith, VisitWith};
+use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, VisitWith};
 
 use crate::{
     path::{ImportResolver, Resolver},
@@ -531,7 +531,7 @@ impl SystemJs {
 }
 
 impl Fold for SystemJs {
-    standard_only_fold!();
+    noop_fold_type!();
 
     fn fold_call_expr(&mut self, expr: CallExpr) -> CallExpr {
         let expr = expr.fold_children_with(self);
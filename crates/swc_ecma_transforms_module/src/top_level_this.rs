@@ -1,5 +1,5 @@
 use swc_ecma_ast::*;
-use swc_ecma_visit::{noop_visit_mut_type, standard_only_visit_mut, VisitMut, VisitMutWith};
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
 
 pub struct TopLevelThis {
     found: bool,
@@ -19,7 +19,7 @@ where
 }
 
 impl VisitMut for TopLevelThis {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     noop_visit_mut_type!(visit_mut_function, Function);
 
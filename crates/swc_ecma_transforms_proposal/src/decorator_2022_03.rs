@@ -16,6 +16,10 @@ pub fn decorator_2022_03() -> impl VisitMut + Fold {
     as_folder(Decorator2022_03::default())
 }
 
+/// Added to the kind of a member if its decorators are pairs of `this` and a
+/// decorator.
+const DECORATORS_HAVE_THIS: usize = 16;
+
 #[derive(Default)]
 struct Decorator2022_03 {
     /// Variables without initializer.
@@ -30,6 +34,10 @@ struct Decorator2022_03 {
 
     rename_map: FxHashMap<Id, Id>,
 
+    /// `this` of decorators like `@obj.method`, by the variable of the
+    /// decorator.
+    decorator_this: FxHashMap<Id, Box<Expr>>,
+
     extra_exports: Vec<ExportSpecifier>,
 }
 
@@ -53,27 +61,134 @@ struct ClassState {
 
     class_lhs: Vec<Option<Pat>>,
     class_decorators: Vec<Option<ExprOrSpread>>,
+    /// `class_decorators` are pairs of `this` and a decorator.
+    class_decorators_have_this: bool,
 
     super_class: Option<Ident>,
 }
 
 impl Decorator2022_03 {
+    /// Returns the decorators, which are pairs of `this` and a decorator if
+    /// the second value is `true`.
     fn preserve_side_effect_of_decorators(
         &mut self,
         decorators: Vec<Decorator>,
-    ) -> Vec<Option<ExprOrSpread>> {
-        decorators
+    ) -> (Vec<Option<ExprOrSpread>>, bool) {
+        let decorators: Vec<_> = decorators
             .into_iter()
-            .map(|e| Some(self.preserve_side_effect_of_decorator(e.expr).as_arg()))
-            .collect()
+            .map(|e| self.preserve_side_effect_of_decorator(e.expr))
+            .collect();
+
+        let has_this = decorators.iter().any(|dec| match &**dec {
+            Expr::Ident(i) => self.decorator_this.contains_key(&i.to_id()),
+            _ => false,
+        });
+
+        if !has_this {
+            return (
+                decorators
+                    .into_iter()
+                    .map(|dec| Some(dec.as_arg()))
+                    .collect(),
+                false,
+            );
+        }
+
+        let decorators = decorators
+            .into_iter()
+            .flat_map(|dec| {
+                let this = match &*dec {
+                    Expr::Ident(i) => self.decorator_this.get(&i.to_id()).cloned(),
+                    _ => None,
+                };
+
+                [
+                    Some(this.unwrap_or_else(|| Expr::undefined(DUMMY_SP)).as_arg()),
+                    Some(dec.as_arg()),
+                ]
+            })
+            .collect();
+
+        (decorators, true)
+    }
+
+    /// Returns the decorators of a member and flags for its kind.
+    fn member_decorators(&mut self, decorators: Vec<Decorator>) -> (Option<ExprOrSpread>, usize) {
+        let (decorators, has_this) = self.preserve_side_effect_of_decorators(decorators);
+
+        if has_this {
+            (
+                Some(
+                    ArrayLit {
+                        span: DUMMY_SP,
+                        elems: decorators,
+                    }
+                    .as_arg(),
+                ),
+                DECORATORS_HAVE_THIS,
+            )
+        } else {
+            (merge_decorators(decorators), 0)
+        }
     }
 
-    fn preserve_side_effect_of_decorator(&mut self, dec: Box<Expr>) -> Box<Expr> {
+    fn preserve_side_effect_of_decorator(&mut self, mut dec: Box<Expr>) -> Box<Expr> {
         if dec.is_ident() || dec.is_arrow() || dec.is_fn_expr() {
             return dec;
         }
 
+        // `@obj.method` is called with `obj` as `this`, which is memoized because the
+        // decorators are applied in a static block of the class.
+        let this_arg: Option<Box<Expr>> = match dec.unwrap_parens_mut() {
+            Expr::Member(MemberExpr { obj, .. }) => Some(match &**obj {
+                Expr::Ident(..) => obj.clone(),
+                _ => {
+                    let alias = alias_ident_for(obj, "_obj");
+                    self.extra_vars.push(VarDeclarator {
+                        span: DUMMY_SP,
+                        name: alias.clone().into(),
+                        init: None,
+                        definite: false,
+                    });
+                    *obj = AssignExpr {
+                        span: DUMMY_SP,
+                        op: op!("="),
+                        left: alias.clone().into(),
+                        right: obj.take(),
+                    }
+                    .into();
+
+                    alias.into()
+                }
+            }),
+            Expr::SuperProp(..) => {
+                let alias = private_ident!("_this");
+                self.extra_vars.push(VarDeclarator {
+                    span: DUMMY_SP,
+                    name: alias.clone().into(),
+                    init: None,
+                    definite: false,
+                });
+                self.pre_class_inits.push(
+                    AssignExpr {
+                        span: DUMMY_SP,
+                        op: op!("="),
+                        left: alias.clone().into(),
+                        right: ThisExpr { span: DUMMY_SP }.into(),
+                    }
+                    .into(),
+                );
+
+                Some(alias.into())
+            }
+            _ => None,
+        };
+
         let ident = private_ident!("_dec");
+        if let Some(this_arg) = this_arg {
+            self.decorator_this.insert(ident.to_id(), this_arg);
+        }
+
         self.extra_vars.push(VarDeclarator {
             span: DUMMY_SP,
             name: ident.clone().into(),
@@ -163,6 +278,14 @@ impl Decorator2022_03 {
             combined_args.push(super_class.clone().as_arg());
         }
 
+        if self.state.class_decorators_have_this {
+            if self.state.super_class.is_none() {
+                combined_args.push(Expr::undefined(DUMMY_SP).as_arg());
+            }
+
+            combined_args.push(true.as_arg());
+        }
+
         let e_pat = if e_lhs.is_empty() {
             None
         } else {
@@ -431,8 +554,10 @@ impl Decorator2022_03 {
             definite: false,
         });
 
-        let decorators = self.preserve_side_effect_of_decorators(class.decorators.take());
+        let (decorators, has_this) =
+            self.preserve_side_effect_of_decorators(class.decorators.take());
         self.state.class_decorators.extend(decorators);
+        self.state.class_decorators_have_this = has_this;
         self.handle_super_class(class);
 
         {
@@ -461,7 +586,8 @@ impl Decorator2022_03 {
     fn handle_class_decl(&mut self, c: &mut ClassDecl) -> Stmt {
         let old_state = take(&mut self.state);
 
-        let decorators = self.preserve_side_effect_of_decorators(c.class.decorators.take());
+        let (decorators, has_this) =
+            self.preserve_side_effect_of_decorators(c.class.decorators.take());
 
         let init_class = private_ident!("_initClass");
 
@@ -491,6 +617,7 @@ impl Decorator2022_03 {
         self.state.class_lhs.push(Some(init_class.clone().into()));
 
         self.state.class_decorators.extend(decorators);
+        self.state.class_decorators_have_this = has_this;
         self.handle_super_class(&mut c.class);
 
         let mut body = c.class.body.take();
@@ -788,7 +915,7 @@ impl Decorator2022_03 {
                 ClassMember::PrivateProp(m) => {
                     self.process_decorators(&mut m.decorators);
                 }
-                ClassMember::AutoAccessor(m) => {
+                ClassMember::AutoAccessor(m) if !m.is_abstract => {
                     self.process_decorators(&mut m.decorators);
                 }
 
@@ -806,6 +933,19 @@ impl VisitMut for Decorator2022_03 {
 
         n.visit_mut_children_with(self);
 
+        // Metadata of decorated members should inherit from the metadata of the
+        // super class, even if the class itself is not decorated.
+        let has_own_super_class = self.state.super_class.is_none()
+            && n.super_class.is_some()
+            && (self.state.init_proto.is_some()
+                || self.state.init_static.is_some()
+                || !self.state.init_proto_args.is_empty()
+                || !self.state.init_static_args.is_empty());
+
+        if has_own_super_class {
+            self.handle_super_class(n);
+        }
+
         if !self.state.is_init_proto_called {
             if let Some(init_proto) = self.state.init_proto.clone() {
                 let c = self.ensure_constructor(n);
@@ -840,6 +980,9 @@ impl VisitMut for Decorator2022_03 {
 
         self.state.init_proto = None;
         self.state.is_init_proto_called = false;
+        if has_own_super_class {
+            self.state.super_class = None;
+        }
 
         self.state.extra_stmts = old_stmts;
     }
@@ -852,8 +995,7 @@ impl VisitMut for Decorator2022_03 {
                 return;
             }
 
-            let decorators = self.preserve_side_effect_of_decorators(p.function.decorators.take());
-            let dec = merge_decorators(decorators);
+            let (dec, flags) = self.member_decorators(p.function.decorators.take());
 
             let init = private_ident!(format!("_call_{}", p.key.name));
 
@@ -885,7 +1027,7 @@ impl VisitMut for Decorator2022_03 {
                     elems: vec![
                         dec,
                         Some(
-                            if p.is_static {
+                            (if p.is_static {
                                 match p.kind {
                                     MethodKind::Method => 7,
                                     MethodKind::Setter => 9,
@@ -897,8 +1039,8 @@ impl VisitMut for Decorator2022_03 {
                                     MethodKind::Setter => 4,
                                     MethodKind::Getter => 3,
                                 }
-                            }
-                            .as_arg(),
+                            } + flags)
+                                .as_arg(),
                         ),
                         Some(p.key.name.clone().as_arg()),
                         Some(caller.as_arg()),
@@ -990,7 +1132,8 @@ impl VisitMut for Decorator2022_03 {
 
         for mut m in members.take() {
             match m {
-                ClassMember::AutoAccessor(mut accessor) => {
+                // Abstract accessors are removed by the typescript transform.
+                ClassMember::AutoAccessor(mut accessor) if !accessor.is_abstract => {
                     accessor.value.visit_mut_with(self);
 
                     let name;
@@ -1143,9 +1286,7 @@ impl VisitMut for Decorator2022_03 {
                     };
 
                     if !accessor.decorators.is_empty() {
-                        let decorators =
-                            self.preserve_side_effect_of_decorators(accessor.decorators.take());
-                        let dec = merge_decorators(decorators);
+                        let (dec, flags) = self.member_decorators(accessor.decorators.take());
 
                         self.extra_vars.push(VarDeclarator {
                             span: accessor.span,
@@ -1169,11 +1310,10 @@ impl VisitMut for Decorator2022_03 {
                                     Key::Private(_) => {
                                         let data = vec![
                                             dec,
-                                            Some(if accessor.is_static {
-                                                6.as_arg()
-                                            } else {
-                                                1.as_arg()
-                                            }),
+                                            Some(
+                                                (if accessor.is_static { 6 } else { 1 } + flags)
+                                                    .as_arg(),
+                                            ),
                                             Some(name.as_arg()),
                                             Some(
                                                 FnExpr {
@@ -1269,11 +1409,10 @@ impl VisitMut for Decorator2022_03 {
                                     Key::Public(_) => {
                                         vec![
                                             dec,
-                                            Some(if accessor.is_static {
-                                                6.as_arg()
-                                            } else {
-                                                1.as_arg()
-                                            }),
+                                            Some(
+                                                (if accessor.is_static { 6 } else { 1 } + flags)
+                                                    .as_arg(),
+                                            ),
                                             Some(name.as_arg()),
                                         ]
                                     }
@@ -1410,8 +1549,7 @@ impl VisitMut for Decorator2022_03 {
             return;
         }
 
-        let decorators = self.preserve_side_effect_of_decorators(n.function.decorators.take());
-        let dec = merge_decorators(decorators);
+        let (dec, flags) = self.member_decorators(n.function.decorators.take());
 
         let (name, _init) = self.initializer_name(&mut n.key, "call");
 
@@ -1431,15 +1569,15 @@ impl VisitMut for Decorator2022_03 {
                 elems: vec![
                     dec,
                     Some(
-                        match (n.is_static, n.kind) {
+                        (match (n.is_static, n.kind) {
                             (true, MethodKind::Method) => 7,
                             (false, MethodKind::Method) => 2,
                             (true, MethodKind::Setter) => 9,
                             (false, MethodKind::Setter) => 4,
                             (true, MethodKind::Getter) => 8,
                             (false, MethodKind::Getter) => 3,
-                        }
-                        .as_arg(),
+                        } + flags)
+                            .as_arg(),
                     ),
                     Some(name.as_arg()),
                 ],
@@ -1464,8 +1602,7 @@ impl VisitMut for Decorator2022_03 {
             return;
         }
 
-        let decorators = self.preserve_side_effect_of_decorators(p.decorators.take());
-        let dec = merge_decorators(decorators);
+        let (dec, flags) = self.member_decorators(p.decorators.take());
 
         let (name, init) = self.initializer_name(&mut p.key, "init");
 
@@ -1495,7 +1632,7 @@ impl VisitMut for Decorator2022_03 {
                     span: DUMMY_SP,
                     elems: vec![
                         dec,
-                        Some(if p.is_static { 5.as_arg() } else { 0.as_arg() }),
+                        Some((if p.is_static { 5 } else { 0 } + flags).as_arg()),
                         Some(name.as_arg()),
                     ],
                 }
@@ -1668,8 +1805,7 @@ impl VisitMut for Decorator2022_03 {
             return;
         }
 
-        let decorators = self.preserve_side_effect_of_decorators(p.decorators.take());
-        let dec = merge_decorators(decorators);
+        let (dec, flags) = self.member_decorators(p.decorators.take());
 
         let init = private_ident!(format!("_init_{}", p.key.name));
 
@@ -1744,7 +1880,7 @@ impl VisitMut for Decorator2022_03 {
                 span: DUMMY_SP,
                 elems: vec![
                     dec,
-                    Some(if p.is_static { 5.as_arg() } else { 0.as_arg() }),
+                    Some((if p.is_static { 5 } else { 0 } + flags).as_arg()),
                     Some((&*p.key.name).as_arg()),
                     Some(
                         FnExpr {
@@ -1,17 +1,15 @@
-use std::ops::Deref;
+use std::mem;
 
 use swc_atoms::JsWord;
 use swc_common::{
-    collections::AHashMap,
+    collections::{AHashMap, AHashSet},
     util::{move_map::MoveMap, take::Take},
-    BytePos, Spanned, DUMMY_SP,
+    BytePos, DUMMY_SP,
 };
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::helper;
 use swc_ecma_utils::{quote_ident, ExprFactory};
-use swc_ecma_visit::{VisitMut, VisitMutWith};
-
-use super::EnumKind;
+use swc_ecma_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
 
 /// https://github.com/leonardfactory/babel-plugin-transform-typescript-metadata/blob/master/src/parameter/parameterVisitor.ts
 pub(super) struct ParamMetadata;
@@ -101,89 +99,254 @@ pub(super) fn remove_span(e: &mut Expr) {
     }
 }
 
-type EnumMapType = AHashMap<JsWord, EnumKind>;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EnumKind {
+    Mixed,
+    Str,
+    Num,
+}
 
-pub(super) struct EnumMap<'a>(&'a EnumMapType);
+#[derive(Default)]
+struct EnumInfo {
+    members: AHashMap<JsWord, EnumKind>,
+}
 
-impl Deref for EnumMap<'_> {
-    type Target = EnumMapType;
+impl EnumInfo {
+    fn kind(&self) -> EnumKind {
+        self.members
+            .values()
+            .copied()
+            .reduce(|a, b| if a == b { a } else { EnumKind::Mixed })
+            .unwrap_or(EnumKind::Num)
+    }
+}
+
+/// Declarations of the current module used to serialize type references like
+/// `tsc` does.
+#[derive(Default)]
+pub(super) struct TypeInfo {
+    classes: AHashSet<Id>,
+    enums: AHashMap<Id, EnumInfo>,
+    interfaces: AHashSet<Id>,
+    aliases: AHashMap<Id, Box<TsTypeAliasDecl>>,
+    type_only_imports: AHashSet<Id>,
+}
+
+impl Visit for TypeInfo {
+    fn visit_class_decl(&mut self, n: &ClassDecl) {
+        self.classes.insert(n.ident.to_id());
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_default_decl(&mut self, n: &DefaultDecl) {
+        if let DefaultDecl::Class(ClassExpr {
+            ident: Some(ident), ..
+        }) = n
+        {
+            self.classes.insert(ident.to_id());
+        }
 
-    fn deref(&self) -> &Self::Target {
-        self.0
+        n.visit_children_with(self);
+    }
+
+    fn visit_import_decl(&mut self, n: &ImportDecl) {
+        for specifier in &n.specifiers {
+            let is_type_only = n.type_only
+                || matches!(
+                    specifier,
+                    ImportSpecifier::Named(ImportNamedSpecifier {
+                        is_type_only: true,
+                        ..
+                    })
+                );
+
+            if is_type_only {
+                self.type_only_imports.insert(specifier.local().to_id());
+            }
+        }
+    }
+
+    fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl) {
+        let info = self.enums.entry(n.id.to_id()).or_default();
+
+        for member in &n.members {
+            let kind = enum_member_kind(&n.id, member.init.as_deref(), &info.members);
+
+            info.members.insert(member.id.as_ref().clone(), kind);
+        }
+    }
+
+    fn visit_ts_interface_decl(&mut self, n: &TsInterfaceDecl) {
+        self.interfaces.insert(n.id.to_id());
+    }
+
+    fn visit_ts_type(&mut self, _: &TsType) {}
+
+    fn visit_ts_type_alias_decl(&mut self, n: &TsTypeAliasDecl) {
+        self.aliases.insert(n.id.to_id(), Box::new(n.clone()));
     }
 }
 
-impl EnumMap<'_> {
-    fn get_kind_as_str(&self, param: Option<&TsTypeAnn>) -> Option<&'static str> {
-        param
-            .and_then(|t| t.type_ann.as_ts_type_ref())
-            .and_then(|t| t.type_name.as_ident())
-            .and_then(|t| self.get(&t.sym))
-            .map(|kind| match kind {
-                EnumKind::Mixed => "Object",
-                EnumKind::Str => "String",
-                EnumKind::Num => "Number",
-            })
+/// Members without constant values must be numbers, but references to other
+/// constants may be strings.
+fn enum_member_kind(
+    enum_id: &Ident,
+    init: Option<&Expr>,
+    members: &AHashMap<JsWord, EnumKind>,
+) -> EnumKind {
+    let init = match init {
+        Some(init) => init,
+        None => return EnumKind::Num,
+    };
+
+    match init {
+        Expr::Lit(Lit::Str(..)) | Expr::Tpl(..) => EnumKind::Str,
+        Expr::Lit(Lit::Num(..)) | Expr::Unary(..) => EnumKind::Num,
+        Expr::Paren(e) => enum_member_kind(enum_id, Some(&e.expr), members),
+        Expr::Bin(BinExpr {
+            op: op!(bin, "+"),
+            left,
+            right,
+            ..
+        }) => {
+            let left = enum_member_kind(enum_id, Some(left), members);
+            let right = enum_member_kind(enum_id, Some(right), members);
+
+            match (left, right) {
+                (EnumKind::Str, _) | (_, EnumKind::Str) => EnumKind::Str,
+                (EnumKind::Num, EnumKind::Num) => EnumKind::Num,
+                _ => EnumKind::Mixed,
+            }
+        }
+        Expr::Bin(..) => EnumKind::Num,
+        Expr::Ident(i) => members.get(&i.sym).copied().unwrap_or(EnumKind::Mixed),
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) if obj.is_ident_ref_to(&enum_id.sym) => {
+            members.get(&prop.sym).copied().unwrap_or(EnumKind::Mixed)
+        }
+        Expr::Member(..) => EnumKind::Mixed,
+        _ => EnumKind::Num,
     }
 }
 
+/// The runtime value a type is serialized to, as classified by `tsc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypeKind {
+    Void,
+    Boolean,
+    Number,
+    BigInt,
+    String,
+    Symbol,
+    Array,
+    Function,
+    Object,
+}
+
+impl From<EnumKind> for TypeKind {
+    fn from(kind: EnumKind) -> Self {
+        match kind {
+            EnumKind::Mixed => TypeKind::Object,
+            EnumKind::Str => TypeKind::String,
+            EnumKind::Num => TypeKind::Number,
+        }
+    }
+}
+
+impl TypeKind {
+    fn into_expr(self) -> Expr {
+        match self {
+            TypeKind::Void => *Expr::undefined(DUMMY_SP),
+            TypeKind::Boolean => quote_ident!("Boolean").into(),
+            TypeKind::Number => quote_ident!("Number").into(),
+            TypeKind::BigInt => check_global(quote_ident!("BigInt").into()),
+            TypeKind::String => quote_ident!("String").into(),
+            TypeKind::Symbol => quote_ident!("Symbol").into(),
+            TypeKind::Array => quote_ident!("Array").into(),
+            TypeKind::Function => quote_ident!("Function").into(),
+            TypeKind::Object => quote_ident!("Object").into(),
+        }
+    }
+}
+
+enum TypeRef {
+    /// A class declared in the current module.
+    Class,
+    Kind(TypeKind),
+    Unknown,
+}
+
 /// https://github.com/leonardfactory/babel-plugin-transform-typescript-metadata/blob/master/src/metadata/metadataVisitor.ts
 pub(super) struct Metadata<'a> {
-    pub(super) enums: EnumMap<'a>,
+    types: &'a TypeInfo,
 
-    pub(super) class_name: Option<&'a Ident>,
+    class_name: Option<&'a Ident>,
+
+    /// Type parameters in scope, which are serialized as `Object`.
+    type_params: Vec<Id>,
+
+    /// Type aliases being resolved, to break cycles.
+    aliases: Vec<Id>,
+
+    /// Unresolved references in branches of conditional types are serialized
+    /// as `Object`.
+    in_conditional_type: bool,
 }
 
 impl VisitMut for Metadata<'_> {
     fn visit_mut_class(&mut self, c: &mut Class) {
-        c.visit_mut_children_with(self);
+        let len = self.type_params.len();
+        self.type_params
+            .extend(type_param_ids(c.type_params.as_deref()));
 
-        if c.decorators.is_empty() {
-            return;
-        }
+        c.visit_mut_children_with(self);
 
         let constructor = c.body.iter().find_map(|m| match m {
             ClassMember::Constructor(c) => Some(c),
             _ => None,
         });
-        if constructor.is_none() {
-            return;
-        }
 
-        {
-            let dec = self
-                .create_metadata_design_decorator("design:type", quote_ident!("Function").as_arg());
-            c.decorators.push(dec);
-        }
-        {
-            let dec = self.create_metadata_design_decorator(
-                "design:paramtypes",
-                ArrayLit {
-                    span: DUMMY_SP,
-                    elems: constructor
-                        .as_ref()
-                        .unwrap()
-                        .params
-                        .iter()
-                        .map(|v| match v {
-                            ParamOrTsParamProp::TsParamProp(p) => {
-                                let ann = match &p.param {
-                                    TsParamPropParam::Ident(i) => i.type_ann.as_deref(),
-                                    TsParamPropParam::Assign(a) => get_type_ann_of_pat(&a.left),
-                                };
-                                Some(serialize_type(self.class_name, ann).as_arg())
-                            }
-                            ParamOrTsParamProp::Param(p) => Some(
-                                serialize_type(self.class_name, get_type_ann_of_pat(&p.pat))
-                                    .as_arg(),
-                            ),
-                        })
-                        .collect(),
-                }
-                .as_arg(),
-            );
-            c.decorators.push(dec);
+        if let (false, Some(constructor)) = (c.decorators.is_empty(), constructor) {
+            let param_types = constructor
+                .params
+                .iter()
+                .map(|v| match v {
+                    ParamOrTsParamProp::TsParamProp(p) => match &p.param {
+                        TsParamPropParam::Ident(i) => {
+                            self.serialize_type(i.type_ann.as_deref().map(|t| &*t.type_ann))
+                        }
+                        TsParamPropParam::Assign(a) => self.serialize_param(&a.left),
+                    },
+                    ParamOrTsParamProp::Param(p) => self.serialize_param(&p.pat),
+                })
+                .map(|v| Some(v.as_arg()))
+                .collect();
+
+            {
+                let dec = self.create_metadata_design_decorator(
+                    "design:type",
+                    quote_ident!("Function").as_arg(),
+                );
+                c.decorators.push(dec);
+            }
+            {
+                let dec = self.create_metadata_design_decorator(
+                    "design:paramtypes",
+                    ArrayLit {
+                        span: DUMMY_SP,
+                        elems: param_types,
+                    }
+                    .as_arg(),
+                );
+                c.decorators.push(dec);
+            }
         }
+
+        self.type_params.truncate(len);
     }
 
     fn visit_mut_class_method(&mut self, m: &mut ClassMethod) {
@@ -191,27 +354,30 @@ impl VisitMut for Metadata<'_> {
             return;
         }
 
+        let len = self.type_params.len();
+        self.type_params
+            .extend(type_param_ids(m.function.type_params.as_deref()));
+
         {
             let dec = self
                 .create_metadata_design_decorator("design:type", quote_ident!("Function").as_arg());
             m.function.decorators.push(dec);
         }
         {
+            let elems = m
+                .function
+                .params
+                .iter()
+                .enumerate()
+                .filter(|(idx, v)| *idx != 0 || !is_this_param(&v.pat))
+                .map(|(_, v)| Some(self.serialize_param(&v.pat).as_arg()))
+                .collect();
+
             let dec = self.create_metadata_design_decorator(
                 "design:paramtypes",
                 ArrayLit {
                     span: DUMMY_SP,
-                    elems: m
-                        .function
-                        .params
-                        .iter()
-                        .map(|v| {
-                            Some(
-                                serialize_type(self.class_name, get_type_ann_of_pat(&v.pat))
-                                    .as_arg(),
-                            )
-                        })
-                        .collect(),
+                    elems,
                 }
                 .as_arg(),
             );
@@ -220,22 +386,21 @@ impl VisitMut for Metadata<'_> {
         {
             // Copy tsc behaviour
             // https://github.com/microsoft/TypeScript/blob/5e8c261b6ab746213f19ee3501eb8c48a6215dd7/src/compiler/transformers/typeSerializer.ts#L242
-            let dec = self.create_metadata_design_decorator(
-                "design:returntype",
-                if m.function.is_async {
-                    quote_ident!("Promise").as_arg()
-                } else {
-                    let return_type = m.function.return_type.as_deref();
+            let return_type = if m.function.is_async {
+                quote_ident!("Promise").into()
+            } else {
+                match m.function.return_type.as_deref() {
+                    Some(ty) => self.serialize_type_node(&ty.type_ann),
+                    None => *Expr::undefined(DUMMY_SP),
+                }
+            };
 
-                    if let Some(kind) = self.enums.get_kind_as_str(return_type) {
-                        quote_ident!(kind).as_arg()
-                    } else {
-                        serialize_type(self.class_name, return_type).as_arg()
-                    }
-                },
-            );
+            let dec =
+                self.create_metadata_design_decorator("design:returntype", return_type.as_arg());
             m.function.decorators.push(dec);
         }
+
+        self.type_params.truncate(len);
     }
 
     fn visit_mut_class_prop(&mut self, p: &mut ClassProp) {
@@ -243,24 +408,21 @@ impl VisitMut for Metadata<'_> {
             return;
         }
 
-        let dec = self.create_metadata_design_decorator("design:type", {
-            let prop_type = p.type_ann.as_deref();
+        let prop_type = self.serialize_type(p.type_ann.as_deref().map(|t| &*t.type_ann));
 
-            if let Some(kind) = self.enums.get_kind_as_str(prop_type) {
-                quote_ident!(kind).as_arg()
-            } else {
-                serialize_type(self.class_name, prop_type).as_arg()
-            }
-        });
+        let dec = self.create_metadata_design_decorator("design:type", prop_type.as_arg());
         p.decorators.push(dec);
     }
 }
 
 impl<'a> Metadata<'a> {
-    pub(super) fn new(enums: &'a EnumMapType, class_name: Option<&'a Ident>) -> Self {
+    pub(super) fn new(types: &'a TypeInfo, class_name: Option<&'a Ident>) -> Self {
         Self {
-            enums: EnumMap(enums),
+            types,
             class_name,
+            type_params: Default::default(),
+            aliases: Default::default(),
+            in_conditional_type: false,
         }
     }
 
@@ -276,113 +438,147 @@ impl<'a> Metadata<'a> {
             .into(),
         }
     }
-}
 
-fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr {
-    fn check_object_existed(expr: Box<Expr>) -> Box<Expr> {
-        match *expr {
-            Expr::Member(ref member_expr) => {
-                let obj_expr = member_expr.obj.clone();
-                BinExpr {
-                    span: DUMMY_SP,
-                    left: check_object_existed(obj_expr),
-                    op: op!("||"),
-                    right: Box::new(
-                        BinExpr {
-                            span: DUMMY_SP,
-                            left: Box::new(Expr::Unary(UnaryExpr {
-                                span: DUMMY_SP,
-                                op: op!("typeof"),
-                                arg: expr,
-                            })),
-                            op: op!("==="),
-                            right: Box::new(Expr::Lit(Lit::Str(Str {
-                                span: DUMMY_SP,
-                                value: "undefined".into(),
-                                raw: None,
-                            }))),
-                        }
-                        .into(),
-                    ),
-                }
-                .into()
-            }
-            _ => BinExpr {
-                span: DUMMY_SP,
-                left: Box::new(
-                    UnaryExpr {
-                        span: DUMMY_SP,
-                        op: op!("typeof"),
-                        arg: expr,
-                    }
-                    .into(),
-                ),
-                op: op!("==="),
-                right: Box::new(
-                    Lit::Str(Str {
-                        span: DUMMY_SP,
-                        value: "undefined".into(),
-                        raw: None,
-                    })
-                    .into(),
-                ),
+    /// Rest parameters are serialized as their element type.
+    fn serialize_param(&mut self, p: &Pat) -> Expr {
+        match p {
+            Pat::Rest(p) => {
+                let elem_type = p.type_ann.as_deref().and_then(|t| match &*t.type_ann {
+                    TsType::TsArrayType(t) => Some(&*t.elem_type),
+                    TsType::TsTypeRef(TsTypeRef {
+                        type_params: Some(type_args),
+                        ..
+                    }) if type_args.params.len() == 1 => Some(&*type_args.params[0]),
+                    _ => None,
+                });
+
+                self.serialize_type(elem_type)
             }
-            .into(),
+            _ => self.serialize_type(get_type_ann_of_pat(p).map(|t| &*t.type_ann)),
         }
     }
 
-    fn serialize_type_ref(class_name: &str, ty: &TsTypeRef) -> Expr {
-        match &ty.type_name {
-            // We should omit references to self (class) since it will throw a ReferenceError at
-            // runtime due to babel transpile output.
-            TsEntityName::Ident(i) if &*i.sym == class_name => {
-                return quote_ident!("Object").into()
-            }
-            _ => {}
+    /// https://github.com/microsoft/TypeScript/blob/5e8c261b6ab746213f19ee3501eb8c48a6215dd7/src/compiler/transformers/typeSerializer.ts
+    fn serialize_type(&mut self, ty: Option<&TsType>) -> Expr {
+        match ty {
+            Some(ty) => self.serialize_type_node(ty),
+            None => quote_ident!("Object").into(),
         }
+    }
+
+    fn serialize_type_node(&mut self, ty: &TsType) -> Expr {
+        match ty {
+            TsType::TsKeywordType(TsKeywordType {
+                kind:
+                    TsKeywordTypeKind::TsVoidKeyword
+                    | TsKeywordTypeKind::TsUndefinedKeyword
+                    | TsKeywordTypeKind::TsNullKeyword
+                    | TsKeywordTypeKind::TsNeverKeyword,
+                ..
+            }) => *Expr::undefined(DUMMY_SP),
+
+            TsType::TsParenthesizedType(ty) => self.serialize_type_node(&ty.type_ann),
 
-        let member_expr = ts_entity_to_member_expr(&ty.type_name);
+            TsType::TsFnOrConstructorType(_) => quote_ident!("Function").into(),
 
-        // We don't know if type is just a type (interface, etc.) or a concrete value
-        // (class, etc.)
-        //
-        // `typeof` operator allows us to use the expression even if it is not defined,
-        // fallback is just `Object`.
+            TsType::TsArrayType(_) | TsType::TsTupleType(_) => quote_ident!("Array").into(),
 
-        CondExpr {
-            span: DUMMY_SP,
-            test: check_object_existed(Box::new(member_expr.clone())),
-            cons: Box::new(quote_ident!("Object").into()),
-            alt: Box::new(member_expr),
+            TsType::TsLitType(TsLitType {
+                lit: TsLit::Bool(..),
+                ..
+            })
+            | TsType::TsTypePredicate(_)
+            | TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsBooleanKeyword,
+                ..
+            }) => quote_ident!("Boolean").into(),
+
+            ty if is_str(ty) => quote_ident!("String").into(),
+
+            TsType::TsLitType(TsLitType {
+                lit: TsLit::Number(..),
+                ..
+            })
+            | TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsNumberKeyword,
+                ..
+            }) => quote_ident!("Number").into(),
+
+            TsType::TsLitType(TsLitType {
+                lit: TsLit::BigInt(..),
+                ..
+            })
+            | TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsBigIntKeyword,
+                ..
+            }) => TypeKind::BigInt.into_expr(),
+
+            TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsSymbolKeyword,
+                ..
+            }) => quote_ident!("Symbol").into(),
+
+            TsType::TsTypeOperator(TsTypeOperator {
+                op: TsTypeOperatorOp::ReadOnly,
+                type_ann,
+                ..
+            }) => self.serialize_type_node(type_ann),
+
+            TsType::TsUnionOrIntersectionType(ty) => match ty {
+                TsUnionOrIntersectionType::TsUnionType(ty) => {
+                    self.serialize_type_list(ty.types.iter().map(|ty| &**ty), false)
+                }
+                TsUnionOrIntersectionType::TsIntersectionType(ty) => {
+                    self.serialize_type_list(ty.types.iter().map(|ty| &**ty), true)
+                }
+            },
+
+            TsType::TsConditionalType(ty) => {
+                let in_conditional_type = mem::replace(&mut self.in_conditional_type, true);
+                let expr = self.serialize_type_list([&*ty.true_type, &*ty.false_type], false);
+                self.in_conditional_type = in_conditional_type;
+
+                expr
+            }
+
+            TsType::TsTypeRef(ty) => self.serialize_type_ref(ty),
+
+            _ => quote_ident!("Object").into(),
         }
-        .into()
     }
 
-    fn serialize_type_list(class_name: &str, types: &[Box<TsType>]) -> Expr {
+    fn serialize_type_list<'b>(
+        &mut self,
+        types: impl IntoIterator<Item = &'b TsType>,
+        is_intersection: bool,
+    ) -> Expr {
         let mut u = None;
         for ty in types {
-            // Skip parens if need be
-            let ty = match &**ty {
-                TsType::TsParenthesizedType(ty) => &ty.type_ann,
-                _ => ty,
-            };
-            match &**ty {
-                // Always elide `never` from the union/intersection if possible
+            match skip_parens(ty) {
+                // Reduce to `never` in an intersection, and elide `never` from a union
                 TsType::TsKeywordType(TsKeywordType {
                     kind: TsKeywordTypeKind::TsNeverKeyword,
                     ..
                 }) => {
+                    if is_intersection {
+                        return *Expr::undefined(DUMMY_SP);
+                    }
                     continue;
                 }
 
-                // Elide null and undefined from unions for metadata, just like what we did prior to
-                // the implementation of strict null checks
+                // Reduce to `unknown` in a union, and elide `unknown` from an intersection
                 TsType::TsKeywordType(TsKeywordType {
-                    kind: TsKeywordTypeKind::TsNullKeyword,
+                    kind: TsKeywordTypeKind::TsUnknownKeyword,
                     ..
-                })
-                | TsType::TsKeywordType(TsKeywordType {
-                    kind: TsKeywordTypeKind::TsUndefinedKeyword,
+                }) => {
+                    if !is_intersection {
+                        return quote_ident!("Object").into();
+                    }
+                    continue;
+                }
+
+                TsType::TsKeywordType(TsKeywordType {
+                    kind: TsKeywordTypeKind::TsAnyKeyword,
                     ..
                 }) => {
                     return quote_ident!("Object").into();
@@ -391,7 +587,7 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
                 _ => {}
             }
 
-            let item = serialize_type_node(class_name, ty);
+            let item = self.serialize_type_node(ty);
 
             // One of the individual is global object, return immediately
             if item.is_ident_ref_to("Object") {
@@ -422,125 +618,268 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
             }
         }
 
+        // Only hit if all constituents are `never`
         match u {
             Some(i) => i,
-            _ => quote_ident!("Object").into(),
+            _ => *Expr::undefined(DUMMY_SP),
         }
     }
 
-    fn serialize_type_node(class_name: &str, ty: &TsType) -> Expr {
-        let span = ty.span();
-        match ty {
-            TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsVoidKeyword,
-                ..
-            })
-            | TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsUndefinedKeyword,
-                ..
-            })
-            | TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsNullKeyword,
-                ..
-            })
-            | TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsNeverKeyword,
-                ..
-            }) => *Expr::undefined(span),
+    fn serialize_type_ref(&mut self, ty: &TsTypeRef) -> Expr {
+        match &ty.type_name {
+            // We should omit references to self (class) since it will throw a ReferenceError at
+            // runtime due to babel transpile output.
+            TsEntityName::Ident(i) if self.class_name.is_some_and(|c| c.sym == i.sym) => {
+                return quote_ident!("Object").into()
+            }
+            _ => {}
+        }
 
-            TsType::TsParenthesizedType(ty) => serialize_type_node(class_name, &ty.type_ann),
+        match self.resolve_type_ref(&ty.type_name) {
+            TypeRef::Class => ts_entity_to_member_expr(&ty.type_name),
+            TypeRef::Kind(kind) => kind.into_expr(),
+            TypeRef::Unknown if self.in_conditional_type => quote_ident!("Object").into(),
+            TypeRef::Unknown => {
+                let member_expr = ts_entity_to_member_expr(&ty.type_name);
 
-            TsType::TsFnOrConstructorType(_) => quote_ident!("Function").into(),
+                // We don't know if type is just a type (interface, etc.) or a concrete value
+                // (class, etc.)
+                //
+                // `typeof` operator allows us to use the expression even if it is not defined,
+                // fallback is just `Object`.
 
-            TsType::TsArrayType(_) | TsType::TsTupleType(_) => quote_ident!("Array").into(),
+                check_global(member_expr)
+            }
+        }
+    }
 
-            TsType::TsLitType(TsLitType {
-                lit: TsLit::Bool(..),
-                ..
-            })
-            | TsType::TsTypePredicate(_)
-            | TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsBooleanKeyword,
-                ..
-            }) => quote_ident!("Boolean").into(),
+    fn resolve_type_ref(&mut self, type_name: &TsEntityName) -> TypeRef {
+        match type_name {
+            TsEntityName::Ident(i) => {
+                let id = i.to_id();
+
+                if self.type_params.contains(&id) {
+                    TypeRef::Kind(TypeKind::Object)
+                } else if self.types.classes.contains(&id) {
+                    TypeRef::Class
+                } else if let Some(info) = self.types.enums.get(&id) {
+                    TypeRef::Kind(info.kind().into())
+                } else if self.types.aliases.contains_key(&id) {
+                    TypeRef::Kind(self.alias_kind(id))
+                } else if self.types.interfaces.contains(&id)
+                    || self.types.type_only_imports.contains(&id)
+                {
+                    TypeRef::Kind(TypeKind::Object)
+                } else {
+                    TypeRef::Unknown
+                }
+            }
+            TsEntityName::TsQualifiedName(q) => {
+                if let TsEntityName::Ident(left) = &q.left {
+                    if let Some(info) = self.types.enums.get(&left.to_id()) {
+                        return TypeRef::Kind(
+                            info.members
+                                .get(&q.right.sym)
+                                .map_or(TypeKind::Object, |&kind| kind.into()),
+                        );
+                    }
+                }
 
-            ty if is_str(ty) => quote_ident!("String").into(),
+                let mut root = &q.left;
+                while let TsEntityName::TsQualifiedName(q) = root {
+                    root = &q.left;
+                }
 
-            TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsObjectKeyword,
-                ..
-            }) => quote_ident!("Object").into(),
+                match root {
+                    TsEntityName::Ident(i) if self.types.type_only_imports.contains(&i.to_id()) => {
+                        TypeRef::Kind(TypeKind::Object)
+                    }
+                    _ => TypeRef::Unknown,
+                }
+            }
+        }
+    }
 
-            TsType::TsLitType(TsLitType {
-                lit: TsLit::Number(..),
-                ..
-            })
-            | TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsNumberKeyword,
-                ..
-            }) => quote_ident!("Number").into(),
+    /// Type aliases are classified by the type they resolve to, so classes
+    /// behind an alias are serialized as `Object`.
+    fn alias_kind(&mut self, id: Id) -> TypeKind {
+        let types = self.types;
+        let alias = &types.aliases[&id];
 
-            TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsBigIntKeyword,
-                ..
-            }) => CondExpr {
-                span: DUMMY_SP,
-                test: check_object_existed(quote_ident!("BigInt").into()),
-                cons: quote_ident!("Object").into(),
-                alt: quote_ident!("BigInt").into(),
-            }
-            .into(),
+        if self.aliases.contains(&id) {
+            return TypeKind::Object;
+        }
 
-            TsType::TsLitType(ty) => {
-                // TODO: Proper error reporting
-                panic!("Bad type for decoration: {:?}", ty);
-            }
+        let len = self.type_params.len();
+        self.type_params
+            .extend(type_param_ids(alias.type_params.as_deref()));
+        self.aliases.push(id);
 
-            TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsSymbolKeyword,
-                ..
-            }) => quote_ident!("Symbol").into(),
+        let kind = self.type_kind(&alias.type_ann);
 
-            TsType::TsTypeQuery(_)
-            | TsType::TsTypeOperator(_)
-            | TsType::TsIndexedAccessType(_)
-            | TsType::TsTypeLit(_)
-            | TsType::TsMappedType(_)
-            | TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsAnyKeyword,
-                ..
-            })
-            | TsType::TsKeywordType(TsKeywordType {
-                kind: TsKeywordTypeKind::TsUnknownKeyword,
-                ..
-            })
-            | TsType::TsThisType(..) => quote_ident!("Object").into(),
+        self.aliases.pop();
+        self.type_params.truncate(len);
 
-            TsType::TsUnionOrIntersectionType(ty) => match ty {
-                TsUnionOrIntersectionType::TsUnionType(ty) => {
-                    serialize_type_list(class_name, &ty.types)
+        kind
+    }
+
+    fn type_kind(&mut self, ty: &TsType) -> TypeKind {
+        match ty {
+            TsType::TsKeywordType(ty) => match ty.kind {
+                TsKeywordTypeKind::TsVoidKeyword
+                | TsKeywordTypeKind::TsUndefinedKeyword
+                | TsKeywordTypeKind::TsNullKeyword
+                | TsKeywordTypeKind::TsNeverKeyword => TypeKind::Void,
+                TsKeywordTypeKind::TsBooleanKeyword => TypeKind::Boolean,
+                TsKeywordTypeKind::TsNumberKeyword => TypeKind::Number,
+                TsKeywordTypeKind::TsBigIntKeyword => TypeKind::BigInt,
+                TsKeywordTypeKind::TsStringKeyword => TypeKind::String,
+                TsKeywordTypeKind::TsSymbolKeyword => TypeKind::Symbol,
+                _ => TypeKind::Object,
+            },
+
+            TsType::TsLitType(ty) => match ty.lit {
+                TsLit::Bool(..) => TypeKind::Boolean,
+                TsLit::Number(..) => TypeKind::Number,
+                TsLit::BigInt(..) => TypeKind::BigInt,
+                TsLit::Str(..) | TsLit::Tpl(..) => TypeKind::String,
+            },
+
+            TsType::TsParenthesizedType(ty) => self.type_kind(&ty.type_ann),
+
+            TsType::TsArrayType(_) | TsType::TsTupleType(_) => TypeKind::Array,
+
+            TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(_)) => TypeKind::Function,
+
+            TsType::TsTypePredicate(_) => TypeKind::Boolean,
+
+            TsType::TsTypeOperator(ty) => match ty.op {
+                TsTypeOperatorOp::ReadOnly => self.type_kind(&ty.type_ann),
+                TsTypeOperatorOp::Unique => TypeKind::Symbol,
+                TsTypeOperatorOp::KeyOf => TypeKind::Object,
+            },
+
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(ty)) => {
+                let mut kind = None;
+
+                for ty in &ty.types {
+                    if is_never(ty) {
+                        continue;
+                    }
+
+                    let item = self.type_kind(ty);
+
+                    match kind {
+                        None => kind = Some(item),
+                        Some(prev) if prev != item => return TypeKind::Object,
+                        _ => {}
+                    }
                 }
-                TsUnionOrIntersectionType::TsIntersectionType(ty) => {
-                    serialize_type_list(class_name, &ty.types)
+
+                kind.unwrap_or(TypeKind::Void)
+            }
+
+            TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+                ty,
+            )) => {
+                let mut kind = TypeKind::Object;
+
+                for ty in &ty.types {
+                    match self.type_kind(ty) {
+                        TypeKind::Void => return TypeKind::Void,
+                        item @ (TypeKind::Boolean
+                        | TypeKind::Number
+                        | TypeKind::BigInt
+                        | TypeKind::String
+                        | TypeKind::Symbol)
+                            if kind == TypeKind::Object =>
+                        {
+                            kind = item
+                        }
+                        _ => {}
+                    }
                 }
-            },
 
-            TsType::TsConditionalType(ty) => {
-                serialize_type_list(class_name, &[ty.true_type.clone(), ty.false_type.clone()])
+                kind
             }
 
-            TsType::TsTypeRef(ty) => serialize_type_ref(class_name, ty),
+            TsType::TsTypeRef(ty) => match self.resolve_type_ref(&ty.type_name) {
+                TypeRef::Class => TypeKind::Object,
+                TypeRef::Kind(kind) => kind,
+                TypeRef::Unknown => match &ty.type_name {
+                    TsEntityName::Ident(i) if &*i.sym == "Array" || &*i.sym == "ReadonlyArray" => {
+                        TypeKind::Array
+                    }
+                    _ => TypeKind::Object,
+                },
+            },
 
-            _ => panic!("Bad type for decorator: {:?}", ty),
+            _ => TypeKind::Object,
         }
     }
+}
 
-    let param = match param {
-        Some(v) => &v.type_ann,
-        None => return *Expr::undefined(DUMMY_SP),
-    };
+/// Creates `typeof expr === "undefined" ? Object : expr`.
+fn check_global(expr: Expr) -> Expr {
+    fn check_object_existed(expr: Box<Expr>) -> Box<Expr> {
+        match *expr {
+            Expr::Member(ref member_expr) => {
+                let obj_expr = member_expr.obj.clone();
+                BinExpr {
+                    span: DUMMY_SP,
+                    left: check_object_existed(obj_expr),
+                    op: op!("||"),
+                    right: Box::new(
+                        BinExpr {
+                            span: DUMMY_SP,
+                            left: Box::new(Expr::Unary(UnaryExpr {
+                                span: DUMMY_SP,
+                                op: op!("typeof"),
+                                arg: expr,
+                            })),
+                            op: op!("==="),
+                            right: Box::new(Expr::Lit(Lit::Str(Str {
+                                span: DUMMY_SP,
+                                value: "undefined".into(),
+                                raw: None,
+                            }))),
+                        }
+                        .into(),
+                    ),
+                }
+                .into()
+            }
+            _ => BinExpr {
+                span: DUMMY_SP,
+                left: Box::new(
+                    UnaryExpr {
+                        span: DUMMY_SP,
+                        op: op!("typeof"),
+                        arg: expr,
+                    }
+                    .into(),
+                ),
+                op: op!("==="),
+                right: Box::new(
+                    Lit::Str(Str {
+                        span: DUMMY_SP,
+                        value: "undefined".into(),
+                        raw: None,
+                    })
+                    .into(),
+                ),
+            }
+            .into(),
+        }
+    }
 
-    serialize_type_node(class_name.map(|v| &*v.sym).unwrap_or(""), param)
+    CondExpr {
+        span: DUMMY_SP,
+        test: check_object_existed(Box::new(expr.clone())),
+        cons: Box::new(quote_ident!("Object").into()),
+        alt: Box::new(expr),
+    }
+    .into()
 }
 
 fn ts_entity_to_member_expr(type_name: &TsEntityName) -> Expr {
@@ -559,6 +898,12 @@ fn ts_entity_to_member_expr(type_name: &TsEntityName) -> Expr {
     }
 }
 
+fn type_param_ids(type_params: Option<&TsTypeParamDecl>) -> impl Iterator<Item = Id> + '_ {
+    type_params
+        .into_iter()
+        .flat_map(|decl| decl.params.iter().map(|param| param.name.to_id()))
+}
+
 fn get_type_ann_of_pat(p: &Pat) -> Option<&TsTypeAnn> {
     match p {
         Pat::Ident(p) => p.type_ann.as_deref(),
@@ -573,6 +918,28 @@ fn get_type_ann_of_pat(p: &Pat) -> Option<&TsTypeAnn> {
     }
 }
 
+fn is_this_param(p: &Pat) -> bool {
+    matches!(p, Pat::Ident(i) if &*i.id.sym == "this")
+}
+
+fn skip_parens(mut ty: &TsType) -> &TsType {
+    while let TsType::TsParenthesizedType(p) = ty {
+        ty = &p.type_ann;
+    }
+
+    ty
+}
+
+fn is_never(ty: &TsType) -> bool {
+    matches!(
+        skip_parens(ty),
+        TsType::TsKeywordType(TsKeywordType {
+            kind: TsKeywordTypeKind::TsNeverKeyword,
+            ..
+        })
+    )
+}
+
 fn is_str(ty: &TsType) -> bool {
     match ty {
         TsType::TsLitType(TsLitType {
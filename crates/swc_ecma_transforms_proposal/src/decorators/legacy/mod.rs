@@ -1,29 +1,21 @@
 use std::{iter, mem};
 
 use metadata::remove_span;
-use swc_atoms::JsWord;
-use swc_common::{collections::AHashMap, util::take::Take, BytePos, DUMMY_SP};
+use swc_common::{util::take::Take, BytePos, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::helper;
 use swc_ecma_utils::{private_ident, prop_name_to_expr_value, quote_ident, ExprFactory, StmtLike};
-use swc_ecma_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+use swc_ecma_visit::{VisitMut, VisitMutWith, VisitWith};
 
-use self::metadata::{Metadata, ParamMetadata};
+use self::metadata::{Metadata, ParamMetadata, TypeInfo};
 use super::contains_decorator;
 
 mod metadata;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum EnumKind {
-    Mixed,
-    Str,
-    Num,
-}
-
 pub(super) fn new(metadata: bool) -> TscDecorator {
     TscDecorator {
         metadata,
-        enums: Default::default(),
+        types: Default::default(),
         vars: Default::default(),
         appended_exprs: Default::default(),
         prepended_exprs: Default::default(),
@@ -36,7 +28,7 @@ pub(super) fn new(metadata: bool) -> TscDecorator {
 pub(super) struct TscDecorator {
     metadata: bool,
 
-    enums: AHashMap<JsWord, EnumKind>,
+    types: TypeInfo,
 
     /// Used for computed keys, and this variables are not initialized.
     vars: Vec<VarDeclarator>,
@@ -192,47 +184,6 @@ impl TscDecorator {
     }
 }
 
-impl Visit for TscDecorator {
-    fn visit_ts_enum_decl(&mut self, e: &TsEnumDecl) {
-        let enum_kind = e
-            .members
-            .iter()
-            .map(|member| member.init.as_ref())
-            .map(|init| match init {
-                Some(e) => match &**e {
-                    Expr::Lit(lit) => match lit {
-                        Lit::Str(_) => EnumKind::Str,
-                        Lit::Num(_) => EnumKind::Num,
-                        _ => EnumKind::Mixed,
-                    },
-                    _ => EnumKind::Mixed,
-                },
-                None => EnumKind::Num,
-            })
-            .fold(None, |opt: Option<EnumKind>, item| {
-                //
-                let a = match item {
-                    EnumKind::Mixed => return Some(EnumKind::Mixed),
-                    _ => item,
-                };
-
-                let b = match opt {
-                    Some(EnumKind::Mixed) => return Some(EnumKind::Mixed),
-                    Some(v) => v,
-                    None => return Some(item),
-                };
-                if a == b {
-                    Some(a)
-                } else {
-                    Some(EnumKind::Mixed)
-                }
-            });
-        if let Some(kind) = enum_kind {
-            self.enums.insert(e.id.sym.clone(), kind);
-        }
-    }
-}
-
 impl VisitMut for TscDecorator {
     fn visit_mut_class(&mut self, n: &mut Class) {
         n.visit_mut_with(&mut ParamMetadata);
@@ -240,7 +191,7 @@ impl VisitMut for TscDecorator {
         if self.metadata {
             let i = self.class_name.clone();
 
-            n.visit_mut_with(&mut Metadata::new(&self.enums, i.as_ref()));
+            n.visit_mut_with(&mut Metadata::new(&self.types, i.as_ref()));
         }
 
         n.visit_mut_children_with(self);
@@ -397,7 +348,7 @@ impl VisitMut for TscDecorator {
     }
 
     fn visit_mut_module(&mut self, n: &mut Module) {
-        n.visit_with(self);
+        n.visit_with(&mut self.types);
 
         n.visit_mut_children_with(self);
     }
@@ -407,7 +358,7 @@ impl VisitMut for TscDecorator {
     }
 
     fn visit_mut_script(&mut self, n: &mut Script) {
-        n.visit_with(self);
+        n.visit_with(&mut self.types);
 
         n.visit_mut_children_with(self);
     }
@@ -32,6 +32,7 @@ fn syntax_default_ts() -> Syntax {
 }
 
 #[testing::fixture("tests/decorators/**/exec.js")]
+#[testing::fixture("tests/decorators/**/exec.ts")]
 fn exec(input: PathBuf) {
     exec_inner(input)
 }
@@ -117,7 +118,13 @@ fn create_pass(comments: Rc<SingleThreadedComments>, input: &Path) -> Box<dyn Fo
     let unresolved_mark = Mark::new();
     let top_level_mark = Mark::new();
 
-    let mut pass: Box<dyn Fold> = Box::new(resolver(unresolved_mark, top_level_mark, false));
+    let is_typescript = options_json
+        .plugins
+        .iter()
+        .any(|plugin| matches!(plugin, BabelPluginEntry::NameOnly(name) if name == "transform-typescript"));
+
+    let mut pass: Box<dyn Fold> =
+        Box::new(resolver(unresolved_mark, top_level_mark, is_typescript));
 
     macro_rules! add {
         ($e:expr) => {{
@@ -157,6 +164,15 @@ fn create_pass(comments: Rc<SingleThreadedComments>, input: &Path) -> Box<dyn Fo
                     ));
                     continue;
                 }
+
+                "transform-typescript" => {
+                    add!(swc_ecma_transforms_typescript::typescript(
+                        Default::default(),
+                        unresolved_mark,
+                        top_level_mark
+                    ));
+                    continue;
+                }
                 _ => {}
             },
             BabelPluginEntry::WithConfig(name, config) => match &**name {
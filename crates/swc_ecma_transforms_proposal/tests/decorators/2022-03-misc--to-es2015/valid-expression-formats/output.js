@@ -6,9 +6,10 @@ var _a = /*#__PURE__*/ new WeakMap();
 class Foo {
     method() {}
     makeClass() {
+        var _ref;
         var _Nested, __;
-        var _dec, _init_bar;
-        _dec = _class_private_field_get(this, _a);
+        var _this, _dec, _init_bar;
+        _dec = _class_private_field_get(_ref = _this = this, _a);
         return _Nested = class Nested {
             constructor(){
                 _define_property(this, "bar", _init_bar(this));
@@ -17,8 +18,11 @@ class Foo {
             writable: true,
             value: { e: [_init_bar] } = _apply_decs_2203_r(_Nested, [
                 [
-                    _dec,
-                    0,
+                    [
+                        _this,
+                        _dec
+                    ],
+                    16,
                     "bar"
                 ]
             ], [])
@@ -37,22 +41,32 @@ var __ = {
     value: { e: [_initProto], c: [_Foo, _initClass] } = _apply_decs_2203_r(Foo, [
         [
             [
+                void 0,
                 dec,
+                void 0,
                 _dec4,
+                void 0,
                 _dec5,
+                void 0,
                 _dec6,
+                array,
                 _dec7
             ],
-            2,
+            18,
             "method"
         ]
     ], [
+        void 0,
         dec,
+        void 0,
         _dec,
+        void 0,
         _dec1,
+        void 0,
         _dec2,
+        array,
         _dec3
-    ])
+    ], void 0, true)
 };
 var __2 = {
     writable: true,
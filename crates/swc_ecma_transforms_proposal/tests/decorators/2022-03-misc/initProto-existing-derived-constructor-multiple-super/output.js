@@ -1,14 +1,14 @@
-var _initProto, _initProto1;
+var _B, _initProto, _B1, _initProto1;
 const dec = ()=>{};
-class A extends B {
+class A extends (_B = B) {
     static{
-        ({ e: [_initProto]  } = _apply_decs_2203_r(this, [
+        ({ e: [_initProto] } = _apply_decs_2203_r(this, [
             [
                 deco,
                 2,
                 "method"
             ]
-        ], []));
+        ], [], _B));
     }
     constructor(){
         if (Math.random() > 0.5) {
@@ -21,15 +21,15 @@ class A extends B {
     }
     method() {}
 }
-class C extends B {
+class C extends (_B1 = B) {
     static{
-        ({ e: [_initProto1]  } = _apply_decs_2203_r(this, [
+        ({ e: [_initProto1] } = _apply_decs_2203_r(this, [
             [
                 deco,
                 2,
                 "method"
             ]
-        ], []));
+        ], [], _B1));
     }
     constructor(){
         try {
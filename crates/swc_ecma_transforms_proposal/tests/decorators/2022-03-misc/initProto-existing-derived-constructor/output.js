@@ -1,14 +1,14 @@
-var _initProto;
+var _B, _initProto;
 const dec = ()=>{};
-class A extends B {
+class A extends (_B = B) {
     static{
-        ({ e: [_initProto]  } = _apply_decs_2203_r(this, [
+        ({ e: [_initProto] } = _apply_decs_2203_r(this, [
             [
                 dec,
                 2,
                 "method"
             ]
-        ], []));
+        ], [], _B));
     }
     constructor(){
         let a = 2;
@@ -1,6 +1,6 @@
-var _call_x, _initProto;
+var _call_x, _Bar, _initProto;
 const dec = ()=>{};
-class Foo extends Bar {
+class Foo extends (_Bar = Bar) {
     static{
         ({ e: [_call_x, _initProto] } = _apply_decs_2203_r(this, [
             [
@@ -11,7 +11,7 @@ class Foo extends Bar {
                     return super.foo();
                 }
             ]
-        ], []));
+        ], [], _Bar));
     }
     constructor(...args){
         super(...args);
@@ -7,22 +7,32 @@ class Foo {
         ({ e: [_initProto], c: [_Foo, _initClass] } = _apply_decs_2203_r(this, [
             [
                 [
+                    void 0,
                     dec,
+                    void 0,
                     _dec4,
+                    void 0,
                     _dec5,
+                    void 0,
                     _dec6,
+                    array,
                     _dec7
                 ],
-                2,
+                18,
                 "method"
             ]
         ], [
+            void 0,
             dec,
+            void 0,
             _dec,
+            void 0,
             _dec1,
+            void 0,
             _dec2,
+            array,
             _dec3
-        ]));
+        ], void 0, true));
     }
     constructor(){
         _initProto(this);
@@ -30,14 +40,17 @@ class Foo {
     #a;
     method() {}
     makeClass() {
-        var _dec, _init_bar;
-        _dec = this.#a;
+        var _this, _dec, _init_bar;
+        _dec = (_this = this).#a;
         return class Nested {
             static{
                 ({ e: [_init_bar] } = _apply_decs_2203_r(this, [
                     [
-                        _dec,
-                        0,
+                        [
+                            _this,
+                            _dec
+                        ],
+                        16,
                         "bar"
                     ]
                 ], []));
@@ -0,0 +1,65 @@
+const log: string[] = [];
+
+function dec<This, V>(
+  target: ClassAccessorDecoratorTarget<This, V>,
+  ctx: ClassAccessorDecoratorContext<This, V>,
+): ClassAccessorDecoratorResult<This, V> {
+  return {
+    get() {
+      log.push(`get ${String(ctx.name)}`);
+      return target.get.call(this);
+    },
+    set(v) {
+      log.push(`set ${String(ctx.name)}`);
+      target.set.call(this, v);
+    },
+  };
+}
+
+abstract class Base {
+  abstract accessor a: number;
+  accessor b = 1;
+  declare c: number;
+}
+
+class Derived extends Base {
+  @dec override accessor a = 2;
+  @dec override accessor b = 3;
+  @dec private accessor d = 4;
+  @dec protected static accessor e = 5;
+  @dec accessor #f = 6;
+
+  getD() {
+    return this.d;
+  }
+
+  static getE() {
+    return this.e;
+  }
+
+  getF() {
+    return this.#f;
+  }
+}
+
+expect(Object.getOwnPropertyDescriptor(Base.prototype, "a")).toBeUndefined();
+
+const d = new Derived();
+expect(Object.hasOwn(d, "c")).toBe(false);
+expect(d.a).toBe(2);
+expect(d.b).toBe(3);
+expect(d.getD()).toBe(4);
+expect(Derived.getE()).toBe(5);
+expect(d.getF()).toBe(6);
+
+d.a = 10;
+expect(d.a).toBe(10);
+expect(log).toEqual([
+  "get a",
+  "get b",
+  "get d",
+  "get e",
+  "get #f",
+  "set a",
+  "get a",
+]);
@@ -0,0 +1,38 @@
+// Ported from the `esDecorators-classDeclaration-accessors-nonStatic`
+// conformance test of TypeScript.
+const calls: unknown[] = [];
+
+const dec =
+  (id: number) =>
+  (_: Function, context: ClassGetterDecoratorContext | ClassSetterDecoratorContext) => {
+    calls.push([id, context.kind, context.name, context.static, context.private]);
+  };
+
+const method3 = "method3";
+let value: unknown;
+
+class C {
+  @dec(11) get method1() { return 1; }
+  @dec(12) set method1(v) { value = [1, v]; }
+  @dec(21) get ["method2"]() { return 2; }
+  @dec(22) set ["method2"](v) { value = [2, v]; }
+  @dec(31) get [method3]() { return 3; }
+  @dec(32) set [method3](v) { value = [3, v]; }
+}
+
+expect(calls).toEqual([
+  [11, "getter", "method1", false, false],
+  [12, "setter", "method1", false, false],
+  [21, "getter", "method2", false, false],
+  [22, "setter", "method2", false, false],
+  [31, "getter", "method3", false, false],
+  [32, "setter", "method3", false, false],
+]);
+
+const c = new C();
+
+expect(c.method1).toBe(1);
+expect(c.method2).toBe(2);
+expect(c.method3).toBe(3);
+c.method3 = "a";
+expect(value).toEqual([3, "a"]);
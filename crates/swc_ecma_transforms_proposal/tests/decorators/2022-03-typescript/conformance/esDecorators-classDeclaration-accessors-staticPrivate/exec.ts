@@ -0,0 +1,45 @@
+// Ported from the `esDecorators-classDeclaration-accessors-staticPrivate`
+// conformance test of TypeScript.
+const calls: unknown[] = [];
+const accesses: any[] = [];
+let stored: unknown;
+
+const dec =
+  (id: number) =>
+  (_: Function, context: ClassGetterDecoratorContext | ClassSetterDecoratorContext) => {
+    calls.push([id, context.kind, context.name, context.static, context.private]);
+    accesses.push(context.access);
+  };
+
+class C {
+  @dec(1) static get #method1() { return 0; }
+  @dec(2) static set #method1(value) { stored = value; }
+}
+
+expect(calls).toEqual([
+  [1, "getter", "#method1", true, true],
+  [2, "setter", "#method1", true, true],
+]);
+// The 2022-03 version calls `access` with the object as `this`
+expect(accesses[0].get.call(C)).toBe(0);
+accesses[1].set.call(C, 2);
+expect(stored).toBe(2);
+
+const classDec = (_: Function, context: ClassDecoratorContext) => {
+  calls.push([context.kind, context.name]);
+};
+let read: unknown;
+
+@classDec
+class D {
+  static get #method1() { return 0; }
+  static set #method1(value) { stored = value; }
+  static {
+    read = this.#method1;
+    this.#method1 = 1;
+  }
+}
+
+expect(calls[2]).toEqual(["class", "D"]);
+expect(read).toBe(0);
+expect(stored).toBe(1);
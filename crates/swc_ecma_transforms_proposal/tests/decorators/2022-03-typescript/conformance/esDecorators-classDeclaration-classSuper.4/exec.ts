@@ -0,0 +1,27 @@
+// Ported from the `esDecorators-classDeclaration-classSuper.4` conformance
+// test of TypeScript.
+class Base {
+  static method(..._args: any[]) {
+    return this;
+  }
+}
+
+const dec = (_: Function, __: ClassDecoratorContext) => {};
+const method = "method";
+
+@dec
+class C extends Base {
+  static a = super.method();
+  static b = super["method"]();
+  static c = super[method]();
+  static d = super.method``;
+  static e = super["method"]``;
+  static f = super[method]``;
+}
+
+expect(C.a).toBe(C);
+expect(C.b).toBe(C);
+expect(C.c).toBe(C);
+expect(C.d).toBe(C);
+expect(C.e).toBe(C);
+expect(C.f).toBe(C);
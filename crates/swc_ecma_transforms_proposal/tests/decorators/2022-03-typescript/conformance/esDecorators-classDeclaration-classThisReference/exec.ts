@@ -0,0 +1,25 @@
+// Ported from the `esDecorators-classDeclaration-classThisReference`
+// conformance test of TypeScript.
+let decorated: unknown;
+
+const dec = (target: Function, _: ClassDecoratorContext) => {
+  decorated = target;
+};
+
+let fromBlock: unknown;
+
+@dec
+class C {
+  static { fromBlock = this; }
+  static x: any = this;
+  static accessor a: any = this;
+  static m() { return this; }
+  static get g() { return this; }
+}
+
+expect(decorated).toBe(C);
+expect(fromBlock).toBe(C);
+expect(C.x).toBe(C);
+expect(C.a).toBe(C);
+expect(C.m()).toBe(C);
+expect(C.g).toBe(C);
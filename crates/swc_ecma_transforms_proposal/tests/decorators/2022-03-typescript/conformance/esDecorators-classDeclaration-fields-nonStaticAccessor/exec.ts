@@ -0,0 +1,37 @@
+// Ported from the `esDecorators-classDeclaration-fields-nonStaticAccessor`
+// conformance test of TypeScript.
+const calls: unknown[] = [];
+
+const dec =
+  (id: number) =>
+  (_: unknown, context: ClassAccessorDecoratorContext) => {
+    calls.push([id, context.kind, context.name, context.static, context.private]);
+
+    return {
+      init: (value: number) => value * 10,
+    };
+  };
+
+const field3 = "field3";
+
+class C {
+  @dec(1) accessor field1 = 1;
+  @dec(2) accessor ["field2"] = 2;
+  @dec(3) accessor [field3] = 3;
+}
+
+expect(calls).toEqual([
+  [1, "accessor", "field1", false, false],
+  [2, "accessor", "field2", false, false],
+  [3, "accessor", "field3", false, false],
+]);
+
+const c = new C();
+
+expect(c.field1).toBe(10);
+expect(c.field2).toBe(20);
+expect(c.field3).toBe(30);
+c.field1 = 4;
+expect(c.field1).toBe(4);
+expect(Object.keys(c)).toEqual([]);
+expect(typeof Object.getOwnPropertyDescriptor(C.prototype, "field3")!.get).toBe("function");
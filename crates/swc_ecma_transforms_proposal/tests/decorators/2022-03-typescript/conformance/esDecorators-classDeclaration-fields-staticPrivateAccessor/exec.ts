@@ -0,0 +1,41 @@
+// Ported from the `esDecorators-classDeclaration-fields-staticPrivateAccessor`
+// conformance test of TypeScript.
+let access: any;
+
+const dec = (_: unknown, context: ClassAccessorDecoratorContext) => {
+  expect(context.kind).toBe("accessor");
+  expect(context.name).toBe("#field1");
+  expect(context.static).toBe(true);
+  expect(context.private).toBe(true);
+  access = context.access;
+
+  return {
+    init: (value: number) => value + 1,
+  };
+};
+
+class C {
+  @dec static accessor #field1 = 0;
+}
+
+// The 2022-03 version calls `access` with the object as `this`
+expect(access.get.call(C)).toBe(1);
+access.set.call(C, 5);
+expect(access.get.call(C)).toBe(5);
+
+const classDec = (_: unknown, context: ClassDecoratorContext) => {
+  expect(context.name).toBe("D");
+};
+const values: unknown[] = [];
+
+@classDec
+class D {
+  static accessor #field1 = 0;
+  static {
+    values.push(this.#field1);
+    this.#field1 = 1;
+    values.push(this.#field1);
+  }
+}
+
+expect(values).toEqual([0, 1]);
@@ -0,0 +1,32 @@
+// Ported from the `esDecorators-classDeclaration-multipleDecorators`
+// conformance test of TypeScript.
+const log: string[] = [];
+
+const dec = (id: number) => {
+  log.push(`evaluate ${id}`);
+
+  return (_: Function, context: ClassDecoratorContext) => {
+    log.push(`apply ${id} to ${context.name}`);
+  };
+};
+const dec1 = dec(1);
+const dec2 = dec(2);
+
+@dec1
+@dec2
+class C {}
+
+@dec(3)
+@dec(4)
+class D {}
+
+expect(log).toEqual([
+  "evaluate 1",
+  "evaluate 2",
+  "apply 2 to C",
+  "apply 1 to C",
+  "evaluate 3",
+  "evaluate 4",
+  "apply 4 to D",
+  "apply 3 to D",
+]);
@@ -0,0 +1,19 @@
+// Ported from the `esDecorators-classDeclaration-parameterProperties`
+// conformance test of TypeScript.
+const bound = (method: Function, context: ClassMethodDecoratorContext) => {
+  context.addInitializer(function (this: any) {
+    this[context.name] = method.bind(this);
+  });
+};
+
+class C {
+  constructor(private message: string) {}
+
+  @bound speak() {
+    return this.message;
+  }
+}
+
+const { speak } = new C("hello");
+
+expect(speak()).toBe("hello");
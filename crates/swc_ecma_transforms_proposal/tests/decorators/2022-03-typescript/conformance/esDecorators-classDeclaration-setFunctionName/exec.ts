@@ -0,0 +1,12 @@
+// Ported from the `esDecorators-classDeclaration-setFunctionName` conformance
+// test of TypeScript.
+const names: unknown[] = [];
+
+const dec = (target: Function, context: ClassDecoratorContext) => {
+  names.push([context.name, target.name]);
+};
+
+@dec class C {}
+
+expect(names).toEqual([["C", "C"]]);
+expect(C.name).toBe("C");
@@ -0,0 +1,54 @@
+// Ported from the `esDecorators-preservesThis` conformance test of
+// TypeScript.
+const receivers: unknown[] = [];
+
+class DecoratorProvider {
+  decorate<T>(this: DecoratorProvider, v: T, _: DecoratorContext): T {
+    receivers.push(this);
+
+    return v;
+  }
+}
+
+const instance = new DecoratorProvider();
+
+// preserve `this` for access
+class C {
+  @instance.decorate
+  method1() {}
+
+  @(instance["decorate"])
+  method2() {}
+
+  // even in parens
+  @((instance.decorate))
+  method3() {}
+}
+
+expect(receivers).toEqual([instance, instance, instance]);
+receivers.forEach((receiver) => expect(receiver).toBe(instance));
+
+// preserve `this` for `super` access
+class D extends DecoratorProvider {
+  m() {
+    class C {
+      @(super.decorate)
+      method1() {}
+
+      @(super["decorate"])
+      method2() {}
+
+      @((super.decorate))
+      method3() {}
+    }
+
+    return C;
+  }
+}
+
+const d = new D();
+
+d.m();
+
+expect(receivers).toHaveLength(6);
+receivers.slice(3).forEach((receiver) => expect(receiver).toBe(d));
@@ -0,0 +1,17 @@
+// Ported from the `esDecoratorsMetadata1` conformance test of TypeScript.
+(Symbol as any).metadata ??= Symbol("Symbol.metadata");
+
+function meta(key: string, value: string) {
+  return (_: unknown, context: DecoratorContext) => {
+    context.metadata[key] = value;
+  };
+}
+
+@meta("a", "x")
+class C {
+  @meta("b", "y")
+  m() {}
+}
+
+expect(C[Symbol.metadata]!.a).toBe("x");
+expect(C[Symbol.metadata]!.b).toBe("y");
@@ -0,0 +1,27 @@
+// Ported from the `esDecoratorsMetadata2` conformance test of TypeScript.
+(Symbol as any).metadata ??= Symbol("Symbol.metadata");
+
+function meta(key: string, value: string) {
+  return (_: unknown, context: DecoratorContext) => {
+    context.metadata[key] = value;
+  };
+}
+
+@meta("a", "x")
+class C {
+  @meta("b", "y")
+  m() {}
+}
+
+expect(C[Symbol.metadata]!.a).toBe("x");
+expect(C[Symbol.metadata]!.b).toBe("y");
+
+class D extends C {
+  @meta("b", "z")
+  m() {}
+}
+
+expect(D[Symbol.metadata]!.a).toBe("x");
+expect(D[Symbol.metadata]!.b).toBe("z");
+expect(C[Symbol.metadata]!.b).toBe("y");
+expect(Object.getPrototypeOf(D[Symbol.metadata])).toBe(C[Symbol.metadata]);
@@ -0,0 +1,18 @@
+// Ported from the `esDecoratorsMetadata3` conformance test of TypeScript.
+(Symbol as any).metadata ??= Symbol("Symbol.metadata");
+
+function appendMeta(key: string, value: string) {
+  return (_: unknown, context: DecoratorContext) => {
+    const existing = (context.metadata[key] as string[]) ?? [];
+    context.metadata[key] = [...existing, value];
+  };
+}
+
+@appendMeta("a", "x")
+class C {}
+
+@appendMeta("a", "z")
+class D extends C {}
+
+expect(C[Symbol.metadata]!.a).toEqual(["x"]);
+expect(D[Symbol.metadata]!.a).toEqual(["x", "z"]);
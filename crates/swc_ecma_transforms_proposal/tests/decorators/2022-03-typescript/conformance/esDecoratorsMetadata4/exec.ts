@@ -0,0 +1,27 @@
+// Ported from the `esDecoratorsMetadata4` conformance test of TypeScript.
+(Symbol as any).metadata ??= Symbol("Symbol.metadata");
+
+const PRIVATE_METADATA = new WeakMap<object, Record<string, string>>();
+
+function meta(key: string, value: string) {
+  return (_: unknown, context: DecoratorContext) => {
+    let metadata = PRIVATE_METADATA.get(context.metadata);
+
+    if (!metadata) {
+      metadata = {};
+      PRIVATE_METADATA.set(context.metadata, metadata);
+    }
+
+    metadata[key] = value;
+  };
+}
+
+@meta("a", "x")
+class C {
+  @meta("b", "y")
+  m() {}
+}
+
+expect(PRIVATE_METADATA.get(C[Symbol.metadata]!)!.a).toBe("x");
+expect(PRIVATE_METADATA.get(C[Symbol.metadata]!)!.b).toBe("y");
+expect(Object.keys(C[Symbol.metadata]!)).toEqual([]);
@@ -0,0 +1,17 @@
+// Ported from the `esDecoratorsMetadata5` conformance test of TypeScript.
+(Symbol as any).metadata ??= Symbol("Symbol.metadata");
+
+const names: unknown[] = [];
+
+// The decorator has the same name as the metadata object of the transform.
+const metadata = (_: unknown, context: DecoratorContext) => {
+  names.push(context.name);
+  context.metadata.decorated = true;
+};
+
+class C {
+  @metadata m() {}
+}
+
+expect(names).toEqual(["m"]);
+expect(C[Symbol.metadata]!.decorated).toBe(true);
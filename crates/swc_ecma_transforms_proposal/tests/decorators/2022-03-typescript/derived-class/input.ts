@@ -0,0 +1,19 @@
+declare function dec(value: unknown, ctx: DecoratorContext): void;
+
+abstract class Base {
+  abstract accessor a: number;
+  @dec method() {}
+}
+
+class Derived extends Base {
+  @dec override accessor a: number = 1;
+  declare b: number;
+  static accessor c = 2;
+  d = this.p;
+
+  constructor(public p: number) {
+    super();
+  }
+
+  @dec method() {}
+}
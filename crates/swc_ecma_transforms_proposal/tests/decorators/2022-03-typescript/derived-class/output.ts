@@ -0,0 +1,55 @@
+var _initProto, _init_a, _Base, _initProto1;
+class Base {
+    static{
+        ({ e: [_initProto] } = _apply_decs_2203_r(this, [
+            [
+                dec,
+                2,
+                "method"
+            ]
+        ], []));
+    }
+    constructor(){
+        _initProto(this);
+    }
+    method() {}
+}
+class Derived extends (_Base = Base) {
+    p;
+    static{
+        ({ e: [_init_a, _initProto1] } = _apply_decs_2203_r(this, [
+            [
+                dec,
+                1,
+                "a"
+            ],
+            [
+                dec,
+                2,
+                "method"
+            ]
+        ], [], _Base));
+    }
+    #___private_a_1;
+    get a() {
+        return this.#___private_a_1;
+    }
+    set a(_v) {
+        this.#___private_a_1 = _v;
+    }
+    static #___private_c_2 = 2;
+    static get c() {
+        return this.#___private_c_2;
+    }
+    static set c(_v) {
+        this.#___private_c_2 = _v;
+    }
+    d;
+    constructor(p){
+        super();
+        this.p = p;
+        this.#___private_a_1 = (_initProto1(this), _init_a(this, 1));
+        this.d = this.p;
+    }
+    method() {}
+}
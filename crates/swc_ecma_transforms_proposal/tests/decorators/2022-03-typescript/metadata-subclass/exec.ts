@@ -0,0 +1,22 @@
+(Symbol as any).metadata ??= Symbol("Symbol.metadata");
+
+const dec = (_: unknown, ctx: DecoratorContext) => {
+  ctx.metadata[ctx.name as string] = ctx.kind;
+};
+
+class Base {
+  @dec method() {}
+}
+
+class Derived extends Base {
+  @dec accessor value: number = 1;
+}
+
+const baseMeta = Base[Symbol.metadata]!;
+const derivedMeta = Derived[Symbol.metadata]!;
+
+expect(Object.getPrototypeOf(derivedMeta)).toBe(baseMeta);
+expect(derivedMeta.value).toBe("accessor");
+expect(derivedMeta.method).toBe("method");
+expect(Object.hasOwn(derivedMeta, "method")).toBe(false);
+expect(new Derived().value).toBe(1);
@@ -0,0 +1,33 @@
+(Symbol as any).metadata ??= Symbol("Symbol.metadata");
+
+const contexts: DecoratorContext[] = [];
+
+const dec = (_: unknown, ctx: DecoratorContext) => {
+  contexts.push(ctx);
+  ctx.metadata[ctx.name as string] = ctx.kind;
+};
+
+@dec
+class C {
+  @dec static accessor a = 1;
+  @dec accessor b = 2;
+  @dec field = 3;
+  @dec method() {}
+  @dec get getter() {
+    return 4;
+  }
+}
+
+const metadata = C[Symbol.metadata]!;
+
+expect(contexts).toHaveLength(6);
+contexts.forEach((ctx) => expect(ctx.metadata).toBe(metadata));
+expect(Object.getPrototypeOf(metadata)).toBe(null);
+expect({ ...metadata }).toEqual({
+  a: "accessor",
+  b: "accessor",
+  field: "field",
+  method: "method",
+  getter: "getter",
+  C: "class",
+});
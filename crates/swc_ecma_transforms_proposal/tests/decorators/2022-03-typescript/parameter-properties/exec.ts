@@ -0,0 +1,51 @@
+const log: string[] = [];
+
+function init<This, V>(
+  _: unknown,
+  ctx: ClassFieldDecoratorContext<This, V> | ClassAccessorDecoratorContext<This, V>,
+): any {
+  const initializer = (value: V) => {
+    log.push(`init ${String(ctx.name)}`);
+    return value;
+  };
+
+  return ctx.kind === "accessor" ? { init: initializer } : initializer;
+}
+
+function bound<This, F extends (this: This) => unknown>(
+  method: F,
+  ctx: ClassMethodDecoratorContext<This, F>,
+) {
+  ctx.addInitializer(function () {
+    log.push(`bind ${String(ctx.name)}`);
+    (this as any)[ctx.name] = method.bind(this);
+  });
+}
+
+class Base {
+  constructor(public readonly base: string) {}
+}
+
+class C extends Base {
+  @init accessor sum = this.x + this.y;
+  @init field = this.x * this.y;
+
+  constructor(
+    private x: number,
+    protected y = 3,
+  ) {
+    super("base");
+  }
+
+  @bound getX() {
+    return this.x;
+  }
+}
+
+const c = new C(2);
+
+expect(c.base).toBe("base");
+expect(c.sum).toBe(5);
+expect(c.field).toBe(6);
+expect(c.getX.call(null)).toBe(2);
+expect(log).toEqual(["bind getX", "init sum", "init field"]);
@@ -0,0 +1,32 @@
+interface Options {
+  prefix: string;
+}
+
+const withPrefix =
+  (options: Options) =>
+  <This>(_: undefined, ctx: ClassFieldDecoratorContext<This, string>) =>
+  (value: string) =>
+    `${options.prefix}${value}`;
+
+const double = <This>(
+  _: ClassAccessorDecoratorTarget<This, number>,
+  ctx: ClassAccessorDecoratorContext<This, number>,
+) => ({
+  init: (value: number) => value * 2,
+});
+
+class C {
+  @withPrefix({ prefix: "a:" } satisfies Options)
+  field = "x" satisfies string;
+
+  @(withPrefix({ prefix: "b:" }) satisfies Function)
+  other = ("y" as const) satisfies "y";
+
+  @double
+  accessor value = 2 satisfies number;
+}
+
+const c = new C();
+expect(c.field).toBe("a:x");
+expect(c.other).toBe("b:y");
+expect(c.value).toBe(4);
@@ -33,6 +33,8 @@ pub enum Runtime {
     Automatic,
     /// `classic`
     Classic,
+    /// `preserve`
+    Preserve,
 }
 
 /// Note: This will changed in v2
@@ -545,6 +547,7 @@ where
                 }
                 .into()
             }
+            Runtime::Preserve => unreachable!("jsx is not transformed when preserved"),
             Runtime::Classic => {
                 CallExpr {
                     span,
@@ -833,6 +836,7 @@ where
                 }
                 .into()
             }
+            Runtime::Preserve => unreachable!("jsx is not transformed when preserved"),
             Runtime::Classic => {
                 CallExpr {
                     span,
@@ -984,7 +988,11 @@ where
 
         if let Some(runtime) = runtime {
             found = true;
-            self.runtime = runtime;
+            // Pragmas select the runtime of transformed jsx, so they don't affect
+            // preserved jsx.
+            if self.runtime != Runtime::Preserve {
+                self.runtime = runtime;
+            }
         }
 
         if let Some(import_source) = import_source {
@@ -1035,6 +1043,10 @@ where
     noop_visit_mut_type!();
 
     fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if self.runtime == Runtime::Preserve {
+            return;
+        }
+
         let top_level_node = self.top_level_node;
         let mut did_work = false;
 
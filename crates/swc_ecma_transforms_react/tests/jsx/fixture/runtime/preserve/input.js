@@ -0,0 +1,10 @@
+/** @jsxImportSource preact */
+import { Fragment } from "preact";
+
+const App = () => (
+  <>
+    <div className="app" {...props}>
+      <Component.Child key="child" />
+    </div>
+  </>
+);
@@ -1,10 +1,22 @@
 use serde::{Deserialize, Serialize};
+use swc_common::sync::Lrc;
+use swc_ecma_transforms_react::Runtime;
+
+use crate::ts_enum::ConstEnumStore;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Config {
     #[serde(default)]
     pub verbatim_module_syntax: bool,
 
+    /// Reports code which can't be transpiled one file at a time, like
+    /// `tsc --isolatedModules`. Errors of `verbatim_module_syntax` are
+    /// reported only if this is enabled too.
+    ///
+    /// https://www.typescriptlang.org/tsconfig#isolatedModules
+    #[serde(default)]
+    pub isolated_modules: bool,
+
     #[serde(default)]
     pub import_not_used_as_values: ImportsNotUsedAsValues,
 
@@ -26,6 +38,12 @@ pub struct Config {
     /// Defaults to false.
     #[serde(default)]
     pub ts_enum_is_mutable: bool,
+
+    /// Members of `const enum`s declared in other modules, which are inlined
+    /// where they are imported. Imports of these enums are removed when all
+    /// uses are inlined, unless `verbatim_module_syntax` is enabled.
+    #[serde(skip)]
+    pub const_enums: Lrc<ConstEnumStore>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -37,6 +55,13 @@ pub struct TsxConfig {
     /// Note: this pass handle jsx directives in comments
     #[serde(default)]
     pub pragma_frag: Option<String>,
+
+    /// Imports of the `pragma` are preserved unless the automatic runtime is
+    /// used, like `tsc` does for `"jsx": "react"` and `"jsx": "preserve"`.
+    ///
+    /// Note: this pass handle jsx directives in comments
+    #[serde(default)]
+    pub runtime: Option<Runtime>,
 }
 
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -74,6 +99,10 @@ pub enum ImportsNotUsedAsValues {
     Remove,
     #[serde(rename = "preserve")]
     Preserve,
+    /// Like [ImportsNotUsedAsValues::Preserve], but reports imports which are
+    /// only used as types.
+    #[serde(rename = "error")]
+    Error,
 }
 
 #[deprecated = "ImportNotUsedAsValues is renamed to ImportsNotUsedAsValues"]
@@ -0,0 +1,228 @@
+use swc_common::{collections::AHashSet, errors::HANDLER, Span};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::{
+    strip_import_export::{DeclareCollect, UsageCollect},
+    ImportsNotUsedAsValues,
+};
+
+/// Reports imports and exports which are elided or kept incorrectly when
+/// files are transpiled one at a time, like `tsc` does with
+/// `isolatedModules`, `verbatimModuleSyntax` and
+/// `importsNotUsedAsValues: "error"`.
+///
+/// Only the current file is analyzed, so mistakes depending on the exports of
+/// other modules (e.g. re-exporting an imported interface) are not reported.
+pub(crate) struct IsolatedModulesCheck {
+    pub isolated_modules: bool,
+    pub verbatim_module_syntax: bool,
+    pub import_not_used_as_values: ImportsNotUsedAsValues,
+    /// Identifiers used by JSX pragmas.
+    pub id_usage: AHashSet<Id>,
+}
+
+impl IsolatedModulesCheck {
+    pub fn check(self, n: &Program) {
+        let flag_name = if self.verbatim_module_syntax {
+            "verbatimModuleSyntax"
+        } else {
+            "isolatedModules"
+        };
+
+        if let Program::Module(module) = n {
+            if self.import_not_used_as_values == ImportsNotUsedAsValues::Error {
+                check_imports_used_as_types(&module.body, self.id_usage);
+            }
+
+            if self.isolated_modules && self.verbatim_module_syntax {
+                check_type_reexports(&module.body, flag_name);
+            }
+        }
+
+        if self.isolated_modules {
+            let mut ambient_const_enums = AmbientConstEnumCollect::default();
+
+            n.visit_with(&mut ambient_const_enums);
+
+            if !ambient_const_enums.ids.is_empty() {
+                n.visit_with(&mut AmbientConstEnumUsage {
+                    ids: ambient_const_enums.ids,
+                    flag_name,
+                });
+            }
+        }
+    }
+}
+
+/// Reports imports whose bindings are only used as types.
+fn check_imports_used_as_types(items: &[ModuleItem], id_usage: AHashSet<Id>) {
+    let mut usage_info = UsageCollect::from(id_usage);
+
+    items.visit_with(&mut usage_info);
+
+    for item in items {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+            continue;
+        };
+
+        if import.type_only
+            || import.specifiers.is_empty()
+            || import.specifiers.iter().all(is_type_only_specifier)
+        {
+            continue;
+        }
+
+        let is_used_as_value = import.specifiers.iter().any(|specifier| {
+            !is_type_only_specifier(specifier) && usage_info.has_usage(&specifier.local().to_id())
+        });
+
+        if !is_used_as_value {
+            // TS1371
+            emit_error(
+                import.span,
+                "This import is never used as a value and must use 'import type' because \
+                 'importsNotUsedAsValues' is set to 'error'.",
+            );
+        }
+    }
+}
+
+/// Reports `export { T }` of types, which is kept with `verbatimModuleSyntax`.
+fn check_type_reexports(items: &[ModuleItem], flag_name: &str) {
+    let mut declare_info = DeclareCollect::default();
+    let mut type_only_imports = AHashSet::default();
+
+    items.visit_with(&mut declare_info);
+
+    for item in items {
+        if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item {
+            for specifier in &import.specifiers {
+                if import.type_only || is_type_only_specifier(specifier) {
+                    type_only_imports.insert(specifier.local().to_id());
+                }
+            }
+        }
+    }
+
+    for item in items {
+        let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+            specifiers,
+            src: None,
+            type_only: false,
+            ..
+        })) = item
+        else {
+            continue;
+        };
+
+        for specifier in specifiers {
+            let ExportSpecifier::Named(ExportNamedSpecifier {
+                span,
+                orig: ModuleExportName::Ident(orig),
+                is_type_only: false,
+                ..
+            }) = specifier
+            else {
+                continue;
+            };
+
+            let id = orig.to_id();
+
+            if type_only_imports.contains(&id) {
+                // TS1448
+                emit_error(
+                    *span,
+                    &format!(
+                        "'{}' resolves to a type-only declaration and must be re-exported using a \
+                         type-only re-export when '{}' is enabled.",
+                        orig.sym, flag_name
+                    ),
+                );
+            } else if declare_info.has_pure_type(&id) {
+                // TS1205
+                emit_error(
+                    *span,
+                    &format!(
+                        "Re-exporting a type when '{}' is enabled requires using 'export type'.",
+                        flag_name
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn is_type_only_specifier(specifier: &ImportSpecifier) -> bool {
+    matches!(
+        specifier,
+        ImportSpecifier::Named(ImportNamedSpecifier {
+            is_type_only: true,
+            ..
+        })
+    )
+}
+
+fn emit_error(span: Span, msg: &str) {
+    HANDLER.with(|handler| {
+        handler.struct_span_err(span, msg).emit();
+    });
+}
+
+/// Collects `const enum`s declared with `declare` or in an ambient namespace.
+#[derive(Default)]
+struct AmbientConstEnumCollect {
+    ids: AHashSet<Id>,
+    in_ambient: bool,
+}
+
+impl Visit for AmbientConstEnumCollect {
+    fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl) {
+        if n.is_const && (n.declare || self.in_ambient) {
+            self.ids.insert(n.id.to_id());
+        }
+    }
+
+    fn visit_ts_module_decl(&mut self, n: &TsModuleDecl) {
+        let in_ambient = self.in_ambient;
+
+        self.in_ambient |= n.declare;
+        n.visit_children_with(self);
+        self.in_ambient = in_ambient;
+    }
+}
+
+/// Reports values of ambient const enums, which are declared in other files.
+struct AmbientConstEnumUsage<'a> {
+    ids: AHashSet<Id>,
+    flag_name: &'a str,
+}
+
+impl Visit for AmbientConstEnumUsage<'_> {
+    noop_visit_type!();
+
+    fn visit_ident(&mut self, n: &Ident) {
+        if self.ids.contains(&n.to_id()) {
+            // TS2748
+            emit_error(
+                n.span,
+                &format!(
+                    "Cannot access ambient const enums when '{}' is enabled.",
+                    self.flag_name
+                ),
+            );
+        }
+    }
+
+    fn visit_ts_enum_decl(&mut self, _: &TsEnumDecl) {
+        // skip
+    }
+
+    fn visit_binding_ident(&mut self, _: &BindingIdent) {
+        // skip
+    }
+
+    fn visit_export_named_specifier(&mut self, _: &ExportNamedSpecifier) {
+        // skip
+    }
+}
@@ -4,6 +4,7 @@
 
 pub use self::{strip_type::*, typescript::*};
 mod config;
+mod isolated_modules;
 mod macros;
 mod strip_import_export;
 mod strip_type;
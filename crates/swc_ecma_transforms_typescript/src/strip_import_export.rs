@@ -8,7 +8,7 @@ use swc_ecma_visit::{noop_visit_type, Visit, VisitMut, VisitMutWith, VisitWith};
 use crate::{strip_type::IsConcrete, ImportsNotUsedAsValues};
 
 #[derive(Debug, Default)]
-struct UsageCollect {
+pub(crate) struct UsageCollect {
     id_usage: AHashSet<Id>,
 }
 
@@ -98,7 +98,7 @@ impl Visit for UsageCollect {
 }
 
 impl UsageCollect {
-    fn has_usage(&self, id: &Id) -> bool {
+    pub(crate) fn has_usage(&self, id: &Id) -> bool {
         self.id_usage.contains(id)
     }
 }
@@ -113,7 +113,7 @@ fn get_module_ident(ts_entity_name: &TsEntityName) -> &Ident {
 }
 
 #[derive(Debug, Default)]
-struct DeclareCollect {
+pub(crate) struct DeclareCollect {
     id_type: AHashSet<Id>,
     id_value: AHashSet<Id>,
 }
@@ -231,7 +231,7 @@ impl Visit for DeclareCollect {
 }
 
 impl DeclareCollect {
-    fn has_pure_type(&self, id: &Id) -> bool {
+    pub(crate) fn has_pure_type(&self, id: &Id) -> bool {
         self.id_type.contains(id) && !self.id_value.contains(id)
     }
 
@@ -309,8 +309,10 @@ impl VisitMut for StripImportExport {
                     }
                 });
 
-                self.import_not_used_as_values == ImportsNotUsedAsValues::Preserve
-                    || !specifiers.is_empty()
+                matches!(
+                    self.import_not_used_as_values,
+                    ImportsNotUsedAsValues::Preserve | ImportsNotUsedAsValues::Error
+                ) || !specifiers.is_empty()
             }
             ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
                 specifiers,
@@ -6,20 +6,23 @@ use std::{
 
 use swc_atoms::JsWord;
 use swc_common::{
-    collections::AHashSet, errors::HANDLER, util::take::Take, Mark, Span, Spanned, SyntaxContext,
-    DUMMY_SP,
+    collections::AHashSet, errors::HANDLER, sync::Lrc, util::take::Take, Mark, Span, Spanned,
+    SyntaxContext, DUMMY_SP,
 };
 use swc_ecma_ast::*;
 use swc_ecma_utils::{
     alias_ident_for, constructor::inject_after_super, find_pat_ids, is_literal, member_expr,
-    private_ident, quote_ident, quote_str, stack_size::maybe_grow_default, ExprFactory, QueryRef,
-    RefRewriter,
+    private_ident, quote_ident, quote_str, stack_size::maybe_grow_default, ExprFactory,
+    IdentUsageFinder, QueryRef, RefRewriter,
 };
 use swc_ecma_visit::{as_folder, noop_visit_mut_type, Fold, VisitMut, VisitMutWith};
 
 use crate::{
     config::TsImportExportAssignConfig,
-    ts_enum::{EnumValueComputer, InlineEnum, TsEnumRecord, TsEnumRecordKey, TsEnumRecordValue},
+    ts_enum::{
+        ConstEnumStore, EnumValueComputer, InlineEnum, TsEnumRecord, TsEnumRecordKey,
+        TsEnumRecordValue,
+    },
     utils::{
         assign_value_to_this_private_prop, assign_value_to_this_prop, AsCollapsibleDecl,
         AsEnumOrModule, Factory, VecStmtLike,
@@ -63,6 +66,10 @@ pub(crate) struct Transform {
     namespace_id: Option<Id>,
     record: TsEnumRecord,
 
+    const_enums: Lrc<ConstEnumStore>,
+    /// Local names of imported `const enum`s found in [Self::const_enums].
+    imported_const_enums: Vec<Id>,
+
     in_class_prop: Vec<Id>,
     in_class_prop_init: Vec<Box<Expr>>,
     class_prop_decls: Vec<VarDeclarator>,
@@ -74,6 +81,7 @@ pub fn transform(
     import_export_assign_config: TsImportExportAssignConfig,
     ts_enum_is_mutable: bool,
     verbatim_module_syntax: bool,
+    const_enums: Lrc<ConstEnumStore>,
 ) -> impl Fold + VisitMut {
     as_folder(Transform {
         unresolved_mark,
@@ -82,6 +90,7 @@ pub fn transform(
         import_export_assign_config,
         ts_enum_is_mutable,
         verbatim_module_syntax,
+        const_enums,
         ..Default::default()
     })
 }
@@ -96,10 +105,15 @@ impl VisitMut for Transform {
             let record = mem::take(&mut self.record);
             n.visit_mut_children_with(&mut InlineEnum::new(record));
         }
+
+        if let Program::Module(module) = n {
+            self.remove_inlined_const_enum_imports(module);
+        }
     }
 
     fn visit_mut_module(&mut self, n: &mut Module) {
         self.visit_mut_for_ts_import_export(n);
+        self.add_imported_const_enums(n);
 
         n.visit_mut_children_with(self);
     }
@@ -531,6 +545,101 @@ impl Transform {
     }
 }
 
+impl Transform {
+    /// Adds members of imported `const enum`s to the record, so their uses are
+    /// inlined like the ones of local enums.
+    fn add_imported_const_enums(&mut self, n: &Module) {
+        if self.const_enums.is_empty() {
+            return;
+        }
+
+        for item in &n.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                continue;
+            };
+
+            if import.type_only {
+                continue;
+            }
+
+            for specifier in &import.specifiers {
+                let ImportSpecifier::Named(ImportNamedSpecifier {
+                    local,
+                    imported,
+                    is_type_only: false,
+                    ..
+                }) = specifier
+                else {
+                    continue;
+                };
+
+                let name = match imported {
+                    Some(ModuleExportName::Ident(imported)) => &imported.sym,
+                    Some(ModuleExportName::Str(imported)) => &imported.value,
+                    None => &local.sym,
+                };
+
+                let Some(members) = self.const_enums.get(&import.src.value, name) else {
+                    continue;
+                };
+
+                for (member_name, value) in members {
+                    self.record.insert(
+                        TsEnumRecordKey {
+                            enum_id: local.to_id(),
+                            member_name: member_name.clone(),
+                        },
+                        value.clone().into(),
+                    );
+                }
+
+                self.imported_const_enums.push(local.to_id());
+            }
+        }
+    }
+
+    /// Removes imports of `const enum`s which are not used anymore, because
+    /// the declaring module may not export them at runtime.
+    fn remove_inlined_const_enum_imports(&mut self, n: &mut Module) {
+        let imported_const_enums = self.imported_const_enums.take();
+
+        if imported_const_enums.is_empty() || self.verbatim_module_syntax {
+            return;
+        }
+
+        let unused = imported_const_enums
+            .into_iter()
+            .filter(|id| {
+                !n.body.iter().any(|item| {
+                    !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(..)))
+                        && IdentUsageFinder::find(id, item)
+                })
+            })
+            .collect::<AHashSet<_>>();
+
+        if unused.is_empty() {
+            return;
+        }
+
+        n.body.retain_mut(|item| {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+                return true;
+            };
+
+            if import.specifiers.is_empty() {
+                return true;
+            }
+
+            import.specifiers.retain(|specifier| match specifier {
+                ImportSpecifier::Named(specifier) => !unused.contains(&specifier.local.to_id()),
+                _ => true,
+            });
+
+            !import.specifiers.is_empty()
+        });
+    }
+}
+
 impl Transform {
     fn transform_ts_enum(&mut self, ts_enum: TsEnumDecl, is_export: bool) -> Stmt {
         let TsEnumDecl {
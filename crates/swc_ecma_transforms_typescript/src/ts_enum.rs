@@ -87,12 +87,188 @@ impl From<TsEnumRecordValue> for Expr {
     }
 }
 
+impl From<ConstEnumValue> for TsEnumRecordValue {
+    fn from(value: ConstEnumValue) -> Self {
+        match value {
+            ConstEnumValue::String(string) => Self::String(string),
+            ConstEnumValue::Number(num) => Self::Number(num),
+        }
+    }
+}
+
 impl From<f64> for TsEnumRecordValue {
     fn from(value: f64) -> Self {
         Self::Number(value)
     }
 }
 
+/// The value of a `const enum` member.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstEnumValue {
+    String(JsWord),
+    Number(f64),
+}
+
+/// Members of `const enum`s exported by other modules, so they can be
+/// inlined where they are imported.
+///
+/// Modules are identified by the specifier of import declarations, e.g.
+/// `./flags` for `import { Flags } from "./flags"`. Imports using a different
+/// specifier for the same module need it to be added for each specifier, or
+/// specifiers need to be normalized before the transform.
+#[derive(Debug, Default, Clone)]
+pub struct ConstEnumStore {
+    modules: AHashMap<JsWord, AHashMap<JsWord, AHashMap<JsWord, ConstEnumValue>>>,
+}
+
+impl ConstEnumStore {
+    /// Adds members of the `const enum` exported as `name` by the module.
+    pub fn insert<I>(&mut self, specifier: JsWord, name: JsWord, members: I)
+    where
+        I: IntoIterator<Item = (JsWord, ConstEnumValue)>,
+    {
+        self.modules
+            .entry(specifier)
+            .or_default()
+            .entry(name)
+            .or_default()
+            .extend(members);
+    }
+
+    /// Adds `const enum`s exported by `module`, which should be parsed from a
+    /// `.ts` or `.d.ts` file and resolved by `resolver` with
+    /// `unresolved_mark`, without applying any other transform.
+    ///
+    /// Members whose value can't be computed (e.g. `A = foo()`) are not
+    /// added, and their uses are kept.
+    pub fn add_module(&mut self, specifier: JsWord, module: &Module, unresolved_mark: Mark) {
+        let mut record = TsEnumRecord::default();
+        let mut const_enums = AHashMap::<Id, Vec<JsWord>>::default();
+        let mut exports = vec![];
+
+        for item in &module.body {
+            let (ts_enum, is_export) = match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsEnum(ts_enum),
+                    ..
+                })) => (ts_enum, true),
+                ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(ts_enum))) => (ts_enum, false),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    specifiers,
+                    src: None,
+                    type_only: false,
+                    ..
+                })) => {
+                    for specifier in specifiers {
+                        if let ExportSpecifier::Named(ExportNamedSpecifier {
+                            orig: ModuleExportName::Ident(orig),
+                            exported,
+                            is_type_only: false,
+                            ..
+                        }) = specifier
+                        {
+                            let exported = match exported {
+                                Some(ModuleExportName::Ident(exported)) => exported.sym.clone(),
+                                Some(ModuleExportName::Str(exported)) => exported.value.clone(),
+                                None => orig.sym.clone(),
+                            };
+
+                            exports.push((orig.to_id(), exported));
+                        }
+                    }
+
+                    continue;
+                }
+                _ => continue,
+            };
+
+            let enum_id = ts_enum.id.to_id();
+            let mut default_init = TsEnumRecordValue::Number(0.0);
+            let mut member_names = vec![];
+
+            for member in &ts_enum.members {
+                let member_name = member.id.as_ref().clone();
+                let value = member
+                    .init
+                    .clone()
+                    .map(|expr| {
+                        EnumValueComputer {
+                            enum_id: &enum_id,
+                            top_level_mark: unresolved_mark,
+                            record: &record,
+                        }
+                        .compute(expr)
+                    })
+                    .filter(TsEnumRecordValue::has_value)
+                    .unwrap_or_else(|| default_init.clone());
+
+                default_init = value.inc();
+
+                let value = if value.is_const() {
+                    value
+                } else {
+                    TsEnumRecordValue::Void
+                };
+
+                record.insert(
+                    TsEnumRecordKey {
+                        enum_id: enum_id.clone(),
+                        member_name: member_name.clone(),
+                    },
+                    value,
+                );
+                member_names.push(member_name);
+            }
+
+            if !ts_enum.is_const {
+                continue;
+            }
+
+            if is_export {
+                exports.push((enum_id.clone(), enum_id.0.clone()));
+            }
+
+            const_enums.insert(enum_id, member_names);
+        }
+
+        for (enum_id, exported) in exports {
+            let Some(member_names) = const_enums.get(&enum_id) else {
+                continue;
+            };
+
+            let members = member_names
+                .iter()
+                .filter_map(|member_name| {
+                    let value = match record.get(&TsEnumRecordKey {
+                        enum_id: enum_id.clone(),
+                        member_name: member_name.clone(),
+                    })? {
+                        TsEnumRecordValue::String(string) => ConstEnumValue::String(string.clone()),
+                        TsEnumRecordValue::Number(num) => ConstEnumValue::Number(*num),
+                        _ => return None,
+                    };
+
+                    Some((member_name.clone(), value))
+                })
+                .collect::<Vec<_>>();
+
+            self.insert(specifier.clone(), exported, members);
+        }
+    }
+
+    pub fn get(
+        &self,
+        specifier: &JsWord,
+        name: &JsWord,
+    ) -> Option<&AHashMap<JsWord, ConstEnumValue>> {
+        self.modules.get(specifier)?.get(name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+}
+
 pub(crate) struct EnumValueComputer<'a> {
     pub enum_id: &'a Id,
     pub top_level_mark: Mark,
@@ -5,11 +5,17 @@ use swc_common::{
     Spanned,
 };
 use swc_ecma_ast::*;
-use swc_ecma_transforms_react::{parse_expr_for_jsx, JsxDirectives};
+use swc_ecma_transforms_react::{parse_expr_for_jsx, JsxDirectives, Runtime};
 use swc_ecma_visit::{as_folder, Fold, VisitMut, VisitMutWith};
 
-pub use crate::config::*;
-use crate::{strip_import_export::StripImportExport, strip_type::StripType, transform::transform};
+pub use crate::{
+    config::*,
+    ts_enum::{ConstEnumStore, ConstEnumValue},
+};
+use crate::{
+    isolated_modules::IsolatedModulesCheck, strip_import_export::StripImportExport,
+    strip_type::StripType, transform::transform,
+};
 
 pub fn typescript(
     config: Config,
@@ -42,6 +48,18 @@ impl VisitMut for TypeScript {
     fn visit_mut_program(&mut self, n: &mut Program) {
         let was_module = n.as_module().and_then(|m| self.get_last_module_span(m));
 
+        if self.config.isolated_modules
+            || self.config.import_not_used_as_values == ImportsNotUsedAsValues::Error
+        {
+            IsolatedModulesCheck {
+                isolated_modules: self.config.isolated_modules,
+                verbatim_module_syntax: self.config.verbatim_module_syntax,
+                import_not_used_as_values: self.config.import_not_used_as_values,
+                id_usage: self.id_usage.clone(),
+            }
+            .check(n);
+        }
+
         if !self.config.verbatim_module_syntax {
             n.visit_mut_with(&mut StripImportExport {
                 id_usage: mem::take(&mut self.id_usage),
@@ -57,6 +75,7 @@ impl VisitMut for TypeScript {
             self.config.import_export_assign_config,
             self.config.ts_enum_is_mutable,
             self.config.verbatim_module_syntax,
+            self.config.const_enums.clone(),
         ));
 
         if let Some(span) = was_module {
@@ -164,8 +183,39 @@ where
         // from import statement.
         // But in `verbatim_module_syntax` mode, we do not remove any unused imports.
         // So we do not need to collect usage info.
-        if !self.config.verbatim_module_syntax {
-            let pragma = parse_expr_for_jsx(
+        if self.config.verbatim_module_syntax {
+            return;
+        }
+
+        let span = if n.shebang.is_some() {
+            n.span
+                .with_lo(n.body.first().map(|s| s.span_lo()).unwrap_or(n.span.lo))
+        } else {
+            n.span
+        };
+
+        let JsxDirectives {
+            runtime,
+            pragma,
+            pragma_frag,
+            ..
+        } = self.comments.with_leading(span.lo, |comments| {
+            JsxDirectives::from_comments(&self.cm, span, comments, self.top_level_mark)
+        });
+
+        // The automatic runtime imports its own factories. Preserved jsx is compiled
+        // later by a tool which expects the pragma to be in scope, like tsc does.
+        let runtime = match self.tsx_config.runtime {
+            Some(Runtime::Preserve) => Runtime::Preserve,
+            config => runtime.or(config).unwrap_or_default(),
+        };
+
+        if runtime == Runtime::Automatic {
+            return;
+        }
+
+        let pragma = pragma.unwrap_or_else(|| {
+            parse_expr_for_jsx(
                 &self.cm,
                 "pragma",
                 self.tsx_config
@@ -173,9 +223,11 @@ where
                     .clone()
                     .unwrap_or_else(|| "React.createElement".to_string()),
                 self.top_level_mark,
-            );
+            )
+        });
 
-            let pragma_frag = parse_expr_for_jsx(
+        let pragma_frag = pragma_frag.unwrap_or_else(|| {
+            parse_expr_for_jsx(
                 &self.cm,
                 "pragma",
                 self.tsx_config
@@ -183,42 +235,15 @@ where
                     .clone()
                     .unwrap_or_else(|| "React.Fragment".to_string()),
                 self.top_level_mark,
-            );
-
-            let pragma_id = id_for_jsx(&pragma).unwrap();
-            let pragma_frag_id = id_for_jsx(&pragma_frag).unwrap();
+            )
+        });
 
+        if let Some(pragma_id) = id_for_jsx(&pragma) {
             self.id_usage.insert(pragma_id);
-            self.id_usage.insert(pragma_frag_id);
         }
 
-        if !self.config.verbatim_module_syntax {
-            let span = if n.shebang.is_some() {
-                n.span
-                    .with_lo(n.body.first().map(|s| s.span_lo()).unwrap_or(n.span.lo))
-            } else {
-                n.span
-            };
-
-            let JsxDirectives {
-                pragma,
-                pragma_frag,
-                ..
-            } = self.comments.with_leading(span.lo, |comments| {
-                JsxDirectives::from_comments(&self.cm, span, comments, self.top_level_mark)
-            });
-
-            if let Some(pragma) = pragma {
-                if let Some(pragma_id) = id_for_jsx(&pragma) {
-                    self.id_usage.insert(pragma_id);
-                }
-            }
-
-            if let Some(pragma_frag) = pragma_frag {
-                if let Some(pragma_frag_id) = id_for_jsx(&pragma_frag) {
-                    self.id_usage.insert(pragma_frag_id);
-                }
-            }
+        if let Some(pragma_frag_id) = id_for_jsx(&pragma_frag) {
+            self.id_usage.insert(pragma_frag_id);
         }
     }
 
@@ -0,0 +1,9 @@
+import { Regular } from "./flags";
+import { Status } from "./status";
+console.log(3, 2, "x", Regular.A);
+var Local;
+(function(Local) {
+    Local[Local["A"] = 2] = "A";
+})(Local || (Local = {}));
+console.log(2);
+console.log("active", Status.Unknown, Infinity);
@@ -0,0 +1,5 @@
+import { Flags } from "./flags";
+function foo(Flags) {
+    return Flags.A;
+}
+Flags.A = 2;
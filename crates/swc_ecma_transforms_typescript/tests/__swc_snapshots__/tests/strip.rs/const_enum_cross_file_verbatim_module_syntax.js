@@ -0,0 +1,2 @@
+import { Flags } from "./flags";
+console.log(3);
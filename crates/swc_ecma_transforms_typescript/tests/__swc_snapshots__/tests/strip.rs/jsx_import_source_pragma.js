@@ -0,0 +1,5 @@
+/** @jsxImportSource preact */ import { jsx as _jsx, Fragment as _Fragment } from "preact/jsx-runtime";
+import { Button } from "./button";
+export const App = ()=>/*#__PURE__*/ _jsx(_Fragment, {
+        children: /*#__PURE__*/ _jsx(Button.Icon, {})
+    });
@@ -0,0 +1,5 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+import { Button } from "./button";
+export const App = ()=>/*#__PURE__*/ _jsx(Button, {
+        children: "Hello"
+    });
@@ -0,0 +1,2 @@
+/** @jsxRuntime automatic */ import { jsx as _jsx } from "react/jsx-runtime";
+export const App = ()=>/*#__PURE__*/ _jsx("div", {});
@@ -0,0 +1,2 @@
+/** @jsxRuntime classic */ import React from "react";
+export const App = ()=>/*#__PURE__*/ React.createElement("div", null);
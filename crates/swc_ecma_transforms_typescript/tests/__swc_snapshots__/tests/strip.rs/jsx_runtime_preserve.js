@@ -0,0 +1,3 @@
+import React from "react";
+import { Button } from "./button";
+export const App = (props)=><Button {...props}/>;
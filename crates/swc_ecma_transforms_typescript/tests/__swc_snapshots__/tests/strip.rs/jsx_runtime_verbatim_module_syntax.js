@@ -0,0 +1,8 @@
+import { jsx as _jsx } from "react/jsx-runtime";
+import React from "react";
+import { Button } from "./button";
+import * as Icons from "./icons";
+export const App = (props)=>/*#__PURE__*/ _jsx(Button, {
+        ...props,
+        children: /*#__PURE__*/ _jsx(Icons.Star, {})
+    });
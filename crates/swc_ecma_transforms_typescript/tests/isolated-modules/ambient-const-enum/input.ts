@@ -0,0 +1,17 @@
+declare const enum Direction {
+    Up = 1,
+    Down,
+}
+
+declare namespace NS {
+    const enum Inner {
+        A,
+    }
+}
+
+const enum Local {
+    A = 1,
+}
+
+let d: Direction = Direction.Up;
+console.log(Local.A, Direction.Down);
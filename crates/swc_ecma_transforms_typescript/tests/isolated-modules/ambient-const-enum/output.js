@@ -0,0 +1,3 @@
+var Local;
+let d = Direction.Up;
+console.log(1, Direction.Down);
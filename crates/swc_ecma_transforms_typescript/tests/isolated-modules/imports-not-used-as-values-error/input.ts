@@ -0,0 +1,9 @@
+import { Foo } from "./foo";
+import { Bar, type Baz } from "./bar";
+import { type Qux } from "./qux";
+import type { Quux } from "./quux";
+import Default, * as ns from "./default";
+import "./side-effect";
+
+let a: Foo = Bar;
+let b: Baz | Qux | Quux | Default | ns.T;
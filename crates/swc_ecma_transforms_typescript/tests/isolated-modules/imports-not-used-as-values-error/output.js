@@ -0,0 +1,7 @@
+import "./foo";
+import { Bar } from "./bar";
+import "./qux";
+import "./default";
+import "./side-effect";
+let a = Bar;
+let b;
@@ -0,0 +1,12 @@
+import type { A } from "./a";
+import { type B, C } from "./b";
+
+interface I {}
+type T = string;
+class K {}
+declare namespace N {
+    type U = number;
+}
+
+export { A, B, C, I, T, K, N };
+export type { I as J };
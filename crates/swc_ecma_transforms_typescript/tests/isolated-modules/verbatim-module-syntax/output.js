@@ -0,0 +1,4 @@
+import { C } from "./b";
+class K {
+}
+export { A, B, C, I, T, K, N };
@@ -4,9 +4,10 @@ use swc_common::{
     chain,
     comments::{NoopComments, SingleThreadedComments},
     pass::Optional,
-    Mark,
+    sync::Lrc,
+    FileName, Mark,
 };
-use swc_ecma_parser::{Syntax, TsSyntax};
+use swc_ecma_parser::{parse_file_as_module, Syntax, TsSyntax};
 use swc_ecma_transforms_base::resolver;
 use swc_ecma_transforms_compat::{
     class_fields_use_set::class_fields_use_set,
@@ -16,12 +17,14 @@ use swc_ecma_transforms_compat::{
     es2022::{class_properties, static_blocks},
 };
 use swc_ecma_transforms_proposal::decorators;
-use swc_ecma_transforms_react::jsx;
-use swc_ecma_transforms_testing::{test, test_exec, test_fixture, Tester};
+use swc_ecma_transforms_react::{jsx, Runtime};
+use swc_ecma_transforms_testing::{
+    parse_options, test, test_exec, test_fixture, FixtureTestConfig, Tester,
+};
 use swc_ecma_transforms_typescript::{
-    tsx, typescript, ImportsNotUsedAsValues, TsImportExportAssignConfig, TsxConfig,
+    tsx, typescript, ConstEnumStore, ImportsNotUsedAsValues, TsImportExportAssignConfig, TsxConfig,
 };
-use swc_ecma_visit::Fold;
+use swc_ecma_visit::{Fold, FoldWith};
 
 fn tr(t: &mut Tester) -> impl Fold {
     tr_config(t, None, None, false)
@@ -60,6 +63,10 @@ fn tr_config(
 }
 
 fn tsxr(t: &Tester) -> impl Fold {
+    tsxr_config(t, None, false)
+}
+
+fn tsxr_config(t: &Tester, runtime: Option<Runtime>, verbatim_module_syntax: bool) -> impl Fold {
     let unresolved_mark = Mark::new();
     let top_level_mark = Mark::new();
 
@@ -70,9 +77,13 @@ fn tsxr(t: &Tester) -> impl Fold {
             typescript::Config {
                 no_empty_export: true,
                 import_not_used_as_values: ImportsNotUsedAsValues::Remove,
+                verbatim_module_syntax,
+                ..Default::default()
+            },
+            TsxConfig {
+                runtime,
                 ..Default::default()
             },
-            TsxConfig::default(),
             t.comments.clone(),
             unresolved_mark,
             top_level_mark,
@@ -80,7 +91,10 @@ fn tsxr(t: &Tester) -> impl Fold {
         swc_ecma_transforms_react::jsx(
             t.cm.clone(),
             Some(t.comments.clone()),
-            swc_ecma_transforms_react::Options::default(),
+            swc_ecma_transforms_react::Options {
+                runtime,
+                ..Default::default()
+            },
             top_level_mark,
             unresolved_mark
         ),
@@ -1842,6 +1856,89 @@ serve((_req) =>
 "#
 );
 
+// The automatic runtime does not reference `React`, so an otherwise unused
+// import of it is elided like tsc does with `jsx: react-jsx`.
+test!(
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tsxr_config(t, Some(Runtime::Automatic), false),
+    jsx_runtime_automatic_unused_react,
+    r#"import React from "react";
+import { Button } from "./button";
+export const App = () => <Button>Hello</Button>;
+"#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tsxr_config(t, None, false),
+    jsx_runtime_pragma_automatic,
+    r#"/** @jsxRuntime automatic */
+import React from "react";
+export const App = () => <div />;
+"#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tsxr_config(t, Some(Runtime::Automatic), false),
+    jsx_runtime_pragma_classic,
+    r#"/** @jsxRuntime classic */
+import React from "react";
+export const App = () => <div />;
+"#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tsxr_config(t, None, false),
+    jsx_import_source_pragma,
+    r#"/** @jsxImportSource preact */
+import { h, Fragment } from "preact";
+import { Button } from "./button";
+export const App = () => <><Button.Icon /></>;
+"#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tsxr_config(t, Some(Runtime::Preserve), false),
+    jsx_runtime_preserve,
+    r#"import React from "react";
+import { Button, type ButtonProps } from "./button";
+export const App = (props: ButtonProps) => <Button {...props} />;
+"#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    }),
+    |t| tsxr_config(t, Some(Runtime::Automatic), true),
+    jsx_runtime_verbatim_module_syntax,
+    r#"import React from "react";
+import { Button } from "./button";
+import * as Icons from "./icons";
+import type { ButtonProps } from "./button";
+export const App = (props: ButtonProps) => <Button {...props}><Icons.Star /></Button>;
+"#
+);
+
 test!(
     Syntax::Typescript(TsSyntax {
         decorators: true,
@@ -2674,6 +2771,36 @@ fn exec(input: PathBuf) {
     );
 }
 
+#[testing::fixture("tests/isolated-modules/**/input.ts")]
+fn isolated_modules(input: PathBuf) {
+    let output = input.with_file_name("output.js");
+    let config: typescript::Config = parse_options(input.parent().unwrap());
+
+    test_fixture(
+        Syntax::Typescript(TsSyntax::default()),
+        &|t| {
+            tr_config(
+                t,
+                Some(typescript::Config {
+                    verbatim_module_syntax: config.verbatim_module_syntax,
+                    isolated_modules: config.isolated_modules,
+                    import_not_used_as_values: config.import_not_used_as_values,
+                    no_empty_export: true,
+                    ..Default::default()
+                }),
+                None,
+                false,
+            )
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            ..Default::default()
+        },
+    );
+}
+
 to!(
     parameter_properties_with_computed,
     "
@@ -2842,3 +2969,117 @@ test!(
     ts_jsx_bad_pragma,
     r#"/** @jsx bad-pragma */"#
 );
+
+fn const_enums(t: &Tester, modules: &[(&str, &str)]) -> Lrc<ConstEnumStore> {
+    let unresolved_mark = Mark::new();
+    let mut store = ConstEnumStore::default();
+
+    for (specifier, src) in modules {
+        let fm = t.cm.new_source_file(FileName::Anon.into(), src.to_string());
+        let module = parse_file_as_module(
+            &fm,
+            Syntax::Typescript(TsSyntax {
+                dts: true,
+                ..Default::default()
+            }),
+            Default::default(),
+            None,
+            &mut vec![],
+        )
+        .unwrap()
+        .fold_with(&mut resolver(unresolved_mark, Mark::new(), true));
+
+        store.add_module((*specifier).into(), &module, unresolved_mark);
+    }
+
+    Lrc::new(store)
+}
+
+fn tr_const_enums(t: &Tester, verbatim_module_syntax: bool) -> impl Fold {
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+    let const_enums = const_enums(
+        t,
+        &[
+            (
+                "./flags",
+                r#"
+                export declare const enum Flags {
+                    A = 1,
+                    B = A << 1,
+                    AB = A | B,
+                }
+                declare const enum Local {
+                    X = "x",
+                }
+                export { Local as Renamed };
+                export declare enum Regular {
+                    A = 1,
+                }
+                "#,
+            ),
+            (
+                "./status",
+                "export const enum Status { Active = 'active', Unknown = status(), Max = Infinity \
+                 }",
+            ),
+        ],
+    );
+
+    chain!(
+        resolver(unresolved_mark, top_level_mark, true),
+        typescript(
+            typescript::Config {
+                no_empty_export: true,
+                verbatim_module_syntax,
+                const_enums,
+                ..Default::default()
+            },
+            unresolved_mark,
+            top_level_mark
+        )
+    )
+}
+
+test!(
+    Syntax::Typescript(TsSyntax::default()),
+    |t| tr_const_enums(t, false),
+    const_enum_cross_file,
+    r#"
+    import { Flags, Renamed as R, Regular } from "./flags";
+    import { Status } from "./status";
+
+    console.log(Flags.AB, Flags["B"], R.X, Regular.A);
+
+    enum Local {
+        A = Flags.A + 1,
+    }
+    console.log(Local.A);
+    console.log(Status.Active, Status.Unknown, Status.Max);
+    "#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax::default()),
+    |t| tr_const_enums(t, true),
+    const_enum_cross_file_verbatim_module_syntax,
+    r#"
+    import { Flags } from "./flags";
+
+    console.log(Flags.AB);
+    "#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax::default()),
+    |t| tr_const_enums(t, false),
+    const_enum_cross_file_shadowed,
+    r#"
+    import { Flags } from "./flags";
+
+    function foo(Flags) {
+        return Flags.A;
+    }
+    Flags.A = 2;
+    "#
+);
@@ -4,7 +4,7 @@ use indexmap::IndexMap;
 use swc_atoms::JsWord;
 use swc_common::{util::take::Take, Span, Spanned, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
-use swc_ecma_visit::{standard_only_visit_mut, VisitMut, VisitMutWith};
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
 
 use crate::ExprFactory;
 
@@ -281,7 +281,7 @@ impl FnEnvHoister {
 }
 
 impl VisitMut for FnEnvHoister {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     fn visit_mut_assign_target_pat(&mut self, n: &mut AssignTargetPat) {
         let in_pat = self.in_pat;
@@ -603,7 +603,7 @@ struct InitThis<'a> {
 
 // babel is skip function and class property
 impl<'a> VisitMut for InitThis<'a> {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     fn visit_mut_class(&mut self, _: &mut Class) {}
 
@@ -22,8 +22,8 @@ use swc_common::{
 };
 use swc_ecma_ast::*;
 use swc_ecma_visit::{
-    noop_visit_mut_type, noop_visit_type, standard_only_visit_mut, visit_mut_obj_and_computed,
-    visit_obj_and_computed, Visit, VisitMut, VisitMutWith, VisitWith,
+    noop_visit_mut_type, noop_visit_type, visit_mut_obj_and_computed, visit_obj_and_computed,
+    Visit, VisitMut, VisitMutWith, VisitWith,
 };
 use tracing::trace;
 
@@ -2678,7 +2678,7 @@ pub struct IdentReplacer<'a> {
 }
 
 impl VisitMut for IdentReplacer<'_> {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     visit_mut_obj_and_computed!();
 
@@ -2958,7 +2958,7 @@ impl<'a> Remapper<'a> {
 }
 
 impl VisitMut for Remapper<'_> {
-    standard_only_visit_mut!();
+    noop_visit_mut_type!();
 
     fn visit_mut_ident(&mut self, i: &mut Ident) {
         if let Some(new_ctxt) = self.vars.get(&i.to_id()).copied() {
@@ -17,6 +17,10 @@ fn fast_typescript(b: &mut Bencher) {
                     module: None,
                     filename: None,
                     parser: Default::default(),
+                    mode: Default::default(),
+                    transform: None,
+                    source_map: false,
+                    use_define_for_class_fields: false,
                 },
             ))
             .unwrap();
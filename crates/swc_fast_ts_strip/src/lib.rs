@@ -1,27 +1,33 @@
 use std::{cell::RefCell, rc::Rc};
 
-use anyhow::Error;
-use serde::Deserialize;
+use anyhow::{bail, Error};
+use serde::{Deserialize, Serialize};
+use swc_atoms::Atom;
 use swc_common::{
     comments::SingleThreadedComments,
     errors::{Handler, HANDLER},
     sync::Lrc,
-    BytePos, FileName, SourceMap, Span, Spanned,
+    BytePos, FileName, LineCol, Mark, SourceMap, Span, Spanned, DUMMY_SP,
 };
 use swc_ecma_ast::{
-    ArrowExpr, BindingIdent, Class, ClassDecl, ClassMethod, ClassProp, EsVersion, ExportAll,
-    ExportDecl, ExportSpecifier, FnDecl, ImportDecl, ImportSpecifier, NamedExport, Param, Pat,
-    Program, TsAsExpr, TsConstAssertion, TsEnumDecl, TsExportAssignment, TsImportEqualsDecl,
-    TsIndexSignature, TsInstantiation, TsInterfaceDecl, TsModuleDecl, TsModuleName,
-    TsNamespaceDecl, TsNonNullExpr, TsParamPropParam, TsSatisfiesExpr, TsTypeAliasDecl, TsTypeAnn,
-    TsTypeAssertion, TsTypeParamDecl, TsTypeParamInstantiation, VarDecl,
+    ArrowExpr, BindingIdent, Callee, Class, ClassDecl, ClassMember, ClassMethod, ClassProp, Decl,
+    EsVersion, ExportAll, ExportDecl, ExportDefaultDecl, ExportSpecifier, Expr, ExprStmt, FnDecl,
+    ImportDecl, ImportSpecifier, Module, ModuleDecl, ModuleItem, NamedExport, Param,
+    ParamOrTsParamProp, Pat, Program, Stmt, TsAsExpr, TsConstAssertion, TsEnumDecl,
+    TsExportAssignment, TsImportEqualsDecl, TsIndexSignature, TsInstantiation, TsInterfaceDecl,
+    TsModuleDecl, TsModuleName, TsNamespaceDecl, TsNonNullExpr, TsParamProp, TsParamPropParam,
+    TsSatisfiesExpr, TsTypeAliasDecl, TsTypeAnn, TsTypeAssertion, TsTypeParamDecl,
+    TsTypeParamInstantiation, VarDecl, VarDeclKind,
 };
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
 use swc_ecma_parser::{
     lexer::Lexer,
     token::{IdentLike, KnownIdent, Token, TokenAndSpan, Word},
     Capturing, Parser, StringInput, Syntax, TsSyntax,
 };
-use swc_ecma_visit::{Visit, VisitWith};
+use swc_ecma_transforms_base::{fixer::fixer, hygiene::hygiene, resolver};
+use swc_ecma_transforms_typescript::typescript;
+use swc_ecma_visit::{Visit, VisitMutWith, VisitWith};
 
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -33,6 +39,49 @@ pub struct Options {
 
     #[serde(default = "default_ts_syntax")]
     pub parser: TsSyntax,
+
+    #[serde(default)]
+    pub mode: Mode,
+
+    /// Options of the TypeScript transform used to generate code in the
+    /// transform mode. Imports are kept as is, like in the strip-only mode, so
+    /// `verbatim_module_syntax` is always enabled.
+    #[serde(default)]
+    pub transform: Option<typescript::Config>,
+
+    /// Generate a source map for the generated code in the transform mode.
+    #[serde(default)]
+    pub source_map: bool,
+
+    /// Declare parameter properties as class fields in the transform mode,
+    /// like `tsc` does with `useDefineForClassFields`. By default they are
+    /// only assigned in the constructor, like the TypeScript transform does.
+    #[serde(default)]
+    pub use_define_for_class_fields: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Mode {
+    /// Replace types with whitespace, so positions in the output are the same
+    /// as in the input. TypeScript syntax with runtime semantics (e.g. enums,
+    /// namespaces and parameter properties) is an error.
+    #[default]
+    StripOnly,
+    /// Transform TypeScript syntax with runtime semantics too. Types are
+    /// still replaced with whitespace, and only enums, namespaces, import
+    /// assignments and parameter properties are replaced with generated code.
+    /// The generated code is kept on the lines of the replaced code, so only
+    /// columns on these lines are changed.
+    Transform,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TransformOutput {
+    pub code: String,
+    /// The source map, as a JSON string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub map: Option<String>,
 }
 
 fn default_ts_syntax() -> TsSyntax {
@@ -47,7 +96,18 @@ pub fn operate(
     handler: &Handler,
     input: String,
     options: Options,
-) -> Result<String, Error> {
+) -> Result<TransformOutput, Error> {
+    if options.mode == Mode::StripOnly
+        && (options.transform.is_some()
+            || options.source_map
+            || options.use_define_for_class_fields)
+    {
+        bail!(
+            "`transform`, `sourceMap` and `useDefineForClassFields` options can only be used in \
+             the transform mode"
+        );
+    }
+
     let filename = options
         .filename
         .map_or(FileName::Anon, |f| FileName::Real(f.into()));
@@ -98,19 +158,46 @@ pub fn operate(
     }
 
     drop(parser);
+
     let mut tokens = RefCell::into_inner(Rc::try_unwrap(tokens).unwrap());
 
     tokens.sort_by_key(|t| t.span);
 
     // Strip typescript types
-    let mut ts_strip = TsStrip::new(fm.src.clone(), tokens);
+    let mut ts_strip = TsStrip::new(
+        fm.src.clone(),
+        tokens,
+        options.mode == Mode::Transform,
+        options.use_define_for_class_fields,
+    );
     program.visit_with(&mut ts_strip);
 
-    let replacements = ts_strip.replacements;
+    let mut replacements = ts_strip.replacements;
     let overwrites = ts_strip.overwrites;
+    let mut edits = ts_strip.insertions;
+
+    if !ts_strip.transforms.is_empty() {
+        let config = options.transform.unwrap_or_default();
+
+        for item in ts_strip.transforms {
+            let span = item.item.span();
 
-    if replacements.is_empty() && overwrites.is_empty() {
-        return Ok(fm.src.to_string());
+            match generate(cm, item, &config)? {
+                Some(edit) => edits.push(edit),
+                // e.g. namespaces with only types
+                None => replacements.push((span.lo, span.hi)),
+            }
+        }
+    }
+
+    if replacements.is_empty() && overwrites.is_empty() && edits.is_empty() {
+        return Ok(TransformOutput {
+            code: fm.src.to_string(),
+            map: options
+                .source_map
+                .then(|| source_map_to_string(cm, &[]))
+                .transpose()?,
+        });
     }
 
     let source = fm.src.clone();
@@ -166,13 +253,251 @@ pub fn operate(
         code[i.0 as usize - 1] = v;
     }
 
-    if cfg!(debug_assertions) {
-        String::from_utf8(code).map_err(|_| anyhow::anyhow!("failed to convert to utf-8"))
+    let code = if cfg!(debug_assertions) {
+        String::from_utf8(code).map_err(|_| anyhow::anyhow!("failed to convert to utf-8"))?
     } else {
         // SAFETY: We've already validated that the source is valid utf-8
         // and our operations are limited to character-level string replacements.
-        unsafe { Ok(String::from_utf8_unchecked(code)) }
+        unsafe { String::from_utf8_unchecked(code) }
+    };
+
+    if edits.is_empty() {
+        return Ok(TransformOutput {
+            code,
+            map: options
+                .source_map
+                .then(|| source_map_to_string(cm, &[]))
+                .transpose()?,
+        });
+    }
+
+    let (code, mappings) = apply_edits(&source, &code, edits);
+
+    Ok(TransformOutput {
+        code,
+        map: options
+            .source_map
+            .then(|| source_map_to_string(cm, &mappings))
+            .transpose()?,
+    })
+}
+
+/// Code replacing `span` of the input in the transform mode. An empty `span`
+/// is an insertion.
+struct Edit {
+    span: Span,
+    code: String,
+    /// Mappings of `code`, relative to its start.
+    mappings: Vec<(BytePos, LineCol)>,
+}
+
+/// A statement transformed with the TypeScript transform.
+struct TransformItem {
+    item: ModuleItem,
+    /// Whether the statement is in a function or a block.
+    is_nested: bool,
+    /// Whether the name of the enum or namespace is already declared by a
+    /// previous statement, e.g. a function merged with a namespace.
+    is_declared: bool,
+}
+
+/// Generates code for `item`, without line breaks unless they are a part of
+/// a string (e.g. in template literals). Returns [None] if nothing is
+/// generated.
+fn generate(
+    cm: &Lrc<SourceMap>,
+    TransformItem {
+        item,
+        is_nested,
+        is_declared,
+    }: TransformItem,
+    config: &typescript::Config,
+) -> Result<Option<Edit>, Error> {
+    let span = item.span();
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+
+    let mut program = Program::Module(Module {
+        span: DUMMY_SP,
+        body: vec![item],
+        shebang: None,
+    });
+
+    program.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, true));
+    program.visit_mut_with(&mut typescript::typescript(
+        typescript::Config {
+            verbatim_module_syntax: true,
+            no_empty_export: true,
+            import_export_assign_config: config.import_export_assign_config,
+            ts_enum_is_mutable: config.ts_enum_is_mutable,
+            ..Default::default()
+        },
+        unresolved_mark,
+        top_level_mark,
+    ));
+    program.visit_mut_with(&mut hygiene());
+    program.visit_mut_with(&mut fixer(None));
+
+    let Program::Module(mut module) = program else {
+        unreachable!()
+    };
+
+    // The transform declares enums and namespaces with `var`, as if they were
+    // the first declaration at the top level.
+    if is_declared || is_nested {
+        module.body.retain_mut(|item| {
+            let var = match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => var,
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(var),
+                    ..
+                })) => var,
+                _ => return true,
+            };
+
+            if var.decls.iter().any(|decl| decl.init.is_some()) {
+                return true;
+            }
+
+            if is_nested {
+                var.kind = VarDeclKind::Let;
+            }
+
+            !is_declared
+        });
+    }
+
+    if module.body.is_empty() {
+        return Ok(None);
+    }
+
+    let mut code = vec![];
+    let mut mappings = vec![];
+
+    {
+        let mut emitter = Emitter {
+            cfg: swc_ecma_codegen::Config::default().with_minify(true),
+            cm: cm.clone(),
+            comments: None,
+            wr: JsWriter::new(cm.clone(), "\n", &mut code, Some(&mut mappings)),
+        };
+
+        emitter.emit_module(&module)?;
+    }
+
+    Ok(Some(Edit {
+        span,
+        code: String::from_utf8(code)?,
+        mappings,
+    }))
+}
+
+/// Applies `edits` to `code`, keeping the line breaks of the replaced code.
+///
+/// Returns the new code and its mappings. Lines without edits are the same as
+/// in `source`, so they only have a mapping for their start.
+fn apply_edits(
+    source: &str,
+    code: &str,
+    mut edits: Vec<Edit>,
+) -> (String, Vec<(BytePos, LineCol)>) {
+    edits.sort_by_key(|edit| edit.span.lo);
+
+    let mut output = String::with_capacity(code.len());
+    let mut mappings = vec![];
+    let mut pos = LineCol { line: 0, col: 0 };
+    let mut last = 0;
+
+    mappings.push((BytePos(1), pos));
+
+    let advance = |pos: &mut LineCol, s: &str| match s.rfind('\n') {
+        Some(index) => {
+            pos.line += s.matches('\n').count() as u32;
+            pos.col = s[index + 1..].encode_utf16().count() as u32;
+        }
+        None => pos.col += s.encode_utf16().count() as u32,
+    };
+
+    for edit in edits {
+        let (lo, hi) = (edit.span.lo.0 as usize - 1, edit.span.hi.0 as usize - 1);
+
+        copy_lines(&mut output, &mut mappings, &mut pos, code, last, lo);
+
+        // The generated code may not have a mapping for its start
+        if mappings.last().map(|(_, line_col)| *line_col) == Some(pos) {
+            mappings.pop();
+        }
+        mappings.push((edit.span.lo, pos));
+        mappings.extend(edit.mappings.into_iter().map(|(byte_pos, line_col)| {
+            (
+                byte_pos,
+                LineCol {
+                    line: pos.line + line_col.line,
+                    col: if line_col.line == 0 {
+                        pos.col + line_col.col
+                    } else {
+                        line_col.col
+                    },
+                },
+            )
+        }));
+        output.push_str(&edit.code);
+        advance(&mut pos, &edit.code);
+
+        let line_breaks = source[lo..hi]
+            .chars()
+            .filter(|c| matches!(c, '\u{000A}' | '\u{000D}' | '\u{2028}' | '\u{2029}'))
+            .collect::<String>();
+
+        output.push_str(&line_breaks);
+        advance(&mut pos, &line_breaks);
+
+        // The rest of the line is moved by the edit
+        mappings.push((edit.span.hi, pos));
+        last = hi;
+    }
+
+    copy_lines(&mut output, &mut mappings, &mut pos, code, last, code.len());
+
+    (output, mappings)
+}
+
+/// Copies `code[start..end]` to `output`, adding a mapping for the start of
+/// each line.
+fn copy_lines(
+    output: &mut String,
+    mappings: &mut Vec<(BytePos, LineCol)>,
+    pos: &mut LineCol,
+    code: &str,
+    start: usize,
+    end: usize,
+) {
+    let mut line_start = start;
+
+    for (index, _) in code[start..end].match_indices('\n') {
+        let next_line_start = start + index + 1;
+
+        output.push_str(&code[line_start..next_line_start]);
+
+        pos.line += 1;
+        pos.col = 0;
+        mappings.push((BytePos(next_line_start as u32 + 1), *pos));
+        line_start = next_line_start;
     }
+
+    output.push_str(&code[line_start..end]);
+    pos.col += code[line_start..end].encode_utf16().count() as u32;
+}
+
+fn source_map_to_string(
+    cm: &Lrc<SourceMap>,
+    mappings: &[(BytePos, LineCol)],
+) -> Result<String, Error> {
+    let mut map = vec![];
+
+    cm.build_source_map(mappings).to_writer(&mut map)?;
+
+    Ok(String::from_utf8(map)?)
 }
 
 struct TsStrip {
@@ -185,15 +510,42 @@ struct TsStrip {
     overwrites: Vec<(BytePos, u8)>,
 
     tokens: Vec<TokenAndSpan>,
+
+    /// Transform TypeScript syntax with runtime semantics instead of
+    /// reporting it.
+    transform: bool,
+
+    /// Declare parameter properties as class fields in the transform mode.
+    use_define_for_class_fields: bool,
+
+    /// Statements replaced with generated code in the transform mode.
+    transforms: Vec<TransformItem>,
+
+    /// Code inserted for parameter properties in the transform mode.
+    insertions: Vec<Edit>,
+
+    /// Names declared by previous statements of the enclosing statement
+    /// lists.
+    scopes: Vec<Vec<Atom>>,
 }
 
 impl TsStrip {
-    fn new(src: Lrc<String>, tokens: Vec<TokenAndSpan>) -> Self {
+    fn new(
+        src: Lrc<String>,
+        tokens: Vec<TokenAndSpan>,
+        transform: bool,
+        use_define_for_class_fields: bool,
+    ) -> Self {
         TsStrip {
             src,
             replacements: Default::default(),
             overwrites: Default::default(),
             tokens,
+            transform,
+            use_define_for_class_fields,
+            transforms: Default::default(),
+            insertions: Default::default(),
+            scopes: Default::default(),
         }
     }
 }
@@ -234,9 +586,253 @@ impl TsStrip {
     fn get_prev_token(&self, pos: BytePos) -> &TokenAndSpan {
         &self.tokens[self.get_prev_token_index(pos)]
     }
+
+    fn add_transform(&mut self, item: ModuleItem, name: Option<&Atom>) {
+        let is_declared = name.map_or(false, |name| {
+            self.scopes
+                .last()
+                .map_or(false, |scope| scope.contains(name))
+        });
+
+        self.transforms.push(TransformItem {
+            item,
+            is_nested: self.scopes.len() > 1,
+            is_declared,
+        });
+    }
+
+    fn declare(&mut self, names: Vec<Atom>) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.extend(names);
+        }
+    }
+
+    /// Assigns parameter properties in the constructor, and declares them as
+    /// class fields if `use_define_for_class_fields` is enabled.
+    fn add_param_prop_insertions(&mut self, class: &Class) {
+        let Some(constructor) = class.body.iter().find_map(|member| match member {
+            ClassMember::Constructor(constructor) => Some(constructor),
+            _ => None,
+        }) else {
+            return;
+        };
+        let Some(body) = &constructor.body else {
+            return;
+        };
+
+        let param_props = constructor
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                ParamOrTsParamProp::TsParamProp(param_prop) => Some(param_prop),
+                ParamOrTsParamProp::Param(..) => None,
+            })
+            .filter_map(|param_prop| {
+                let name = match &param_prop.param {
+                    TsParamPropParam::Ident(ident) => &ident.sym,
+                    TsParamPropParam::Assign(assign) => &assign.left.as_ident()?.sym,
+                };
+
+                Some((param_prop.span, name))
+            })
+            .collect::<Vec<_>>();
+
+        if param_props.is_empty() {
+            return;
+        }
+
+        // `super()` must be called before `this` is used
+        let super_call = body.stmts.iter().find(|stmt| match stmt {
+            Stmt::Expr(ExprStmt { expr, .. }) => matches!(
+                &**expr,
+                Expr::Call(call) if matches!(call.callee, Callee::Super(..))
+            ),
+            _ => false,
+        });
+        let (pos, prefix) = match super_call {
+            Some(super_call) => {
+                let hi = super_call.span().hi;
+                let has_semi = self.get_src_slice(span(hi - BytePos(1), hi)) == ";";
+
+                (hi, if has_semi { "" } else { ";" })
+            }
+            None if class.super_class.is_some() => {
+                HANDLER.with(|handler| {
+                    handler.span_err(
+                        constructor.span,
+                        "A 'super' call must be a root-level statement within a constructor of a \
+                         derived class that contains parameter properties",
+                    );
+                });
+
+                return;
+            }
+            None => (body.span.lo + BytePos(1), ""),
+        };
+
+        self.insertions
+            .push(param_prop_insertion(pos, prefix, &param_props, |name| {
+                format!("this.{name}={name};")
+            }));
+
+        if !self.use_define_for_class_fields {
+            return;
+        }
+
+        // The body of the class starts after the last part of its head
+        let head_hi = [
+            class.decorators.last().map(|decorator| decorator.span.hi),
+            class
+                .type_params
+                .as_ref()
+                .map(|type_params| type_params.span.hi),
+            class
+                .super_class
+                .as_ref()
+                .map(|super_class| super_class.span().hi),
+            class
+                .super_type_params
+                .as_ref()
+                .map(|type_params| type_params.span.hi),
+            class.implements.last().map(|implements| implements.span.hi),
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or(class.span.lo);
+        let l_brace = self.tokens[self.get_next_token_index(head_hi)..]
+            .iter()
+            .find(|token| token.token == Token::LBrace)
+            .map(|token| token.span.hi);
+
+        if let Some(l_brace) = l_brace {
+            self.insertions
+                .push(param_prop_insertion(l_brace, "", &param_props, |name| {
+                    format!("{name};")
+                }));
+        }
+    }
+}
+
+/// Inserts code generated for each parameter property at `pos`, mapped to
+/// the parameter properties.
+fn param_prop_insertion(
+    pos: BytePos,
+    prefix: &str,
+    param_props: &[(Span, &Atom)],
+    generate: impl Fn(&Atom) -> String,
+) -> Edit {
+    let mut code = prefix.to_string();
+    let mut mappings = vec![];
+
+    for (param_span, name) in param_props {
+        mappings.push((
+            param_span.lo,
+            LineCol {
+                line: 0,
+                col: code.encode_utf16().count() as u32,
+            },
+        ));
+        code.push_str(&generate(name));
+    }
+
+    Edit {
+        span: span(pos, pos),
+        code,
+        mappings,
+    }
+}
+
+/// Names declared by a statement, which can conflict with an enum or a
+/// namespace.
+fn declared_names(item: &ModuleItem) -> Vec<Atom> {
+    let decl = match item {
+        ModuleItem::Stmt(Stmt::Decl(decl))
+        | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => decl,
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl, ..
+        })) => {
+            return match decl {
+                swc_ecma_ast::DefaultDecl::Class(class) => class.ident.iter(),
+                swc_ecma_ast::DefaultDecl::Fn(function) => function.ident.iter(),
+                swc_ecma_ast::DefaultDecl::TsInterfaceDecl(..) => None.iter(),
+            }
+            .map(|ident| ident.sym.clone())
+            .collect();
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+            return import
+                .specifiers
+                .iter()
+                .map(|specifier| match specifier {
+                    ImportSpecifier::Named(named) => named.local.sym.clone(),
+                    ImportSpecifier::Default(default) => default.local.sym.clone(),
+                    ImportSpecifier::Namespace(namespace) => namespace.local.sym.clone(),
+                })
+                .collect();
+        }
+        _ => return vec![],
+    };
+
+    match decl {
+        Decl::Class(class) => vec![class.ident.sym.clone()],
+        Decl::Fn(function) => vec![function.ident.sym.clone()],
+        Decl::Var(var) => {
+            let mut names = vec![];
+
+            var.decls
+                .visit_with(&mut BindingCollector { names: &mut names });
+
+            names
+        }
+        Decl::TsEnum(ts_enum) => vec![ts_enum.id.sym.clone()],
+        Decl::TsModule(ts_module) => match &ts_module.id {
+            TsModuleName::Ident(ident) => vec![ident.sym.clone()],
+            TsModuleName::Str(..) => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+struct BindingCollector<'a> {
+    names: &'a mut Vec<Atom>,
+}
+
+impl Visit for BindingCollector<'_> {
+    fn visit_binding_ident(&mut self, n: &BindingIdent) {
+        self.names.push(n.sym.clone());
+    }
+
+    /// Default values and initializers don't declare names
+    fn visit_expr(&mut self, _: &Expr) {}
 }
 
 impl Visit for TsStrip {
+    fn visit_module_items(&mut self, n: &[ModuleItem]) {
+        self.scopes.push(vec![]);
+
+        for item in n {
+            item.visit_with(self);
+            self.declare(declared_names(item));
+        }
+
+        self.scopes.pop();
+    }
+
+    fn visit_stmts(&mut self, n: &[Stmt]) {
+        self.scopes.push(vec![]);
+
+        for stmt in n {
+            stmt.visit_with(self);
+
+            if let Stmt::Decl(..) = stmt {
+                self.declare(declared_names(&ModuleItem::Stmt(stmt.clone())));
+            }
+        }
+
+        self.scopes.pop();
+    }
+
     fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
         if let Some(ret) = &n.return_type {
             self.add_replacement(ret.span);
@@ -331,6 +927,10 @@ impl Visit for TsStrip {
             self.add_replacement(span);
         }
 
+        if self.transform {
+            self.add_param_prop_insertions(n);
+        }
+
         n.visit_children_with(self);
     }
 
@@ -425,11 +1025,19 @@ impl Visit for TsStrip {
     }
 
     fn visit_export_decl(&mut self, n: &ExportDecl) {
-        match n.decl {
-            swc_ecma_ast::Decl::TsInterface(_)
-            | swc_ecma_ast::Decl::TsTypeAlias(_)
-            | swc_ecma_ast::Decl::TsEnum(_)
-            | swc_ecma_ast::Decl::TsModule(_) => {
+        match &n.decl {
+            Decl::TsEnum(ts_enum) if self.transform && !ts_enum.declare => {
+                self.add_transform(n.clone().into(), Some(&ts_enum.id.sym));
+            }
+            Decl::TsModule(ts_module) if self.transform && !ts_module.declare => {
+                match &ts_module.id {
+                    TsModuleName::Ident(ident) => {
+                        self.add_transform(n.clone().into(), Some(&ident.sym))
+                    }
+                    TsModuleName::Str(..) => self.add_replacement(n.span),
+                }
+            }
+            Decl::TsInterface(_) | Decl::TsTypeAlias(_) | Decl::TsEnum(_) | Decl::TsModule(_) => {
                 self.add_replacement(n.span);
             }
 
@@ -501,6 +1109,11 @@ impl Visit for TsStrip {
             return;
         }
 
+        if self.transform {
+            self.add_transform(ModuleDecl::TsImportEquals(Box::new(n.clone())).into(), None);
+            return;
+        }
+
         HANDLER.with(|handler| {
             handler.span_err(
                 n.span,
@@ -510,6 +1123,11 @@ impl Visit for TsStrip {
     }
 
     fn visit_ts_export_assignment(&mut self, n: &TsExportAssignment) {
+        if self.transform {
+            self.add_transform(ModuleDecl::TsExportAssignment(n.clone()).into(), None);
+            return;
+        }
+
         HANDLER.with(|handler| {
             handler.span_err(
                 n.span,
@@ -561,6 +1179,14 @@ impl Visit for TsStrip {
             return;
         }
 
+        if self.transform {
+            self.add_transform(
+                Stmt::Decl(Decl::TsEnum(Box::new(e.clone()))).into(),
+                Some(&e.id.sym),
+            );
+            return;
+        }
+
         HANDLER.with(|handler| {
             handler.span_err(
                 e.span,
@@ -585,6 +1211,14 @@ impl Visit for TsStrip {
             return;
         }
 
+        if let (true, TsModuleName::Ident(ident)) = (self.transform, &n.id) {
+            self.add_transform(
+                Stmt::Decl(Decl::TsModule(Box::new(n.clone()))).into(),
+                Some(&ident.sym),
+            );
+            return;
+        }
+
         HANDLER.with(|handler| {
             handler.span_err(
                 n.span(),
@@ -613,6 +1247,46 @@ impl Visit for TsStrip {
         n.expr.visit_children_with(self);
     }
 
+    fn visit_ts_param_prop(&mut self, n: &TsParamProp) {
+        if !self.transform {
+            n.visit_children_with(self);
+            return;
+        }
+
+        // Assignments are inserted by `add_param_prop_insertions`
+        let param_pos = n.param.span_lo();
+        let mut index = self.get_next_token_index(n.span_lo());
+
+        while let Some(TokenAndSpan { token, span, .. }) = self.tokens.get(index) {
+            if span.lo >= param_pos {
+                break;
+            }
+
+            index += 1;
+
+            match token {
+                Token::Word(Word::Ident(IdentLike::Known(
+                    KnownIdent::Readonly
+                    | KnownIdent::Public
+                    | KnownIdent::Protected
+                    | KnownIdent::Private,
+                ))) => {
+                    self.add_replacement(*span);
+                }
+                Token::Word(Word::Ident(IdentLike::Other(o))) if *o == "override" => {
+                    self.add_replacement(*span);
+                }
+                _ => {}
+            }
+        }
+
+        n.decorators.visit_with(self);
+        match &n.param {
+            TsParamPropParam::Ident(ident) => ident.visit_with(self),
+            TsParamPropParam::Assign(assign) => assign.visit_with(self),
+        }
+    }
+
     fn visit_ts_param_prop_param(&mut self, n: &TsParamPropParam) {
         HANDLER.with(|handler| {
             handler.span_err(
@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 use swc_ecma_parser::TsSyntax;
-use swc_fast_ts_strip::{operate, Options};
+use swc_fast_ts_strip::{operate, Mode, Options};
 use testing::NormalizedOutput;
 
 #[testing::fixture("tests/fixture/**/*.ts")]
@@ -10,7 +10,9 @@ fn test(input: PathBuf) {
     let output_file = input.with_extension("js");
 
     testing::run_test(false, |cm, handler| {
-        let code = operate(&cm, handler, input_code, opts()).expect("should not return Err()");
+        let code = operate(&cm, handler, input_code, opts(Mode::StripOnly))
+            .expect("should not return Err()")
+            .code;
 
         NormalizedOutput::new_raw(code)
             .compare_to_file(output_file)
@@ -27,7 +29,7 @@ fn error(input: PathBuf) {
     let output_file = input.with_extension("swc-stderr");
 
     testing::run_test(false, |cm, handler| {
-        operate(&cm, handler, input_code, opts()).expect("should not return Err()");
+        operate(&cm, handler, input_code, opts(Mode::StripOnly)).expect("should not return Err()");
 
         Err::<(), _>(())
     })
@@ -36,7 +38,88 @@ fn error(input: PathBuf) {
     .unwrap();
 }
 
-fn opts() -> Options {
+#[testing::fixture("tests/transform-errors/**/*.ts")]
+fn transform_error(input: PathBuf) {
+    let input_code = std::fs::read_to_string(&input).unwrap();
+    let output_file = input.with_extension("swc-stderr");
+
+    testing::run_test(false, |cm, handler| {
+        operate(&cm, handler, input_code, opts(Mode::Transform)).expect("should not return Err()");
+
+        Err::<(), _>(())
+    })
+    .expect_err("should fail")
+    .compare_to_file(output_file)
+    .unwrap();
+}
+
+#[test]
+fn transform_options_in_strip_only_mode() {
+    testing::run_test(false, |cm, handler| {
+        let err = operate(
+            &cm,
+            handler,
+            "enum Foo {}".into(),
+            Options {
+                transform: Some(Default::default()),
+                ..opts(Mode::StripOnly)
+            },
+        )
+        .expect_err("should return Err()");
+
+        assert_eq!(
+            err.to_string(),
+            "`transform`, `sourceMap` and `useDefineForClassFields` options can only be used in \
+             the transform mode"
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[testing::fixture("tests/transform/**/*.ts")]
+fn transform(input: PathBuf) {
+    transform_inner(input, false)
+}
+
+#[testing::fixture("tests/transform-define-fields/**/*.ts")]
+fn transform_define_fields(input: PathBuf) {
+    transform_inner(input, true)
+}
+
+fn transform_inner(input: PathBuf, use_define_for_class_fields: bool) {
+    let input_code = std::fs::read_to_string(&input).unwrap();
+    let output_file = input.with_extension("js");
+    let map_file = input.with_extension("map");
+
+    testing::run_test(false, |cm, handler| {
+        let output = operate(
+            &cm,
+            handler,
+            input_code,
+            Options {
+                filename: Some("input.ts".into()),
+                source_map: true,
+                use_define_for_class_fields,
+                ..opts(Mode::Transform)
+            },
+        )
+        .expect("should not return Err()");
+
+        NormalizedOutput::new_raw(output.code)
+            .compare_to_file(output_file)
+            .unwrap();
+        NormalizedOutput::new_raw(output.map.expect("should generate a source map"))
+            .compare_to_file(map_file)
+            .unwrap();
+
+        Ok(())
+    })
+    .expect("should not fail");
+}
+
+fn opts(mode: Mode) -> Options {
     Options {
         module: None,
         filename: None,
@@ -44,5 +127,9 @@ fn opts() -> Options {
             decorators: true,
             ..Default::default()
         },
+        mode,
+        transform: None,
+        source_map: false,
+        use_define_for_class_fields: false,
     }
 }
@@ -0,0 +1,17 @@
+import { Base } from "./base";
+                                         
+
+export class Point extends Base {x;y;options;
+    constructor(
+                        x        ,
+                y        ,
+                  options          ,
+    ) {
+        super();this.x=x;this.y=y;this.options=options;
+        console.log(this.x + this.y);
+    }
+}
+
+class Plain                       {value;
+    constructor(        value    = null ) {this.value=value;}
+}
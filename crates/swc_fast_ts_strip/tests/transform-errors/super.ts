@@ -0,0 +1,9 @@
+class Derived extends Base {
+    constructor(public value: number) {
+        if (value) {
+            super();
+        } else {
+            super();
+        }
+    }
+}
@@ -0,0 +1,19 @@
+var Direction;(function(Direction){Direction[Direction["Up"]=1]="Up";Direction[Direction["Down"]=2]="Down";})(Direction||(Direction={}));
+
+
+
+
+export var Color;(function(Color){Color["Red"]="red";Color["Green"]="green";})(Color||(Color={}));
+
+
+
+
+var Flags;(function(Flags){Flags[Flags["None"]=0]="None";Flags[Flags["A"]=1]="A";Flags[Flags["B"]=2]="B";Flags[Flags["AB"]=3]="AB";})(Flags||(Flags={}));
+
+
+
+
+
+
+let flags        = Flags.AB;
+let direction = Direction.Up             ;
@@ -0,0 +1,19 @@
+enum Direction {
+    Up = 1,
+    Down,
+}
+
+export enum Color {
+    Red = "red",
+    Green = "green",
+}
+
+const enum Flags {
+    None = 0,
+    A = 1 << 0,
+    B = 1 << 1,
+    AB = A | B,
+}
+
+let flags: Flags = Flags.AB;
+let direction = Direction.Up as Direction;
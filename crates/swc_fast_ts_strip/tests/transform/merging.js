@@ -0,0 +1,28 @@
+function greet(name        )         {
+    return `Hello, ${name}`;
+}
+(function(greet){greet.punctuation="!";})(greet||(greet={}));
+
+
+
+class Point { x = 0; }
+(function(Point){function origin(){return new Point;}Point.origin=origin;})(Point||(Point={}));
+
+
+
+
+
+var Color;(function(Color){Color[Color["Red"]=0]="Red";})(Color||(Color={}));
+(function(Color){Color[Color["Green"]=2]="Green";})(Color||(Color={}));
+
+function scoped() {
+    let Local;(function(Local){Local[Local["A"]=0]="A";Local[Local["B"]=1]="B";})(Local||(Local={}));
+    return Local.B          ;
+}
+
+                 
+                             
+ 
+
+const fs=require("fs");
+export const Origin=Point.origin;
@@ -0,0 +1,28 @@
+function greet(name: string): string {
+    return `Hello, ${name}`;
+}
+namespace greet {
+    export const punctuation: string = "!";
+}
+
+class Point { x = 0; }
+namespace Point {
+    export function origin(): Point {
+        return new Point();
+    }
+}
+
+enum Color { Red }
+enum Color { Green = 2 }
+
+function scoped() {
+    enum Local { A, B }
+    return Local.B as number;
+}
+
+namespace Types {
+    export interface Shape {}
+}
+
+import fs = require("fs");
+export import Origin = Point.origin;
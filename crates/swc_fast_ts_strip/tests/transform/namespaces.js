@@ -0,0 +1,23 @@
+var Validation;(function(Validation){const lettersRegexp=/^[A-Za-z]+$/;class LettersOnlyValidator{isAcceptable(s){return lettersRegexp.test(s);}}Validation.LettersOnlyValidator=LettersOnlyValidator;let Nested;(function(Nested){Nested.value=1;})(Nested=Validation.Nested||(Validation.Nested={}));})(Validation||(Validation={}));
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+                            
+                        
+ 
+
+let validator = new Validation.LettersOnlyValidator();
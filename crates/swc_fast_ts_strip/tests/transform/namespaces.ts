@@ -0,0 +1,23 @@
+namespace Validation {
+    export interface StringValidator {
+        isAcceptable(s: string): boolean;
+    }
+
+    const lettersRegexp = /^[A-Za-z]+$/;
+
+    export class LettersOnlyValidator implements StringValidator {
+        isAcceptable(s: string) {
+            return lettersRegexp.test(s);
+        }
+    }
+
+    export namespace Nested {
+        export const value: number = 1;
+    }
+}
+
+declare namespace Declared {
+    const value: string;
+}
+
+let validator = new Validation.LettersOnlyValidator();
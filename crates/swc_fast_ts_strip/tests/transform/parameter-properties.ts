@@ -0,0 +1,17 @@
+import { Base } from "./base";
+import type { Options } from "./options";
+
+export class Point extends Base {
+    constructor(
+        public readonly x: number,
+        private y: number,
+        protected options?: Options,
+    ) {
+        super()
+        console.log(this.x + this.y);
+    }
+}
+
+class Plain<T> implements Options {
+    constructor(private value: T = null!) {}
+}
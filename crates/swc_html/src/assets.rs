@@ -0,0 +1,264 @@
+//! Rewriting of asset URLs and [subresource integrity](https://developer.mozilla.org/en-US/docs/Web/Security/Subresource_Integrity).
+//!
+//! Used to post-process documents of builds, where assets are emitted with
+//! hashed file names. It should be applied before minification, so the
+//! minifier can normalize the new attributes.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use sha2::{Digest, Sha256, Sha384};
+use swc_common::DUMMY_SP;
+use swc_html_ast::{Attribute, Document, DocumentFragment, Element, Namespace};
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityAlgorithm {
+    Sha256,
+    Sha384,
+}
+
+impl IntegrityAlgorithm {
+    /// Returns the integrity metadata of `content`, i.e. the name of the
+    /// algorithm and the base64 encoded digest, e.g. `sha384-...`.
+    pub fn integrity(self, content: &[u8]) -> String {
+        match self {
+            IntegrityAlgorithm::Sha256 => {
+                format!("sha256-{}", STANDARD.encode(Sha256::digest(content)))
+            }
+            IntegrityAlgorithm::Sha384 => {
+                format!("sha384-{}", STANDARD.encode(Sha384::digest(content)))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AssetsConfig {
+    /// Add the `integrity` attribute to `script` elements and to `link`
+    /// elements loading stylesheets and scripts, when the content of the asset
+    /// is resolved. An existing `integrity` attribute is replaced.
+    ///
+    /// Note that cross-origin assets also need the `crossorigin` attribute,
+    /// which is not added.
+    pub integrity: Option<IntegrityAlgorithm>,
+}
+
+/// An asset returned by the resolver.
+#[derive(Debug, Clone)]
+pub struct ResolvedAsset {
+    /// The URL written to the document, e.g. with a hashed file name.
+    pub url: String,
+    /// The content of the asset, required to compute the integrity.
+    pub content: Option<Vec<u8>>,
+}
+
+/// Rewrites URLs of `script`, `link`, `img` and `source` elements (including
+/// candidates of `srcset`) with `resolver`, which is called with the URL as
+/// written in the document. URLs are kept when `resolver` returns [None].
+pub fn rewrite_document_assets<F>(document: &mut Document, config: &AssetsConfig, resolver: F)
+where
+    F: FnMut(&str) -> Option<ResolvedAsset>,
+{
+    document.visit_mut_with(&mut AssetRewriter { config, resolver });
+}
+
+/// Same as [rewrite_document_assets], but for a document fragment.
+pub fn rewrite_document_fragment_assets<F>(
+    document_fragment: &mut DocumentFragment,
+    config: &AssetsConfig,
+    resolver: F,
+) where
+    F: FnMut(&str) -> Option<ResolvedAsset>,
+{
+    document_fragment.visit_mut_with(&mut AssetRewriter { config, resolver });
+}
+
+struct AssetRewriter<'a, F>
+where
+    F: FnMut(&str) -> Option<ResolvedAsset>,
+{
+    config: &'a AssetsConfig,
+    resolver: F,
+}
+
+impl<F> AssetRewriter<'_, F>
+where
+    F: FnMut(&str) -> Option<ResolvedAsset>,
+{
+    /// Rewrites the URL of the attribute, returning the content of the asset.
+    fn rewrite_url(&mut self, element: &mut Element, name: &str) -> Option<Vec<u8>> {
+        let attribute = find_attribute(&mut element.attributes, name)?;
+        let url = attribute.value.as_deref()?.trim();
+
+        if url.is_empty() {
+            return None;
+        }
+
+        let asset = (self.resolver)(url)?;
+
+        attribute.value = Some(asset.url.into());
+        attribute.raw_value = None;
+
+        asset.content
+    }
+
+    fn rewrite_srcset(&mut self, element: &mut Element) {
+        let Some(attribute) = find_attribute(&mut element.attributes, "srcset") else {
+            return;
+        };
+        let Some(value) = &attribute.value else {
+            return;
+        };
+
+        let mut srcset = String::with_capacity(value.len());
+        let mut is_changed = false;
+
+        for (url, descriptors) in parse_srcset(value) {
+            if !srcset.is_empty() {
+                srcset.push_str(", ");
+            }
+
+            match (self.resolver)(url) {
+                Some(asset) => {
+                    srcset.push_str(&asset.url);
+                    is_changed = true;
+                }
+                None => srcset.push_str(url),
+            }
+
+            if !descriptors.is_empty() {
+                srcset.push(' ');
+                srcset.push_str(descriptors);
+            }
+        }
+
+        if is_changed {
+            attribute.value = Some(srcset.into());
+            attribute.raw_value = None;
+        }
+    }
+
+    fn set_integrity(&self, element: &mut Element, content: Option<Vec<u8>>) {
+        let (Some(algorithm), Some(content)) = (self.config.integrity, content) else {
+            return;
+        };
+        let integrity = algorithm.integrity(&content);
+
+        match find_attribute(&mut element.attributes, "integrity") {
+            Some(attribute) => {
+                attribute.value = Some(integrity.into());
+                attribute.raw_value = None;
+            }
+            None => element.attributes.push(Attribute {
+                span: DUMMY_SP,
+                namespace: None,
+                prefix: None,
+                name: "integrity".into(),
+                raw_name: None,
+                value: Some(integrity.into()),
+                raw_value: None,
+            }),
+        }
+    }
+}
+
+impl<F> VisitMut for AssetRewriter<'_, F>
+where
+    F: FnMut(&str) -> Option<ResolvedAsset>,
+{
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        n.visit_mut_children_with(self);
+
+        if n.namespace != Namespace::HTML {
+            return;
+        }
+
+        match &*n.tag_name {
+            "script" => {
+                let content = self.rewrite_url(n, "src");
+
+                self.set_integrity(n, content);
+            }
+            "link" => {
+                let content = self.rewrite_url(n, "href");
+                // Browsers only check the integrity of stylesheets and scripts
+                let is_integrity_supported = find_attribute(&mut n.attributes, "rel")
+                    .and_then(|rel| rel.value.as_ref())
+                    .map_or(false, |rel| {
+                        rel.split_ascii_whitespace().any(|keyword| {
+                            keyword.eq_ignore_ascii_case("stylesheet")
+                                || keyword.eq_ignore_ascii_case("preload")
+                                || keyword.eq_ignore_ascii_case("modulepreload")
+                        })
+                    });
+
+                if is_integrity_supported {
+                    self.set_integrity(n, content);
+                }
+            }
+            "img" | "source" => {
+                self.rewrite_url(n, "src");
+                self.rewrite_srcset(n);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn find_attribute<'a>(attributes: &'a mut [Attribute], name: &str) -> Option<&'a mut Attribute> {
+    attributes
+        .iter_mut()
+        .find(|attribute| attribute.namespace.is_none() && attribute.name == name)
+}
+
+/// Splits a `srcset` attribute into URLs and descriptors, see
+/// <https://html.spec.whatwg.org/multipage/images.html#parse-a-srcset-attribute>
+fn parse_srcset(mut value: &str) -> Vec<(&str, &str)> {
+    let mut candidates = vec![];
+
+    loop {
+        value = value.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+
+        if value.is_empty() {
+            break;
+        }
+
+        let url_end = value
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(value.len());
+        let url = &value[..url_end];
+
+        value = &value[url_end..];
+
+        // A URL ending with commas has no descriptors
+        if url.ends_with(',') {
+            candidates.push((url.trim_end_matches(','), ""));
+
+            continue;
+        }
+
+        let mut depth = 0usize;
+        let descriptors_end = value
+            .char_indices()
+            .find(|&(_, c)| match c {
+                '(' => {
+                    depth += 1;
+
+                    false
+                }
+                ')' => {
+                    depth = depth.saturating_sub(1);
+
+                    false
+                }
+                ',' => depth == 0,
+                _ => false,
+            })
+            .map_or(value.len(), |(index, _)| index);
+
+        candidates.push((url, value[..descriptors_end].trim()));
+
+        value = &value[descriptors_end..];
+    }
+
+    candidates
+}
@@ -0,0 +1,78 @@
+use std::path::PathBuf;
+
+use swc_html::{
+    assets::{rewrite_document_assets, AssetsConfig, IntegrityAlgorithm, ResolvedAsset},
+    codegen::{
+        writer::basic::{BasicHtmlWriter, BasicHtmlWriterConfig},
+        CodeGenerator, CodegenConfig, Emit,
+    },
+    parser::parse_file_as_document,
+};
+use testing::NormalizedOutput;
+
+/// Resolves local URLs to hashed file names, the content of `.js` and `.css`
+/// files is their name.
+fn resolve(url: &str) -> Option<ResolvedAsset> {
+    if url.contains("://") || url.starts_with("data:") {
+        return None;
+    }
+
+    let (stem, extension) = url.rsplit_once('.')?;
+    let content = matches!(extension, "js" | "css").then(|| url.as_bytes().to_vec());
+
+    Some(ResolvedAsset {
+        url: format!("{}.{:08x}.{}", stem, url.len() * 0x1234567, extension),
+        content,
+    })
+}
+
+#[testing::fixture("tests/assets/**/input.html")]
+fn assets(input: PathBuf) {
+    let output = input.parent().unwrap().join("output.html");
+
+    testing::run_test2(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+
+        let mut errors = vec![];
+        let mut document = parse_file_as_document(&fm, Default::default(), &mut errors).unwrap();
+
+        for err in errors {
+            err.to_diagnostics(&handler).emit();
+        }
+
+        rewrite_document_assets(
+            &mut document,
+            &AssetsConfig {
+                integrity: Some(IntegrityAlgorithm::Sha384),
+            },
+            resolve,
+        );
+
+        let mut html_str = String::new();
+        {
+            let wr = BasicHtmlWriter::new(&mut html_str, None, BasicHtmlWriterConfig::default());
+            let mut gen = CodeGenerator::new(wr, CodegenConfig::default());
+
+            gen.emit(&document).unwrap();
+        }
+
+        NormalizedOutput::from(html_str)
+            .compare_to_file(&output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn integrity() {
+    assert_eq!(
+        IntegrityAlgorithm::Sha256.integrity(b"alert('Hello, world.');"),
+        "sha256-qznLcsROx4GACP2dm0UCKCzCG+HiZ1guq6ZZDob/Tng="
+    );
+    assert_eq!(
+        IntegrityAlgorithm::Sha384.integrity(b"alert('Hello, world.');"),
+        "sha384-H8BRh8j48O9oYatfu5AZzq6A9RINhZO5H16dQZngK7T62em8MUt1FLm52t+eX6xO"
+    );
+}
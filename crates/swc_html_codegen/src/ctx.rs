@@ -23,6 +23,8 @@ where
 #[derive(Debug, Default, Clone, Copy)]
 pub(crate) struct Ctx {
     pub need_escape_text: bool,
+    /// Whitespace of children must be printed as is, even in `pretty` mode.
+    pub preserve_whitespace: bool,
 }
 
 pub(super) struct WithCtx<'w, 'a, I: 'w + HtmlWriter> {
@@ -4,14 +4,14 @@
 #![allow(non_local_definitions)]
 
 pub use std::fmt::Result;
-use std::{borrow::Cow, iter::Peekable, str::Chars};
+use std::{borrow::Cow, iter::Peekable, mem::take, str::Chars};
 
 use swc_atoms::JsWord;
-use swc_common::Spanned;
+use swc_common::{collections::AHashMap, BytePos, LineCol, Span, Spanned, DUMMY_SP};
 use swc_html_ast::*;
 use swc_html_codegen_macros::emitter;
 use swc_html_utils::HTML_ENTITIES;
-use writer::HtmlWriter;
+use writer::{basic::BasicHtmlWriter, HtmlWriter};
 
 pub use self::emit::*;
 use self::{ctx::Ctx, list::ListFormat};
@@ -39,6 +39,65 @@ pub struct CodegenConfig<'a> {
     /// Always print quotes or remove them where possible (only when `minify`
     /// enabled) By default `false` when `minify` enabled, otherwise `true`
     pub quotes: Option<bool>,
+    /// Reformat the output, i.e. indent children of block-level elements and
+    /// normalize whitespace (only when `minify` disabled). Indentation and line
+    /// feeds are configured by the writer.
+    pub pretty: bool,
+    /// Maximum line width (only when `pretty` enabled) By default `80`
+    pub print_width: Option<usize>,
+    /// How attributes are wrapped (only when `pretty` enabled)
+    pub attribute_wrapping: AttributeWrapping,
+    /// Which whitespace can be changed (only when `pretty` enabled)
+    pub whitespace_sensitivity: WhitespaceSensitivity,
+    /// Source maps of text generated by other code generators, i.e. of
+    /// minified `script` and `style` elements, added to the mappings of the
+    /// writer
+    pub embedded_source_maps: Option<&'a EmbeddedSourceMaps>,
+}
+
+/// Mappings of the content of text nodes, keyed by the span of the text node.
+/// Lines and columns are relative to the start of the text.
+pub type EmbeddedSourceMaps = AHashMap<Span, Vec<(BytePos, LineCol)>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttributeWrapping {
+    /// Print each attribute on its own line if the start tag doesn't fit in
+    /// `print_width`.
+    #[default]
+    Auto,
+    /// Always print each attribute on its own line if there are several
+    /// attributes.
+    Force,
+    /// Never wrap attributes.
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WhitespaceSensitivity {
+    /// Respect the default CSS `display` of elements, i.e. whitespace around
+    /// block-level elements and at the edges of their content is not
+    /// significant and may be added or removed.
+    #[default]
+    Css,
+    /// Whitespace is significant everywhere, existing whitespace can only be
+    /// replaced by a line break and no whitespace is added.
+    Strict,
+    /// Whitespace is not significant, every element is formatted as a
+    /// block-level element.
+    Ignore,
+}
+
+#[derive(Clone, Copy)]
+enum PrettyParent<'a> {
+    Document,
+    DocumentFragment,
+    Element(&'a Element),
+}
+
+enum PrettyItem<'a> {
+    Space,
+    Word(Cow<'a, str>),
+    Node(usize),
 }
 
 enum TagOmissionParent<'a> {
@@ -60,6 +119,10 @@ where
     tag_omission: bool,
     self_closing_void_elements: bool,
     quotes: bool,
+    pretty: bool,
+    print_width: usize,
+    // Used to measure the width of nodes printed on a single line
+    is_measuring: bool,
 }
 
 impl<'a, W> CodeGenerator<'a, W>
@@ -70,6 +133,8 @@ where
         let tag_omission = config.tag_omission.unwrap_or(config.minify);
         let self_closing_void_elements = config.tag_omission.unwrap_or(!config.minify);
         let quotes = config.quotes.unwrap_or(!config.minify);
+        let pretty = config.pretty && !config.minify;
+        let print_width = config.print_width.unwrap_or(80);
 
         CodeGenerator {
             wr,
@@ -79,12 +144,19 @@ where
             tag_omission,
             self_closing_void_elements,
             quotes,
+            pretty,
+            print_width,
+            is_measuring: false,
         }
     }
 
     #[emitter]
     fn emit_document(&mut self, n: &Document) -> Result {
-        if self.tag_omission {
+        if self.pretty {
+            self.emit_pretty_children(&n.children, PrettyParent::Document)?;
+
+            formatting_newline!(self);
+        } else if self.tag_omission {
             self.emit_list_for_tag_omission(TagOmissionParent::Document(n))?;
         } else {
             self.emit_list(&n.children, ListFormat::NotDelimited)?;
@@ -99,7 +171,12 @@ where
             Default::default()
         };
 
-        if self.tag_omission {
+        if self.pretty && !ctx.preserve_whitespace {
+            self.with_ctx(ctx)
+                .emit_pretty_children(&n.children, PrettyParent::DocumentFragment)?;
+
+            formatting_newline!(self);
+        } else if self.tag_omission {
             self.with_ctx(ctx)
                 .emit_list_for_tag_omission(TagOmissionParent::DocumentFragment(n))?;
         } else {
@@ -342,10 +419,29 @@ where
         };
 
         if !can_omit_start_tag {
-            write_raw!(self, "<");
+            let start_span = if n.span.is_dummy() {
+                DUMMY_SP
+            } else {
+                n.span.with_hi(n.span.lo + BytePos(1))
+            };
+
+            write_raw!(self, start_span, "<");
             write_raw!(self, &n.tag_name);
 
-            if has_attributes {
+            let wrap_attributes = has_attributes && self.need_wrap_attributes(n);
+
+            if wrap_attributes {
+                self.wr.increase_indent();
+
+                for attribute in &n.attributes {
+                    newline!(self);
+                    emit!(self, attribute);
+                }
+
+                self.wr.decrease_indent();
+
+                newline!(self);
+            } else if has_attributes {
                 space!(self);
 
                 self.emit_list(&n.attributes, ListFormat::SpaceDelimited)?;
@@ -372,7 +468,7 @@ where
                     if need_space {
                         write_raw!(self, " ");
                     }
-                } else {
+                } else if !wrap_attributes {
                     write_raw!(self, " ");
                 }
 
@@ -395,7 +491,14 @@ where
         }
 
         if let Some(content) = &n.content {
-            emit!(self, content);
+            if self.pretty && !self.ctx.preserve_whitespace {
+                let ctx = self.create_context_for_element(n);
+
+                self.with_ctx(ctx)
+                    .emit_pretty_children(&content.children, PrettyParent::Element(n))?;
+            } else {
+                emit!(self, content);
+            }
         } else if !n.children.is_empty() {
             let ctx = self.create_context_for_element(n);
 
@@ -405,14 +508,22 @@ where
             if need_extra_newline {
                 if let Some(Child::Text(Text { data, .. })) = &n.children.first() {
                     if data.contains('\n') {
-                        newline!(self);
+                        if self.pretty {
+                            // The content must not be indented
+                            write_multiline_raw!(self, DUMMY_SP, "\n");
+                        } else {
+                            newline!(self);
+                        }
                     } else {
                         formatting_newline!(self);
                     }
                 }
             }
 
-            if self.tag_omission {
+            if self.pretty && !ctx.preserve_whitespace {
+                self.with_ctx(ctx)
+                    .emit_pretty_children(&n.children, PrettyParent::Element(n))?;
+            } else if self.tag_omission {
                 self.with_ctx(ctx)
                     .emit_list_for_tag_omission(TagOmissionParent::Element(n))?;
             } else {
@@ -761,6 +872,12 @@ where
 
     #[emitter]
     fn emit_attribute(&mut self, n: &Attribute) -> Result {
+        let attribute = self.print_attribute(n);
+
+        write_multiline_raw!(self, n.span, &attribute);
+    }
+
+    fn print_attribute(&self, n: &Attribute) -> String {
         let mut attribute = String::with_capacity(
             if let Some(prefix) = &n.prefix {
                 prefix.len() + 1
@@ -805,7 +922,7 @@ where
             }
         }
 
-        write_multiline_raw!(self, n.span, &attribute);
+        attribute
     }
 
     #[emitter]
@@ -817,7 +934,19 @@ where
                 write_multiline_raw!(self, n.span, &escape_string(&n.data, false));
             }
         } else {
-            write_multiline_raw!(self, n.span, &n.data);
+            match self
+                .config
+                .embedded_source_maps
+                .and_then(|source_maps| source_maps.get(&n.span))
+            {
+                Some(mappings) => {
+                    self.wr
+                        .write_multiline_raw_with_mappings(n.span, &n.data, mappings)?;
+                }
+                None => {
+                    write_multiline_raw!(self, n.span, &n.data);
+                }
+            }
         }
     }
 
@@ -839,13 +968,407 @@ where
             _ if self.is_plaintext => false,
             _ => true,
         };
+        let preserve_whitespace = self.ctx.preserve_whitespace
+            || !need_escape_text
+            || match n.namespace {
+                Namespace::HTML => matches!(&*n.tag_name, "pre" | "textarea" | "listing"),
+                // Whitespace in SVG and MathML is only insignificant between elements
+                _ => !is_foreign_container(n),
+            };
 
         Ctx {
             need_escape_text,
+            preserve_whitespace,
             ..self.ctx
         }
     }
 
+    fn need_wrap_attributes(&self, n: &Element) -> bool {
+        if !self.pretty || self.ctx.preserve_whitespace || n.attributes.len() < 2 {
+            return false;
+        }
+
+        match self.config.attribute_wrapping {
+            AttributeWrapping::Force => true,
+            AttributeWrapping::Never => false,
+            AttributeWrapping::Auto if self.is_measuring => false,
+            AttributeWrapping::Auto => {
+                // `<`, tag name, attributes with a leading space and `>`
+                let width = n
+                    .attributes
+                    .iter()
+                    .fold(n.tag_name.len() + 2, |width, attribute| {
+                        width + 1 + self.print_attribute(attribute).chars().count()
+                    });
+
+                self.wr.column().saturating_add(width) > self.print_width
+            }
+        }
+    }
+
+    fn emit_pretty_children(&mut self, children: &[Child], parent: PrettyParent) -> Result {
+        let is_structural = match parent {
+            PrettyParent::Document => true,
+            PrettyParent::DocumentFragment => false,
+            PrettyParent::Element(element) => is_structural_element(element),
+        };
+        let items = self.create_pretty_items(children);
+
+        if is_structural
+            || children
+                .iter()
+                .any(|child| self.is_block_level(child, is_structural))
+        {
+            self.emit_pretty_block_layout(children, parent, is_structural, items)
+        } else {
+            self.emit_pretty_inline_layout(children, parent, items)
+        }
+    }
+
+    /// Every block-level child and every run of inline content between them
+    /// is printed on its own line.
+    fn emit_pretty_block_layout(
+        &mut self,
+        children: &[Child],
+        parent: PrettyParent,
+        is_structural: bool,
+        items: Vec<PrettyItem>,
+    ) -> Result {
+        let mut lines = vec![];
+        let mut run = vec![];
+
+        for item in items {
+            match item {
+                PrettyItem::Node(idx) if self.is_block_level(&children[idx], is_structural) => {
+                    if !run.is_empty() {
+                        lines.push(take(&mut run));
+                    }
+
+                    lines.push(vec![item]);
+                }
+                // Whitespace around block-level elements is not significant
+                PrettyItem::Space if run.is_empty() => {}
+                _ => {
+                    run.push(item);
+                }
+            }
+        }
+
+        if !run.is_empty() {
+            lines.push(run);
+        }
+
+        if lines.is_empty() {
+            return Ok(());
+        }
+
+        let element = match parent {
+            PrettyParent::Element(element) => Some(element),
+            _ => None,
+        };
+
+        if element.is_some() {
+            self.wr.increase_indent();
+        }
+
+        for mut line in lines {
+            if matches!(line.last(), Some(PrettyItem::Space)) {
+                line.pop();
+            }
+
+            newline!(self);
+
+            self.emit_pretty_fill(children, element, &line, true)?;
+        }
+
+        if element.is_some() {
+            self.wr.decrease_indent();
+
+            newline!(self);
+        }
+
+        Ok(())
+    }
+
+    /// Content is printed on the same line as the parent if it fits, otherwise
+    /// it is wrapped at whitespace.
+    fn emit_pretty_inline_layout(
+        &mut self,
+        children: &[Child],
+        parent: PrettyParent,
+        mut items: Vec<PrettyItem>,
+    ) -> Result {
+        let element = match parent {
+            PrettyParent::Element(element) => Some(element),
+            _ => None,
+        };
+        let is_edge_whitespace_significant = match self.config.whitespace_sensitivity {
+            WhitespaceSensitivity::Css => element.map_or(false, |element| {
+                !(element.namespace == Namespace::HTML
+                    && is_block_level_tag_name(&element.tag_name))
+            }),
+            WhitespaceSensitivity::Strict => element.is_some(),
+            WhitespaceSensitivity::Ignore => false,
+        };
+
+        if !is_edge_whitespace_significant {
+            if matches!(items.first(), Some(PrettyItem::Space)) {
+                items.remove(0);
+            }
+
+            if matches!(items.last(), Some(PrettyItem::Space)) {
+                items.pop();
+            }
+        }
+
+        if items.is_empty() {
+            return Ok(());
+        }
+
+        // `</`, tag name and `>`
+        let end_tag_width = element.map_or(0, |element| element.tag_name.len() + 3);
+        let fits = self.is_measuring
+            || self
+                .measure_pretty_items(children, element, &items)
+                .map_or(false, |width| {
+                    self.wr
+                        .column()
+                        .saturating_add(width)
+                        .saturating_add(end_tag_width)
+                        <= self.print_width
+                });
+
+        if fits {
+            for item in &items {
+                match item {
+                    PrettyItem::Space => {
+                        space!(self);
+                    }
+                    _ => {
+                        self.emit_pretty_item(children, element, item)?;
+                    }
+                }
+            }
+
+            return Ok(());
+        }
+
+        let has_leading_space = matches!(items.first(), Some(PrettyItem::Space));
+        let has_trailing_space = matches!(items.last(), Some(PrettyItem::Space));
+
+        if element.is_some() && (!is_edge_whitespace_significant || has_leading_space) {
+            if has_leading_space {
+                items.remove(0);
+            }
+
+            if has_trailing_space {
+                items.pop();
+            }
+
+            self.wr.increase_indent();
+
+            newline!(self);
+
+            self.emit_pretty_fill(children, element, &items, true)?;
+
+            self.wr.decrease_indent();
+
+            if !is_edge_whitespace_significant || has_trailing_space {
+                newline!(self);
+            }
+        } else {
+            self.emit_pretty_fill(children, element, &items, element.is_none())?;
+        }
+
+        Ok(())
+    }
+
+    /// Prints items and replaces whitespace with a line break where the next
+    /// chunk of content doesn't fit.
+    fn emit_pretty_fill(
+        &mut self,
+        children: &[Child],
+        parent: Option<&Element>,
+        items: &[PrettyItem],
+        mut is_line_start: bool,
+    ) -> Result {
+        let mut need_space = false;
+
+        for (idx, item) in items.iter().enumerate() {
+            if let PrettyItem::Space = item {
+                need_space = true;
+
+                continue;
+            }
+
+            if need_space {
+                need_space = false;
+
+                if !is_line_start {
+                    let chunk_end = items[idx..]
+                        .iter()
+                        .position(|item| matches!(item, PrettyItem::Space))
+                        .map_or(items.len(), |pos| idx + pos);
+                    let fits = self
+                        .measure_pretty_items(children, parent, &items[idx..chunk_end])
+                        .map_or(false, |width| {
+                            self.wr.column().saturating_add(width).saturating_add(1)
+                                <= self.print_width
+                        });
+
+                    if fits {
+                        space!(self);
+                    } else {
+                        newline!(self);
+                    }
+                }
+            }
+
+            self.emit_pretty_item(children, parent, item)?;
+
+            is_line_start = false;
+        }
+
+        if need_space && !is_line_start {
+            space!(self);
+        }
+
+        Ok(())
+    }
+
+    fn emit_pretty_item(
+        &mut self,
+        children: &[Child],
+        parent: Option<&Element>,
+        item: &PrettyItem,
+    ) -> Result {
+        match item {
+            PrettyItem::Space => {
+                space!(self);
+            }
+            PrettyItem::Word(word) => {
+                write_raw!(self, word);
+            }
+            PrettyItem::Node(idx) => match &children[*idx] {
+                Child::Element(element) => {
+                    let prev = if *idx > 0 {
+                        children.get(*idx - 1)
+                    } else {
+                        None
+                    };
+                    let next = children.get(*idx + 1);
+
+                    self.basic_emit_element(element, parent, prev, next)?;
+                }
+                child => {
+                    emit!(self, child);
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Returns the width of items printed on a single line, or `None` if they
+    /// can't be printed on a single line.
+    fn measure_pretty_items(
+        &self,
+        children: &[Child],
+        parent: Option<&Element>,
+        items: &[PrettyItem],
+    ) -> Option<usize> {
+        if self.is_measuring {
+            return Some(0);
+        }
+
+        let mut width = 0;
+
+        for item in items {
+            width += match item {
+                PrettyItem::Space => 1,
+                PrettyItem::Word(word) => word.chars().count(),
+                PrettyItem::Node(..) => {
+                    let mut measured = String::new();
+
+                    {
+                        let wr = BasicHtmlWriter::new(&mut measured, None, Default::default());
+                        let mut gen = CodeGenerator::new(wr, self.config.clone());
+
+                        gen.ctx = self.ctx;
+                        gen.is_plaintext = self.is_plaintext;
+                        gen.is_measuring = true;
+
+                        gen.emit_pretty_item(children, parent, item).ok()?;
+                    }
+
+                    if measured.contains('\n') {
+                        return None;
+                    }
+
+                    measured.chars().count()
+                }
+            };
+        }
+
+        Some(width)
+    }
+
+    fn create_pretty_items<'b>(&self, children: &'b [Child]) -> Vec<PrettyItem<'b>> {
+        let mut items = vec![];
+
+        for (idx, child) in children.iter().enumerate() {
+            match child {
+                Child::Text(text) => {
+                    // Runs of whitespace are collapsed to a single space when rendering
+                    for (i, word) in text
+                        .data
+                        .split(|c: char| c.is_ascii_whitespace())
+                        .enumerate()
+                    {
+                        if i > 0 && !matches!(items.last(), Some(PrettyItem::Space)) {
+                            items.push(PrettyItem::Space);
+                        }
+
+                        if !word.is_empty() {
+                            items.push(PrettyItem::Word(if self.ctx.need_escape_text {
+                                escape_string(word, false)
+                            } else {
+                                Cow::Borrowed(word)
+                            }));
+                        }
+                    }
+                }
+                _ => {
+                    items.push(PrettyItem::Node(idx));
+                }
+            }
+        }
+
+        items
+    }
+
+    fn is_block_level(&self, child: &Child, is_structural_parent: bool) -> bool {
+        match child {
+            Child::DocumentType(..) => true,
+            Child::Text(..) => false,
+            Child::Comment(..) => {
+                is_structural_parent
+                    || self.config.whitespace_sensitivity == WhitespaceSensitivity::Ignore
+            }
+            Child::Element(element) => {
+                is_structural_parent
+                    || match self.config.whitespace_sensitivity {
+                        WhitespaceSensitivity::Css => {
+                            element.namespace == Namespace::HTML
+                                && is_block_level_tag_name(&element.tag_name)
+                        }
+                        WhitespaceSensitivity::Strict => false,
+                        WhitespaceSensitivity::Ignore => true,
+                    }
+            }
+        }
+    }
+
     fn emit_list_for_tag_omission(&mut self, parent: TagOmissionParent) -> Result {
         let nodes = match &parent {
             TagOmissionParent::Document(document) => &document.children,
@@ -1163,6 +1686,121 @@ fn escape_string(value: &str, is_attribute_mode: bool) -> Cow<'_, str> {
     Cow::Owned(result)
 }
 
+/// Elements whose whitespace-only text children are never rendered.
+fn is_structural_element(element: &Element) -> bool {
+    match element.namespace {
+        Namespace::HTML => matches!(
+            &*element.tag_name,
+            "html"
+                | "head"
+                | "table"
+                | "thead"
+                | "tbody"
+                | "tfoot"
+                | "tr"
+                | "colgroup"
+                | "select"
+                | "optgroup"
+                | "datalist"
+                | "frameset"
+        ),
+        _ => is_foreign_container(element),
+    }
+}
+
+/// SVG and MathML elements containing only elements and whitespace.
+fn is_foreign_container(element: &Element) -> bool {
+    element
+        .children
+        .iter()
+        .any(|child| matches!(child, Child::Element(..)))
+        && element.children.iter().all(|child| match child {
+            Child::Text(text) => text.data.chars().all(|c| c.is_ascii_whitespace()),
+            _ => true,
+        })
+}
+
+/// Elements which are not `display: inline` by default, including elements
+/// which are not rendered.
+fn is_block_level_tag_name(tag_name: &str) -> bool {
+    matches!(
+        tag_name,
+        "address"
+            | "article"
+            | "aside"
+            | "base"
+            | "basefont"
+            | "blockquote"
+            | "body"
+            | "caption"
+            | "center"
+            | "col"
+            | "colgroup"
+            | "datalist"
+            | "dd"
+            | "details"
+            | "dialog"
+            | "dir"
+            | "div"
+            | "dl"
+            | "dt"
+            | "fieldset"
+            | "figcaption"
+            | "figure"
+            | "footer"
+            | "form"
+            | "frame"
+            | "frameset"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "head"
+            | "header"
+            | "hgroup"
+            | "hr"
+            | "html"
+            | "legend"
+            | "li"
+            | "link"
+            | "listing"
+            | "main"
+            | "menu"
+            | "meta"
+            | "nav"
+            | "noembed"
+            | "noframes"
+            | "ol"
+            | "optgroup"
+            | "option"
+            | "p"
+            | "param"
+            | "plaintext"
+            | "pre"
+            | "rp"
+            | "script"
+            | "search"
+            | "section"
+            | "source"
+            | "style"
+            | "summary"
+            | "table"
+            | "tbody"
+            | "td"
+            | "template"
+            | "tfoot"
+            | "th"
+            | "thead"
+            | "title"
+            | "tr"
+            | "track"
+            | "ul"
+            | "xmp"
+    )
+}
+
 fn is_html_tag_name(namespace: Namespace, tag_name: &JsWord) -> bool {
     if namespace != Namespace::HTML {
         return false;
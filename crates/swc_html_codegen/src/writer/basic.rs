@@ -1,7 +1,7 @@
 use std::fmt::{Result, Write};
 
 use rustc_hash::FxHashSet;
-use swc_common::{BytePos, LineCol, Span};
+use swc_common::{BytePos, LineCol, Span, DUMMY_SP};
 
 use super::HtmlWriter;
 
@@ -138,20 +138,22 @@ where
     }
 
     fn srcmap(&mut self, byte_pos: BytePos) {
+        self.srcmap_at(
+            byte_pos,
+            LineCol {
+                line: self.line as _,
+                col: self.col as _,
+            },
+        )
+    }
+
+    fn srcmap_at(&mut self, byte_pos: BytePos, loc: LineCol) {
         if byte_pos.is_dummy() {
             return;
         }
 
         if let Some(ref mut srcmap) = self.srcmap {
-            if self
-                .srcmap_done
-                .insert((byte_pos, self.line as _, self.col as _))
-            {
-                let loc = LineCol {
-                    line: self.line as _,
-                    col: self.col as _,
-                };
-
+            if self.srcmap_done.insert((byte_pos, loc.line, loc.col)) {
                 srcmap.push((byte_pos, loc));
             }
         }
@@ -221,6 +223,54 @@ where
         Ok(())
     }
 
+    fn write_multiline_raw_with_mappings(
+        &mut self,
+        span: Span,
+        s: &str,
+        mappings: &[(BytePos, LineCol)],
+    ) -> Result {
+        if s.is_empty() || self.srcmap.is_none() {
+            return self.write_multiline_raw(span, s);
+        }
+
+        if self.line_start {
+            self.write_indent_string()?;
+            self.line_start = false;
+
+            if let Some(pending) = self.pending_srcmap.take() {
+                self.srcmap(pending);
+            }
+        }
+
+        let (line, col) = (self.line as u32, self.col as u32);
+
+        if !span.is_dummy() {
+            self.srcmap(span.lo())
+        }
+
+        for &(byte_pos, loc) in mappings {
+            self.srcmap_at(
+                byte_pos,
+                LineCol {
+                    line: line + loc.line,
+                    col: if loc.line == 0 {
+                        col + loc.col
+                    } else {
+                        loc.col
+                    },
+                },
+            );
+        }
+
+        self.write_multiline_raw(DUMMY_SP, s)?;
+
+        if !span.is_dummy() {
+            self.srcmap(span.hi())
+        }
+
+        Ok(())
+    }
+
     fn increase_indent(&mut self) {
         self.indent_level += 1;
     }
@@ -233,6 +283,14 @@ where
 
         self.indent_level -= 1;
     }
+
+    fn column(&self) -> usize {
+        if self.line_start {
+            self.config.indent_width as usize * self.indent_level
+        } else {
+            self.col
+        }
+    }
 }
 
 fn compute_line_starts(s: &str) -> Vec<usize> {
@@ -1,7 +1,7 @@
 use std::fmt::Result;
 
 use auto_impl::auto_impl;
-use swc_common::Span;
+use swc_common::{BytePos, LineCol, Span};
 
 pub mod basic;
 
@@ -15,7 +15,30 @@ pub trait HtmlWriter {
 
     fn write_multiline_raw(&mut self, span: Span, s: &str) -> Result;
 
+    /// Same as [HtmlWriter::write_multiline_raw], but also adds `mappings`
+    /// generated for `s`, e.g. by the code generator of an inline script.
+    /// Lines and columns of `mappings` are relative to the start of `s`.
+    fn write_multiline_raw_with_mappings(
+        &mut self,
+        span: Span,
+        s: &str,
+        mappings: &[(BytePos, LineCol)],
+    ) -> Result {
+        let _ = mappings;
+
+        self.write_multiline_raw(span, s)
+    }
+
     fn increase_indent(&mut self);
 
     fn decrease_indent(&mut self);
+
+    /// Column where the next written text starts, including pending
+    /// indentation.
+    ///
+    /// Writers which don't track columns return `0`, so only the width of the
+    /// printed content is checked against the print width.
+    fn column(&self) -> usize {
+        0
+    }
 }
@@ -5,11 +5,11 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use swc_common::{FileName, Span};
+use swc_common::{FileName, SourceFile, Span};
 use swc_html_ast::*;
 use swc_html_codegen::{
     writer::basic::{BasicHtmlWriter, BasicHtmlWriterConfig, IndentType, LineFeed},
-    CodeGenerator, CodegenConfig, Emit,
+    AttributeWrapping, CodeGenerator, CodegenConfig, Emit, WhitespaceSensitivity,
 };
 use swc_html_parser::{
     parse_file_as_document, parse_file_as_document_fragment, parser::ParserConfig,
@@ -160,6 +160,59 @@ fn print_document_fragment(
     .unwrap();
 }
 
+fn print_document_pretty(
+    input: &Path,
+    writer_config: Option<BasicHtmlWriterConfig>,
+    codegen_config: CodegenConfig,
+) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.html");
+    let writer_config = writer_config.unwrap_or_default();
+
+    run_test2(false, |cm, handler| {
+        let print = |fm: &SourceFile| -> String {
+            let mut errors = vec![];
+            let document: Document =
+                parse_file_as_document(fm, Default::default(), &mut errors).unwrap();
+
+            for err in errors {
+                err.to_diagnostics(&handler).emit();
+            }
+
+            let mut html_str = String::new();
+            let wr = BasicHtmlWriter::new(
+                &mut html_str,
+                None,
+                BasicHtmlWriterConfig {
+                    indent_type: writer_config.indent_type,
+                    indent_width: writer_config.indent_width,
+                    linefeed: writer_config.linefeed,
+                },
+            );
+            let mut gen = CodeGenerator::new(wr, codegen_config.clone());
+
+            gen.emit(&document).unwrap();
+
+            html_str
+        };
+
+        let fm = cm.load_file(input).unwrap();
+        let html_str = print(&fm);
+
+        // Formatting must be stable
+        let fm_output = cm.new_source_file(FileName::Anon.into(), html_str.clone());
+
+        assert_eq!(html_str, print(&fm_output));
+
+        NormalizedOutput::new_raw(html_str)
+            .compare_to_file(output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
 fn verify_document(
     input: &Path,
     parser_config: Option<ParserConfig>,
@@ -467,6 +520,87 @@ fn test_indent_type_option(input: PathBuf) {
     );
 }
 
+#[testing::fixture("tests/options/pretty/default/**/input.html")]
+fn test_pretty(input: PathBuf) {
+    print_document_pretty(
+        &input,
+        None,
+        CodegenConfig {
+            pretty: true,
+            ..Default::default()
+        },
+    );
+}
+
+#[testing::fixture("tests/options/pretty/strict/**/input.html")]
+fn test_pretty_whitespace_sensitivity_strict(input: PathBuf) {
+    print_document_pretty(
+        &input,
+        None,
+        CodegenConfig {
+            pretty: true,
+            whitespace_sensitivity: WhitespaceSensitivity::Strict,
+            ..Default::default()
+        },
+    );
+}
+
+#[testing::fixture("tests/options/pretty/ignore/**/input.html")]
+fn test_pretty_whitespace_sensitivity_ignore(input: PathBuf) {
+    print_document_pretty(
+        &input,
+        Some(BasicHtmlWriterConfig {
+            indent_type: IndentType::Tab,
+            indent_width: 1,
+            linefeed: LineFeed::default(),
+        }),
+        CodegenConfig {
+            pretty: true,
+            whitespace_sensitivity: WhitespaceSensitivity::Ignore,
+            ..Default::default()
+        },
+    );
+}
+
+#[testing::fixture("tests/options/pretty/attribute_wrapping_force/**/input.html")]
+fn test_pretty_attribute_wrapping_force(input: PathBuf) {
+    print_document_pretty(
+        &input,
+        None,
+        CodegenConfig {
+            pretty: true,
+            attribute_wrapping: AttributeWrapping::Force,
+            ..Default::default()
+        },
+    );
+}
+
+#[testing::fixture("tests/options/pretty/attribute_wrapping_never/**/input.html")]
+fn test_pretty_attribute_wrapping_never(input: PathBuf) {
+    print_document_pretty(
+        &input,
+        None,
+        CodegenConfig {
+            pretty: true,
+            attribute_wrapping: AttributeWrapping::Never,
+            ..Default::default()
+        },
+    );
+}
+
+#[testing::fixture("tests/options/pretty/print_width/**/input.html")]
+fn test_pretty_print_width(input: PathBuf) {
+    print_document_pretty(
+        &input,
+        None,
+        CodegenConfig {
+            pretty: true,
+            print_width: Some(40),
+            ..Default::default()
+        },
+    );
+}
+
 #[testing::fixture("../swc_html_parser/tests/fixture/**/*.html")]
 fn parser_verify(input: PathBuf) {
     verify_document(&input, None, None, None, false);
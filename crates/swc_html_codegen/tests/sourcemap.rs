@@ -0,0 +1,106 @@
+use std::{fmt::Write, path::PathBuf};
+
+use sourcemap::SourceMap;
+use swc_common::{BytePos, LineCol};
+use swc_html_ast::Document;
+use swc_html_codegen::{
+    writer::basic::{BasicHtmlWriter, BasicHtmlWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_html_parser::parse_file_as_document;
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/sourcemap/**/input.html")]
+fn sourcemap(input: PathBuf) {
+    let dir = input.parent().unwrap();
+
+    for minify in [false, true] {
+        let output = if minify {
+            dir.join("output.min.mappings")
+        } else {
+            dir.join("output.mappings")
+        };
+
+        testing::run_test2(false, |cm, handler| {
+            let fm = cm.load_file(&input).unwrap();
+
+            let mut errors = vec![];
+            let document: Document =
+                parse_file_as_document(&fm, Default::default(), &mut errors).unwrap();
+
+            for err in errors {
+                err.to_diagnostics(&handler).emit();
+            }
+
+            let mut html_str = String::new();
+            let mut src_map_buf: Vec<(BytePos, LineCol)> = vec![];
+
+            {
+                let wr = BasicHtmlWriter::new(
+                    &mut html_str,
+                    Some(&mut src_map_buf),
+                    BasicHtmlWriterConfig::default(),
+                );
+                let mut gen = CodeGenerator::new(
+                    wr,
+                    CodegenConfig {
+                        minify,
+                        ..Default::default()
+                    },
+                );
+
+                gen.emit(&document).unwrap();
+            }
+
+            let source_map = cm.build_source_map(&src_map_buf);
+
+            NormalizedOutput::from(print_mappings(&html_str, &source_map))
+                .compare_to_file(&output)
+                .unwrap();
+
+            Ok(())
+        })
+        .unwrap();
+    }
+}
+
+/// Prints the code and the original position of each mapped output segment.
+fn print_mappings(code: &str, map: &SourceMap) -> String {
+    let lines = code.lines().collect::<Vec<_>>();
+    let tokens = map.tokens().collect::<Vec<_>>();
+
+    let mut buf = String::new();
+
+    writeln!(buf, "{}\n", code).unwrap();
+
+    for (idx, token) in tokens.iter().enumerate() {
+        let line = lines
+            .get(token.get_dst_line() as usize)
+            .copied()
+            .unwrap_or_default();
+        let start = token.get_dst_col() as usize;
+        let end = tokens
+            .get(idx + 1)
+            .filter(|next| next.get_dst_line() == token.get_dst_line())
+            .map(|next| next.get_dst_col() as usize)
+            .unwrap_or_else(|| line.chars().count());
+        let segment = line
+            .chars()
+            .skip(start)
+            .take(end.saturating_sub(start))
+            .collect::<String>();
+
+        writeln!(
+            buf,
+            "{:?} {}:{} -> {}:{}",
+            segment,
+            token.get_dst_line() + 1,
+            token.get_dst_col(),
+            token.get_src_line() + 1,
+            token.get_src_col(),
+        )
+        .unwrap();
+    }
+
+    buf
+}
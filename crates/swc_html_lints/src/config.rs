@@ -0,0 +1,98 @@
+use std::fmt::Debug;
+
+use serde::{Deserialize, Serialize};
+
+use crate::rules::{
+    attribute_no_obsolete::AttributeNoObsoleteConfig,
+    element_no_deprecated::ElementNoDeprecatedConfig,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintRuleReaction {
+    Off,
+    Warning,
+    Error,
+}
+
+impl Default for LintRuleReaction {
+    fn default() -> Self {
+        Self::Off
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(untagged)]
+enum LintRuleLevel {
+    Str(LintRuleReaction),
+    Number(u8),
+}
+
+impl Default for LintRuleLevel {
+    fn default() -> Self {
+        Self::Str(LintRuleReaction::Off)
+    }
+}
+
+impl From<LintRuleLevel> for LintRuleReaction {
+    fn from(level: LintRuleLevel) -> Self {
+        match level {
+            LintRuleLevel::Str(level) => level,
+            LintRuleLevel::Number(level) => match level {
+                1 => LintRuleReaction::Warning,
+                2 => LintRuleReaction::Error,
+                _ => LintRuleReaction::Off,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleConfig<T: Debug + Clone + Serialize + Default>(
+    #[serde(default)] LintRuleLevel,
+    #[serde(default)] T,
+);
+
+impl<T: Debug + Clone + Serialize + Default> RuleConfig<T> {
+    #[inline]
+    pub(crate) fn get_rule_reaction(&self) -> LintRuleReaction {
+        self.0.into()
+    }
+
+    #[inline]
+    pub(crate) fn get_rule_config(&self) -> &T {
+        &self.1
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "kebab-case")]
+pub struct RulesConfig {
+    #[serde(default, alias = "imgAltRequire")]
+    pub img_alt_require: RuleConfig<()>,
+
+    #[serde(default, alias = "idNoDuplicate")]
+    pub id_no_duplicate: RuleConfig<()>,
+
+    #[serde(default, alias = "elementNoInvalidNesting")]
+    pub element_no_invalid_nesting: RuleConfig<()>,
+
+    #[serde(default, alias = "htmlLangRequire")]
+    pub html_lang_require: RuleConfig<()>,
+
+    #[serde(default, alias = "formControlLabelRequire")]
+    pub form_control_label_require: RuleConfig<()>,
+
+    #[serde(default, alias = "elementNoDeprecated")]
+    pub element_no_deprecated: RuleConfig<ElementNoDeprecatedConfig>,
+
+    #[serde(default, alias = "attributeNoObsolete")]
+    pub attribute_no_obsolete: RuleConfig<AttributeNoObsoleteConfig>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LintConfig {
+    #[serde(default)]
+    pub rules: RulesConfig,
+}
@@ -0,0 +1,21 @@
+use swc_html_ast::{Attribute, Element};
+
+pub(crate) fn get_attribute<'a>(element: &'a Element, name: &str) -> Option<&'a Attribute> {
+    element
+        .attributes
+        .iter()
+        .find(|attribute| attribute.namespace.is_none() && attribute.name == *name)
+}
+
+pub(crate) fn get_attribute_value<'a>(element: &'a Element, name: &str) -> Option<&'a str> {
+    get_attribute(element, name).and_then(|attribute| attribute.value.as_deref())
+}
+
+pub(crate) fn has_attribute(element: &Element, name: &str) -> bool {
+    get_attribute(element, name).is_some()
+}
+
+/// Returns `true` if the attribute is present and has a non-blank value.
+pub(crate) fn has_non_empty_attribute(element: &Element, name: &str) -> bool {
+    get_attribute_value(element, name).map_or(false, |value| !value.trim().is_empty())
+}
@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("invalid regex")]
+    Regex(#[from] swc_cached::regex::Error),
+}
@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    pattern::NamePattern,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    ConfigError,
+};
+
+/// Obsolete attributes and the elements they are obsolete on.
+///
+/// https://html.spec.whatwg.org/multipage/obsolete.html#non-conforming-features
+const OBSOLETE_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("abbr", &["td"]),
+    ("accept", &["form"]),
+    (
+        "align",
+        &[
+            "caption", "col", "div", "embed", "h1", "h2", "h3", "h4", "h5", "h6", "hr", "iframe",
+            "img", "input", "legend", "object", "p", "table", "tbody", "td", "tfoot", "th",
+            "thead", "tr",
+        ],
+    ),
+    ("alink", &["body"]),
+    ("allowtransparency", &["iframe"]),
+    ("archive", &["object"]),
+    ("axis", &["td", "th"]),
+    (
+        "background",
+        &["body", "table", "td", "th", "thead", "tbody", "tfoot", "tr"],
+    ),
+    ("bgcolor", &["body", "table", "td", "th", "tr"]),
+    ("border", &["img", "object"]),
+    ("bordercolor", &["table"]),
+    ("bottommargin", &["body"]),
+    ("cellpadding", &["table"]),
+    ("cellspacing", &["table"]),
+    (
+        "char",
+        &["col", "tbody", "td", "tfoot", "th", "thead", "tr"],
+    ),
+    (
+        "charoff",
+        &["col", "tbody", "td", "tfoot", "th", "thead", "tr"],
+    ),
+    ("charset", &["a", "link"]),
+    ("classid", &["object"]),
+    ("clear", &["br"]),
+    ("code", &["object"]),
+    ("codebase", &["object"]),
+    ("codetype", &["object"]),
+    ("color", &["hr"]),
+    ("compact", &["dl", "menu", "ol", "ul"]),
+    ("coords", &["a"]),
+    ("declare", &["object"]),
+    ("event", &["script"]),
+    ("for", &["script"]),
+    ("frame", &["table"]),
+    ("frameborder", &["iframe"]),
+    ("height", &["table", "td", "th", "tr"]),
+    ("hspace", &["embed", "iframe", "img", "input", "object"]),
+    ("ismap", &["input"]),
+    ("language", &["script"]),
+    ("leftmargin", &["body"]),
+    ("link", &["body"]),
+    ("longdesc", &["iframe", "img"]),
+    ("lowsrc", &["img"]),
+    ("marginheight", &["body", "iframe"]),
+    ("marginwidth", &["body", "iframe"]),
+    ("methods", &["a", "link"]),
+    ("name", &["a", "embed", "img", "option"]),
+    ("nohref", &["area"]),
+    ("noshade", &["hr"]),
+    ("nowrap", &["td", "th"]),
+    ("profile", &["head"]),
+    ("rev", &["a", "link"]),
+    ("rightmargin", &["body"]),
+    ("rules", &["table"]),
+    ("scheme", &["meta"]),
+    ("scope", &["td"]),
+    ("scrolling", &["iframe"]),
+    ("shape", &["a"]),
+    ("size", &["hr"]),
+    ("standby", &["object"]),
+    ("summary", &["table"]),
+    ("target", &["link"]),
+    ("text", &["body"]),
+    ("topmargin", &["body"]),
+    ("type", &["li", "param", "ul"]),
+    ("typemustmatch", &["object"]),
+    ("urn", &["a", "link"]),
+    ("usemap", &["input"]),
+    (
+        "valign",
+        &["col", "tbody", "td", "tfoot", "th", "thead", "tr"],
+    ),
+    ("valuetype", &["param"]),
+    ("version", &["html"]),
+    ("vlink", &["body"]),
+    ("vspace", &["embed", "iframe", "img", "input", "object"]),
+    ("width", &["col", "hr", "pre", "table", "td", "th"]),
+];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttributeNoObsoleteConfig {
+    ignore_attributes: Option<Vec<String>>,
+}
+
+pub fn attribute_no_obsolete(
+    ctx: LintRuleContext<AttributeNoObsoleteConfig>,
+) -> Result<Box<dyn LintRule>, ConfigError> {
+    let ignored_attributes = ctx
+        .config()
+        .ignore_attributes
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(NamePattern::try_from)
+        .collect::<Result<_, _>>()?;
+
+    Ok(visitor_rule(
+        ctx.reaction(),
+        AttributeNoObsolete {
+            ctx,
+            ignored_attributes,
+        },
+    ))
+}
+
+#[derive(Debug, Default)]
+struct AttributeNoObsolete {
+    ctx: LintRuleContext<AttributeNoObsoleteConfig>,
+    ignored_attributes: Vec<NamePattern>,
+}
+
+impl Visit for AttributeNoObsolete {
+    fn visit_element(&mut self, element: &Element) {
+        if element.namespace == Namespace::HTML {
+            let tag_name = &*element.tag_name;
+
+            for attribute in &element.attributes {
+                if attribute.namespace.is_some() {
+                    continue;
+                }
+
+                let name = &*attribute.name;
+                let is_obsolete = OBSOLETE_ATTRIBUTES
+                    .iter()
+                    .any(|(obsolete, elements)| *obsolete == name && elements.contains(&tag_name));
+
+                if is_obsolete
+                    && self
+                        .ignored_attributes
+                        .iter()
+                        .all(|item| !item.is_match(name))
+                {
+                    let message = format!(
+                        "Unexpected obsolete attribute \"{}\" on <{}>.",
+                        name, tag_name
+                    );
+
+                    self.ctx.report(attribute, message);
+                }
+            }
+        }
+
+        element.visit_children_with(self);
+    }
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    pattern::NamePattern,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+    ConfigError,
+};
+
+/// https://html.spec.whatwg.org/multipage/obsolete.html#non-conforming-features
+const DEPRECATED_ELEMENTS: &[&str] = &[
+    "acronym",
+    "applet",
+    "basefont",
+    "bgsound",
+    "big",
+    "blink",
+    "center",
+    "dir",
+    "font",
+    "frame",
+    "frameset",
+    "isindex",
+    "keygen",
+    "listing",
+    "marquee",
+    "menuitem",
+    "multicol",
+    "nextid",
+    "nobr",
+    "noembed",
+    "noframes",
+    "plaintext",
+    "rb",
+    "rtc",
+    "spacer",
+    "strike",
+    "tt",
+    "xmp",
+];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ElementNoDeprecatedConfig {
+    ignore_elements: Option<Vec<String>>,
+}
+
+pub fn element_no_deprecated(
+    ctx: LintRuleContext<ElementNoDeprecatedConfig>,
+) -> Result<Box<dyn LintRule>, ConfigError> {
+    let ignored_elements = ctx
+        .config()
+        .ignore_elements
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(NamePattern::try_from)
+        .collect::<Result<_, _>>()?;
+
+    Ok(visitor_rule(
+        ctx.reaction(),
+        ElementNoDeprecated {
+            ctx,
+            ignored_elements,
+        },
+    ))
+}
+
+#[derive(Debug, Default)]
+struct ElementNoDeprecated {
+    ctx: LintRuleContext<ElementNoDeprecatedConfig>,
+    ignored_elements: Vec<NamePattern>,
+}
+
+impl Visit for ElementNoDeprecated {
+    fn visit_element(&mut self, element: &Element) {
+        let tag_name = &*element.tag_name;
+
+        if element.namespace == Namespace::HTML
+            && DEPRECATED_ELEMENTS.contains(&tag_name)
+            && self
+                .ignored_elements
+                .iter()
+                .all(|item| !item.is_match(tag_name))
+        {
+            let message = format!("Unexpected deprecated element <{}>.", tag_name);
+
+            self.ctx.report(element, message);
+        }
+
+        element.visit_children_with(self);
+    }
+}
@@ -0,0 +1,100 @@
+use swc_atoms::JsWord;
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    element::{get_attribute_value, has_attribute},
+    rule::{visitor_rule, LintRule, LintRuleContext},
+};
+
+pub fn element_no_invalid_nesting(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(
+        ctx.reaction(),
+        ElementNoInvalidNesting {
+            ctx,
+            interactive_ancestor: None,
+            in_label: false,
+        },
+    )
+}
+
+/// Checks nesting which is invalid, but isn't fixed up by the HTML parser, so
+/// it survives in the parsed tree.
+#[derive(Debug, Default)]
+struct ElementNoInvalidNesting {
+    ctx: LintRuleContext<()>,
+    /// The closest `<a>` or `<button>` ancestor.
+    interactive_ancestor: Option<JsWord>,
+    in_label: bool,
+}
+
+impl ElementNoInvalidNesting {
+    fn report_nesting(&self, element: &Element, parent: &str) {
+        let message = format!("Unexpected <{}> inside <{}>.", element.tag_name, parent);
+
+        self.ctx.report(element, message);
+    }
+}
+
+impl Visit for ElementNoInvalidNesting {
+    fn visit_element(&mut self, element: &Element) {
+        if element.namespace != Namespace::HTML {
+            element.visit_children_with(self);
+
+            return;
+        }
+
+        let tag_name = &*element.tag_name;
+
+        if is_interactive_content(element) {
+            if let Some(ancestor) = &self.interactive_ancestor {
+                self.report_nesting(element, ancestor);
+            }
+        }
+
+        if tag_name == "label" && self.in_label {
+            self.report_nesting(element, "label");
+        }
+
+        if matches!(tag_name, "ul" | "ol" | "menu") {
+            for child in &element.children {
+                if let Child::Element(child) = child {
+                    if child.namespace == Namespace::HTML
+                        && !matches!(&*child.tag_name, "li" | "script" | "template")
+                    {
+                        self.report_nesting(child, tag_name);
+                    }
+                }
+            }
+        }
+
+        let old_interactive_ancestor = self.interactive_ancestor.clone();
+        let old_in_label = self.in_label;
+
+        if matches!(tag_name, "a" | "button") {
+            self.interactive_ancestor = Some(element.tag_name.clone());
+        }
+
+        if tag_name == "label" {
+            self.in_label = true;
+        }
+
+        element.visit_children_with(self);
+
+        self.interactive_ancestor = old_interactive_ancestor;
+        self.in_label = old_in_label;
+    }
+}
+
+/// https://html.spec.whatwg.org/multipage/dom.html#interactive-content
+fn is_interactive_content(element: &Element) -> bool {
+    match &*element.tag_name {
+        "button" | "details" | "embed" | "iframe" | "label" | "select" | "textarea" => true,
+        "a" => has_attribute(element, "href"),
+        "audio" | "video" => has_attribute(element, "controls"),
+        "img" => has_attribute(element, "usemap"),
+        "input" => !get_attribute_value(element, "type")
+            .map_or(false, |value| value.eq_ignore_ascii_case("hidden")),
+        _ => false,
+    }
+}
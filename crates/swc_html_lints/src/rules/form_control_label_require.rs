@@ -0,0 +1,116 @@
+use swc_atoms::JsWord;
+use swc_common::collections::AHashSet;
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    element::{get_attribute, get_attribute_value, has_non_empty_attribute},
+    rule::{visitor_rule, LintRule, LintRuleContext},
+};
+
+pub fn form_control_label_require(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(
+        ctx.reaction(),
+        FormControlLabelRequire {
+            ctx,
+            label_for: Default::default(),
+            in_label: false,
+        },
+    )
+}
+
+#[derive(Debug, Default)]
+struct FormControlLabelRequire {
+    ctx: LintRuleContext<()>,
+    /// Values of the `for` attribute of all `<label>` elements.
+    label_for: AHashSet<JsWord>,
+    in_label: bool,
+}
+
+impl FormControlLabelRequire {
+    fn is_labeled(&self, element: &Element) -> bool {
+        if self.in_label
+            || has_non_empty_attribute(element, "aria-label")
+            || has_non_empty_attribute(element, "aria-labelledby")
+            || has_non_empty_attribute(element, "title")
+        {
+            return true;
+        }
+
+        match get_attribute(element, "id").and_then(|attribute| attribute.value.as_ref()) {
+            Some(id) => self.label_for.contains(id),
+            _ => false,
+        }
+    }
+}
+
+impl Visit for FormControlLabelRequire {
+    fn visit_document(&mut self, document: &Document) {
+        // Labels may come after the control they label.
+        let mut collector = LabelForCollector::default();
+
+        document.visit_with(&mut collector);
+
+        self.label_for = collector.label_for;
+
+        document.visit_children_with(self);
+    }
+
+    fn visit_element(&mut self, element: &Element) {
+        if element.namespace != Namespace::HTML {
+            element.visit_children_with(self);
+
+            return;
+        }
+
+        if is_labelable_form_control(element) && !self.is_labeled(element) {
+            let message = format!(
+                "Expected <{}> to have an associated label.",
+                element.tag_name
+            );
+
+            self.ctx.report(element, message);
+        }
+
+        let old_in_label = self.in_label;
+
+        if element.tag_name == "label" {
+            self.in_label = true;
+        }
+
+        element.visit_children_with(self);
+
+        self.in_label = old_in_label;
+    }
+}
+
+fn is_labelable_form_control(element: &Element) -> bool {
+    match &*element.tag_name {
+        "select" | "textarea" => true,
+        // Buttons are labeled by their value
+        "input" => !get_attribute_value(element, "type").map_or(false, |value| {
+            matches!(
+                &*value.to_ascii_lowercase(),
+                "hidden" | "submit" | "reset" | "button" | "image"
+            )
+        }),
+        _ => false,
+    }
+}
+
+#[derive(Debug, Default)]
+struct LabelForCollector {
+    label_for: AHashSet<JsWord>,
+}
+
+impl Visit for LabelForCollector {
+    fn visit_element(&mut self, element: &Element) {
+        if element.namespace == Namespace::HTML && element.tag_name == "label" {
+            if let Some(value) = get_attribute_value(element, "for") {
+                self.label_for.insert(value.into());
+            }
+        }
+
+        element.visit_children_with(self);
+    }
+}
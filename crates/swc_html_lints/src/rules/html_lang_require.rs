@@ -0,0 +1,37 @@
+use swc_html_ast::*;
+use swc_html_visit::Visit;
+
+use crate::{
+    element::has_non_empty_attribute,
+    rule::{visitor_rule, LintRule, LintRuleContext},
+};
+
+const MESSAGE: &str = "Expected \"lang\" attribute on <html>.";
+
+pub fn html_lang_require(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(ctx.reaction(), HtmlLangRequire { ctx })
+}
+
+#[derive(Debug, Default)]
+struct HtmlLangRequire {
+    ctx: LintRuleContext<()>,
+}
+
+impl Visit for HtmlLangRequire {
+    fn visit_document(&mut self, document: &Document) {
+        // Only the root `<html>` element matters, so we don't visit the whole tree.
+        for child in &document.children {
+            if let Child::Element(element) = child {
+                // Implied `<html>` elements (e.g. in partial templates) have no
+                // location to report.
+                if element.namespace == Namespace::HTML
+                    && element.tag_name == "html"
+                    && !element.span.is_dummy()
+                    && !has_non_empty_attribute(element, "lang")
+                {
+                    self.ctx.report(element, MESSAGE);
+                }
+            }
+        }
+    }
+}
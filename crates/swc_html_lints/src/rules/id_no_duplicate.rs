@@ -0,0 +1,41 @@
+use swc_atoms::JsWord;
+use swc_common::collections::AHashSet;
+use swc_html_ast::*;
+use swc_html_visit::Visit;
+
+use crate::rule::{visitor_rule, LintRule, LintRuleContext};
+
+pub fn id_no_duplicate(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(
+        ctx.reaction(),
+        IdNoDuplicate {
+            ctx,
+            ids: Default::default(),
+        },
+    )
+}
+
+#[derive(Debug, Default)]
+struct IdNoDuplicate {
+    ctx: LintRuleContext<()>,
+    ids: AHashSet<JsWord>,
+}
+
+impl Visit for IdNoDuplicate {
+    fn visit_attribute(&mut self, attribute: &Attribute) {
+        if attribute.namespace.is_some() || attribute.name != "id" {
+            return;
+        }
+
+        match &attribute.value {
+            Some(value) if !value.is_empty() => {
+                if !self.ids.insert(value.clone()) {
+                    let message = format!("Unexpected duplicate id \"{}\".", value);
+
+                    self.ctx.report(attribute, message);
+                }
+            }
+            _ => {}
+        }
+    }
+}
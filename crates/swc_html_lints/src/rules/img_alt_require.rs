@@ -0,0 +1,39 @@
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{
+    element::{get_attribute_value, has_attribute},
+    rule::{visitor_rule, LintRule, LintRuleContext},
+};
+
+pub fn img_alt_require(ctx: LintRuleContext<()>) -> Box<dyn LintRule> {
+    visitor_rule(ctx.reaction(), ImgAltRequire { ctx })
+}
+
+#[derive(Debug, Default)]
+struct ImgAltRequire {
+    ctx: LintRuleContext<()>,
+}
+
+impl Visit for ImgAltRequire {
+    fn visit_element(&mut self, element: &Element) {
+        if element.namespace == Namespace::HTML {
+            let requires_alt = match &*element.tag_name {
+                "img" => true,
+                // An `<area>` without `href` is only a placeholder
+                "area" => has_attribute(element, "href"),
+                "input" => get_attribute_value(element, "type")
+                    .map_or(false, |value| value.eq_ignore_ascii_case("image")),
+                _ => false,
+            };
+
+            if requires_alt && !has_attribute(element, "alt") {
+                let message = format!("Expected \"alt\" attribute on <{}>.", element.tag_name);
+
+                self.ctx.report(element, message);
+            }
+        }
+
+        element.visit_children_with(self);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{
+    config::LintConfig,
+    error::ConfigError,
+    rule::LintRule,
+    rules::{
+        attribute_no_obsolete::attribute_no_obsolete, element_no_deprecated::element_no_deprecated,
+        element_no_invalid_nesting::element_no_invalid_nesting,
+        form_control_label_require::form_control_label_require,
+        html_lang_require::html_lang_require, id_no_duplicate::id_no_duplicate,
+        img_alt_require::img_alt_require,
+    },
+};
+
+pub mod attribute_no_obsolete;
+pub mod element_no_deprecated;
+pub mod element_no_invalid_nesting;
+pub mod form_control_label_require;
+pub mod html_lang_require;
+pub mod id_no_duplicate;
+pub mod img_alt_require;
+
+pub struct LintParams<'a> {
+    pub lint_config: &'a LintConfig,
+}
+
+pub fn get_rules(
+    LintParams { lint_config }: &LintParams,
+) -> Result<Vec<Box<dyn LintRule>>, ConfigError> {
+    let rules_config = &lint_config.rules;
+
+    let rules = vec![
+        img_alt_require((&rules_config.img_alt_require).into()),
+        id_no_duplicate((&rules_config.id_no_duplicate).into()),
+        element_no_invalid_nesting((&rules_config.element_no_invalid_nesting).into()),
+        html_lang_require((&rules_config.html_lang_require).into()),
+        form_control_label_require((&rules_config.form_control_label_require).into()),
+        element_no_deprecated((&rules_config.element_no_deprecated).into())?,
+        attribute_no_obsolete((&rules_config.attribute_no_obsolete).into())?,
+    ];
+
+    Ok(rules)
+}
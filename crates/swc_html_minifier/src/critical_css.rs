@@ -0,0 +1,415 @@
+//! Inlining of critical CSS.
+//!
+//! Rules of linked stylesheets which match elements above the fold are
+//! inlined in a `style` element, and the stylesheets are loaded
+//! asynchronously with the `media="print"` and `onload` pattern, so they
+//! don't block the first render.
+//!
+//! The layout is not computed, so the fold is approximated by a number of
+//! elements of the `body` element in tree order.
+
+use swc_common::{collections::AHashSet, FileName, SourceMap, DUMMY_SP};
+use swc_css_ast::{
+    AtRule, AtRuleName, ComplexSelector, ComplexSelectorChildren, ComponentValue, QualifiedRule,
+    QualifiedRulePrelude, Rule, SelectorList, Stylesheet, SubclassSelector,
+};
+use swc_html_ast::{Attribute, Child, Document, Element, Namespace, Text};
+use swc_html_query::{query_selector, query_selector_all, QueryConfig};
+
+use crate::option::{CriticalCssOptions, CssOptions};
+
+/// Inlines critical rules of stylesheets loaded by `link` elements of
+/// `document`, and makes these `link` elements load asynchronously.
+///
+/// `load_stylesheet` is called with the `href` attribute of each `link`
+/// element and returns the content of the stylesheet, so no network access
+/// is needed. Stylesheets which aren't loaded or can't be parsed are kept
+/// as is, as well as `link` elements with a `media` attribute (other than
+/// `all`), an `onload` attribute or the `disabled` attribute.
+pub fn inline_critical_css<F>(
+    document: &mut Document,
+    options: &CriticalCssOptions,
+    mut load_stylesheet: F,
+) where
+    F: FnMut(&str) -> Option<String>,
+{
+    let mut hrefs = vec![];
+
+    collect_stylesheet_links(&document.children, &mut hrefs);
+
+    if hrefs.is_empty() {
+        return;
+    }
+
+    let critical_elements = options.above_the_fold_elements.map(|limit| {
+        let mut collector = CriticalElementsCollector {
+            elements: Default::default(),
+            limit,
+            count: 0,
+        };
+
+        collector.collect(&document.children, false);
+
+        collector.elements
+    });
+    let extractor = CriticalCssExtractor {
+        document,
+        critical_elements: critical_elements.as_ref(),
+        config: Default::default(),
+    };
+    let css_options = options.css.clone().unwrap_or_else(|| CssOptions {
+        parser: Default::default(),
+        minifier: Default::default(),
+        codegen: Default::default(),
+    });
+    let critical_css = hrefs
+        .iter()
+        .map(|href| {
+            let css = load_stylesheet(href)?;
+
+            extractor.extract(css, &css_options)
+        })
+        .collect::<Vec<_>>();
+
+    rewrite_stylesheet_links(
+        &mut document.children,
+        &mut critical_css.into_iter(),
+        options,
+    );
+}
+
+/// Returns the `href` attribute of a `link` element which can be loaded
+/// asynchronously.
+fn get_stylesheet_href(element: &Element) -> Option<&str> {
+    if element.namespace != Namespace::HTML || element.tag_name != "link" {
+        return None;
+    }
+
+    let mut href = None;
+    let mut is_stylesheet = false;
+
+    for attribute in &element.attributes {
+        if attribute.namespace.is_some() {
+            continue;
+        }
+
+        let value = attribute.value.as_deref();
+
+        match &*attribute.name {
+            "rel" => {
+                let mut keywords = value.unwrap_or_default().split_ascii_whitespace();
+
+                // Alternative stylesheets are not applied by default
+                is_stylesheet = !keywords
+                    .clone()
+                    .any(|keyword| keyword.eq_ignore_ascii_case("alternate"))
+                    && keywords.any(|keyword| keyword.eq_ignore_ascii_case("stylesheet"));
+            }
+            "href" => href = value.map(str::trim).filter(|href| !href.is_empty()),
+            "media" if !value.unwrap_or_default().trim().eq_ignore_ascii_case("all") => {
+                return None
+            }
+            "onload" | "disabled" => return None,
+            _ => {}
+        }
+    }
+
+    if is_stylesheet {
+        href
+    } else {
+        None
+    }
+}
+
+/// Collects `href` attributes of `link` elements in tree order, skipping
+/// contents of `noscript` elements.
+fn collect_stylesheet_links<'a>(children: &'a [Child], hrefs: &mut Vec<&'a str>) {
+    for child in children {
+        let Child::Element(element) = child else {
+            continue;
+        };
+
+        if let Some(href) = get_stylesheet_href(element) {
+            hrefs.push(href);
+        } else if element.tag_name != "noscript" {
+            collect_stylesheet_links(&element.children, hrefs);
+        }
+    }
+}
+
+/// Visits `link` elements in the same order as [collect_stylesheet_links].
+fn rewrite_stylesheet_links(
+    children: &mut Vec<Child>,
+    critical_css: &mut impl Iterator<Item = Option<String>>,
+    options: &CriticalCssOptions,
+) {
+    let mut new_children = Vec::with_capacity(children.len());
+
+    for mut child in children.drain(..) {
+        let Child::Element(element) = &mut child else {
+            new_children.push(child);
+
+            continue;
+        };
+
+        if get_stylesheet_href(element).is_none() {
+            if element.tag_name != "noscript" {
+                rewrite_stylesheet_links(&mut element.children, critical_css, options);
+            }
+
+            new_children.push(child);
+
+            continue;
+        }
+
+        let Some(css) = critical_css.next().flatten() else {
+            new_children.push(child);
+
+            continue;
+        };
+
+        let noscript = options
+            .noscript_fallback
+            .then(|| create_element("noscript", vec![Child::Element(element.clone())]));
+
+        set_attribute(element, "media", "print");
+        set_attribute(element, "onload", "this.media='all'");
+
+        if !css.is_empty() {
+            new_children.push(Child::Element(create_element(
+                "style",
+                vec![Child::Text(Text {
+                    span: DUMMY_SP,
+                    data: css.into(),
+                    raw: None,
+                })],
+            )));
+        }
+
+        new_children.push(child);
+
+        if let Some(noscript) = noscript {
+            new_children.push(Child::Element(noscript));
+        }
+    }
+
+    *children = new_children;
+}
+
+fn create_element(tag_name: &str, children: Vec<Child>) -> Element {
+    Element {
+        span: DUMMY_SP,
+        tag_name: tag_name.into(),
+        namespace: Namespace::HTML,
+        attributes: vec![],
+        children,
+        content: None,
+        is_self_closing: false,
+    }
+}
+
+fn set_attribute(element: &mut Element, name: &str, value: &str) {
+    match element
+        .attributes
+        .iter_mut()
+        .find(|attribute| attribute.namespace.is_none() && attribute.name == name)
+    {
+        Some(attribute) => {
+            attribute.value = Some(value.into());
+            attribute.raw_value = None;
+        }
+        None => element.attributes.push(Attribute {
+            span: DUMMY_SP,
+            namespace: None,
+            prefix: None,
+            name: name.into(),
+            raw_name: None,
+            value: Some(value.into()),
+            raw_value: None,
+        }),
+    }
+}
+
+/// Collects elements above the fold, i.e. all elements outside of `body` and
+/// the first `limit` elements of `body`.
+struct CriticalElementsCollector {
+    elements: AHashSet<*const Element>,
+    limit: usize,
+    count: usize,
+}
+
+impl CriticalElementsCollector {
+    fn collect(&mut self, children: &[Child], is_in_body: bool) {
+        for child in children {
+            let Child::Element(element) = child else {
+                continue;
+            };
+
+            if is_in_body {
+                if self.count == self.limit {
+                    return;
+                }
+
+                self.count += 1;
+            }
+
+            self.elements.insert(element as *const Element);
+            self.collect(
+                &element.children,
+                is_in_body || (element.namespace == Namespace::HTML && element.tag_name == "body"),
+            );
+        }
+    }
+}
+
+struct CriticalCssExtractor<'a> {
+    document: &'a Document,
+    /// [None] if all elements are critical.
+    critical_elements: Option<&'a AHashSet<*const Element>>,
+    config: QueryConfig,
+}
+
+impl CriticalCssExtractor<'_> {
+    /// Returns the minified critical rules of the stylesheet, or [None] if it
+    /// can't be parsed or inlined.
+    fn extract(&self, css: String, options: &CssOptions) -> Option<String> {
+        let cm = SourceMap::default();
+        let fm = cm.new_source_file(FileName::Anon.into(), css);
+        let mut errors = vec![];
+        let mut stylesheet: Stylesheet =
+            swc_css_parser::parse_file(&fm, None, options.parser, &mut errors).ok()?;
+
+        // Like the minifier, avoid changing invalid CSS
+        if !errors.is_empty() {
+            return None;
+        }
+
+        stylesheet.rules.retain_mut(|rule| match rule {
+            Rule::QualifiedRule(qualified_rule) => self.retain_qualified_rule(qualified_rule),
+            Rule::AtRule(at_rule) => self.retain_at_rule(at_rule),
+            _ => true,
+        });
+
+        swc_css_minifier::minify(&mut stylesheet, options.minifier.clone());
+
+        let mut minified = String::new();
+        let wr = swc_css_codegen::writer::basic::BasicCssWriter::new(
+            &mut minified,
+            None,
+            swc_css_codegen::writer::basic::BasicCssWriterConfig::default(),
+        );
+        let mut gen = swc_css_codegen::CodeGenerator::new(
+            wr,
+            swc_css_codegen::CodegenConfig { minify: true },
+        );
+
+        swc_css_codegen::Emit::emit(&mut gen, &stylesheet).ok()?;
+
+        // The content of a `style` element can't contain its end tag
+        if minified.to_ascii_lowercase().contains("</style") {
+            return None;
+        }
+
+        Some(minified)
+    }
+
+    fn retain_qualified_rule(&self, qualified_rule: &mut QualifiedRule) -> bool {
+        match &mut qualified_rule.prelude {
+            QualifiedRulePrelude::SelectorList(selector_list) => {
+                selector_list
+                    .children
+                    .retain(|selector| self.is_complex_selector_critical(selector));
+
+                !selector_list.children.is_empty()
+            }
+            // Nested rules are relative to their parent rule
+            QualifiedRulePrelude::RelativeSelectorList(..)
+            | QualifiedRulePrelude::ListOfComponentValues(..) => true,
+        }
+    }
+
+    fn retain_at_rule(&self, at_rule: &mut AtRule) -> bool {
+        let name = match &at_rule.name {
+            AtRuleName::Ident(ident) => ident.value.to_ascii_lowercase(),
+            AtRuleName::DashedIdent(..) => return true,
+        };
+        let is_conditional_group_rule = match &*name {
+            "media" | "supports" | "container" | "document" | "-moz-document" => true,
+            // Removing an empty `@layer` block would change the order of layers
+            "layer" | "scope" | "starting-style" => false,
+            // Imported stylesheets are loaded by the full stylesheet
+            "import" => return false,
+            _ => return true,
+        };
+        let block = match &mut at_rule.block {
+            Some(block) => block,
+            None => return true,
+        };
+
+        let mut has_rules = false;
+
+        block.value.retain_mut(|component_value| {
+            let is_retained = match component_value {
+                ComponentValue::QualifiedRule(qualified_rule) => {
+                    self.retain_qualified_rule(qualified_rule)
+                }
+                ComponentValue::AtRule(at_rule) => self.retain_at_rule(at_rule),
+                _ => return true,
+            };
+
+            has_rules |= is_retained;
+
+            is_retained
+        });
+
+        has_rules || !is_conditional_group_rule
+    }
+
+    /// Pseudo-elements and pseudo-classes of user interactions are ignored,
+    /// so the rule is critical when the element itself is.
+    fn is_complex_selector_critical(&self, complex_selector: &ComplexSelector) -> bool {
+        let mut complex_selector = complex_selector.clone();
+
+        for child in &mut complex_selector.children {
+            if let ComplexSelectorChildren::CompoundSelector(compound_selector) = child {
+                compound_selector
+                    .subclass_selectors
+                    .retain(|subclass_selector| !is_dynamic_subclass_selector(subclass_selector));
+            }
+        }
+
+        let selector_list = SelectorList {
+            span: DUMMY_SP,
+            children: vec![complex_selector],
+        };
+
+        match self.critical_elements {
+            Some(critical_elements) => {
+                query_selector_all(self.document, &selector_list, &self.config)
+                    .into_iter()
+                    .any(|element| critical_elements.contains(&(element as *const Element)))
+            }
+            None => query_selector(self.document, &selector_list, &self.config).is_some(),
+        }
+    }
+}
+
+fn is_dynamic_subclass_selector(subclass_selector: &SubclassSelector) -> bool {
+    match subclass_selector {
+        SubclassSelector::PseudoElement(..) => true,
+        SubclassSelector::PseudoClass(pseudo_class_selector) => matches!(
+            &*pseudo_class_selector.name.value.to_ascii_lowercase(),
+            "hover"
+                | "active"
+                | "focus"
+                | "focus-visible"
+                | "focus-within"
+                | "visited"
+                | "target"
+                | "target-within"
+                | "user-valid"
+                | "user-invalid"
+        ),
+        _ => false,
+    }
+}
@@ -7,20 +7,29 @@ use serde_json::Value;
 use swc_atoms::{js_word, JsWord};
 use swc_cached::regex::CachedRegex;
 use swc_common::{
-    collections::AHashMap, comments::SingleThreadedComments, sync::Lrc, EqIgnoreSpan, FileName,
-    FilePathMapping, Mark, SourceMap, DUMMY_SP,
+    collections::AHashMap, comments::SingleThreadedComments, sync::Lrc, BytePos, EqIgnoreSpan,
+    FileName, FilePathMapping, LineCol, Mark, SourceFile, SourceMap, DUMMY_SP,
 };
 use swc_html_ast::*;
+use swc_html_codegen::EmbeddedSourceMaps;
 use swc_html_parser::parser::ParserConfig;
 use swc_html_utils::{HTML_ELEMENTS_AND_ATTRIBUTES, SVG_ELEMENTS_AND_ATTRIBUTES};
 use swc_html_visit::{VisitMut, VisitMutWith};
 
-use crate::option::{
-    CollapseWhitespaces, CssOptions, JsOptions, JsParserOptions, JsonOptions, MinifierType,
-    MinifyCssOption, MinifyJsOption, MinifyJsonOption, MinifyOptions, RemoveRedundantAttributes,
+use crate::{
+    option::{
+        CollapseWhitespaces, CssOptions, JsOptions, JsParserOptions, JsonOptions, MinifierType,
+        MinifyCssOption, MinifyJsOption, MinifyJsonOption, MinifyOptions, MinifySvgOption,
+        RemoveRedundantAttributes, RemoveUnusedCssOption, RemoveUnusedCssOptions, SvgOptions,
+    },
+    svg::optimize_svg,
+    unused_css::{remove_unused_css, UsedNames},
 };
 
+pub mod critical_css;
 pub mod option;
+pub mod svg;
+pub mod unused_css;
 
 static ALLOW_TO_TRIM_HTML_ATTRIBUTES: &[(&str, &str)] = &[
     ("head", "profile"),
@@ -231,6 +240,10 @@ struct Minifier<'a> {
     latest_element: Option<Child>,
     descendant_of_pre: bool,
     attribute_name_counter: Option<AHashMap<JsWord, usize>>,
+    used_names: Option<&'a UsedNames>,
+    /// Mappings of minified `script` and `style` elements, only collected when
+    /// requested
+    source_maps: Option<EmbeddedSourceMaps>,
 }
 
 fn get_white_space(namespace: Namespace, tag_name: &str) -> WhiteSpace {
@@ -1966,6 +1979,17 @@ impl Minifier<'_> {
 
     // TODO source map url output for JS and CSS?
     fn minify_js(&self, data: String, is_module: bool, is_attribute: bool) -> Option<String> {
+        self.minify_js_with_source_map(data, is_module, is_attribute, None)
+    }
+
+    /// Positions of the mappings added to `srcmap` are offsets in `data`.
+    fn minify_js_with_source_map(
+        &self,
+        data: String,
+        is_module: bool,
+        is_attribute: bool,
+        mut srcmap: Option<&mut Vec<(BytePos, LineCol)>>,
+    ) -> Option<String> {
         let mut errors: Vec<_> = vec![];
 
         let cm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
@@ -2066,7 +2090,7 @@ impl Minifier<'_> {
                 cm.clone(),
                 "\n",
                 &mut buf,
-                None,
+                srcmap.as_deref_mut(),
             )) as Box<dyn swc_ecma_codegen::text_writer::WriteJs>;
 
             wr = Box::new(swc_ecma_codegen::text_writer::omit_trailing_semi(wr));
@@ -2090,13 +2114,30 @@ impl Minifier<'_> {
         }
 
         let minified = match String::from_utf8(buf) {
-            // Avoid generating the sequence "</script" in JS code
-            // TODO move it to ecma codegen under the option?
-            Ok(minified) => minified.replace("</script>", "<\\/script>"),
+            Ok(minified) => minified,
             _ => return None,
         };
 
-        Some(minified)
+        if let Some(srcmap) = srcmap {
+            relative_to_source_file(srcmap, &fm);
+
+            // Every escaped end tag moves the rest of the line by one column
+            for (index, _) in minified.match_indices("</script>") {
+                let line = minified[..index].matches('\n').count() as u32;
+                let line_start = minified[..index].rfind('\n').map_or(0, |index| index + 1);
+                let col = minified[line_start..index].chars().count() as u32;
+
+                for (_, loc) in srcmap.iter_mut() {
+                    if loc.line == line && loc.col > col {
+                        loc.col += 1;
+                    }
+                }
+            }
+        }
+
+        // Avoid generating the sequence "</script" in JS code
+        // TODO move it to ecma codegen under the option?
+        Some(minified.replace("</script>", "<\\/script>"))
     }
 
     fn need_minify_css(&self) -> bool {
@@ -2147,7 +2188,66 @@ impl Minifier<'_> {
         }
     }
 
+    fn need_minify_svg(&self) -> bool {
+        match self.options.minify_svg {
+            MinifySvgOption::Bool(value) => value,
+            MinifySvgOption::Options(_) => true,
+        }
+    }
+
+    fn get_svg_options(&self) -> SvgOptions {
+        match &self.options.minify_svg {
+            MinifySvgOption::Bool(_) => SvgOptions::default(),
+            MinifySvgOption::Options(svg_options) => *svg_options.clone(),
+        }
+    }
+
+    /// Optimizes `svg` elements in `children` before other minifications.
+    fn minify_svg_children(&self, children: &mut [Child]) {
+        if !self.need_minify_svg() {
+            return;
+        }
+
+        let options = self.get_svg_options();
+
+        for child in children {
+            match child {
+                Child::Element(element)
+                    if element.namespace == Namespace::SVG && element.tag_name == "svg" =>
+                {
+                    optimize_svg(element, &options);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn need_remove_unused_css(&self) -> bool {
+        match self.options.remove_unused_css {
+            RemoveUnusedCssOption::Bool(value) => value,
+            RemoveUnusedCssOption::Options(_) => true,
+        }
+    }
+
+    fn get_remove_unused_css_options(&self) -> RemoveUnusedCssOptions {
+        match &self.options.remove_unused_css {
+            RemoveUnusedCssOption::Bool(_) => RemoveUnusedCssOptions::default(),
+            RemoveUnusedCssOption::Options(options) => *options.clone(),
+        }
+    }
+
     fn minify_css(&self, data: String, mode: CssMinificationMode) -> Option<String> {
+        self.minify_css_with_source_map(data, mode, None)
+    }
+
+    /// Positions of the mappings added to `srcmap` are offsets in `data`,
+    /// mappings are only generated for stylesheets.
+    fn minify_css_with_source_map(
+        &self,
+        data: String,
+        mode: CssMinificationMode,
+        mut srcmap: Option<&mut Vec<(BytePos, LineCol)>>,
+    ) -> Option<String> {
         let mut errors: Vec<_> = vec![];
 
         let cm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
@@ -2254,12 +2354,23 @@ impl Minifier<'_> {
             return None;
         }
 
+        if let (CssMinificationMode::Stylesheet, Some(used_names)) = (&mode, self.used_names) {
+            remove_unused_css(
+                &mut stylesheet,
+                used_names,
+                &self.get_remove_unused_css_options(),
+            );
+        }
+
         swc_css_minifier::minify(&mut stylesheet, options.minifier);
 
         let mut minified = String::new();
         let wr = swc_css_codegen::writer::basic::BasicCssWriter::new(
             &mut minified,
-            None,
+            match mode {
+                CssMinificationMode::Stylesheet => srcmap.as_deref_mut(),
+                _ => None,
+            },
             swc_css_codegen::writer::basic::BasicCssWriterConfig::default(),
         );
 
@@ -2301,9 +2412,49 @@ impl Minifier<'_> {
             }
         }
 
+        if let (CssMinificationMode::Stylesheet, Some(srcmap)) = (mode, srcmap) {
+            relative_to_source_file(srcmap, &fm);
+        }
+
         Some(minified)
     }
 
+    /// Moves the mappings of minified `text` to the source file of the
+    /// document.
+    fn add_embedded_source_map(&mut self, text: &Text, srcmap: Option<Vec<(BytePos, LineCol)>>) {
+        let (Some(source_maps), Some(mut srcmap)) = (&mut self.source_maps, srcmap) else {
+            return;
+        };
+
+        if text.span.is_dummy() {
+            return;
+        }
+
+        // The parser normalizes newlines, so `\r\n` in the source is `\n` in the
+        // text, other differences can't be mapped
+        let data = text.data.as_bytes();
+        let source_len = (text.span.hi - text.span.lo).0 as usize;
+        let is_crlf = if source_len == data.len() {
+            false
+        } else if source_len == data.len() + data.iter().filter(|c| **c == b'\n').count() {
+            true
+        } else {
+            return;
+        };
+
+        for (pos, _) in srcmap.iter_mut() {
+            let mut offset = (pos.0 as usize).min(data.len());
+
+            if is_crlf {
+                offset += data[..offset].iter().filter(|c| **c == b'\n').count();
+            }
+
+            *pos = text.span.lo + BytePos(offset as u32);
+        }
+
+        source_maps.insert(text.span, srcmap);
+    }
+
     fn minify_html(&self, data: String, mode: HtmlMinificationMode) -> Option<String> {
         let mut errors: Vec<_> = vec![];
 
@@ -2364,11 +2515,16 @@ impl Minifier<'_> {
             HtmlRoot::Document(ref mut document) => {
                 minify_document(document, self.options);
             }
-            HtmlRoot::DocumentFragment(ref mut document_fragment) => minify_document_fragment(
-                document_fragment,
-                context_element.as_ref().unwrap(),
-                self.options,
-            ),
+            // Content of conditional comments is a part of the current document, so it uses
+            // the same names for removing unused CSS
+            HtmlRoot::DocumentFragment(ref mut document_fragment) => {
+                let mut minifier =
+                    create_minifier(Some(context_element.as_ref().unwrap()), self.options);
+
+                minifier.used_names = self.used_names;
+
+                minify_document_fragment_with(document_fragment, minifier);
+            }
         }
 
         let mut minified = String::new();
@@ -2386,6 +2542,7 @@ impl Minifier<'_> {
                 tag_omission: None,
                 self_closing_void_elements: None,
                 quotes: None,
+                ..Default::default()
             },
         );
 
@@ -2656,6 +2813,8 @@ impl VisitMut for Minifier<'_> {
     }
 
     fn visit_mut_document_fragment(&mut self, n: &mut DocumentFragment) {
+        self.minify_svg_children(&mut n.children);
+
         n.children = self.minify_children(&mut n.children);
 
         n.visit_mut_children_with(self);
@@ -2711,6 +2870,10 @@ impl VisitMut for Minifier<'_> {
             self.descendant_of_pre = get_white_space(n.namespace, &n.tag_name) == WhiteSpace::Pre;
         }
 
+        if n.namespace == Namespace::HTML {
+            self.minify_svg_children(&mut n.children);
+        }
+
         n.children = self.minify_children(&mut n.children);
 
         n.visit_mut_children_with(self);
@@ -2889,19 +3052,20 @@ impl VisitMut for Minifier<'_> {
         }
 
         match text_type {
-            Some(MinifierType::JsScript) => {
-                let minified = match self.minify_js(n.data.to_string(), false, false) {
+            Some(MinifierType::JsScript | MinifierType::JsModule) => {
+                let is_module = matches!(text_type, Some(MinifierType::JsModule));
+                let mut srcmap = self.source_maps.is_some().then(Vec::new);
+                let minified = match self.minify_js_with_source_map(
+                    n.data.to_string(),
+                    is_module,
+                    false,
+                    srcmap.as_mut(),
+                ) {
                     Some(minified) => minified,
                     None => return,
                 };
 
-                n.data = minified.into();
-            }
-            Some(MinifierType::JsModule) => {
-                let minified = match self.minify_js(n.data.to_string(), true, false) {
-                    Some(minified) => minified,
-                    None => return,
-                };
+                self.add_embedded_source_map(n, srcmap);
 
                 n.data = minified.into();
             }
@@ -2914,11 +3078,17 @@ impl VisitMut for Minifier<'_> {
                 n.data = minified.into();
             }
             Some(MinifierType::Css) => {
-                let minified =
-                    match self.minify_css(n.data.to_string(), CssMinificationMode::Stylesheet) {
-                        Some(minified) => minified,
-                        None => return,
-                    };
+                let mut srcmap = self.source_maps.is_some().then(Vec::new);
+                let minified = match self.minify_css_with_source_map(
+                    n.data.to_string(),
+                    CssMinificationMode::Stylesheet,
+                    srcmap.as_mut(),
+                ) {
+                    Some(minified) => minified,
+                    None => return,
+                };
+
+                self.add_embedded_source_map(n, srcmap);
 
                 n.data = minified.into();
             }
@@ -3010,11 +3180,41 @@ fn create_minifier<'a>(
         latest_element: None,
         descendant_of_pre: is_pre,
         attribute_name_counter: None,
+        used_names: None,
+        source_maps: None,
     }
 }
 
 pub fn minify_document(document: &mut Document, options: &MinifyOptions) {
+    minify_document_inner(document, options, false);
+}
+
+/// Same as [minify_document], but also returns source maps of minified
+/// `script` and `style` elements, which should be passed to the code generator
+/// using [swc_html_codegen::CodegenConfig::embedded_source_maps].
+pub fn minify_document_with_source_maps(
+    document: &mut Document,
+    options: &MinifyOptions,
+) -> EmbeddedSourceMaps {
+    minify_document_inner(document, options, true).unwrap_or_default()
+}
+
+fn minify_document_inner(
+    document: &mut Document,
+    options: &MinifyOptions,
+    with_source_maps: bool,
+) -> Option<EmbeddedSourceMaps> {
     let mut minifier = create_minifier(None, options);
+    let used_names;
+
+    if with_source_maps {
+        minifier.source_maps = Some(Default::default());
+    }
+
+    if minifier.need_remove_unused_css() && minifier.need_minify_css() {
+        used_names = UsedNames::collect(&*document);
+        minifier.used_names = Some(&used_names);
+    }
 
     if options.sort_attributes {
         let mut attribute_name_counter = AttributeNameCounter {
@@ -3027,6 +3227,8 @@ pub fn minify_document(document: &mut Document, options: &MinifyOptions) {
     }
 
     document.visit_mut_with(&mut minifier);
+
+    minifier.source_maps
 }
 
 pub fn minify_document_fragment(
@@ -3034,9 +3236,47 @@ pub fn minify_document_fragment(
     context_element: &Element,
     options: &MinifyOptions,
 ) {
+    minify_document_fragment_inner(document_fragment, context_element, options, false);
+}
+
+/// Same as [minify_document_fragment], but also returns source maps of
+/// minified `script` and `style` elements, see
+/// [minify_document_with_source_maps].
+pub fn minify_document_fragment_with_source_maps(
+    document_fragment: &mut DocumentFragment,
+    context_element: &Element,
+    options: &MinifyOptions,
+) -> EmbeddedSourceMaps {
+    minify_document_fragment_inner(document_fragment, context_element, options, true)
+        .unwrap_or_default()
+}
+
+fn minify_document_fragment_inner(
+    document_fragment: &mut DocumentFragment,
+    context_element: &Element,
+    options: &MinifyOptions,
+    with_source_maps: bool,
+) -> Option<EmbeddedSourceMaps> {
     let mut minifier = create_minifier(Some(context_element), options);
+    let used_names;
 
-    if options.sort_attributes {
+    if with_source_maps {
+        minifier.source_maps = Some(Default::default());
+    }
+
+    if minifier.need_remove_unused_css() && minifier.need_minify_css() {
+        used_names = UsedNames::collect(&*document_fragment);
+        minifier.used_names = Some(&used_names);
+    }
+
+    minify_document_fragment_with(document_fragment, minifier)
+}
+
+fn minify_document_fragment_with(
+    document_fragment: &mut DocumentFragment,
+    mut minifier: Minifier,
+) -> Option<EmbeddedSourceMaps> {
+    if minifier.options.sort_attributes {
         let mut attribute_name_counter = AttributeNameCounter {
             tree: Default::default(),
         };
@@ -3047,4 +3287,16 @@ pub fn minify_document_fragment(
     }
 
     document_fragment.visit_mut_with(&mut minifier);
+
+    minifier.source_maps
+}
+
+/// Makes positions of mappings relative to the start of `fm`, dropping
+/// positions outside of it.
+fn relative_to_source_file(srcmap: &mut Vec<(BytePos, LineCol)>, fm: &SourceFile) {
+    srcmap.retain(|(pos, _)| fm.start_pos <= *pos && *pos <= fm.end_pos);
+
+    for (pos, _) in srcmap.iter_mut() {
+        *pos = *pos - fm.start_pos;
+    }
 }
@@ -126,6 +126,108 @@ pub struct CssOptions {
     pub codegen: CssCodegenOptions,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+#[serde(untagged)]
+pub enum RemoveUnusedCssOption {
+    Bool(bool),
+    Options(Box<RemoveUnusedCssOptions>),
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct RemoveUnusedCssOptions {
+    /// Classes, ids, tag names and attribute names which are considered used,
+    /// even if they are not in the document (for example, classes added by
+    /// scripts)
+    #[serde(default)]
+    pub safelist: Option<Vec<CachedRegex>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+#[serde(untagged)]
+pub enum MinifySvgOption {
+    Bool(bool),
+    Options(Box<SvgOptions>),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct SvgOptions {
+    /// Maximum number of digits after the decimal point in path data and
+    /// numeric attributes
+    #[serde(default = "default_float_precision")]
+    pub float_precision: usize,
+    /// Remove unnecessary separators and command letters from path data
+    #[serde(default = "true_by_default")]
+    pub convert_path_data: bool,
+    /// Round numbers in numeric attributes, `points` and `viewBox`
+    #[serde(default = "true_by_default")]
+    pub cleanup_numeric_values: bool,
+    /// Remove elements and attributes of editors (i.e. `sodipodi:*` and
+    /// `inkscape:*`)
+    #[serde(default = "true_by_default")]
+    pub remove_editors_data: bool,
+    /// Remove `metadata` elements
+    #[serde(default = "true_by_default")]
+    pub remove_metadata: bool,
+    /// Remove attributes with default values, except inherited ones (i.e.
+    /// `fill`), because the inherited value can be changed by CSS
+    #[serde(default = "true_by_default")]
+    pub remove_default_attributes: bool,
+    /// Remove empty groups, replace groups without attributes with their
+    /// children, and groups with only one child and inheritable attributes
+    /// with this child
+    #[serde(default = "true_by_default")]
+    pub collapse_groups: bool,
+    /// Merge `style` elements into one
+    #[serde(default = "true_by_default")]
+    pub merge_styles: bool,
+    /// Remove presentation attributes (i.e. `fill`) which are overridden by a
+    /// declaration in the `style` attribute of the same element
+    #[serde(default = "true_by_default")]
+    pub merge_style_attributes: bool,
+    /// Move declarations of the `style` attribute to presentation
+    /// attributes. Disabled by default, because presentation attributes can
+    /// be overridden by CSS targeting the element while the `style` attribute
+    /// can't
+    #[serde(default)]
+    pub convert_style_to_attributes: bool,
+}
+
+/// Implement default using serde.
+impl Default for SvgOptions {
+    fn default() -> Self {
+        serde_json::from_value(serde_json::Value::Object(Default::default())).unwrap()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct CriticalCssOptions {
+    /// Number of elements of the `body` element (in tree order) which are
+    /// considered above the fold, all elements by default
+    #[serde(default)]
+    pub above_the_fold_elements: Option<usize>,
+    /// Add a `noscript` element loading the stylesheet synchronously after
+    /// each `link` element loaded asynchronously
+    #[serde(default = "true_by_default")]
+    pub noscript_fallback: bool,
+    #[serde(default)]
+    pub css: Option<CssOptions>,
+}
+
+/// Implement default using serde.
+impl Default for CriticalCssOptions {
+    fn default() -> Self {
+        serde_json::from_value(serde_json::Value::Object(Default::default())).unwrap()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
@@ -171,6 +273,13 @@ pub struct MinifyOptions {
     pub minify_js: MinifyJsOption,
     #[serde(default = "minify_css_by_default")]
     pub minify_css: MinifyCssOption,
+    /// Remove rules of `style` elements whose selectors can't match any
+    /// element of the document, only applied when `minifyCss` is enabled
+    #[serde(default = "remove_unused_css_by_default")]
+    pub remove_unused_css: RemoveUnusedCssOption,
+    /// Optimize inline `svg` elements
+    #[serde(default = "minify_svg_by_default")]
+    pub minify_svg: MinifySvgOption,
     // Allow to compress value of custom script elements,
     // i.e. `<script type="text/html"><div><!-- text --> <div data-foo="bar> Text </div></script>`
     //
@@ -216,6 +325,18 @@ const fn minify_css_by_default() -> MinifyCssOption {
     MinifyCssOption::Bool(true)
 }
 
+const fn minify_svg_by_default() -> MinifySvgOption {
+    MinifySvgOption::Bool(false)
+}
+
+const fn default_float_precision() -> usize {
+    3
+}
+
+const fn remove_unused_css_by_default() -> RemoveUnusedCssOption {
+    RemoveUnusedCssOption::Bool(false)
+}
+
 fn default_preserve_comments() -> Option<Vec<CachedRegex>> {
     Some(vec![
         // License comments
@@ -0,0 +1,847 @@
+//! Optimizations of SVG subtrees, like [SVGO](https://github.com/svg/svgo).
+//!
+//! Used for inline `svg` elements by the minifier when the `minifySvg`
+//! option is enabled, and can be used for `.svg` files parsed as a document
+//! fragment with the `svg` element as the context element.
+
+use std::{iter::once, mem::take};
+
+use swc_atoms::JsWord;
+use swc_html_ast::{Attribute, Child, DocumentFragment, Element, Namespace, Text};
+use swc_html_utils::SVG_ELEMENTS_AND_ATTRIBUTES;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+use crate::option::SvgOptions;
+
+/// Prefixes of elements and attributes created by editors.
+static EDITOR_PREFIXES: &[&str] = &["sodipodi:", "inkscape:", "sketch:", "serif:"];
+
+/// Attributes whose value is a number, e.g. coordinates, sizes and opacities.
+static NUMERIC_ATTRIBUTES: &[&str] = &[
+    "x",
+    "y",
+    "x1",
+    "y1",
+    "x2",
+    "y2",
+    "dx",
+    "dy",
+    "cx",
+    "cy",
+    "fx",
+    "fy",
+    "r",
+    "rx",
+    "ry",
+    "width",
+    "height",
+    "stroke-width",
+    "stroke-miterlimit",
+    "opacity",
+    "fill-opacity",
+    "stroke-opacity",
+    "stop-opacity",
+    "flood-opacity",
+];
+
+/// Attributes which can also be set with CSS, and which are overridden by
+/// declarations of the same property in the `style` attribute.
+static PRESENTATION_ATTRIBUTES: &[&str] = &[
+    "alignment-baseline",
+    "baseline-shift",
+    "clip",
+    "clip-path",
+    "clip-rule",
+    "color",
+    "color-interpolation",
+    "color-interpolation-filters",
+    "color-profile",
+    "color-rendering",
+    "cursor",
+    "direction",
+    "display",
+    "dominant-baseline",
+    "enable-background",
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "filter",
+    "flood-color",
+    "flood-opacity",
+    "font-family",
+    "font-size",
+    "font-size-adjust",
+    "font-stretch",
+    "font-style",
+    "font-variant",
+    "font-weight",
+    "glyph-orientation-horizontal",
+    "glyph-orientation-vertical",
+    "image-rendering",
+    "kerning",
+    "letter-spacing",
+    "lighting-color",
+    "marker-end",
+    "marker-mid",
+    "marker-start",
+    "mask",
+    "opacity",
+    "overflow",
+    "pointer-events",
+    "shape-rendering",
+    "stop-color",
+    "stop-opacity",
+    "stroke",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-opacity",
+    "stroke-width",
+    "text-anchor",
+    "text-decoration",
+    "text-rendering",
+    "unicode-bidi",
+    "visibility",
+    "word-spacing",
+    "writing-mode",
+];
+
+/// Optimizes an `svg` element and its descendants.
+pub fn optimize_svg(element: &mut Element, options: &SvgOptions) {
+    if options.merge_styles {
+        merge_styles(&mut element.children);
+    }
+
+    element.visit_mut_with(&mut SvgOptimizer { options });
+}
+
+/// Optimizes a document fragment parsed with the `svg` element as the context
+/// element, e.g. the content of an `.svg` file.
+pub fn optimize_svg_document_fragment(
+    document_fragment: &mut DocumentFragment,
+    options: &SvgOptions,
+) {
+    if options.merge_styles {
+        merge_styles(&mut document_fragment.children);
+    }
+
+    let mut optimizer = SvgOptimizer { options };
+
+    document_fragment.visit_mut_children_with(&mut optimizer);
+    optimizer.optimize_children(None, &mut document_fragment.children);
+}
+
+struct SvgOptimizer<'a> {
+    options: &'a SvgOptions,
+}
+
+impl SvgOptimizer<'_> {
+    fn optimize_attributes(&self, element: &mut Element) {
+        if self.options.merge_style_attributes || self.options.convert_style_to_attributes {
+            merge_style_attribute(element, self.options.convert_style_to_attributes);
+        }
+
+        let tag_name = element.tag_name.clone();
+
+        element.attributes.retain_mut(|attribute| {
+            if attribute.namespace.is_some() {
+                return true;
+            }
+
+            if self.options.remove_editors_data && is_editor_attribute(attribute) {
+                return false;
+            }
+
+            if self.options.remove_default_attributes && is_default_attribute(&tag_name, attribute)
+            {
+                return false;
+            }
+
+            let value = match &attribute.value {
+                Some(value) => value,
+                None => return true,
+            };
+            let optimized = match (&*tag_name, &*attribute.name) {
+                ("path", "d") if self.options.convert_path_data => {
+                    convert_path_data(value, self.options.float_precision)
+                }
+                ("polyline" | "polygon", "points") | (_, "viewBox")
+                    if self.options.cleanup_numeric_values =>
+                {
+                    convert_number_list(value, self.options.float_precision)
+                }
+                (_, name)
+                    if self.options.cleanup_numeric_values
+                        && NUMERIC_ATTRIBUTES.contains(&name) =>
+                {
+                    parse_number(value.trim())
+                        .map(|number| format_number(number, self.options.float_precision))
+                }
+                _ => None,
+            };
+
+            if let Some(optimized) = optimized {
+                attribute.value = Some(optimized.into());
+                attribute.raw_value = None;
+            }
+
+            true
+        });
+    }
+
+    /// Removes editor and metadata elements, empty containers and useless
+    /// groups from `children`.
+    fn optimize_children(&self, parent: Option<&Element>, children: &mut Vec<Child>) {
+        // Only the first matching child of `switch` is rendered
+        let can_collapse_groups = self.options.collapse_groups
+            && !matches!(parent, Some(parent) if parent.tag_name == *"switch");
+
+        let mut new_children = Vec::with_capacity(children.len());
+
+        for child in take(children) {
+            let mut element = match child {
+                Child::Element(element) if element.namespace == Namespace::SVG => element,
+                _ => {
+                    new_children.push(child);
+
+                    continue;
+                }
+            };
+
+            if self.options.remove_editors_data && is_editor_name(&element.tag_name) {
+                continue;
+            }
+
+            if self.options.remove_metadata && element.tag_name == *"metadata" {
+                continue;
+            }
+
+            if self.options.collapse_groups
+                && matches!(&*element.tag_name, "g" | "defs")
+                && element.attributes.is_empty()
+                && is_empty_children(&element.children)
+            {
+                continue;
+            }
+
+            if can_collapse_groups && element.tag_name == *"g" {
+                if element.attributes.is_empty() {
+                    new_children.append(&mut element.children);
+
+                    continue;
+                }
+
+                if let Some(only_child) = move_attributes_to_only_child(&mut element) {
+                    new_children.push(Child::Element(only_child));
+
+                    continue;
+                }
+            }
+
+            new_children.push(Child::Element(element));
+        }
+
+        *children = new_children;
+    }
+}
+
+impl VisitMut for SvgOptimizer<'_> {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        if n.namespace != Namespace::SVG {
+            return;
+        }
+
+        // Children of `foreignObject` are not SVG
+        if n.tag_name != *"foreignObject" {
+            n.visit_mut_children_with(self);
+
+            let mut children = take(&mut n.children);
+
+            self.optimize_children(Some(n), &mut children);
+
+            n.children = children;
+        }
+
+        self.optimize_attributes(n);
+    }
+}
+
+fn is_editor_name(name: &str) -> bool {
+    EDITOR_PREFIXES
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+}
+
+fn is_editor_attribute(attribute: &Attribute) -> bool {
+    let name = &*attribute.name;
+
+    is_editor_name(name)
+        || name
+            .strip_prefix("xmlns:")
+            .map(|prefix| {
+                EDITOR_PREFIXES
+                    .iter()
+                    .any(|editor_prefix| editor_prefix.strip_suffix(':') == Some(prefix))
+            })
+            .unwrap_or_default()
+}
+
+/// Only attributes which are not inherited are removed, because the value
+/// inherited from an ancestor can be changed by CSS outside of the SVG.
+fn is_default_attribute(tag_name: &JsWord, attribute: &Attribute) -> bool {
+    let value = match &attribute.value {
+        Some(value) => value.trim(),
+        None => return false,
+    };
+    let attribute_info = match SVG_ELEMENTS_AND_ATTRIBUTES
+        .get(tag_name)
+        .and_then(|element| element.other.get(&attribute.name))
+    {
+        Some(attribute_info) => attribute_info,
+        None => return false,
+    };
+
+    attribute_info.inherited != Some(true) && attribute_info.initial.as_deref() == Some(value)
+}
+
+fn is_empty_children(children: &[Child]) -> bool {
+    children.iter().all(|child| match child {
+        Child::Text(text) => text.data.chars().all(|c| c.is_ascii_whitespace()),
+        Child::Comment(..) => true,
+        _ => false,
+    })
+}
+
+/// Replaces a group having only inheritable attributes with its only child,
+/// when the child doesn't have these attributes.
+fn move_attributes_to_only_child(group: &mut Element) -> Option<Element> {
+    let mut elements = group
+        .children
+        .iter()
+        .filter(|child| !is_empty_children(std::slice::from_ref(child)));
+    let only_child = match (elements.next(), elements.next()) {
+        (Some(Child::Element(element)), None) if element.namespace == Namespace::SVG => element,
+        _ => return None,
+    };
+    let group_attributes = SVG_ELEMENTS_AND_ATTRIBUTES.get(&JsWord::from("g"))?;
+    let is_movable = group.attributes.iter().all(|attribute| {
+        attribute.namespace.is_none()
+            && matches!(
+                group_attributes.other.get(&attribute.name),
+                Some(attribute_info) if attribute_info.inherited == Some(true)
+            )
+            && !only_child
+                .attributes
+                .iter()
+                .any(|child_attribute| child_attribute.name == attribute.name)
+    });
+
+    if !is_movable {
+        return None;
+    }
+
+    let index = group
+        .children
+        .iter()
+        .position(|child| matches!(child, Child::Element(..)))?;
+    let mut only_child = match group.children.swap_remove(index) {
+        Child::Element(element) => element,
+        _ => unreachable!(),
+    };
+
+    only_child.attributes.append(&mut group.attributes);
+
+    Some(only_child)
+}
+
+fn is_mergeable_style(element: &Element) -> bool {
+    element.namespace == Namespace::SVG
+        && element.tag_name == *"style"
+        && element.attributes.iter().all(|attribute| {
+            attribute.name == *"type"
+                && matches!(attribute.value.as_deref(), Some(value) if value.trim().eq_ignore_ascii_case("text/css"))
+        })
+}
+
+fn collect_styles(children: &[Child], styles: &mut Vec<String>) {
+    for child in children {
+        if let Child::Element(element) = child {
+            if is_mergeable_style(element) {
+                styles.push(
+                    element
+                        .children
+                        .iter()
+                        .filter_map(|child| match child {
+                            Child::Text(text) => Some(&*text.data),
+                            _ => None,
+                        })
+                        .collect(),
+                );
+            } else if element.namespace == Namespace::SVG && element.tag_name != *"foreignObject" {
+                collect_styles(&element.children, styles);
+            }
+        }
+    }
+}
+
+/// Replaces the content of the first style with `css` and removes the other
+/// styles.
+fn remove_styles(children: &mut Vec<Child>, css: &mut Option<String>) {
+    children.retain_mut(|child| {
+        let element = match child {
+            Child::Element(element) => element,
+            _ => return true,
+        };
+
+        if is_mergeable_style(element) {
+            return match css.take() {
+                Some(css) => {
+                    element.children = vec![Child::Text(Text {
+                        span: Default::default(),
+                        data: css.into(),
+                        raw: None,
+                    })];
+
+                    true
+                }
+                None => false,
+            };
+        }
+
+        if element.namespace == Namespace::SVG && element.tag_name != *"foreignObject" {
+            remove_styles(&mut element.children, css);
+        }
+
+        true
+    });
+}
+
+/// Merges all `style` elements into the first one.
+fn merge_styles(children: &mut Vec<Child>) {
+    let mut styles = vec![];
+
+    collect_styles(children, &mut styles);
+
+    if styles.len() < 2 {
+        return;
+    }
+
+    remove_styles(children, &mut Some(styles.join("\n")));
+}
+
+/// Splits a list of declarations into names and values, returns [None] if it
+/// contains something which is not a plain declaration, like a comment.
+fn parse_declarations(style: &str) -> Option<Vec<(String, &str)>> {
+    if style.contains("/*") || style.contains('\\') {
+        return None;
+    }
+
+    let mut declarations = vec![];
+    let mut start = 0;
+    let mut depth = 0usize;
+    let mut quote = None;
+
+    for (index, c) in style.char_indices().chain(once((style.len(), ';'))) {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(..), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.checked_sub(1)?,
+            (None, ';') if depth == 0 => {
+                let declaration = style[start..index].trim();
+
+                if !declaration.is_empty() {
+                    let (name, value) = declaration.split_once(':')?;
+                    let (name, value) = (name.trim(), value.trim());
+
+                    if name.is_empty() || value.is_empty() {
+                        return None;
+                    }
+
+                    // Names of custom properties are case-sensitive
+                    let name = if name.starts_with("--") {
+                        name.to_string()
+                    } else {
+                        name.to_ascii_lowercase()
+                    };
+
+                    declarations.push((name, value));
+                }
+
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if quote.is_some() || depth != 0 {
+        return None;
+    }
+
+    Some(declarations)
+}
+
+fn is_important(value: &str) -> bool {
+    value
+        .rsplit_once('!')
+        .is_some_and(|(_, flag)| flag.trim().eq_ignore_ascii_case("important"))
+}
+
+/// Removes presentation attributes overridden by the `style` attribute, and
+/// moves declarations of the `style` attribute to presentation attributes if
+/// `convert` is `true`.
+fn merge_style_attribute(element: &mut Element, convert: bool) {
+    let Some(style_index) = element
+        .attributes
+        .iter()
+        .position(|attribute| attribute.namespace.is_none() && attribute.name == *"style")
+    else {
+        return;
+    };
+    let Some(style) = element.attributes[style_index].value.clone() else {
+        return;
+    };
+    let Some(declarations) = parse_declarations(&style) else {
+        return;
+    };
+
+    let is_presentation = |name: &str| PRESENTATION_ATTRIBUTES.contains(&name);
+    let is_convertible = |value: &str| !is_important(value) && !value.contains("var(");
+
+    let mut converted: Vec<(String, &str)> = vec![];
+    let mut rest: Vec<(String, &str)> = vec![];
+
+    for (name, value) in declarations {
+        // A later declaration overrides an earlier one, unless the earlier one is
+        // `!important`
+        converted.retain(|(converted_name, _)| *converted_name != name);
+
+        if convert
+            && is_presentation(&name)
+            && is_convertible(value)
+            && !rest.iter().any(|(rest_name, _)| *rest_name == name)
+        {
+            converted.push((name, value));
+        } else {
+            rest.push((name, value));
+        }
+    }
+
+    if !converted.is_empty() {
+        let style = &mut element.attributes[style_index];
+
+        style.value = Some(
+            rest.iter()
+                .map(|(name, value)| format!("{}:{}", name, value))
+                .collect::<Vec<_>>()
+                .join(";")
+                .into(),
+        );
+        style.raw_value = None;
+    }
+
+    let style_span = element.attributes[style_index].span;
+    let mut converted: Vec<(JsWord, JsWord)> = converted
+        .into_iter()
+        .map(|(name, value)| (name.into(), value.into()))
+        .collect();
+
+    element.attributes.retain_mut(|attribute| {
+        if attribute.namespace.is_some() {
+            return true;
+        }
+
+        if attribute.name == *"style" {
+            return !matches!(attribute.value.as_deref(), Some(value) if value.is_empty());
+        }
+
+        if let Some(index) = converted
+            .iter()
+            .position(|(name, _)| *name == attribute.name)
+        {
+            attribute.value = Some(converted.remove(index).1);
+            attribute.raw_value = None;
+
+            return true;
+        }
+
+        // Presentation attributes overridden by the `style` attribute
+        !rest
+            .iter()
+            .any(|(name, _)| is_presentation(name) && *name == *attribute.name)
+    });
+
+    element
+        .attributes
+        .extend(converted.into_iter().map(|(name, value)| Attribute {
+            span: style_span,
+            namespace: None,
+            prefix: None,
+            name,
+            raw_name: None,
+            value: Some(value),
+            raw_value: None,
+        }));
+}
+
+/// Parses a number, returns [None] if `value` isn't exactly a number.
+fn parse_number(value: &str) -> Option<f64> {
+    let mut lexer = NumberLexer::new(value);
+    let number = lexer.next_number()?;
+
+    if lexer.rest().is_empty() {
+        Some(number)
+    } else {
+        None
+    }
+}
+
+/// Formats a number with at most `precision` digits after the decimal point,
+/// without leading and trailing zeros.
+fn format_number(value: f64, precision: usize) -> String {
+    let mut formatted = format!("{:.*}", precision, value);
+
+    if formatted.contains('.') {
+        formatted = formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string();
+    }
+
+    if let Some(rest) = formatted.strip_prefix("0.") {
+        formatted = format!(".{}", rest);
+    } else if let Some(rest) = formatted.strip_prefix("-0.") {
+        formatted = format!("-.{}", rest);
+    }
+
+    if formatted == "-0" {
+        formatted = "0".into();
+    }
+
+    formatted
+}
+
+/// Appends a number to `output`, with a separator only when it is required.
+fn push_number(output: &mut String, number: &str, last_number: &mut Option<String>) {
+    if let Some(last_number) = last_number {
+        let needs_separator = if number.starts_with('-') {
+            false
+        } else if number.starts_with('.') {
+            // `1.5.5` is `1.5` and `.5`, but `1.5` is a single number
+            !last_number.contains('.') || last_number.contains(['e', 'E'])
+        } else {
+            true
+        };
+
+        if needs_separator {
+            output.push(' ');
+        }
+    }
+
+    output.push_str(number);
+    *last_number = Some(number.to_string());
+}
+
+/// Optimizes a list of numbers separated by whitespaces or commas, i.e. the
+/// value of `points` or `viewBox`.
+fn convert_number_list(value: &str, precision: usize) -> Option<String> {
+    let mut lexer = NumberLexer::new(value);
+    let mut output = String::with_capacity(value.len());
+    let mut last_number = None;
+
+    lexer.skip_separators();
+
+    while !lexer.rest().is_empty() {
+        let number = lexer.next_number()?;
+
+        push_number(
+            &mut output,
+            &format_number(number, precision),
+            &mut last_number,
+        );
+        lexer.skip_separators();
+    }
+
+    Some(output)
+}
+
+/// Number of arguments of path commands.
+fn get_path_command_arity(command: char) -> Option<usize> {
+    Some(match command.to_ascii_lowercase() {
+        'z' => 0,
+        'h' | 'v' => 1,
+        'm' | 'l' | 't' => 2,
+        's' | 'q' => 4,
+        'c' => 6,
+        'a' => 7,
+        _ => return None,
+    })
+}
+
+/// Removes unnecessary separators and command letters from path data and
+/// rounds numbers, returns [None] when the path data is invalid.
+fn convert_path_data(value: &str, precision: usize) -> Option<String> {
+    let mut lexer = NumberLexer::new(value);
+    let mut output = String::with_capacity(value.len());
+    let mut last_number = None;
+    // Command used when a command letter is omitted
+    let mut implicit_command = None;
+
+    lexer.skip_separators();
+
+    while let Some(letter) = lexer.rest().chars().next() {
+        let arity = get_path_command_arity(letter)?;
+        let mut command = letter;
+
+        lexer.bump(letter.len_utf8());
+
+        loop {
+            lexer.skip_separators();
+
+            // Command letter can be omitted for repeated commands, e.g. `L1 1 2 2`
+            if implicit_command != Some(command) {
+                output.push(command);
+                last_number = None;
+            }
+
+            for index in 0..arity {
+                if index > 0 {
+                    lexer.skip_separators();
+                }
+
+                let number = if command.eq_ignore_ascii_case(&'a') && (index == 3 || index == 4) {
+                    // Flags of arcs can be written without separators, e.g. `a1 1 0 00 1 1`
+                    match lexer.rest().chars().next()? {
+                        flag @ ('0' | '1') => {
+                            lexer.bump(1);
+
+                            flag.to_string()
+                        }
+                        _ => return None,
+                    }
+                } else {
+                    format_number(lexer.next_number()?, precision)
+                };
+
+                push_number(&mut output, &number, &mut last_number);
+            }
+
+            // Arguments after `M` are arguments of `L`
+            implicit_command = match command {
+                'M' => Some('L'),
+                'm' => Some('l'),
+                'Z' | 'z' => None,
+                _ => Some(command),
+            };
+
+            lexer.skip_separators();
+
+            let has_next_arguments = arity > 0
+                && lexer
+                    .rest()
+                    .starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.'));
+
+            if !has_next_arguments {
+                break;
+            }
+
+            command = implicit_command?;
+        }
+    }
+
+    Some(output)
+}
+
+struct NumberLexer<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> NumberLexer<'a> {
+    fn new(input: &'a str) -> Self {
+        NumberLexer { input, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn bump(&mut self, len: usize) {
+        self.pos += len;
+    }
+
+    fn skip_separators(&mut self) {
+        let rest = self.rest();
+
+        self.pos += rest.len()
+            - rest
+                .trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',')
+                .len();
+    }
+
+    /// Reads a number in the format used by SVG, e.g. `-1.5e3`, `.5` or `+2`.
+    fn next_number(&mut self) -> Option<f64> {
+        let bytes = self.rest().as_bytes();
+        let mut end = 0;
+
+        if matches!(bytes.first(), Some(b'+' | b'-')) {
+            end += 1;
+        }
+
+        let digits_start = end;
+
+        while matches!(bytes.get(end), Some(b'0'..=b'9')) {
+            end += 1;
+        }
+
+        let mut has_digits = end > digits_start;
+
+        if bytes.get(end) == Some(&b'.') {
+            let fraction_start = end + 1;
+            let mut fraction_end = fraction_start;
+
+            while matches!(bytes.get(fraction_end), Some(b'0'..=b'9')) {
+                fraction_end += 1;
+            }
+
+            if fraction_end > fraction_start {
+                has_digits = true;
+                end = fraction_end;
+            } else if has_digits {
+                // `1.` is a valid number
+                end = fraction_end;
+            }
+        }
+
+        if !has_digits {
+            return None;
+        }
+
+        if matches!(bytes.get(end), Some(b'e' | b'E')) {
+            let mut exponent_end = end + 1;
+
+            if matches!(bytes.get(exponent_end), Some(b'+' | b'-')) {
+                exponent_end += 1;
+            }
+
+            let exponent_digits_start = exponent_end;
+
+            while matches!(bytes.get(exponent_end), Some(b'0'..=b'9')) {
+                exponent_end += 1;
+            }
+
+            if exponent_end > exponent_digits_start {
+                end = exponent_end;
+            }
+        }
+
+        let number = self.rest()[..end].parse().ok()?;
+
+        self.pos += end;
+
+        Some(number)
+    }
+}
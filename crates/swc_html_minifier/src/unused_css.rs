@@ -0,0 +1,305 @@
+//! Removal of CSS rules whose selectors can't match any element of a
+//! document.
+//!
+//! Like PurgeCSS, only names are compared: a selector is kept when all tag
+//! names, ids, classes and attribute names it requires are used somewhere in
+//! the document, so the check is fast and never removes a rule which could
+//! match, but may keep some rules which don't.
+
+use swc_atoms::JsWord;
+use swc_cached::regex::CachedRegex;
+use swc_common::{collections::AHashSet, FileName, SourceMap};
+use swc_css_ast::{
+    AtRule, AtRuleName, ComplexSelector, ComplexSelectorChildren, ComponentValue, CompoundSelector,
+    ForgivingComplexSelector, ForgivingRelativeSelector, PseudoClassSelectorChildren,
+    QualifiedRule, QualifiedRulePrelude, Rule, Stylesheet, SubclassSelector, TypeSelector,
+};
+use swc_html_ast::{Comment, DocumentFragment, DocumentMode, Element, Namespace};
+use swc_html_visit::{Visit, VisitWith};
+
+use crate::{option::RemoveUnusedCssOptions, CONDITIONAL_COMMENT_END, CONDITIONAL_COMMENT_START};
+
+/// Names used by elements of a document.
+#[derive(Debug, Default, Clone)]
+pub struct UsedNames {
+    pub tag_names: AHashSet<JsWord>,
+    pub ids: AHashSet<JsWord>,
+    pub classes: AHashSet<JsWord>,
+    pub attributes: AHashSet<JsWord>,
+}
+
+impl UsedNames {
+    /// Collects names from `node` and its descendants, including the contents
+    /// of `template` elements and conditional comments.
+    pub fn collect<N>(node: &N) -> Self
+    where
+        N: VisitWith<UsedNamesCollector>,
+    {
+        let mut collector = UsedNamesCollector::default();
+
+        node.visit_with(&mut collector);
+
+        collector.used_names
+    }
+
+    fn has_tag_name(&self, name: &JsWord) -> bool {
+        self.tag_names.contains(name) || self.tag_names.contains(&name.to_ascii_lowercase())
+    }
+
+    fn has_attribute(&self, name: &JsWord) -> bool {
+        self.attributes.contains(name) || self.attributes.contains(&name.to_ascii_lowercase())
+    }
+}
+
+#[derive(Default)]
+pub struct UsedNamesCollector {
+    used_names: UsedNames,
+}
+
+impl Visit for UsedNamesCollector {
+    fn visit_element(&mut self, n: &Element) {
+        let used_names = &mut self.used_names;
+
+        used_names.tag_names.insert(n.tag_name.clone());
+
+        for attribute in &n.attributes {
+            used_names.attributes.insert(attribute.name.clone());
+
+            let value = match (&attribute.namespace, &attribute.value) {
+                (None, Some(value)) => value,
+                _ => continue,
+            };
+
+            match &*attribute.name {
+                "id" => {
+                    used_names.ids.insert(value.clone());
+                }
+                "class" => {
+                    used_names
+                        .classes
+                        .extend(value.split_ascii_whitespace().map(JsWord::from));
+                }
+                _ => {}
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_comment(&mut self, n: &Comment) {
+        if !CONDITIONAL_COMMENT_START.is_match(&n.data)
+            || !CONDITIONAL_COMMENT_END.is_match(&n.data)
+        {
+            return;
+        }
+
+        let (start, end) = match (n.data.find("]>"), n.data.find("<![")) {
+            (Some(start), Some(end)) if start + 2 <= end => (start + 2, end),
+            _ => return,
+        };
+        let cm = SourceMap::default();
+        let fm = cm.new_source_file(FileName::Anon.into(), n.data[start..end].to_string());
+        let context_element = Element {
+            span: Default::default(),
+            tag_name: "template".into(),
+            namespace: Namespace::HTML,
+            attributes: vec![],
+            children: vec![],
+            content: None,
+            is_self_closing: false,
+        };
+        let document_fragment: Option<DocumentFragment> =
+            swc_html_parser::parse_file_as_document_fragment(
+                &fm,
+                &context_element,
+                DocumentMode::NoQuirks,
+                None,
+                Default::default(),
+                &mut vec![],
+            )
+            .ok();
+
+        if let Some(document_fragment) = document_fragment {
+            document_fragment.visit_with(self);
+        }
+    }
+}
+
+/// Removes selectors which can't match elements using `used_names`, and rules
+/// without any remaining selectors.
+///
+/// Conditional group rules (e.g. `@media`) left empty are removed too, other
+/// at-rules (e.g. `@font-face` or `@keyframes`) are always kept.
+pub fn remove_unused_css(
+    stylesheet: &mut Stylesheet,
+    used_names: &UsedNames,
+    options: &RemoveUnusedCssOptions,
+) {
+    let remover = UnusedCssRemover {
+        used_names,
+        safelist: options.safelist.as_deref().unwrap_or_default(),
+    };
+
+    stylesheet.rules.retain_mut(|rule| match rule {
+        Rule::QualifiedRule(qualified_rule) => remover.retain_qualified_rule(qualified_rule),
+        Rule::AtRule(at_rule) => remover.retain_at_rule(at_rule),
+        _ => true,
+    });
+}
+
+struct UnusedCssRemover<'a> {
+    used_names: &'a UsedNames,
+    safelist: &'a [CachedRegex],
+}
+
+impl UnusedCssRemover<'_> {
+    fn retain_qualified_rule(&self, qualified_rule: &mut QualifiedRule) -> bool {
+        match &mut qualified_rule.prelude {
+            QualifiedRulePrelude::SelectorList(selector_list) => {
+                selector_list
+                    .children
+                    .retain(|selector| self.is_complex_selector_used(selector));
+
+                !selector_list.children.is_empty()
+            }
+            // Nested rules are relative to their parent rule
+            QualifiedRulePrelude::RelativeSelectorList(..)
+            | QualifiedRulePrelude::ListOfComponentValues(..) => true,
+        }
+    }
+
+    fn retain_at_rule(&self, at_rule: &mut AtRule) -> bool {
+        let name = match &at_rule.name {
+            AtRuleName::Ident(ident) => ident.value.to_ascii_lowercase(),
+            AtRuleName::DashedIdent(..) => return true,
+        };
+        let is_conditional_group_rule = match &*name {
+            "media" | "supports" | "container" | "document" | "-moz-document" => true,
+            // Removing an empty `@layer` block would change the order of layers
+            "layer" | "scope" | "starting-style" => false,
+            _ => return true,
+        };
+        let block = match &mut at_rule.block {
+            Some(block) => block,
+            None => return true,
+        };
+
+        let mut has_rules = false;
+
+        block.value.retain_mut(|component_value| {
+            let is_retained = match component_value {
+                ComponentValue::QualifiedRule(qualified_rule) => {
+                    self.retain_qualified_rule(qualified_rule)
+                }
+                ComponentValue::AtRule(at_rule) => self.retain_at_rule(at_rule),
+                _ => return true,
+            };
+
+            has_rules |= is_retained;
+
+            is_retained
+        });
+
+        has_rules || !is_conditional_group_rule
+    }
+
+    fn is_complex_selector_used(&self, complex_selector: &ComplexSelector) -> bool {
+        complex_selector.children.iter().all(|child| match child {
+            ComplexSelectorChildren::CompoundSelector(compound_selector) => {
+                self.is_compound_selector_used(compound_selector)
+            }
+            ComplexSelectorChildren::Combinator(..) => true,
+        })
+    }
+
+    fn is_compound_selector_used(&self, compound_selector: &CompoundSelector) -> bool {
+        if let Some(TypeSelector::TagName(tag_name_selector)) =
+            compound_selector.type_selector.as_deref()
+        {
+            let name = &tag_name_selector.name.value.value;
+
+            if !self.used_names.has_tag_name(name) && !self.is_safelisted(name) {
+                return false;
+            }
+        }
+
+        compound_selector
+            .subclass_selectors
+            .iter()
+            .all(|subclass_selector| self.is_subclass_selector_used(subclass_selector))
+    }
+
+    fn is_subclass_selector_used(&self, subclass_selector: &SubclassSelector) -> bool {
+        match subclass_selector {
+            SubclassSelector::Id(id_selector) => {
+                let name = &id_selector.text.value;
+
+                self.used_names.ids.contains(name) || self.is_safelisted(name)
+            }
+            SubclassSelector::Class(class_selector) => {
+                let name = &class_selector.text.value;
+
+                self.used_names.classes.contains(name) || self.is_safelisted(name)
+            }
+            SubclassSelector::Attribute(attribute_selector) => {
+                let name = &attribute_selector.name.value.value;
+
+                self.used_names.has_attribute(name) || self.is_safelisted(name)
+            }
+            SubclassSelector::PseudoClass(pseudo_class_selector) => {
+                let children = match &pseudo_class_selector.children {
+                    Some(children) => children,
+                    None => return true,
+                };
+
+                match &*pseudo_class_selector.name.value {
+                    "is" | "where" | "matches" | "any" | "has" => children
+                        .iter()
+                        .any(|child| self.is_pseudo_class_argument_used(child)),
+                    // Negations and other pseudo-classes can match without the names used in
+                    // their arguments
+                    _ => true,
+                }
+            }
+            SubclassSelector::PseudoElement(..) | SubclassSelector::ScssPlaceholder(..) => true,
+        }
+    }
+
+    fn is_pseudo_class_argument_used(&self, argument: &PseudoClassSelectorChildren) -> bool {
+        match argument {
+            PseudoClassSelectorChildren::SelectorList(selector_list) => selector_list
+                .children
+                .iter()
+                .any(|selector| self.is_complex_selector_used(selector)),
+            PseudoClassSelectorChildren::ForgivingSelectorList(selector_list) => selector_list
+                .children
+                .iter()
+                .any(|selector| match selector {
+                    ForgivingComplexSelector::ComplexSelector(selector) => {
+                        self.is_complex_selector_used(selector)
+                    }
+                    ForgivingComplexSelector::ListOfComponentValues(..) => false,
+                }),
+            PseudoClassSelectorChildren::RelativeSelectorList(selector_list) => selector_list
+                .children
+                .iter()
+                .any(|selector| self.is_complex_selector_used(&selector.selector)),
+            PseudoClassSelectorChildren::ForgivingRelativeSelectorList(selector_list) => {
+                selector_list
+                    .children
+                    .iter()
+                    .any(|selector| match selector {
+                        ForgivingRelativeSelector::RelativeSelector(selector) => {
+                            self.is_complex_selector_used(&selector.selector)
+                        }
+                        ForgivingRelativeSelector::ListOfComponentValues(..) => false,
+                    })
+            }
+            _ => true,
+        }
+    }
+
+    fn is_safelisted(&self, name: &str) -> bool {
+        self.safelist.iter().any(|regex| regex.is_match(name))
+    }
+}
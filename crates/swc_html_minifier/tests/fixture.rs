@@ -5,12 +5,20 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use swc_css_ast::Stylesheet;
+use swc_css_codegen::writer::basic::{BasicCssWriter, BasicCssWriterConfig};
 use swc_html_ast::{Document, DocumentFragment, DocumentMode, Element, Namespace};
 use swc_html_codegen::{
     writer::basic::{BasicHtmlWriter, BasicHtmlWriterConfig},
     CodeGenerator, CodegenConfig, Emit,
 };
-use swc_html_minifier::{minify_document, minify_document_fragment, option::MinifyOptions};
+use swc_html_minifier::{
+    critical_css::inline_critical_css,
+    minify_document, minify_document_fragment,
+    option::{CriticalCssOptions, MinifyOptions, RemoveUnusedCssOptions, SvgOptions},
+    svg::optimize_svg_document_fragment,
+    unused_css::{remove_unused_css, UsedNames},
+};
 use swc_html_parser::{parse_file_as_document, parse_file_as_document_fragment};
 use testing::NormalizedOutput;
 
@@ -251,3 +259,183 @@ fn test_minify_recovery(input: PathBuf) {
         );
     }
 }
+
+#[testing::fixture("tests/unused_css/**/input.html")]
+fn test_remove_unused_css(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.css");
+
+    testing::run_test(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+
+        let mut errors = vec![];
+        let result: Result<Document, _> =
+            parse_file_as_document(&fm, Default::default(), &mut errors);
+
+        for err in errors {
+            err.to_diagnostics(handler).emit();
+        }
+
+        if handler.has_errors() {
+            return Err(());
+        }
+
+        let document = result.unwrap();
+        let config = match find_config(dir) {
+            Some(config) => serde_json::from_str(&config).unwrap(),
+            None => RemoveUnusedCssOptions::default(),
+        };
+
+        let css_fm = cm.load_file(&dir.join("input.css")).unwrap();
+        let mut css_errors = vec![];
+        let mut stylesheet: Stylesheet =
+            swc_css_parser::parse_file(&css_fm, None, Default::default(), &mut css_errors).unwrap();
+
+        for err in css_errors {
+            err.to_diagnostics(handler).emit();
+        }
+
+        if handler.has_errors() {
+            return Err(());
+        }
+
+        let used_names = UsedNames::collect(&document);
+
+        remove_unused_css(&mut stylesheet, &used_names, &config);
+
+        let mut css_str = String::new();
+        {
+            let wr = BasicCssWriter::new(&mut css_str, None, BasicCssWriterConfig::default());
+            let mut gen = swc_css_codegen::CodeGenerator::new(wr, Default::default());
+
+            swc_css_codegen::Emit::emit(&mut gen, &stylesheet).unwrap();
+        }
+
+        NormalizedOutput::new_raw(css_str)
+            .compare_to_file(&output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[testing::fixture("tests/critical_css/**/input.html")]
+fn test_inline_critical_css(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.html");
+
+    testing::run_test(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+
+        let mut errors = vec![];
+        let result: Result<Document, _> =
+            parse_file_as_document(&fm, Default::default(), &mut errors);
+
+        for err in errors {
+            err.to_diagnostics(handler).emit();
+        }
+
+        if handler.has_errors() {
+            return Err(());
+        }
+
+        let mut document = result.unwrap();
+        let config = match find_config(dir) {
+            Some(config) => serde_json::from_str(&config).unwrap(),
+            None => CriticalCssOptions::default(),
+        };
+
+        inline_critical_css(&mut document, &config, |href| {
+            read_to_string(dir.join(href)).ok()
+        });
+
+        let mut html_str = String::new();
+        {
+            let wr = BasicHtmlWriter::new(&mut html_str, None, BasicHtmlWriterConfig::default());
+            let mut gen = CodeGenerator::new(
+                wr,
+                CodegenConfig {
+                    scripting_enabled: false,
+                    ..Default::default()
+                },
+            );
+
+            gen.emit(&document).unwrap();
+        }
+
+        NormalizedOutput::new_raw(html_str)
+            .compare_to_file(&output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[testing::fixture("tests/svg/**/input.svg")]
+fn test_optimize_svg(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let output = dir.join("output.min.svg");
+
+    testing::run_test(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+        let context_element = Element {
+            span: Default::default(),
+            tag_name: "svg".into(),
+            namespace: Namespace::SVG,
+            attributes: vec![],
+            children: vec![],
+            content: None,
+            is_self_closing: false,
+        };
+
+        let mut errors = vec![];
+        let result: Result<DocumentFragment, _> = parse_file_as_document_fragment(
+            &fm,
+            &context_element,
+            DocumentMode::NoQuirks,
+            None,
+            Default::default(),
+            &mut errors,
+        );
+
+        for err in errors {
+            err.to_diagnostics(handler).emit();
+        }
+
+        if handler.has_errors() {
+            return Err(());
+        }
+
+        let mut document_fragment = result.unwrap();
+        let config = match find_config(dir) {
+            Some(config) => serde_json::from_str(&config).unwrap(),
+            None => SvgOptions::default(),
+        };
+
+        optimize_svg_document_fragment(&mut document_fragment, &config);
+
+        let mut svg_str = String::new();
+        {
+            let wr = BasicHtmlWriter::new(&mut svg_str, None, BasicHtmlWriterConfig::default());
+            let mut gen = CodeGenerator::new(
+                wr,
+                CodegenConfig {
+                    scripting_enabled: false,
+                    context_element: Some(&context_element),
+                    ..Default::default()
+                },
+            );
+
+            gen.emit(&document_fragment).unwrap();
+        }
+
+        NormalizedOutput::new_raw(svg_str)
+            .compare_to_file(&output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
@@ -0,0 +1,136 @@
+use std::{fmt::Write, path::PathBuf};
+
+use sourcemap::SourceMap;
+use swc_common::{BytePos, FileName, LineCol};
+use swc_html_ast::Document;
+use swc_html_codegen::{
+    writer::basic::{BasicHtmlWriter, BasicHtmlWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_html_minifier::{minify_document_with_source_maps, option::MinifyOptions};
+use swc_html_parser::parse_file_as_document;
+use testing::NormalizedOutput;
+
+/// Minifies the document and prints the original position of each mapped
+/// output segment, including segments of minified `script` and `style`
+/// elements.
+fn minify_with_mappings(cm: &swc_common::SourceMap, document: &mut Document) -> String {
+    let source_maps = minify_document_with_source_maps(document, &MinifyOptions::default());
+
+    let mut html_str = String::new();
+    let mut src_map_buf: Vec<(BytePos, LineCol)> = vec![];
+
+    {
+        let wr = BasicHtmlWriter::new(
+            &mut html_str,
+            Some(&mut src_map_buf),
+            BasicHtmlWriterConfig::default(),
+        );
+        let mut gen = CodeGenerator::new(
+            wr,
+            CodegenConfig {
+                minify: true,
+                embedded_source_maps: Some(&source_maps),
+                ..Default::default()
+            },
+        );
+
+        gen.emit(&*document).unwrap();
+    }
+
+    let source_map = cm.build_source_map(&src_map_buf);
+
+    print_mappings(&html_str, &source_map)
+}
+
+#[testing::fixture("tests/sourcemap/**/input.html")]
+fn sourcemap(input: PathBuf) {
+    let output = input.parent().unwrap().join("output.mappings");
+
+    testing::run_test2(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+
+        let mut errors = vec![];
+        let mut document = parse_file_as_document(&fm, Default::default(), &mut errors).unwrap();
+
+        for err in errors {
+            err.to_diagnostics(&handler).emit();
+        }
+
+        NormalizedOutput::from(minify_with_mappings(&cm, &mut document))
+            .compare_to_file(&output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn crlf() {
+    testing::run_test2(false, |cm, _| {
+        let fm = cm.new_source_file(
+            FileName::Anon.into(),
+            "<script>\r\nvar a = 1;\r\n\r\nfunction f() {\r\n  return a;\r\n}\r\n</script>".into(),
+        );
+
+        let mut errors = vec![];
+        let mut document = parse_file_as_document(&fm, Default::default(), &mut errors).unwrap();
+        let mappings = minify_with_mappings(&cm, &mut document);
+
+        assert!(
+            mappings.contains(" -> 4:0\n"),
+            "unexpected mappings:\n{}",
+            mappings
+        );
+        assert!(
+            mappings.contains(" -> 5:2\n"),
+            "unexpected mappings:\n{}",
+            mappings
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Prints the code and the original position of each mapped output segment.
+fn print_mappings(code: &str, map: &SourceMap) -> String {
+    let lines = code.lines().collect::<Vec<_>>();
+    let tokens = map.tokens().collect::<Vec<_>>();
+
+    let mut buf = String::new();
+
+    writeln!(buf, "{}\n", code).unwrap();
+
+    for (idx, token) in tokens.iter().enumerate() {
+        let line = lines
+            .get(token.get_dst_line() as usize)
+            .copied()
+            .unwrap_or_default();
+        let start = token.get_dst_col() as usize;
+        let end = tokens
+            .get(idx + 1)
+            .filter(|next| next.get_dst_line() == token.get_dst_line())
+            .map(|next| next.get_dst_col() as usize)
+            .unwrap_or_else(|| line.chars().count());
+        let segment = line
+            .chars()
+            .skip(start)
+            .take(end.saturating_sub(start))
+            .collect::<String>();
+
+        writeln!(
+            buf,
+            "{:?} {}:{} -> {}:{}",
+            segment,
+            token.get_dst_line() + 1,
+            token.get_dst_col(),
+            token.get_src_line() + 1,
+            token.get_src_col(),
+        )
+        .unwrap();
+    }
+
+    buf
+}
@@ -0,0 +1,216 @@
+#![deny(clippy::all)]
+
+//! Querying elements of [swc_html_ast] trees with CSS selectors, like
+//! `querySelector` and `querySelectorAll` of the DOM.
+//!
+//! Selectors are parsed by [swc_css_parser], so a [SelectorList] created in
+//! any other way can be used too.
+//!
+//! Pseudo-classes depending on the user interaction, the layout or the
+//! browsing context (e.g. `:hover`, `:visited`) and pseudo-elements never
+//! match.
+
+use swc_atoms::JsWord;
+use swc_common::{collections::AHashMap, input::StringInput, BytePos, Span};
+use swc_css_ast::SelectorList;
+use swc_css_parser::{
+    error::{Error, ErrorKind},
+    lexer::Lexer,
+    parser::Parser,
+    Parse,
+};
+use swc_html_ast::{Child, Document, DocumentFragment, DocumentMode, Element, Namespace};
+
+use self::matcher::{Frame, Matcher};
+
+mod matcher;
+
+#[derive(Debug, Clone, Default)]
+pub struct QueryConfig {
+    /// Namespace prefixes which can be used in selectors, e.g. `svg` for
+    /// `svg|a`, like `@namespace` rules with a prefix.
+    pub namespaces: AHashMap<JsWord, Namespace>,
+    /// Namespace of type selectors and universal selectors without a prefix,
+    /// like the `@namespace` rule without a prefix. By default elements in any
+    /// namespace are matched.
+    pub default_namespace: Option<Namespace>,
+}
+
+/// Node whose descendants are queried.
+///
+/// The node itself is never included in the results, but selectors are
+/// matched in its context, i.e. it can be matched by the compound selectors
+/// on the left of a combinator and by `:scope`.
+#[derive(Debug, Clone, Copy)]
+pub enum QueryRoot<'a> {
+    Document(&'a Document),
+    DocumentFragment(&'a DocumentFragment),
+    Element(&'a Element),
+}
+
+impl<'a> From<&'a Document> for QueryRoot<'a> {
+    fn from(document: &'a Document) -> Self {
+        QueryRoot::Document(document)
+    }
+}
+
+impl<'a> From<&'a DocumentFragment> for QueryRoot<'a> {
+    fn from(document_fragment: &'a DocumentFragment) -> Self {
+        QueryRoot::DocumentFragment(document_fragment)
+    }
+}
+
+impl<'a> From<&'a Element> for QueryRoot<'a> {
+    fn from(element: &'a Element) -> Self {
+        QueryRoot::Element(element)
+    }
+}
+
+/// Parses a selector list, e.g. `link[rel=preload], script[type=module]`.
+pub fn parse_selector_list(selector: &str) -> Result<SelectorList, Error> {
+    let end = BytePos(selector.len() as u32);
+    let input = StringInput::new(selector, BytePos(0), end);
+    let config = Default::default();
+    let lexer = Lexer::new(input, None, config);
+    let mut parser = Parser::new(lexer, config);
+
+    let selector_list: SelectorList = parser.parse()?;
+
+    if let Some(err) = parser.take_errors().into_iter().next() {
+        return Err(err);
+    }
+
+    // The parser stops at the first token which can't continue the list
+    let trimmed_end = BytePos(selector.trim_end().len() as u32);
+
+    if selector_list.span.hi < trimmed_end {
+        return Err(Error::new(
+            Span::new(selector_list.span.hi, end),
+            ErrorKind::Expected("',' or the end of the selector"),
+        ));
+    }
+
+    Ok(selector_list)
+}
+
+/// Returns the first descendant of `root` in tree order matching `selectors`.
+pub fn query_selector<'a>(
+    root: impl Into<QueryRoot<'a>>,
+    selectors: &SelectorList,
+    config: &QueryConfig,
+) -> Option<&'a Element> {
+    let mut result = None;
+
+    walk_root(root.into(), selectors, config, &mut |element| {
+        result = Some(element);
+
+        false
+    });
+
+    result
+}
+
+/// Returns all descendants of `root` in tree order matching `selectors`.
+pub fn query_selector_all<'a>(
+    root: impl Into<QueryRoot<'a>>,
+    selectors: &SelectorList,
+    config: &QueryConfig,
+) -> Vec<&'a Element> {
+    let mut result = vec![];
+
+    walk_root(root.into(), selectors, config, &mut |element| {
+        result.push(element);
+
+        true
+    });
+
+    result
+}
+
+/// Returns `true` if the last element of `path` matches `selectors`, where
+/// `path` contains the element and all of its ancestors, outermost first.
+///
+/// It is useful when the tree is not available, e.g. for streaming. Siblings
+/// of the elements are unknown, so sibling combinators and pseudo-classes
+/// depending on siblings (e.g. `:first-child`) never match.
+pub fn matches_path(path: &[&Element], selectors: &SelectorList, config: &QueryConfig) -> bool {
+    let matcher = Matcher::new(config, false, true, None);
+    let path = path
+        .iter()
+        .map(|element| Frame {
+            element,
+            siblings: None,
+            index: 0,
+        })
+        .collect::<Vec<_>>();
+
+    matcher.matches_selector_list(selectors, &path)
+}
+
+/// Calls `on_match` for each matching descendant until it returns `false`.
+fn walk_root<'a>(
+    root: QueryRoot<'a>,
+    selectors: &SelectorList,
+    config: &QueryConfig,
+    on_match: &mut dyn FnMut(&'a Element) -> bool,
+) {
+    let (children, mode, scope) = match root {
+        QueryRoot::Document(document) => (&*document.children, document.mode, None),
+        QueryRoot::DocumentFragment(document_fragment) => {
+            (&*document_fragment.children, DocumentMode::NoQuirks, None)
+        }
+        QueryRoot::Element(element) => (&*element.children, DocumentMode::NoQuirks, Some(element)),
+    };
+    let matcher = Matcher::new(
+        config,
+        mode == DocumentMode::Quirks,
+        matches!(root, QueryRoot::Document(..)),
+        scope,
+    );
+    let mut path = vec![];
+
+    if let Some(scope) = scope {
+        path.push(Frame {
+            element: scope,
+            siblings: None,
+            index: 0,
+        });
+    }
+
+    walk(children, &mut path, &mut |path| {
+        if matcher.matches_selector_list(selectors, path) {
+            on_match(path.last().unwrap().element)
+        } else {
+            true
+        }
+    });
+}
+
+/// Visits elements in tree order, stops when `f` returns `false`.
+///
+/// Like the DOM, contents of `<template>` are not visited.
+pub(crate) fn walk<'a>(
+    children: &'a [Child],
+    path: &mut Vec<Frame<'a>>,
+    f: &mut dyn FnMut(&[Frame<'a>]) -> bool,
+) -> bool {
+    for (index, child) in children.iter().enumerate() {
+        if let Child::Element(element) = child {
+            path.push(Frame {
+                element,
+                siblings: Some(children),
+                index,
+            });
+
+            let should_continue = f(path) && walk(&element.children, path, f);
+
+            path.pop();
+
+            if !should_continue {
+                return false;
+            }
+        }
+    }
+
+    true
+}
@@ -0,0 +1,758 @@
+use std::ptr;
+
+use swc_css_ast::{
+    AnPlusB, AttributeSelector, AttributeSelectorMatcherValue, AttributeSelectorValue,
+    CombinatorValue, ComplexSelector, ComplexSelectorChildren, CompoundSelector,
+    ForgivingComplexSelector, ForgivingRelativeSelector, NamespacePrefix, PseudoClassSelector,
+    PseudoClassSelectorChildren, RelativeSelector, SelectorList, SubclassSelector, TypeSelector,
+};
+use swc_html_ast::{Attribute, Child, Element, Namespace};
+
+use crate::{walk, QueryConfig};
+
+/// Element and its position among the children of its parent.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Frame<'a> {
+    pub element: &'a Element,
+    /// [None] for the root of a query on an element, because its parent is
+    /// unknown.
+    pub siblings: Option<&'a [Child]>,
+    pub index: usize,
+}
+
+impl<'a> Frame<'a> {
+    fn previous_siblings(self) -> impl Iterator<Item = Frame<'a>> {
+        let siblings = self.siblings.unwrap_or_default();
+
+        siblings[..self.index.min(siblings.len())]
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(move |(index, child)| match child {
+                Child::Element(element) => Some(Frame {
+                    element,
+                    siblings: Some(siblings),
+                    index,
+                }),
+                _ => None,
+            })
+    }
+
+    fn next_siblings(self) -> impl Iterator<Item = Frame<'a>> {
+        let siblings = self.siblings.unwrap_or_default();
+
+        siblings
+            .iter()
+            .enumerate()
+            .skip(self.index + 1)
+            .filter_map(move |(index, child)| match child {
+                Child::Element(element) => Some(Frame {
+                    element,
+                    siblings: Some(siblings),
+                    index,
+                }),
+                _ => None,
+            })
+    }
+}
+
+/// Relation required between the element matched by the leftmost compound
+/// selector of a relative selector and the element having `:has()`.
+type Anchor<'a> = Option<(CombinatorValue, &'a Element)>;
+
+pub(crate) struct Matcher<'a> {
+    config: &'a QueryConfig,
+    is_quirks: bool,
+    is_document: bool,
+    scope: Option<&'a Element>,
+}
+
+impl<'a> Matcher<'a> {
+    pub fn new(
+        config: &'a QueryConfig,
+        is_quirks: bool,
+        is_document: bool,
+        scope: Option<&'a Element>,
+    ) -> Self {
+        Matcher {
+            config,
+            is_quirks,
+            is_document,
+            scope,
+        }
+    }
+
+    /// `path` contains the element to match and all of its ancestors known to
+    /// the query, outermost first.
+    pub fn matches_selector_list(&self, selector_list: &SelectorList, path: &[Frame]) -> bool {
+        match path.split_last() {
+            Some((current, parents)) => self.matches_any(selector_list, parents, *current),
+            None => false,
+        }
+    }
+
+    fn matches_any(&self, selector_list: &SelectorList, parents: &[Frame], current: Frame) -> bool {
+        selector_list
+            .children
+            .iter()
+            .any(|selector| self.matches_complex_selector(selector, parents, current, None))
+    }
+
+    fn matches_complex_selector(
+        &self,
+        selector: &ComplexSelector,
+        parents: &[Frame],
+        current: Frame,
+        anchor: Anchor,
+    ) -> bool {
+        self.matches_complex_selector_children(&selector.children, parents, current, anchor)
+    }
+
+    /// Matches compound selectors from right to left, trying every candidate
+    /// for descendant and later sibling combinators.
+    fn matches_complex_selector_children(
+        &self,
+        children: &[ComplexSelectorChildren],
+        parents: &[Frame],
+        current: Frame,
+        anchor: Anchor,
+    ) -> bool {
+        let (compound_selector, rest) = match children.split_last() {
+            Some((ComplexSelectorChildren::CompoundSelector(compound_selector), rest)) => {
+                (compound_selector, rest)
+            }
+            _ => return false,
+        };
+
+        if !self.matches_compound_selector(compound_selector, parents, current) {
+            return false;
+        }
+
+        let (combinator, rest) = match rest.split_last() {
+            Some((ComplexSelectorChildren::Combinator(combinator), rest)) => {
+                (combinator.value, rest)
+            }
+            Some(_) => return false,
+            None => {
+                return match anchor {
+                    Some((combinator, anchor)) => is_related(combinator, anchor, parents, current),
+                    None => true,
+                }
+            }
+        };
+
+        match combinator {
+            CombinatorValue::Descendant => (0..parents.len()).rev().any(|index| {
+                self.matches_complex_selector_children(
+                    rest,
+                    &parents[..index],
+                    parents[index],
+                    anchor,
+                )
+            }),
+            CombinatorValue::Child => match parents.split_last() {
+                Some((parent, grandparents)) => {
+                    self.matches_complex_selector_children(rest, grandparents, *parent, anchor)
+                }
+                None => false,
+            },
+            CombinatorValue::NextSibling => match current.previous_siblings().next() {
+                Some(sibling) => {
+                    self.matches_complex_selector_children(rest, parents, sibling, anchor)
+                }
+                None => false,
+            },
+            CombinatorValue::LaterSibling => current.previous_siblings().any(|sibling| {
+                self.matches_complex_selector_children(rest, parents, sibling, anchor)
+            }),
+            // Tables are not laid out, so there are no columns
+            CombinatorValue::Column => false,
+        }
+    }
+
+    fn matches_compound_selector(
+        &self,
+        compound_selector: &CompoundSelector,
+        parents: &[Frame],
+        current: Frame,
+    ) -> bool {
+        // Outside of nested style rules `&` is the same as `:scope`
+        if compound_selector.nesting_selector.is_some() && !self.is_scope(parents, current) {
+            return false;
+        }
+
+        if let Some(type_selector) = &compound_selector.type_selector {
+            if !self.matches_type_selector(type_selector, current.element) {
+                return false;
+            }
+        }
+
+        compound_selector
+            .subclass_selectors
+            .iter()
+            .all(|subclass_selector| {
+                self.matches_subclass_selector(subclass_selector, parents, current)
+            })
+    }
+
+    fn matches_type_selector(&self, type_selector: &TypeSelector, element: &Element) -> bool {
+        match type_selector {
+            TypeSelector::TagName(tag_name_selector) => {
+                let name = &tag_name_selector.name;
+
+                self.matches_element_namespace(name.prefix.as_ref(), element)
+                    && if element.namespace == Namespace::HTML {
+                        name.value.value.eq_ignore_ascii_case(&element.tag_name)
+                    } else {
+                        name.value.value == element.tag_name
+                    }
+            }
+            TypeSelector::Universal(universal_selector) => {
+                self.matches_element_namespace(universal_selector.prefix.as_ref(), element)
+            }
+        }
+    }
+
+    fn matches_element_namespace(
+        &self,
+        prefix: Option<&NamespacePrefix>,
+        element: &Element,
+    ) -> bool {
+        match prefix {
+            // `a`
+            None => match self.config.default_namespace {
+                Some(namespace) => element.namespace == namespace,
+                None => true,
+            },
+            Some(prefix) => match &prefix.namespace {
+                // `|a`, elements always have a namespace in HTML documents
+                None => false,
+                // `*|a`
+                Some(swc_css_ast::Namespace::Any(..)) => true,
+                // `svg|a`
+                Some(swc_css_ast::Namespace::Named(named)) => {
+                    self.config.namespaces.get(&named.name.value) == Some(&element.namespace)
+                }
+            },
+        }
+    }
+
+    fn matches_subclass_selector(
+        &self,
+        subclass_selector: &SubclassSelector,
+        parents: &[Frame],
+        current: Frame,
+    ) -> bool {
+        let element = current.element;
+
+        match subclass_selector {
+            SubclassSelector::Id(id_selector) => match get_attribute_value(element, "id") {
+                Some(id) => self.eq_in_quirks_mode(&id_selector.text.value, id),
+                None => false,
+            },
+            SubclassSelector::Class(class_selector) => {
+                match get_attribute_value(element, "class") {
+                    Some(class) => class
+                        .split_ascii_whitespace()
+                        .any(|class| self.eq_in_quirks_mode(&class_selector.text.value, class)),
+                    None => false,
+                }
+            }
+            SubclassSelector::Attribute(attribute_selector) => {
+                self.matches_attribute_selector(attribute_selector, element)
+            }
+            SubclassSelector::PseudoClass(pseudo_class_selector) => {
+                self.matches_pseudo_class_selector(pseudo_class_selector, parents, current)
+            }
+            // Pseudo-elements are not elements of the tree
+            SubclassSelector::PseudoElement(..) => false,
+            SubclassSelector::ScssPlaceholder(..) => false,
+        }
+    }
+
+    /// Ids and classes are ASCII case-insensitive in quirks mode.
+    fn eq_in_quirks_mode(&self, a: &str, b: &str) -> bool {
+        if self.is_quirks {
+            a.eq_ignore_ascii_case(b)
+        } else {
+            a == b
+        }
+    }
+
+    fn matches_attribute_selector(
+        &self,
+        attribute_selector: &AttributeSelector,
+        element: &Element,
+    ) -> bool {
+        let is_html = element.namespace == Namespace::HTML;
+        let name = &attribute_selector.name;
+
+        element.attributes.iter().any(|attribute| {
+            let is_same_name = if is_html {
+                name.value.value.eq_ignore_ascii_case(&attribute.name)
+            } else {
+                name.value.value == attribute.name
+            };
+
+            if !is_same_name || !self.matches_attribute_namespace(name.prefix.as_ref(), attribute) {
+                return false;
+            }
+
+            let (matcher, value) = match (&attribute_selector.matcher, &attribute_selector.value) {
+                (Some(matcher), Some(value)) => (matcher.value, value),
+                _ => return true,
+            };
+            let value = match value {
+                AttributeSelectorValue::Str(value) => &*value.value,
+                AttributeSelectorValue::Ident(value) => &*value.value,
+            };
+            let is_case_insensitive = match &attribute_selector.modifier {
+                Some(modifier) => modifier.value.value.eq_ignore_ascii_case("i"),
+                None => is_html && is_case_insensitive_attribute(&attribute.name),
+            };
+
+            matches_attribute_value(
+                matcher,
+                attribute.value.as_deref().unwrap_or_default(),
+                value,
+                is_case_insensitive,
+            )
+        })
+    }
+
+    fn matches_attribute_namespace(
+        &self,
+        prefix: Option<&NamespacePrefix>,
+        attribute: &Attribute,
+    ) -> bool {
+        match prefix.and_then(|prefix| prefix.namespace.as_ref()) {
+            // `[a]` and `[|a]`, the default namespace does not apply to attributes
+            None => attribute.namespace.is_none(),
+            // `[*|a]`
+            Some(swc_css_ast::Namespace::Any(..)) => true,
+            // `[xlink|a]`
+            Some(swc_css_ast::Namespace::Named(named)) => {
+                attribute.namespace.is_some()
+                    && self.config.namespaces.get(&named.name.value) == attribute.namespace.as_ref()
+            }
+        }
+    }
+
+    fn matches_pseudo_class_selector(
+        &self,
+        pseudo_class_selector: &PseudoClassSelector,
+        parents: &[Frame],
+        current: Frame,
+    ) -> bool {
+        let element = current.element;
+        let children = pseudo_class_selector
+            .children
+            .as_deref()
+            .unwrap_or_default();
+
+        match &*pseudo_class_selector.name.value {
+            "not" => match children.first() {
+                Some(PseudoClassSelectorChildren::SelectorList(selector_list)) => {
+                    !self.matches_any(selector_list, parents, current)
+                }
+                _ => false,
+            },
+            "is" | "where" | "matches" | "any" => match children.first() {
+                Some(PseudoClassSelectorChildren::SelectorList(selector_list)) => {
+                    self.matches_any(selector_list, parents, current)
+                }
+                Some(PseudoClassSelectorChildren::ForgivingSelectorList(selector_list)) => {
+                    selector_list
+                        .children
+                        .iter()
+                        .any(|selector| match selector {
+                            ForgivingComplexSelector::ComplexSelector(selector) => {
+                                self.matches_complex_selector(selector, parents, current, None)
+                            }
+                            // Invalid selectors are ignored
+                            ForgivingComplexSelector::ListOfComponentValues(..) => false,
+                        })
+                }
+                _ => false,
+            },
+            "has" => {
+                let selectors: Vec<&RelativeSelector> = match children.first() {
+                    Some(PseudoClassSelectorChildren::RelativeSelectorList(selector_list)) => {
+                        selector_list.children.iter().collect()
+                    }
+                    Some(PseudoClassSelectorChildren::ForgivingRelativeSelectorList(
+                        selector_list,
+                    )) => selector_list
+                        .children
+                        .iter()
+                        .filter_map(|selector| match selector {
+                            ForgivingRelativeSelector::RelativeSelector(selector) => Some(selector),
+                            ForgivingRelativeSelector::ListOfComponentValues(..) => None,
+                        })
+                        .collect(),
+                    _ => return false,
+                };
+
+                selectors
+                    .iter()
+                    .any(|selector| self.matches_relative_selector(selector, parents, current))
+            }
+            "nth-child" | "nth-last-child" | "nth-of-type" | "nth-last-of-type" => {
+                let an_plus_b = match children.first() {
+                    Some(PseudoClassSelectorChildren::AnPlusB(an_plus_b)) => an_plus_b,
+                    _ => return false,
+                };
+                let of_selector_list = match children.get(2) {
+                    Some(PseudoClassSelectorChildren::SelectorList(selector_list)) => {
+                        Some(selector_list)
+                    }
+                    _ => None,
+                };
+                let name = &*pseudo_class_selector.name.value;
+                let is_of_type = name.ends_with("of-type");
+
+                if let Some(selector_list) = of_selector_list {
+                    if is_of_type || !self.matches_any(selector_list, parents, current) {
+                        return false;
+                    }
+                }
+
+                let is_counted = |sibling: &Frame| {
+                    if is_of_type {
+                        is_same_type(sibling.element, element)
+                    } else if let Some(selector_list) = of_selector_list {
+                        self.matches_any(selector_list, parents, *sibling)
+                    } else {
+                        true
+                    }
+                };
+                let position = match current.siblings {
+                    Some(..) if name.starts_with("nth-last") => {
+                        current.next_siblings().filter(is_counted).count() + 1
+                    }
+                    Some(..) => current.previous_siblings().filter(is_counted).count() + 1,
+                    None => return false,
+                };
+
+                matches_an_plus_b(an_plus_b, position as i32)
+            }
+            "first-child" => {
+                current.siblings.is_some() && current.previous_siblings().next().is_none()
+            }
+            "last-child" => current.siblings.is_some() && current.next_siblings().next().is_none(),
+            "only-child" => {
+                current.siblings.is_some()
+                    && current.previous_siblings().next().is_none()
+                    && current.next_siblings().next().is_none()
+            }
+            "first-of-type" => {
+                current.siblings.is_some()
+                    && !current
+                        .previous_siblings()
+                        .any(|sibling| is_same_type(sibling.element, element))
+            }
+            "last-of-type" => {
+                current.siblings.is_some()
+                    && !current
+                        .next_siblings()
+                        .any(|sibling| is_same_type(sibling.element, element))
+            }
+            "only-of-type" => {
+                current.siblings.is_some()
+                    && !current
+                        .previous_siblings()
+                        .chain(current.next_siblings())
+                        .any(|sibling| is_same_type(sibling.element, element))
+            }
+            "root" => self.is_root(parents),
+            "scope" => self.is_scope(parents, current),
+            "empty" => element
+                .children
+                .iter()
+                .all(|child| matches!(child, Child::Comment(..))),
+            // Elements are in their initial state, links are never visited
+            "link" | "any-link" => {
+                element.namespace == Namespace::HTML
+                    && matches!(&*element.tag_name, "a" | "area")
+                    && get_attribute_value(element, "href").is_some()
+            }
+            "checked" => {
+                element.namespace == Namespace::HTML
+                    && match &*element.tag_name {
+                        "input" => {
+                            matches!(
+                                get_attribute_value(element, "type")
+                                    .map(|value| value.to_ascii_lowercase())
+                                    .as_deref(),
+                                Some("checkbox" | "radio")
+                            ) && get_attribute_value(element, "checked").is_some()
+                        }
+                        "option" => get_attribute_value(element, "selected").is_some(),
+                        _ => false,
+                    }
+            }
+            "disabled" => is_form_element(element) && is_disabled(parents, current),
+            "enabled" => is_form_element(element) && !is_disabled(parents, current),
+            "required" => {
+                is_input_element(element) && get_attribute_value(element, "required").is_some()
+            }
+            "optional" => {
+                is_input_element(element) && get_attribute_value(element, "required").is_none()
+            }
+            _ => false,
+        }
+    }
+
+    /// Matches `selector` against the descendants (or the later siblings and
+    /// their descendants) of `current`, which is the anchor of the selector.
+    fn matches_relative_selector(
+        &self,
+        selector: &RelativeSelector,
+        parents: &[Frame],
+        current: Frame,
+    ) -> bool {
+        let combinator = selector
+            .combinator
+            .as_ref()
+            .map(|combinator| combinator.value)
+            .unwrap_or(CombinatorValue::Descendant);
+        let anchor = Some((combinator, current.element));
+        let mut is_found = false;
+        let mut check = |path: &[Frame]| {
+            let (candidate, candidate_parents) = path.split_last().unwrap();
+
+            is_found = self.matches_complex_selector(
+                &selector.selector,
+                candidate_parents,
+                *candidate,
+                anchor,
+            );
+
+            !is_found
+        };
+        let mut path = parents.to_vec();
+
+        match combinator {
+            CombinatorValue::Descendant | CombinatorValue::Child => {
+                path.push(current);
+
+                walk(&current.element.children, &mut path, &mut check);
+            }
+            CombinatorValue::NextSibling | CombinatorValue::LaterSibling => {
+                if let Some(siblings) = current.siblings {
+                    walk(siblings, &mut path, &mut check);
+                }
+            }
+            CombinatorValue::Column => {}
+        }
+
+        is_found
+    }
+
+    fn is_root(&self, parents: &[Frame]) -> bool {
+        self.is_document && parents.is_empty()
+    }
+
+    /// Without a scoping element, `:scope` is the same as `:root`.
+    fn is_scope(&self, parents: &[Frame], current: Frame) -> bool {
+        match self.scope {
+            Some(scope) => ptr::eq(scope, current.element),
+            None => self.is_root(parents),
+        }
+    }
+}
+
+fn is_related(
+    combinator: CombinatorValue,
+    anchor: &Element,
+    parents: &[Frame],
+    current: Frame,
+) -> bool {
+    match combinator {
+        CombinatorValue::Descendant => parents.iter().any(|parent| ptr::eq(parent.element, anchor)),
+        CombinatorValue::Child => {
+            matches!(parents.last(), Some(parent) if ptr::eq(parent.element, anchor))
+        }
+        CombinatorValue::NextSibling => matches!(
+            current.previous_siblings().next(),
+            Some(sibling) if ptr::eq(sibling.element, anchor)
+        ),
+        CombinatorValue::LaterSibling => current
+            .previous_siblings()
+            .any(|sibling| ptr::eq(sibling.element, anchor)),
+        CombinatorValue::Column => false,
+    }
+}
+
+fn get_attribute_value<'a>(element: &'a Element, name: &str) -> Option<&'a str> {
+    element
+        .attributes
+        .iter()
+        .find(|attribute| attribute.namespace.is_none() && attribute.name == *name)
+        .map(|attribute| attribute.value.as_deref().unwrap_or_default())
+}
+
+fn is_same_type(a: &Element, b: &Element) -> bool {
+    a.namespace == b.namespace && a.tag_name == b.tag_name
+}
+
+fn matches_an_plus_b(an_plus_b: &AnPlusB, position: i32) -> bool {
+    let (a, b) = match an_plus_b {
+        AnPlusB::Ident(ident) if ident.value.eq_ignore_ascii_case("odd") => (2, 1),
+        AnPlusB::Ident(ident) if ident.value.eq_ignore_ascii_case("even") => (2, 0),
+        AnPlusB::Ident(..) => return false,
+        AnPlusB::AnPlusBNotation(notation) => (
+            notation.a.unwrap_or_default(),
+            notation.b.unwrap_or_default(),
+        ),
+    };
+
+    // Is there an `n >= 0` with `a * n + b == position`?
+    if a == 0 {
+        position == b
+    } else {
+        let diff = position - b;
+
+        diff % a == 0 && diff / a >= 0
+    }
+}
+
+fn matches_attribute_value(
+    matcher: AttributeSelectorMatcherValue,
+    actual: &str,
+    expected: &str,
+    is_case_insensitive: bool,
+) -> bool {
+    let (actual, expected) = if is_case_insensitive {
+        (actual.to_ascii_lowercase(), expected.to_ascii_lowercase())
+    } else {
+        (actual.to_string(), expected.to_string())
+    };
+
+    match matcher {
+        AttributeSelectorMatcherValue::Equals => actual == expected,
+        AttributeSelectorMatcherValue::Tilde => {
+            !expected.is_empty()
+                && !expected.contains(|c: char| c.is_ascii_whitespace())
+                && actual.split_ascii_whitespace().any(|word| word == expected)
+        }
+        AttributeSelectorMatcherValue::Bar => {
+            actual == expected
+                || (actual.starts_with(&*expected) && actual[expected.len()..].starts_with('-'))
+        }
+        AttributeSelectorMatcherValue::Caret => {
+            !expected.is_empty() && actual.starts_with(&*expected)
+        }
+        AttributeSelectorMatcherValue::Dollar => {
+            !expected.is_empty() && actual.ends_with(&*expected)
+        }
+        AttributeSelectorMatcherValue::Asterisk => {
+            !expected.is_empty() && actual.contains(&*expected)
+        }
+    }
+}
+
+/// Attributes of HTML elements whose values are matched case-insensitively
+/// in HTML documents, see https://html.spec.whatwg.org/multipage/semantics-other.html#case-sensitivity-of-selectors
+fn is_case_insensitive_attribute(name: &str) -> bool {
+    matches!(
+        name,
+        "accept"
+            | "accept-charset"
+            | "align"
+            | "alink"
+            | "axis"
+            | "bgcolor"
+            | "charset"
+            | "checked"
+            | "clear"
+            | "codetype"
+            | "color"
+            | "compact"
+            | "declare"
+            | "defer"
+            | "dir"
+            | "direction"
+            | "disabled"
+            | "enctype"
+            | "face"
+            | "frame"
+            | "hreflang"
+            | "http-equiv"
+            | "lang"
+            | "language"
+            | "link"
+            | "media"
+            | "method"
+            | "multiple"
+            | "nohref"
+            | "noresize"
+            | "noshade"
+            | "nowrap"
+            | "readonly"
+            | "rel"
+            | "rev"
+            | "rules"
+            | "scope"
+            | "scrolling"
+            | "selected"
+            | "shape"
+            | "target"
+            | "text"
+            | "type"
+            | "valign"
+            | "valuetype"
+            | "vlink"
+    )
+}
+
+fn is_form_element(element: &Element) -> bool {
+    element.namespace == Namespace::HTML
+        && matches!(
+            &*element.tag_name,
+            "button" | "input" | "select" | "textarea" | "optgroup" | "option" | "fieldset"
+        )
+}
+
+fn is_input_element(element: &Element) -> bool {
+    element.namespace == Namespace::HTML
+        && matches!(&*element.tag_name, "input" | "select" | "textarea")
+}
+
+/// https://html.spec.whatwg.org/multipage/semantics-other.html#concept-element-disabled
+fn is_disabled(parents: &[Frame], current: Frame) -> bool {
+    let element = current.element;
+
+    if get_attribute_value(element, "disabled").is_some() {
+        return true;
+    }
+
+    match &*element.tag_name {
+        "option" => matches!(
+            parents.last(),
+            Some(parent) if parent.element.tag_name == *"optgroup"
+                && get_attribute_value(parent.element, "disabled").is_some()
+        ),
+        "optgroup" => false,
+        // Descendants of a disabled fieldset, except of its first legend
+        _ => parents.iter().enumerate().any(|(index, parent)| {
+            if parent.element.tag_name != *"fieldset"
+                || get_attribute_value(parent.element, "disabled").is_none()
+            {
+                return false;
+            }
+
+            let first_legend = parent
+                .element
+                .children
+                .iter()
+                .find_map(|child| match child {
+                    Child::Element(child) if child.tag_name == *"legend" => Some(child),
+                    _ => None,
+                });
+            let child = parents.get(index + 1).unwrap_or(&current);
+
+            !matches!(first_legend, Some(legend) if ptr::eq(legend, child.element))
+        }),
+    }
+}
@@ -0,0 +1,212 @@
+use std::{fmt::Write, fs, path::PathBuf};
+
+use swc_html_ast::{DocumentMode, Element, Namespace};
+use swc_html_parser::{parse_file_as_document, parse_file_as_document_fragment};
+use swc_html_query::{parse_selector_list, query_selector, query_selector_all, QueryConfig};
+use testing::NormalizedOutput;
+
+fn query_config() -> QueryConfig {
+    let mut config = QueryConfig::default();
+
+    config.namespaces.insert("html".into(), Namespace::HTML);
+    config.namespaces.insert("svg".into(), Namespace::SVG);
+    config.namespaces.insert("math".into(), Namespace::MATHML);
+    config.namespaces.insert("xlink".into(), Namespace::XLINK);
+
+    config
+}
+
+fn print_start_tag(element: &Element) -> String {
+    let mut start_tag = format!("<{}", element.tag_name);
+
+    for attribute in &element.attributes {
+        match &attribute.prefix {
+            Some(prefix) => write!(start_tag, " {}:{}", prefix, attribute.name).unwrap(),
+            None => write!(start_tag, " {}", attribute.name).unwrap(),
+        }
+
+        if let Some(value) = &attribute.value {
+            write!(start_tag, "=\"{}\"", value).unwrap();
+        }
+    }
+
+    start_tag.push('>');
+    start_tag
+}
+
+/// Runs each line of `selectors.txt` against `input.html` and prints the
+/// start tags of the matched elements.
+#[testing::fixture("tests/fixture/**/input.html")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let selectors = fs::read_to_string(dir.join("selectors.txt")).unwrap();
+    let config = query_config();
+
+    testing::run_test2(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+        let mut errors = vec![];
+        let document = match parse_file_as_document(&fm, Default::default(), &mut errors) {
+            Ok(document) => document,
+            Err(err) => {
+                err.to_diagnostics(&handler).emit();
+
+                return Err(());
+            }
+        };
+        let mut output = String::new();
+
+        for selector in selectors.lines().filter(|line| !line.trim().is_empty()) {
+            writeln!(output, "{}", selector).unwrap();
+
+            match parse_selector_list(selector) {
+                Ok(selector_list) => {
+                    for element in query_selector_all(&document, &selector_list, &config) {
+                        writeln!(output, "  {}", print_start_tag(element)).unwrap();
+                    }
+                }
+                Err(err) => {
+                    writeln!(output, "  error: {}", err.message()).unwrap();
+                }
+            }
+        }
+
+        NormalizedOutput::from(output)
+            .compare_to_file(dir.join("output.txt"))
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn query_element() {
+    testing::run_test2(false, |cm, _| {
+        let fm = cm.new_source_file(
+            swc_common::FileName::Anon.into(),
+            "<div id=outer><p id=first><span></span></p><p id=second></p></div>".into(),
+        );
+        let document = parse_file_as_document(&fm, Default::default(), &mut vec![]).unwrap();
+        let config = query_config();
+        let outer =
+            query_selector(&document, &parse_selector_list("#outer").unwrap(), &config).unwrap();
+
+        // The root is never included, but can be matched by combinators
+        let result = query_selector_all(
+            outer,
+            &parse_selector_list("div, div > p").unwrap(),
+            &config,
+        );
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|element| print_start_tag(element))
+                .collect::<Vec<_>>(),
+            vec!["<p id=\"first\">", "<p id=\"second\">"]
+        );
+
+        let result =
+            query_selector_all(outer, &parse_selector_list(":scope > *").unwrap(), &config);
+
+        assert_eq!(result.len(), 2);
+
+        let result = query_selector(
+            outer,
+            &parse_selector_list(":root, html span").unwrap(),
+            &config,
+        );
+
+        assert!(result.is_none());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn query_document_fragment() {
+    testing::run_test2(false, |cm, _| {
+        let fm = cm.new_source_file(
+            swc_common::FileName::Anon.into(),
+            "<td>1</td><td class=last>2</td>".into(),
+        );
+        let context_element = Element {
+            span: Default::default(),
+            tag_name: "tr".into(),
+            namespace: Namespace::HTML,
+            attributes: vec![],
+            children: vec![],
+            content: None,
+            is_self_closing: false,
+        };
+        let document_fragment = parse_file_as_document_fragment(
+            &fm,
+            &context_element,
+            DocumentMode::NoQuirks,
+            None,
+            Default::default(),
+            &mut vec![],
+        )
+        .unwrap();
+        let config = query_config();
+        let result = query_selector_all(
+            &document_fragment,
+            &parse_selector_list("td:last-child, :root").unwrap(),
+            &config,
+        );
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|element| print_start_tag(element))
+                .collect::<Vec<_>>(),
+            vec!["<td class=\"last\">"]
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn match_path() {
+    testing::run_test2(false, |cm, _| {
+        let fm = cm.new_source_file(
+            swc_common::FileName::Anon.into(),
+            "<ul class=menu><li><a href=/>Home</a></li><li><a>About</a></li></ul>".into(),
+        );
+        let document = parse_file_as_document(&fm, Default::default(), &mut vec![]).unwrap();
+        let config = query_config();
+        let ancestors = |selector: &str| {
+            let element =
+                query_selector(&document, &parse_selector_list(selector).unwrap(), &config)
+                    .unwrap();
+            let mut path = vec![];
+
+            for ancestor in ["html", "body", "ul", "li"] {
+                path.push(
+                    query_selector(&document, &parse_selector_list(ancestor).unwrap(), &config)
+                        .unwrap(),
+                );
+            }
+
+            path.push(element);
+            path
+        };
+        let path = ancestors("a[href]");
+        let matches = |selector: &str| {
+            swc_html_query::matches_path(&path, &parse_selector_list(selector).unwrap(), &config)
+        };
+
+        assert!(matches(".menu a"));
+        assert!(matches("ul > li > a[href='/']"));
+        assert!(matches(":root a"));
+        assert!(!matches("li > .menu a"));
+        // Siblings are unknown
+        assert!(!matches("li:first-child a"));
+
+        Ok(())
+    })
+    .unwrap();
+}
@@ -0,0 +1,249 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
+
+use swc_atoms::{Atom, JsWord};
+use swc_html_ast::{AttributeToken, Namespace};
+
+/// How the content passed to [RewritableElement] is written to the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentType {
+    /// The content is written as is.
+    Html,
+    /// `&`, `<` and `>` are escaped, so the content is always displayed as
+    /// text.
+    Text,
+}
+
+#[derive(Debug)]
+struct RewritableAttribute {
+    name: JsWord,
+    value: Option<JsWord>,
+    /// The source text of the name and the value, unset when the value was
+    /// changed by a handler.
+    raw: Option<(Option<Atom>, Option<Atom>)>,
+}
+
+/// A start tag matched by an [crate::ElementHandler].
+///
+/// Only the start tag is available: the content of the element has not been
+/// read yet when handlers are called.
+#[derive(Debug)]
+pub struct RewritableElement {
+    tag_name: JsWord,
+    raw_tag_name: Option<Atom>,
+    namespace: Namespace,
+    is_self_closing: bool,
+    can_have_content: bool,
+    attributes: Vec<RewritableAttribute>,
+    pub(crate) is_attributes_modified: bool,
+    pub(crate) is_removed: bool,
+    pub(crate) before: String,
+    pub(crate) prepend: String,
+    pub(crate) append: String,
+    pub(crate) after: String,
+}
+
+impl RewritableElement {
+    pub(crate) fn new(
+        tag_name: JsWord,
+        raw_tag_name: Option<Atom>,
+        namespace: Namespace,
+        is_self_closing: bool,
+        can_have_content: bool,
+        attributes: &[AttributeToken],
+    ) -> Self {
+        RewritableElement {
+            tag_name,
+            raw_tag_name,
+            namespace,
+            is_self_closing,
+            can_have_content,
+            attributes: attributes
+                .iter()
+                .map(|attribute| RewritableAttribute {
+                    name: attribute.name.clone(),
+                    value: attribute.value.clone(),
+                    raw: Some((attribute.raw_name.clone(), attribute.raw_value.clone())),
+                })
+                .collect(),
+            is_attributes_modified: false,
+            is_removed: false,
+            before: String::new(),
+            prepend: String::new(),
+            append: String::new(),
+            after: String::new(),
+        }
+    }
+
+    /// The name of the element, lowercase for HTML elements.
+    pub fn tag_name(&self) -> &str {
+        &self.tag_name
+    }
+
+    pub fn namespace(&self) -> Namespace {
+        self.namespace
+    }
+
+    /// Returns `false` for void elements (e.g. `img`) and self-closing
+    /// foreign elements, for which [RewritableElement::prepend] and
+    /// [RewritableElement::append] are ignored.
+    pub fn can_have_content(&self) -> bool {
+        self.can_have_content
+    }
+
+    /// Names and values of the attributes, in the source order. The value
+    /// of an attribute without a value is `None`.
+    pub fn attributes(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.attributes
+            .iter()
+            .map(|attribute| (&*attribute.name, attribute.value.as_deref()))
+    }
+
+    /// Returns the value of the attribute, an empty string for an attribute
+    /// without a value.
+    pub fn get_attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name.eq_ignore_ascii_case(name))
+            .map(|attribute| attribute.value.as_deref().unwrap_or(""))
+    }
+
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.get_attribute(name).is_some()
+    }
+
+    /// Sets the value of the attribute, adding it after other attributes if
+    /// it doesn't exist.
+    pub fn set_attribute(&mut self, name: &str, value: &str) {
+        self.is_attributes_modified = true;
+
+        match self
+            .attributes
+            .iter_mut()
+            .find(|attribute| attribute.name.eq_ignore_ascii_case(name))
+        {
+            Some(attribute) => {
+                attribute.value = Some(value.into());
+                attribute.raw = None;
+            }
+            None => self.attributes.push(RewritableAttribute {
+                name: name.to_ascii_lowercase().into(),
+                value: Some(value.into()),
+                raw: None,
+            }),
+        }
+    }
+
+    pub fn remove_attribute(&mut self, name: &str) {
+        let len = self.attributes.len();
+
+        self.attributes
+            .retain(|attribute| !attribute.name.eq_ignore_ascii_case(name));
+
+        if self.attributes.len() != len {
+            self.is_attributes_modified = true;
+        }
+    }
+
+    /// Inserts the content before the start tag.
+    pub fn before(&mut self, content: &str, content_type: ContentType) {
+        self.before.push_str(&to_html(content, content_type));
+    }
+
+    /// Inserts the content after the end tag, or after the start tag if the
+    /// element can't have content.
+    pub fn after(&mut self, content: &str, content_type: ContentType) {
+        // The content inserted last is closest to the element.
+        self.after.insert_str(0, &to_html(content, content_type));
+    }
+
+    /// Inserts the content right after the start tag.
+    pub fn prepend(&mut self, content: &str, content_type: ContentType) {
+        self.prepend.insert_str(0, &to_html(content, content_type));
+    }
+
+    /// Inserts the content right before the end tag.
+    pub fn append(&mut self, content: &str, content_type: ContentType) {
+        self.append.push_str(&to_html(content, content_type));
+    }
+
+    /// Removes the element with its content. Content inserted with
+    /// [RewritableElement::before] and [RewritableElement::after] is kept.
+    pub fn remove(&mut self) {
+        self.is_removed = true;
+    }
+
+    pub fn is_removed(&self) -> bool {
+        self.is_removed
+    }
+
+    pub(crate) fn is_modified(&self) -> bool {
+        self.is_attributes_modified
+            || self.is_removed
+            || !self.before.is_empty()
+            || !self.prepend.is_empty()
+            || !self.append.is_empty()
+            || !self.after.is_empty()
+    }
+
+    pub(crate) fn write_start_tag<W: Write>(&self, output: &mut W) -> fmt::Result {
+        output.write_char('<')?;
+        output.write_str(self.raw_tag_name.as_deref().unwrap_or(&self.tag_name))?;
+
+        for attribute in &self.attributes {
+            output.write_char(' ')?;
+
+            match &attribute.raw {
+                Some((raw_name, raw_value)) => {
+                    output.write_str(raw_name.as_deref().unwrap_or(&attribute.name))?;
+
+                    // The raw value contains quotes.
+                    if let Some(raw_value) = raw_value {
+                        write!(output, "={}", raw_value)?;
+                    } else if attribute.value.is_some() {
+                        output.write_str("=\"\"")?;
+                    }
+                }
+                None => {
+                    output.write_str(&attribute.name)?;
+
+                    if let Some(value) = &attribute.value {
+                        write!(output, "=\"{}\"", escape_attribute_value(value))?;
+                    }
+                }
+            }
+        }
+
+        if self.is_self_closing {
+            output.write_str(" />")
+        } else {
+            output.write_char('>')
+        }
+    }
+}
+
+fn to_html(content: &str, content_type: ContentType) -> Cow<str> {
+    match content_type {
+        ContentType::Html => Cow::Borrowed(content),
+        ContentType::Text => {
+            let mut buf = String::with_capacity(content.len());
+
+            for c in content.chars() {
+                match c {
+                    '&' => buf.push_str("&amp;"),
+                    '<' => buf.push_str("&lt;"),
+                    '>' => buf.push_str("&gt;"),
+                    _ => buf.push(c),
+                }
+            }
+
+            Cow::Owned(buf)
+        }
+    }
+}
+
+fn escape_attribute_value(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;")
+}
@@ -0,0 +1,824 @@
+#![deny(clippy::all)]
+
+//! Streaming rewriting of HTML documents, similar to
+//! [lol-html](https://github.com/cloudflare/lol-html).
+//!
+//! The document is tokenized by the lexer of `swc_html_parser` and start tags
+//! matching selectors are passed to [ElementHandler]s, without building a
+//! tree. The source text is copied to the output as is, except for tags
+//! modified by handlers, so the output is written while tokenizing.
+//!
+//! [rewrite_str] rewrites a document which is already in memory, and
+//! [HtmlRewriter] rewrites a document received in chunks. The latter only
+//! keeps the input after the last complete tag, so its memory usage depends on
+//! the depth of nested elements and the longest text between two tags (e.g.
+//! the content of a `script` element), not on the size of the document.
+//!
+//! Only ancestors of an element are known when its start tag is handled, so
+//! selectors can't use sibling combinators and sibling dependent
+//! pseudo-classes (see [swc_html_query::matches_path]). The tree construction
+//! is approximated: end tags implied by start tags (e.g. `<li>` closes the
+//! previous `li`) and foreign content are handled, but misnested tags are not
+//! reparented like a parser would do.
+
+use std::{fmt::Write, iter::once, mem::take};
+
+use swc_atoms::JsWord;
+use swc_common::{input::StringInput, BytePos, DUMMY_SP};
+use swc_css_ast::SelectorList;
+use swc_html_ast::{Attribute, AttributeToken, Element, Namespace, Token, TokenAndSpan};
+use swc_html_parser::{
+    lexer::{Lexer, State},
+    parser::input::ParserInput,
+};
+use swc_html_query::{matches_path, QueryConfig};
+
+pub use self::element::{ContentType, RewritableElement};
+
+mod element;
+
+#[derive(Debug, Clone, Default)]
+pub struct RewriterConfig {
+    /// Namespaces used in selectors.
+    pub query: QueryConfig,
+    /// Tokenize the content of `noscript` elements as text, like browsers
+    /// with scripting enabled do.
+    pub scripting_enabled: bool,
+}
+
+/// Calls a function for every element matching a selector list.
+pub struct ElementHandler<'a> {
+    selectors: SelectorList,
+    handler: Box<dyn FnMut(&mut RewritableElement) + 'a>,
+}
+
+impl<'a> ElementHandler<'a> {
+    pub fn new(selectors: SelectorList, handler: impl FnMut(&mut RewritableElement) + 'a) -> Self {
+        ElementHandler {
+            selectors,
+            handler: Box::new(handler),
+        }
+    }
+}
+
+/// Rewrites `input` with `handlers`, writing the result to `output`.
+///
+/// Handlers are called in order for every start tag matching their selectors,
+/// except inside removed elements.
+pub fn rewrite_str<W: Write>(
+    input: &str,
+    handlers: &mut [ElementHandler],
+    config: &RewriterConfig,
+    output: &mut W,
+) -> std::fmt::Result {
+    let mut rewriter = Rewriter {
+        input,
+        handlers,
+        config,
+        output,
+        pos: 0,
+        state: &mut RewriterState::default(),
+    };
+
+    rewriter.rewrite(true).map(|_| ())
+}
+
+/// Rewrites a document received in chunks, producing the same output as
+/// [rewrite_str].
+///
+/// The output of a tag is written as soon as the tag is complete. Invalid
+/// UTF-8 sequences are replaced with `U+FFFD`.
+pub struct HtmlRewriter<'a, 'b, W: Write> {
+    handlers: &'a mut [ElementHandler<'b>],
+    config: &'a RewriterConfig,
+    output: &'a mut W,
+    /// The input after the end of the last tag.
+    buffer: String,
+    /// An incomplete UTF-8 sequence at the end of the last chunk.
+    incomplete: Vec<u8>,
+    state: RewriterState,
+}
+
+impl<'a, 'b, W: Write> HtmlRewriter<'a, 'b, W> {
+    pub fn new(
+        handlers: &'a mut [ElementHandler<'b>],
+        config: &'a RewriterConfig,
+        output: &'a mut W,
+    ) -> Self {
+        HtmlRewriter {
+            handlers,
+            config,
+            output,
+            buffer: String::new(),
+            incomplete: Vec::new(),
+            state: RewriterState::default(),
+        }
+    }
+
+    /// Rewrites the next chunk of the document.
+    pub fn write(&mut self, chunk: &[u8]) -> std::fmt::Result {
+        self.incomplete.extend_from_slice(chunk);
+
+        let mut bytes = &*take(&mut self.incomplete);
+
+        loop {
+            match std::str::from_utf8(bytes) {
+                Ok(valid) => {
+                    self.buffer.push_str(valid);
+
+                    break;
+                }
+                Err(err) => {
+                    let (valid, rest) = bytes.split_at(err.valid_up_to());
+
+                    // Safety: `valid` was validated by `from_utf8`.
+                    self.buffer
+                        .push_str(unsafe { std::str::from_utf8_unchecked(valid) });
+
+                    match err.error_len() {
+                        Some(len) => {
+                            self.buffer.push(char::REPLACEMENT_CHARACTER);
+                            bytes = &rest[len..];
+                        }
+                        None => {
+                            self.incomplete = rest.to_vec();
+
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        self.rewrite(false)
+    }
+
+    /// Rewrites the rest of the document and closes open elements.
+    pub fn end(mut self) -> std::fmt::Result {
+        if !self.incomplete.is_empty() {
+            self.buffer.push(char::REPLACEMENT_CHARACTER);
+        }
+
+        self.rewrite(true)
+    }
+
+    fn rewrite(&mut self, is_last: bool) -> std::fmt::Result {
+        let mut rewriter = Rewriter {
+            input: &self.buffer,
+            handlers: &mut *self.handlers,
+            config: self.config,
+            output: &mut *self.output,
+            pos: 0,
+            state: &mut self.state,
+        };
+        let end = rewriter.rewrite(is_last)?;
+
+        self.buffer.drain(..end);
+
+        Ok(())
+    }
+}
+
+struct OpenElement {
+    /// Used for matching, without children.
+    element: Element,
+    append: String,
+    after: String,
+}
+
+/// State kept between the chunks of a document.
+#[derive(Default)]
+struct RewriterState {
+    open_elements: Vec<OpenElement>,
+    /// The index of the removed element in `open_elements`, nothing is
+    /// written until it is closed.
+    removed: Option<usize>,
+    /// The state of the lexer after the last tag, if it's not the data state.
+    lexer_state: Option<State>,
+    last_start_tag_name: Option<JsWord>,
+}
+
+struct Rewriter<'a, 'b, W: Write> {
+    input: &'a str,
+    handlers: &'a mut [ElementHandler<'b>],
+    config: &'a RewriterConfig,
+    output: &'a mut W,
+    /// The input before this position is either written or removed.
+    pos: usize,
+    state: &'a mut RewriterState,
+}
+
+impl<W: Write> Rewriter<'_, '_, W> {
+    /// Rewrites the input up to the end of the last tag, or the whole input
+    /// if `is_last` is `true`, and returns the end of the rewritten input.
+    ///
+    /// The lexer can be restarted after a tag, because its state only depends
+    /// on the element opened by the tag.
+    fn rewrite(&mut self, is_last: bool) -> Result<usize, std::fmt::Error> {
+        let input = self.input;
+        let mut lexer = Lexer::new(StringInput::new(
+            input,
+            BytePos(0),
+            BytePos(input.len() as u32),
+        ));
+        let mut end = 0;
+
+        if let Some(state) = &self.state.lexer_state {
+            lexer.set_input_state(state.clone());
+        }
+        if let Some(tag_name) = &self.state.last_start_tag_name {
+            lexer.set_last_start_tag_name(tag_name);
+        }
+        lexer.set_adjusted_current_node_to_html_namespace(self.is_current_node_html());
+
+        while let Some(TokenAndSpan { span, token }) = lexer.next() {
+            let (lo, hi) = (span.lo.0 as usize, span.hi.0 as usize);
+            // A span starts after the previous token, so it can contain
+            // characters which are not tokens (e.g. the end of a CDATA
+            // section).
+            let lo = match token {
+                Token::StartTag { .. } | Token::EndTag { .. } => {
+                    input[lo..hi].find('<').map_or(lo, |offset| lo + offset)
+                }
+                _ => lo,
+            };
+
+            match token {
+                Token::StartTag {
+                    tag_name,
+                    raw_tag_name,
+                    is_self_closing,
+                    attributes,
+                } => {
+                    self.start_tag(
+                        &mut lexer,
+                        (lo, hi),
+                        tag_name,
+                        raw_tag_name,
+                        is_self_closing,
+                        &attributes,
+                    )?;
+                    end = hi;
+                }
+                Token::EndTag { tag_name, .. } => {
+                    self.end_tag((lo, hi), &tag_name)?;
+                    self.state.lexer_state = None;
+                    end = hi;
+                }
+                Token::Eof => break,
+                _ => {}
+            }
+
+            lexer.set_adjusted_current_node_to_html_namespace(self.is_current_node_html());
+        }
+
+        if !is_last {
+            // The input after the last tag is lexed again with the next chunk.
+            self.copy_to(end)?;
+
+            return Ok(end);
+        }
+
+        self.close_elements(0, input.len())?;
+        self.copy_to(input.len())?;
+
+        Ok(input.len())
+    }
+
+    fn is_current_node_html(&self) -> bool {
+        self.state
+            .open_elements
+            .last()
+            .map_or(true, |open| open.element.namespace == Namespace::HTML)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn start_tag(
+        &mut self,
+        lexer: &mut Lexer<StringInput>,
+        (lo, hi): (usize, usize),
+        tag_name: JsWord,
+        raw_tag_name: Option<swc_atoms::Atom>,
+        is_self_closing: bool,
+        attributes: &[AttributeToken],
+    ) -> std::fmt::Result {
+        let namespace = self.namespace_of(&tag_name)?;
+
+        self.state.last_start_tag_name = Some(tag_name.clone());
+
+        if namespace == Namespace::HTML {
+            self.close_implied_elements(&tag_name, lo)?;
+        }
+
+        let tag_name = match namespace {
+            Namespace::SVG => adjust_svg_tag_name(&tag_name).unwrap_or(tag_name),
+            _ => tag_name,
+        };
+        let can_have_content = match namespace {
+            Namespace::HTML => !is_void_element(&tag_name),
+            _ => !is_self_closing,
+        };
+        let element = Element {
+            span: DUMMY_SP,
+            tag_name,
+            namespace,
+            attributes: attributes
+                .iter()
+                .map(|attribute| Attribute {
+                    span: DUMMY_SP,
+                    namespace: None,
+                    prefix: None,
+                    name: attribute.name.clone(),
+                    raw_name: None,
+                    value: attribute.value.clone(),
+                    raw_value: None,
+                })
+                .collect(),
+            children: Vec::new(),
+            content: None,
+            is_self_closing,
+        };
+
+        let mut rewritable = None;
+
+        if self.state.removed.is_none() {
+            let path = self
+                .state
+                .open_elements
+                .iter()
+                .map(|open| &open.element)
+                .chain(once(&element))
+                .collect::<Vec<_>>();
+
+            for handler in self.handlers.iter_mut() {
+                if matches_path(&path, &handler.selectors, &self.config.query) {
+                    let rewritable = rewritable.get_or_insert_with(|| {
+                        RewritableElement::new(
+                            element.tag_name.clone(),
+                            raw_tag_name.clone(),
+                            namespace,
+                            is_self_closing,
+                            can_have_content,
+                            attributes,
+                        )
+                    });
+
+                    (handler.handler)(rewritable);
+                }
+            }
+        }
+
+        let (mut append, mut after) = (String::new(), String::new());
+
+        match rewritable {
+            Some(mut rewritable) if rewritable.is_modified() => {
+                self.copy_to(lo)?;
+                self.write(&rewritable.before)?;
+
+                if !rewritable.is_removed {
+                    if rewritable.is_attributes_modified {
+                        rewritable.write_start_tag(&mut *self.output)?;
+                    } else {
+                        self.write(&self.input[lo..hi])?;
+                    }
+
+                    if can_have_content {
+                        self.write(&rewritable.prepend)?;
+                    }
+                }
+
+                self.pos = hi;
+
+                if can_have_content {
+                    append = take(&mut rewritable.append);
+
+                    if rewritable.is_removed {
+                        self.state.removed = Some(self.state.open_elements.len());
+                    }
+                }
+
+                after = take(&mut rewritable.after);
+            }
+            _ => {}
+        }
+
+        if namespace == Namespace::HTML {
+            let state = match &*element.tag_name {
+                "title" | "textarea" => Some(State::Rcdata),
+                "style" | "xmp" | "iframe" | "noembed" | "noframes" => Some(State::Rawtext),
+                "noscript" if self.config.scripting_enabled => Some(State::Rawtext),
+                "script" => Some(State::ScriptData),
+                "plaintext" => Some(State::PlainText),
+                _ => None,
+            };
+
+            if let Some(state) = &state {
+                lexer.set_input_state(state.clone());
+            }
+
+            self.state.lexer_state = state;
+        } else {
+            self.state.lexer_state = None;
+        }
+
+        if can_have_content {
+            self.state.open_elements.push(OpenElement {
+                element,
+                append,
+                after,
+            });
+        } else {
+            self.write(&after)?;
+        }
+
+        Ok(())
+    }
+
+    fn end_tag(&mut self, (lo, hi): (usize, usize), tag_name: &str) -> std::fmt::Result {
+        let index = self.state.open_elements.iter().rposition(|open| {
+            if open.element.namespace == Namespace::HTML {
+                &*open.element.tag_name == tag_name
+            } else {
+                open.element.tag_name.eq_ignore_ascii_case(tag_name)
+            }
+        });
+
+        // Unmatched end tags are ignored by parsers, so they are kept as is.
+        if let Some(index) = index {
+            self.close_elements(index + 1, lo)?;
+            self.copy_to(lo)?;
+
+            let open = self.state.open_elements.pop().unwrap();
+
+            self.write(&open.append)?;
+
+            if self.state.removed == Some(index) {
+                self.pos = hi;
+                self.state.removed = None;
+            } else {
+                self.copy_to(hi)?;
+            }
+
+            self.write(&open.after)?;
+        }
+
+        Ok(())
+    }
+
+    /// Closes elements until there are `len` open elements, without end tags.
+    fn close_elements(&mut self, len: usize, pos: usize) -> std::fmt::Result {
+        while self.state.open_elements.len() > len {
+            self.copy_to(pos)?;
+
+            let open = self.state.open_elements.pop().unwrap();
+
+            self.write(&open.append)?;
+
+            if self.state.removed == Some(self.state.open_elements.len()) {
+                self.state.removed = None;
+            }
+
+            self.write(&open.after)?;
+        }
+
+        Ok(())
+    }
+
+    /// Closes elements whose end tag is implied by the start tag.
+    fn close_implied_elements(&mut self, tag_name: &str, pos: usize) -> std::fmt::Result {
+        const SCOPE: &[&str] = &[
+            "applet", "caption", "html", "table", "td", "th", "marquee", "object", "template",
+        ];
+
+        let index = match tag_name {
+            "li" => self.find_in_scope(&["li"], &[SCOPE, &["ol", "ul"]].concat()),
+            "dd" | "dt" => self.find_in_scope(&["dd", "dt"], SCOPE),
+            "option" => self.find_current(&["option"]),
+            "optgroup" => self
+                .find_current(&["option"])
+                .map(|index| {
+                    if index > 0 && self.is_html(index - 1, "optgroup") {
+                        index - 1
+                    } else {
+                        index
+                    }
+                })
+                .or_else(|| self.find_current(&["optgroup"])),
+            "tr" => self.find_in_scope(&["tr"], &["table", "tbody", "thead", "tfoot", "html"]),
+            "td" | "th" => self.find_in_scope(&["td", "th"], &["tr", "table", "html"]),
+            "tbody" | "thead" | "tfoot" => {
+                self.find_in_scope(&["tbody", "thead", "tfoot"], &["table", "html"])
+            }
+            "body" => self.find_in_scope(&["head"], &["html"]),
+            _ => None,
+        };
+        let index = index.or_else(|| {
+            if closes_p_element(tag_name) {
+                self.find_in_scope(&["p"], &[SCOPE, &["button"]].concat())
+            } else {
+                None
+            }
+        });
+
+        match index {
+            Some(index) => self.close_elements(index, pos),
+            None => Ok(()),
+        }
+    }
+
+    /// Finds the innermost HTML element with one of `tag_names`, stopping at
+    /// `boundaries` and foreign elements.
+    fn find_in_scope(&self, tag_names: &[&str], boundaries: &[&str]) -> Option<usize> {
+        for (index, open) in self.state.open_elements.iter().enumerate().rev() {
+            if open.element.namespace != Namespace::HTML {
+                return None;
+            }
+
+            if tag_names.contains(&&*open.element.tag_name) {
+                return Some(index);
+            }
+
+            if boundaries.contains(&&*open.element.tag_name) {
+                return None;
+            }
+        }
+
+        None
+    }
+
+    fn find_current(&self, tag_names: &[&str]) -> Option<usize> {
+        let index = self.state.open_elements.len().checked_sub(1)?;
+
+        tag_names
+            .iter()
+            .any(|tag_name| self.is_html(index, tag_name))
+            .then_some(index)
+    }
+
+    fn is_html(&self, index: usize, tag_name: &str) -> bool {
+        let element = &self.state.open_elements[index].element;
+
+        element.namespace == Namespace::HTML && &*element.tag_name == tag_name
+    }
+
+    /// Returns the namespace of a new element, closing foreign elements if it
+    /// breaks out of foreign content.
+    fn namespace_of(&mut self, tag_name: &str) -> Result<Namespace, std::fmt::Error> {
+        let parent = match self.state.open_elements.last() {
+            Some(open) => &open.element,
+            None => return Ok(namespace_of_root(tag_name)),
+        };
+
+        Ok(match parent.namespace {
+            Namespace::SVG | Namespace::MATHML if is_html_integration_point(parent) => {
+                namespace_of_root(tag_name)
+            }
+            Namespace::MATHML
+                if is_mathml_text_integration_point(parent)
+                    && !matches!(tag_name, "mglyph" | "malignmark") =>
+            {
+                namespace_of_root(tag_name)
+            }
+            Namespace::MATHML if &*parent.tag_name == "annotation-xml" && tag_name == "svg" => {
+                Namespace::SVG
+            }
+            Namespace::SVG | Namespace::MATHML if is_breakout_tag(tag_name) => {
+                // The element is inserted after the closest HTML element.
+                let len = self
+                    .state
+                    .open_elements
+                    .iter()
+                    .rposition(|open| {
+                        open.element.namespace == Namespace::HTML
+                            || is_html_integration_point(&open.element)
+                            || is_mathml_text_integration_point(&open.element)
+                    })
+                    .map_or(0, |index| index + 1);
+
+                self.close_elements(len, self.pos)?;
+
+                return self.namespace_of(tag_name);
+            }
+            Namespace::SVG | Namespace::MATHML => parent.namespace,
+            _ => namespace_of_root(tag_name),
+        })
+    }
+
+    /// Copies the input up to `pos` to the output, unless it is removed.
+    fn copy_to(&mut self, pos: usize) -> std::fmt::Result {
+        if pos > self.pos {
+            if self.state.removed.is_none() {
+                self.output.write_str(&self.input[self.pos..pos])?;
+            }
+
+            self.pos = pos;
+        }
+
+        Ok(())
+    }
+
+    fn write(&mut self, content: &str) -> std::fmt::Result {
+        if self.state.removed.is_none() {
+            self.output.write_str(content)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn namespace_of_root(tag_name: &str) -> Namespace {
+    match tag_name {
+        "svg" => Namespace::SVG,
+        "math" => Namespace::MATHML,
+        _ => Namespace::HTML,
+    }
+}
+
+fn is_html_integration_point(element: &Element) -> bool {
+    match element.namespace {
+        Namespace::SVG => matches!(&*element.tag_name, "foreignObject" | "desc" | "title"),
+        Namespace::MATHML => {
+            &*element.tag_name == "annotation-xml"
+                && element.attributes.iter().any(|attribute| {
+                    &*attribute.name == "encoding"
+                        && attribute.value.as_ref().map_or(false, |value| {
+                            value.eq_ignore_ascii_case("text/html")
+                                || value.eq_ignore_ascii_case("application/xhtml+xml")
+                        })
+                })
+        }
+        _ => false,
+    }
+}
+
+fn is_mathml_text_integration_point(element: &Element) -> bool {
+    element.namespace == Namespace::MATHML
+        && matches!(&*element.tag_name, "mi" | "mo" | "mn" | "ms" | "mtext")
+}
+
+fn is_breakout_tag(tag_name: &str) -> bool {
+    matches!(
+        tag_name,
+        "b" | "big"
+            | "blockquote"
+            | "body"
+            | "br"
+            | "center"
+            | "code"
+            | "dd"
+            | "div"
+            | "dl"
+            | "dt"
+            | "em"
+            | "embed"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "head"
+            | "hr"
+            | "i"
+            | "img"
+            | "li"
+            | "listing"
+            | "menu"
+            | "meta"
+            | "nobr"
+            | "ol"
+            | "p"
+            | "pre"
+            | "ruby"
+            | "s"
+            | "small"
+            | "span"
+            | "strong"
+            | "strike"
+            | "sub"
+            | "sup"
+            | "table"
+            | "tt"
+            | "u"
+            | "ul"
+            | "var"
+    )
+}
+
+fn closes_p_element(tag_name: &str) -> bool {
+    matches!(
+        tag_name,
+        "address"
+            | "article"
+            | "aside"
+            | "blockquote"
+            | "center"
+            | "details"
+            | "dialog"
+            | "dir"
+            | "div"
+            | "dl"
+            | "fieldset"
+            | "figcaption"
+            | "figure"
+            | "footer"
+            | "form"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "header"
+            | "hgroup"
+            | "hr"
+            | "main"
+            | "menu"
+            | "nav"
+            | "ol"
+            | "p"
+            | "pre"
+            | "listing"
+            | "search"
+            | "section"
+            | "summary"
+            | "table"
+            | "ul"
+            | "li"
+            | "dd"
+            | "dt"
+            | "plaintext"
+            | "xmp"
+    )
+}
+
+fn is_void_element(tag_name: &str) -> bool {
+    matches!(
+        tag_name,
+        "area"
+            | "base"
+            | "basefont"
+            | "bgsound"
+            | "br"
+            | "col"
+            | "embed"
+            | "frame"
+            | "hr"
+            | "image"
+            | "img"
+            | "input"
+            | "keygen"
+            | "link"
+            | "meta"
+            | "param"
+            | "source"
+            | "track"
+            | "wbr"
+    )
+}
+
+/// The lexer lowercases tag names, SVG tag names are case-sensitive.
+fn adjust_svg_tag_name(tag_name: &str) -> Option<JsWord> {
+    let tag_name = match tag_name {
+        "altglyph" => "altGlyph",
+        "altglyphdef" => "altGlyphDef",
+        "altglyphitem" => "altGlyphItem",
+        "animatecolor" => "animateColor",
+        "animatemotion" => "animateMotion",
+        "animatetransform" => "animateTransform",
+        "clippath" => "clipPath",
+        "feblend" => "feBlend",
+        "fecolormatrix" => "feColorMatrix",
+        "fecomponenttransfer" => "feComponentTransfer",
+        "fecomposite" => "feComposite",
+        "feconvolvematrix" => "feConvolveMatrix",
+        "fediffuselighting" => "feDiffuseLighting",
+        "fedisplacementmap" => "feDisplacementMap",
+        "fedistantlight" => "feDistantLight",
+        "fedropshadow" => "feDropShadow",
+        "feflood" => "feFlood",
+        "fefunca" => "feFuncA",
+        "fefuncb" => "feFuncB",
+        "fefuncg" => "feFuncG",
+        "fefuncr" => "feFuncR",
+        "fegaussianblur" => "feGaussianBlur",
+        "feimage" => "feImage",
+        "femerge" => "feMerge",
+        "femergenode" => "feMergeNode",
+        "femorphology" => "feMorphology",
+        "feoffset" => "feOffset",
+        "fepointlight" => "fePointLight",
+        "fespecularlighting" => "feSpecularLighting",
+        "fespotlight" => "feSpotLight",
+        "fetile" => "feTile",
+        "feturbulence" => "feTurbulence",
+        "foreignobject" => "foreignObject",
+        "glyphref" => "glyphRef",
+        "lineargradient" => "linearGradient",
+        "radialgradient" => "radialGradient",
+        "textpath" => "textPath",
+        _ => return None,
+    };
+
+    Some(tag_name.into())
+}
@@ -0,0 +1,171 @@
+use std::{cell::Cell, fs, path::PathBuf};
+
+use swc_html_query::parse_selector_list;
+use swc_html_rewriter::{rewrite_str, ContentType, ElementHandler, HtmlRewriter, RewriterConfig};
+use testing::NormalizedOutput;
+
+fn rewrite(input: &str, handlers: &mut [ElementHandler]) -> String {
+    let mut output = String::new();
+
+    rewrite_str(input, handlers, &RewriterConfig::default(), &mut output).unwrap();
+
+    output
+}
+
+fn rewrite_chunked(input: &[u8], handlers: &mut [ElementHandler], chunk_size: usize) -> String {
+    let mut output = String::new();
+    let config = RewriterConfig::default();
+    let mut rewriter = HtmlRewriter::new(handlers, &config, &mut output);
+
+    for chunk in input.chunks(chunk_size) {
+        rewriter.write(chunk).unwrap();
+    }
+    rewriter.end().unwrap();
+
+    output
+}
+
+fn handler<'a>(
+    selector: &str,
+    handler: impl FnMut(&mut swc_html_rewriter::RewritableElement) + 'a,
+) -> ElementHandler<'a> {
+    ElementHandler::new(parse_selector_list(selector).unwrap(), handler)
+}
+
+/// Rewrites `input.html` with a set of handlers driven by attributes:
+///
+/// - `data-set="name=value"` sets an attribute
+/// - `data-remove-attr="name"` removes an attribute
+/// - `data-before`, `data-after`, `data-prepend`, `data-append` insert a
+///   comment containing the tag name
+/// - `.remove` removes the element
+/// - `script` elements get a `nonce` attribute
+#[testing::fixture("tests/fixture/**/input.html")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let source = fs::read_to_string(&input).unwrap();
+    let mut handlers = vec![
+        handler("[data-set]", |element| {
+            let value = element.get_attribute("data-set").unwrap().to_string();
+
+            if let Some((name, value)) = value.split_once('=') {
+                element.set_attribute(name, value);
+            }
+        }),
+        handler("[data-remove-attr]", |element| {
+            let name = element
+                .get_attribute("data-remove-attr")
+                .unwrap()
+                .to_string();
+
+            element.remove_attribute(&name);
+        }),
+        handler("[data-before]", |element| {
+            let content = format!("<!--before {}-->", element.tag_name());
+
+            element.before(&content, ContentType::Html);
+        }),
+        handler("[data-after]", |element| {
+            let content = format!("<!--after {}-->", element.tag_name());
+
+            element.after(&content, ContentType::Html);
+        }),
+        handler("[data-prepend]", |element| {
+            let content = format!("<!--prepend {}-->", element.tag_name());
+
+            element.prepend(&content, ContentType::Html);
+        }),
+        handler("[data-append]", |element| {
+            let content = format!("<!--append {}-->", element.tag_name());
+
+            element.append(&content, ContentType::Html);
+        }),
+        handler(".remove", |element| element.remove()),
+        handler("script", |element| element.set_attribute("nonce", "r4nd0m")),
+    ];
+
+    let output = rewrite(&source, &mut handlers);
+
+    for chunk_size in [1, 7, 64] {
+        assert_eq!(
+            rewrite_chunked(source.as_bytes(), &mut handlers, chunk_size),
+            output,
+            "chunk size: {}",
+            chunk_size
+        );
+    }
+
+    NormalizedOutput::from(output)
+        .compare_to_file(dir.join("output.html"))
+        .unwrap();
+}
+
+#[test]
+fn unmodified() {
+    let input = "<!DOCTYPE html><html><HEAD><Title>a &amp; b</title></head><body \
+                 CLASS=foo><p>text<!-- comment --></body></html>";
+    let called = Cell::new(0);
+    let mut handlers = vec![handler("*", |_| called.set(called.get() + 1))];
+
+    assert_eq!(rewrite(input, &mut handlers), input);
+    assert_eq!(called.get(), 5);
+}
+
+#[test]
+fn text_content() {
+    let mut handlers = vec![handler("div", |element| {
+        element.before("<b>", ContentType::Text);
+        element.append("a & b", ContentType::Text);
+        element.set_attribute("title", "\"&\"");
+    })];
+
+    assert_eq!(
+        rewrite("<div>x</div>", &mut handlers),
+        "&lt;b&gt;<div title=\"&quot;&amp;&quot;\">xa &amp; b</div>"
+    );
+}
+
+#[test]
+fn insertion_order() {
+    let mut handlers = vec![
+        handler("p", |element| {
+            element.prepend("1", ContentType::Text);
+            element.append("1", ContentType::Text);
+            element.after("1", ContentType::Text);
+        }),
+        handler("p", |element| {
+            element.prepend("2", ContentType::Text);
+            element.append("2", ContentType::Text);
+            element.after("2", ContentType::Text);
+        }),
+    ];
+
+    assert_eq!(rewrite("<p>x</p>", &mut handlers), "<p>21x12</p>21");
+}
+
+#[test]
+fn ancestors() {
+    let mut handlers = vec![
+        handler("ul > li", |element| element.set_attribute("class", "item")),
+        handler("ol li", |element| element.set_attribute("class", "nested")),
+    ];
+
+    assert_eq!(
+        rewrite("<ul><li>a<li>b<ol><li>c</ol></ul><li>d", &mut handlers),
+        "<ul><li class=\"item\">a<li class=\"item\">b<ol><li class=\"nested\">c</ol></ul><li>d"
+    );
+}
+
+#[test]
+fn chunked_utf8() {
+    let mut handlers = vec![handler("p", |element| element.set_attribute("lang", "ko"))];
+    let mut input = "<p>안녕</p>".as_bytes().to_vec();
+    input.extend_from_slice(b"<p>\xff</p>");
+
+    for chunk_size in 1..input.len() {
+        assert_eq!(
+            rewrite_chunked(&input, &mut handlers, chunk_size),
+            "<p lang=\"ko\">안녕</p><p lang=\"ko\">\u{fffd}</p>"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use swc_atoms::Atom;
 use swc_common::{FileName, Span};
 
 #[derive(Debug, Clone)]
@@ -33,3 +34,13 @@ impl DtsIssue {
         }
     }
 }
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum DtsBundleError {
+    #[error("entry module `{0}` is not found")]
+    EntryNotFound(String),
+    #[error("module `{module}` has no export named `{name}`")]
+    MissingExport { module: String, name: Atom },
+    #[error("unsupported syntax in module `{module}`, only ES module syntax can be bundled")]
+    Unsupported { module: String, span: Span },
+}
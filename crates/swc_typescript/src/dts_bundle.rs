@@ -0,0 +1,1217 @@
+//! Bundling of declaration files.
+//!
+//! Declarations of modules imported with relative specifiers are inlined
+//! into a single module, like `rollup-plugin-dts` or `api-extractor` do.
+//! Only declarations reachable from the exports of the entry module are kept,
+//! and top-level identifiers are renamed when names of different modules
+//! conflict. Imports of other modules are preserved, including type-only
+//! imports and imports for side effects.
+//!
+//! Relative specifiers which can't be resolved to an added module are
+//! preserved too. `import("./foo")` types and module augmentations of inlined
+//! modules are not rewritten.
+
+use swc_atoms::Atom;
+use swc_common::{
+    collections::{AHashMap, AHashSet},
+    util::take::Take,
+    Spanned, DUMMY_SP,
+};
+use swc_ecma_ast::{
+    Class, ClassDecl, Decl, DefaultDecl, ExportAll, ExportDecl, ExportNamedSpecifier,
+    ExportSpecifier, Expr, FnDecl, Function, Ident, ImportDecl, ImportDefaultSpecifier,
+    ImportNamedSpecifier, ImportSpecifier, ImportStarAsSpecifier, Module, ModuleDecl,
+    ModuleExportName, ModuleItem, NamedExport, Stmt, TsCallSignatureDecl, TsConditionalType,
+    TsConstructSignatureDecl, TsConstructorType, TsEnumMember, TsFnType, TsGetterSignature,
+    TsImportType, TsInferType, TsInterfaceDecl, TsMappedType, TsMethodSignature, TsModuleBlock,
+    TsModuleDecl, TsModuleName, TsModuleRef, TsNamespaceBody, TsNamespaceDecl, TsPropertySignature,
+    TsSetterSignature, TsTypeAliasDecl, TsTypeParam, TsTypeParamDecl,
+};
+use swc_ecma_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+use crate::diagnostic::DtsBundleError;
+
+/// Bundles declaration modules, e.g. the output of
+/// [FastDts](crate::fast_dts::FastDts), into a single module.
+///
+/// Modules are identified by their path, e.g. `src/index.d.ts`. Extensions
+/// are ignored when resolving relative specifiers, and `./foo` resolves to
+/// `foo/index` too.
+#[derive(Default)]
+pub struct DtsBundler {
+    modules: Vec<(String, Module)>,
+}
+
+impl DtsBundler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_module(&mut self, id: impl Into<String>, module: Module) {
+        self.modules.push((id.into(), module));
+    }
+
+    /// Returns a module declaring the exports of the module `entry`.
+    pub fn bundle(self, entry: &str) -> Result<Module, DtsBundleError> {
+        let mut ids = AHashMap::default();
+
+        for (index, (id, _)) in self.modules.iter().enumerate() {
+            ids.entry(strip_extension(&normalize_path(id)))
+                .or_insert(index);
+        }
+
+        let entry = *ids
+            .get(&strip_extension(&normalize_path(entry)))
+            .ok_or_else(|| DtsBundleError::EntryNotFound(entry.to_string()))?;
+
+        let modules = self
+            .modules
+            .into_iter()
+            .map(|(id, module)| ModuleInfo::new(id, module, &ids))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Bundle {
+            modules,
+            bindings: vec![],
+            seen: Default::default(),
+            included: vec![],
+            globals: Default::default(),
+            namespaces: vec![],
+        }
+        .bundle(entry)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Source {
+    Internal(usize),
+    External(Atom),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ImportKind {
+    /// `default` for default imports.
+    Named(Atom),
+    Namespace,
+}
+
+#[derive(Debug, Clone)]
+enum Export {
+    Local(Atom),
+    Reexport(Source, ImportKind),
+}
+
+/// A declaration of the bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Binding {
+    Local(usize, Atom),
+    /// A namespace object of `import * as ns` or `export * as ns`.
+    Namespace(usize),
+    External(Atom, ImportKind),
+}
+
+struct ModuleInfo {
+    id: String,
+    items: Vec<ModuleItem>,
+    /// Names declared by each item, empty for module augmentations which are
+    /// always kept.
+    item_names: Vec<Vec<Atom>>,
+    locals: AHashMap<Atom, Vec<usize>>,
+    imports: AHashMap<Atom, (Source, ImportKind)>,
+    exports: Vec<(Atom, Export)>,
+    star_exports: Vec<Source>,
+    /// Specifiers of external modules imported for their side effects, e.g.
+    /// `import "foo/augment"`.
+    side_effect_imports: Vec<Atom>,
+    /// External bindings imported or re-exported without `type`. The others
+    /// are imported with `import type`.
+    value_externals: AHashSet<Binding>,
+    dependencies: Vec<usize>,
+}
+
+impl ModuleInfo {
+    fn new(
+        id: String,
+        module: Module,
+        ids: &AHashMap<String, usize>,
+    ) -> Result<Self, DtsBundleError> {
+        let mut info = ModuleInfo {
+            id,
+            items: vec![],
+            item_names: vec![],
+            locals: Default::default(),
+            imports: Default::default(),
+            exports: vec![],
+            star_exports: vec![],
+            side_effect_imports: vec![],
+            value_externals: Default::default(),
+            dependencies: vec![],
+        };
+
+        for item in module.body {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                    let source = info.resolve(&import.src.value, ids);
+
+                    if import.specifiers.is_empty() {
+                        if let Source::External(src) = &source {
+                            info.side_effect_imports.push(src.clone());
+                        }
+                    }
+
+                    for specifier in import.specifiers {
+                        let (local, kind, type_only) = match specifier {
+                            ImportSpecifier::Named(ImportNamedSpecifier {
+                                local,
+                                imported,
+                                is_type_only,
+                                ..
+                            }) => {
+                                let imported = match imported {
+                                    Some(imported) => imported.atom().clone(),
+                                    None => local.sym.clone(),
+                                };
+
+                                (local, ImportKind::Named(imported), is_type_only)
+                            }
+                            ImportSpecifier::Default(ImportDefaultSpecifier { local, .. }) => {
+                                (local, ImportKind::Named("default".into()), false)
+                            }
+                            ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                                (local, ImportKind::Namespace, false)
+                            }
+                        };
+
+                        info.add_external(&source, &kind, import.type_only || type_only);
+                        info.imports.insert(local.sym, (source.clone(), kind));
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+                    for name in decl_names(&export.decl) {
+                        info.exports.push((name.clone(), Export::Local(name)));
+                    }
+
+                    info.add_item(ModuleItem::Stmt(Stmt::Decl(into_declaration(export.decl))));
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) => {
+                    let source = export.src.map(|src| info.resolve(&src.value, ids));
+
+                    for specifier in export.specifiers {
+                        let (exported, export) = match (specifier, &source) {
+                            (
+                                ExportSpecifier::Named(ExportNamedSpecifier {
+                                    orig,
+                                    exported,
+                                    is_type_only,
+                                    ..
+                                }),
+                                _,
+                            ) => {
+                                let orig = orig.atom().clone();
+                                let exported = match exported {
+                                    Some(exported) => exported.atom().clone(),
+                                    None => orig.clone(),
+                                };
+
+                                match &source {
+                                    Some(source) => {
+                                        let kind = ImportKind::Named(orig);
+
+                                        info.add_external(
+                                            source,
+                                            &kind,
+                                            export.type_only || is_type_only,
+                                        );
+                                        (exported, Export::Reexport(source.clone(), kind))
+                                    }
+                                    None => (exported, Export::Local(orig)),
+                                }
+                            }
+                            (ExportSpecifier::Namespace(specifier), Some(source)) => {
+                                info.add_external(source, &ImportKind::Namespace, export.type_only);
+                                (
+                                    specifier.name.atom().clone(),
+                                    Export::Reexport(source.clone(), ImportKind::Namespace),
+                                )
+                            }
+                            (specifier, _) => {
+                                return Err(DtsBundleError::Unsupported {
+                                    module: info.id,
+                                    span: specifier.span(),
+                                })
+                            }
+                        };
+
+                        info.exports.push((exported, export));
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export)) => {
+                    let source = info.resolve(&export.src.value, ids);
+
+                    info.star_exports.push(source);
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)) => {
+                    let default_ident = || Ident::new_no_ctxt("_default".into(), DUMMY_SP);
+                    let decl = match export.decl {
+                        DefaultDecl::Class(class) => Decl::Class(ClassDecl {
+                            ident: class.ident.unwrap_or_else(default_ident),
+                            declare: true,
+                            class: class.class,
+                        }),
+                        DefaultDecl::Fn(function) => Decl::Fn(FnDecl {
+                            ident: function.ident.unwrap_or_else(default_ident),
+                            declare: true,
+                            function: function.function,
+                        }),
+                        DefaultDecl::TsInterfaceDecl(interface) => Decl::TsInterface(interface),
+                    };
+
+                    for name in decl_names(&decl) {
+                        info.exports.push(("default".into(), Export::Local(name)));
+                    }
+
+                    info.add_item(ModuleItem::Stmt(Stmt::Decl(decl)));
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
+                    let Expr::Ident(ident) = *export.expr else {
+                        return Err(DtsBundleError::Unsupported {
+                            module: info.id,
+                            span: export.span,
+                        });
+                    };
+
+                    info.exports
+                        .push(("default".into(), Export::Local(ident.sym)));
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(mut import))
+                    if matches!(import.module_ref, TsModuleRef::TsEntityName(..)) =>
+                {
+                    if import.is_export {
+                        import.is_export = false;
+                        info.exports
+                            .push((import.id.sym.clone(), Export::Local(import.id.sym.clone())));
+                    }
+
+                    info.add_item(ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)));
+                }
+                ModuleItem::ModuleDecl(decl) => {
+                    return Err(DtsBundleError::Unsupported {
+                        module: info.id,
+                        span: decl.span(),
+                    })
+                }
+                ModuleItem::Stmt(Stmt::Decl(decl)) => {
+                    info.add_item(ModuleItem::Stmt(Stmt::Decl(decl)));
+                }
+                ModuleItem::Stmt(..) => {}
+            }
+        }
+
+        Ok(info)
+    }
+
+    fn resolve(&mut self, specifier: &Atom, ids: &AHashMap<String, usize>) -> Source {
+        match resolve_specifier(&self.id, specifier, ids) {
+            Some(index) => {
+                if !self.dependencies.contains(&index) {
+                    self.dependencies.push(index);
+                }
+
+                Source::Internal(index)
+            }
+            None => Source::External(specifier.clone()),
+        }
+    }
+
+    fn add_external(&mut self, source: &Source, kind: &ImportKind, type_only: bool) {
+        if let (Source::External(src), false) = (source, type_only) {
+            self.value_externals
+                .insert(Binding::External(src.clone(), kind.clone()));
+        }
+    }
+
+    fn add_item(&mut self, item: ModuleItem) {
+        let names = match &item {
+            ModuleItem::Stmt(Stmt::Decl(decl)) => decl_names(decl),
+            ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)) => {
+                vec![import.id.sym.clone()]
+            }
+            _ => vec![],
+        };
+
+        for name in &names {
+            self.locals
+                .entry(name.clone())
+                .or_default()
+                .push(self.items.len());
+        }
+
+        self.items.push(item);
+        self.item_names.push(names);
+    }
+}
+
+struct Bundle {
+    modules: Vec<ModuleInfo>,
+    /// Included bindings with the preferred name, in the order of discovery.
+    bindings: Vec<(Binding, Atom)>,
+    seen: AHashSet<Binding>,
+    /// Whether each item of each module is included.
+    included: Vec<Vec<bool>>,
+    /// Referenced names which are not declared by any module, e.g. `Promise`.
+    globals: AHashSet<Atom>,
+    /// Members of included namespace objects.
+    namespaces: Vec<(usize, Vec<(Atom, Binding)>)>,
+}
+
+impl Bundle {
+    fn bundle(mut self, entry: usize) -> Result<Module, DtsBundleError> {
+        self.included = self
+            .modules
+            .iter()
+            .map(|info| vec![false; info.items.len()])
+            .collect();
+
+        let mut order = vec![];
+
+        self.sort_modules(entry, &mut AHashSet::default(), &mut order);
+
+        let mut external_stars = vec![];
+        let exports = self.collect_exports(entry, &mut external_stars)?;
+
+        for (exported, binding) in &exports {
+            self.include(binding.clone(), exported.clone());
+        }
+
+        // Module augmentations can't be tree-shaken
+        for &module in &order {
+            for index in 0..self.modules[module].items.len() {
+                if self.modules[module].item_names[index].is_empty() {
+                    self.include_item(module, index)?;
+                }
+            }
+        }
+
+        let mut next = 0;
+
+        while let Some((binding, _)) = self.bindings.get(next).cloned() {
+            next += 1;
+
+            match binding {
+                Binding::Local(module, name) => {
+                    let indexes = self.modules[module]
+                        .locals
+                        .get(&name)
+                        .cloned()
+                        .unwrap_or_default();
+
+                    for index in indexes {
+                        self.include_item(module, index)?;
+                    }
+                }
+                Binding::Namespace(module) => {
+                    let members = self.collect_exports(module, &mut vec![])?;
+
+                    for (exported, binding) in &members {
+                        self.include(binding.clone(), exported.clone());
+                    }
+
+                    self.namespaces.push((module, members));
+                }
+                Binding::External(..) => {}
+            }
+        }
+
+        let names = self.assign_names();
+
+        let mut body = self.create_imports(&names, &order);
+
+        for module in order {
+            let mut renames = AHashMap::default();
+            let info = &self.modules[module];
+
+            for name in info.locals.keys() {
+                if let Some(new_name) = names.get(&Binding::Local(module, name.clone())) {
+                    renames.insert(name.clone(), new_name.clone());
+                }
+            }
+
+            for (local, (source, kind)) in &info.imports {
+                if let Ok(binding) = self.resolve_import(source, kind, &mut Default::default()) {
+                    if let Some(new_name) = names.get(&binding) {
+                        renames.insert(local.clone(), new_name.clone());
+                    }
+                }
+            }
+
+            let mut renamer = Renamer {
+                names: &renames,
+                scopes: vec![],
+            };
+
+            for (index, item) in self.modules[module].items.iter_mut().enumerate() {
+                if self.included[module][index] {
+                    let mut item = item.take();
+
+                    item.visit_mut_with(&mut renamer);
+                    body.push(item);
+                }
+            }
+        }
+
+        for (module, members) in &self.namespaces {
+            let specifiers = members
+                .iter()
+                .map(|(exported, binding)| export_specifier(&names[binding], exported))
+                .collect();
+
+            body.push(ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(Box::new(
+                TsModuleDecl {
+                    span: DUMMY_SP,
+                    declare: true,
+                    global: false,
+                    id: TsModuleName::Ident(Ident::new_no_ctxt(
+                        names[&Binding::Namespace(*module)].clone(),
+                        DUMMY_SP,
+                    )),
+                    body: Some(TsNamespaceBody::TsModuleBlock(TsModuleBlock {
+                        span: DUMMY_SP,
+                        body: vec![named_export(specifiers)],
+                    })),
+                },
+            )))));
+        }
+
+        for src in external_stars {
+            body.push(ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ExportAll {
+                span: DUMMY_SP,
+                src: Box::new(src.into()),
+                type_only: false,
+                with: None,
+            })));
+        }
+
+        if !exports.is_empty() {
+            body.push(named_export(
+                exports
+                    .iter()
+                    .map(|(exported, binding)| export_specifier(&names[binding], exported))
+                    .collect(),
+            ));
+        }
+
+        Ok(Module {
+            span: DUMMY_SP,
+            body,
+            shebang: None,
+        })
+    }
+
+    /// Sorts modules of the graph so dependencies come first.
+    fn sort_modules(&self, module: usize, visited: &mut AHashSet<usize>, order: &mut Vec<usize>) {
+        if !visited.insert(module) {
+            return;
+        }
+
+        for &dependency in &self.modules[module].dependencies {
+            self.sort_modules(dependency, visited, order);
+        }
+
+        order.push(module);
+    }
+
+    /// Returns all exports of `module`, including names exported by `export *`
+    /// declarations. Specifiers of `export *` declarations of external modules
+    /// are added to `external_stars`.
+    fn collect_exports(
+        &self,
+        module: usize,
+        external_stars: &mut Vec<Atom>,
+    ) -> Result<Vec<(Atom, Binding)>, DtsBundleError> {
+        let mut names = vec![];
+
+        self.collect_export_names(
+            module,
+            true,
+            &mut names,
+            external_stars,
+            &mut AHashSet::default(),
+        );
+
+        names
+            .into_iter()
+            .map(|name| {
+                let binding = self.resolve_export(module, &name, &mut Default::default())?;
+
+                match binding {
+                    Some(binding) => Ok((name, binding)),
+                    None => Err(self.missing_export(module, name)),
+                }
+            })
+            .collect()
+    }
+
+    fn collect_export_names(
+        &self,
+        module: usize,
+        is_root: bool,
+        names: &mut Vec<Atom>,
+        external_stars: &mut Vec<Atom>,
+        visited: &mut AHashSet<usize>,
+    ) {
+        if !visited.insert(module) {
+            return;
+        }
+
+        let info = &self.modules[module];
+
+        for (exported, _) in &info.exports {
+            // `export *` doesn't re-export the default export
+            if (is_root || exported != "default") && !names.contains(exported) {
+                names.push(exported.clone());
+            }
+        }
+
+        for source in &info.star_exports {
+            match source {
+                Source::Internal(module) => {
+                    self.collect_export_names(*module, false, names, external_stars, visited)
+                }
+                Source::External(src) => {
+                    if !external_stars.contains(src) {
+                        external_stars.push(src.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    fn resolve_export(
+        &self,
+        module: usize,
+        name: &Atom,
+        visited: &mut AHashSet<(usize, Atom)>,
+    ) -> Result<Option<Binding>, DtsBundleError> {
+        if !visited.insert((module, name.clone())) {
+            return Ok(None);
+        }
+
+        let info = &self.modules[module];
+
+        if let Some((_, export)) = info.exports.iter().find(|(exported, _)| exported == name) {
+            return match export {
+                Export::Local(local) => self.resolve_local(module, local, visited),
+                Export::Reexport(source, kind) => {
+                    self.resolve_import(source, kind, visited).map(Some)
+                }
+            };
+        }
+
+        if name == "default" {
+            return Ok(None);
+        }
+
+        for source in &info.star_exports {
+            if let Source::Internal(module) = source {
+                if let Some(binding) = self.resolve_export(*module, name, visited)? {
+                    return Ok(Some(binding));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns [None] for names which are not declared or imported by
+    /// `module`, i.e. globals.
+    fn resolve_local(
+        &self,
+        module: usize,
+        name: &Atom,
+        visited: &mut AHashSet<(usize, Atom)>,
+    ) -> Result<Option<Binding>, DtsBundleError> {
+        let info = &self.modules[module];
+
+        if let Some((source, kind)) = info.imports.get(name) {
+            return self.resolve_import(source, kind, visited).map(Some);
+        }
+
+        if info.locals.contains_key(name) {
+            return Ok(Some(Binding::Local(module, name.clone())));
+        }
+
+        Ok(None)
+    }
+
+    fn resolve_import(
+        &self,
+        source: &Source,
+        kind: &ImportKind,
+        visited: &mut AHashSet<(usize, Atom)>,
+    ) -> Result<Binding, DtsBundleError> {
+        match (source, kind) {
+            (Source::External(src), _) => Ok(Binding::External(src.clone(), kind.clone())),
+            (Source::Internal(module), ImportKind::Namespace) => Ok(Binding::Namespace(*module)),
+            (Source::Internal(module), ImportKind::Named(name)) => {
+                match self.resolve_export(*module, name, visited)? {
+                    Some(binding) => Ok(binding),
+                    None => Err(self.missing_export(*module, name.clone())),
+                }
+            }
+        }
+    }
+
+    fn missing_export(&self, module: usize, name: Atom) -> DtsBundleError {
+        DtsBundleError::MissingExport {
+            module: self.modules[module].id.clone(),
+            name,
+        }
+    }
+
+    fn include(&mut self, binding: Binding, preferred_name: Atom) {
+        if self.seen.insert(binding.clone()) {
+            self.bindings.push((binding, preferred_name));
+        }
+    }
+
+    /// Includes an item and the bindings it references.
+    fn include_item(&mut self, module: usize, index: usize) -> Result<(), DtsBundleError> {
+        if self.included[module][index] {
+            return Ok(());
+        }
+
+        self.included[module][index] = true;
+
+        let mut collector = ReferenceCollector::default();
+
+        self.modules[module].items[index].visit_with(&mut collector);
+
+        for name in collector.references {
+            if collector.type_params.contains(&name)
+                || self.modules[module].item_names[index].contains(&name)
+            {
+                continue;
+            }
+
+            match self.resolve_local(module, &name, &mut Default::default())? {
+                Some(binding) => self.include(binding, name),
+                None => {
+                    self.globals.insert(name);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Assigns unique names to included bindings, preferring the original
+    /// ones. Bindings of the entry module come first, so its names are kept.
+    fn assign_names(&self) -> AHashMap<Binding, Atom> {
+        let mut used = self.globals.clone();
+        let mut names = AHashMap::default();
+
+        for (binding, preferred_name) in &self.bindings {
+            let base = match binding {
+                Binding::Local(_, name) => name,
+                Binding::External(_, ImportKind::Named(name)) if name != "default" => name,
+                _ => preferred_name,
+            };
+            let mut name = base.clone();
+            let mut suffix = 1;
+
+            while used.contains(&name) {
+                name = format!("{}${}", base, suffix).into();
+                suffix += 1;
+            }
+
+            used.insert(name.clone());
+            names.insert(binding.clone(), name);
+        }
+
+        names
+    }
+
+    /// Creates imports of external bindings, grouped by module, and imports of
+    /// external modules which are imported only for their side effects.
+    fn create_imports(&self, names: &AHashMap<Binding, Atom>, order: &[usize]) -> Vec<ModuleItem> {
+        let mut imports: Vec<ImportDecl> = vec![];
+
+        for (binding, _) in &self.bindings {
+            let Binding::External(src, kind) = binding else {
+                continue;
+            };
+            let type_only = order
+                .iter()
+                .all(|&module| !self.modules[module].value_externals.contains(binding));
+
+            let local = Ident::new_no_ctxt(names[binding].clone(), DUMMY_SP);
+            let specifier = match kind {
+                ImportKind::Named(name) if name == "default" => {
+                    ImportSpecifier::Default(ImportDefaultSpecifier {
+                        span: DUMMY_SP,
+                        local,
+                    })
+                }
+                ImportKind::Named(name) => ImportSpecifier::Named(ImportNamedSpecifier {
+                    span: DUMMY_SP,
+                    imported: (*name != local.sym).then(|| {
+                        ModuleExportName::Ident(Ident::new_no_ctxt(name.clone(), DUMMY_SP))
+                    }),
+                    local,
+                    is_type_only: false,
+                }),
+                ImportKind::Namespace => ImportSpecifier::Namespace(ImportStarAsSpecifier {
+                    span: DUMMY_SP,
+                    local,
+                }),
+            };
+
+            // A namespace import can be combined only with a default import, and a
+            // type-only import can't combine a default import with other ones
+            let existing = imports.iter_mut().find(|import| {
+                import.src.value == *src
+                    && import.type_only == type_only
+                    && match &specifier {
+                        ImportSpecifier::Named(..) if type_only => import
+                            .specifiers
+                            .iter()
+                            .all(|specifier| specifier.is_named()),
+                        _ if type_only => false,
+                        ImportSpecifier::Named(..) => import
+                            .specifiers
+                            .iter()
+                            .all(|specifier| !specifier.is_namespace()),
+                        ImportSpecifier::Default(..) => import
+                            .specifiers
+                            .iter()
+                            .all(|specifier| !specifier.is_default()),
+                        ImportSpecifier::Namespace(..) => import
+                            .specifiers
+                            .iter()
+                            .all(|specifier| specifier.is_default()),
+                    }
+            });
+
+            match existing {
+                Some(import) => {
+                    if specifier.is_default() {
+                        import.specifiers.insert(0, specifier);
+                    } else {
+                        import.specifiers.push(specifier);
+                    }
+                }
+                None => imports.push(ImportDecl {
+                    span: DUMMY_SP,
+                    specifiers: vec![specifier],
+                    src: Box::new(src.clone().into()),
+                    type_only,
+                    with: None,
+                    phase: Default::default(),
+                }),
+            }
+        }
+
+        let mut side_effect_imports = vec![];
+
+        for &module in order {
+            for src in &self.modules[module].side_effect_imports {
+                if side_effect_imports
+                    .iter()
+                    .chain(&imports)
+                    .all(|import: &ImportDecl| import.src.value != *src)
+                {
+                    side_effect_imports.push(ImportDecl {
+                        span: DUMMY_SP,
+                        specifiers: vec![],
+                        src: Box::new(src.clone().into()),
+                        type_only: false,
+                        with: None,
+                        phase: Default::default(),
+                    });
+                }
+            }
+        }
+
+        side_effect_imports
+            .into_iter()
+            .chain(imports)
+            .map(|import| ModuleItem::ModuleDecl(ModuleDecl::Import(import)))
+            .collect()
+    }
+}
+
+fn export_specifier(orig: &Atom, exported: &Atom) -> ExportSpecifier {
+    ExportSpecifier::Named(ExportNamedSpecifier {
+        span: DUMMY_SP,
+        orig: ModuleExportName::Ident(Ident::new_no_ctxt(orig.clone(), DUMMY_SP)),
+        exported: (orig != exported)
+            .then(|| ModuleExportName::Ident(Ident::new_no_ctxt(exported.clone(), DUMMY_SP))),
+        is_type_only: false,
+    })
+}
+
+fn named_export(specifiers: Vec<ExportSpecifier>) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+        span: DUMMY_SP,
+        specifiers,
+        src: None,
+        type_only: false,
+        with: None,
+    }))
+}
+
+/// Returns names declared by `decl`. Module declarations with a string name
+/// and `declare global` augment other modules and declare nothing.
+fn decl_names(decl: &Decl) -> Vec<Atom> {
+    match decl {
+        Decl::Class(ClassDecl { ident, .. }) | Decl::Fn(FnDecl { ident, .. }) => {
+            vec![ident.sym.clone()]
+        }
+        Decl::Var(var) => var
+            .decls
+            .iter()
+            .filter_map(|decl| decl.name.as_ident())
+            .map(|ident| ident.sym.clone())
+            .collect(),
+        Decl::TsInterface(interface) => vec![interface.id.sym.clone()],
+        Decl::TsTypeAlias(alias) => vec![alias.id.sym.clone()],
+        Decl::TsEnum(ts_enum) => vec![ts_enum.id.sym.clone()],
+        Decl::TsModule(module) => match &module.id {
+            TsModuleName::Ident(ident) if !module.global => vec![ident.sym.clone()],
+            _ => vec![],
+        },
+        Decl::Using(..) => vec![],
+    }
+}
+
+/// Adds `declare` to a declaration which was exported.
+fn into_declaration(mut decl: Decl) -> Decl {
+    match &mut decl {
+        Decl::Class(class) => class.declare = true,
+        Decl::Fn(function) => function.declare = true,
+        Decl::Var(var) => var.declare = true,
+        Decl::TsEnum(ts_enum) => ts_enum.declare = true,
+        Decl::TsModule(module) => module.declare = true,
+        Decl::TsInterface(..) | Decl::TsTypeAlias(..) | Decl::Using(..) => {}
+    }
+
+    decl
+}
+
+fn resolve_specifier(
+    importer: &str,
+    specifier: &str,
+    ids: &AHashMap<String, usize>,
+) -> Option<usize> {
+    if !(specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || specifier == "."
+        || specifier == "..")
+    {
+        return None;
+    }
+
+    let importer = normalize_path(importer);
+    let dir = match importer.rfind('/') {
+        Some(index) => &importer[..index + 1],
+        None => "",
+    };
+    let path = strip_extension(&normalize_path(&format!("{}{}", dir, specifier)));
+
+    ids.get(&path)
+        .or_else(|| ids.get(&format!("{}/index", path)))
+        .copied()
+}
+
+/// Removes `.` and `..` segments and converts backslashes.
+fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let mut segments: Vec<&str> = vec![];
+
+    for segment in path.split('/') {
+        match segment {
+            "." => {}
+            ".." if matches!(segments.last(), Some(last) if *last != ".." && !last.is_empty()) => {
+                segments.pop();
+            }
+            "" if !segments.is_empty() => {}
+            _ => segments.push(segment),
+        }
+    }
+
+    segments.join("/")
+}
+
+fn strip_extension(path: &str) -> String {
+    const EXTENSIONS: &[&str] = &[
+        ".d.ts", ".d.mts", ".d.cts", ".ts", ".tsx", ".mts", ".cts", ".js", ".jsx", ".mjs", ".cjs",
+    ];
+
+    for extension in EXTENSIONS {
+        if let Some(path) = path.strip_suffix(extension) {
+            return path.to_string();
+        }
+    }
+
+    path.to_string()
+}
+
+/// Collects identifiers which may reference top-level declarations, skipping
+/// property names and parameters.
+#[derive(Default)]
+struct ReferenceCollector {
+    references: Vec<Atom>,
+    seen: AHashSet<Atom>,
+    type_params: AHashSet<Atom>,
+}
+
+impl Visit for ReferenceCollector {
+    fn visit_ident(&mut self, n: &Ident) {
+        if self.seen.insert(n.sym.clone()) {
+            self.references.push(n.sym.clone());
+        }
+    }
+
+    fn visit_binding_ident(&mut self, n: &swc_ecma_ast::BindingIdent) {
+        n.type_ann.visit_with(self);
+    }
+
+    fn visit_ts_type_param(&mut self, n: &TsTypeParam) {
+        self.type_params.insert(n.name.sym.clone());
+
+        n.constraint.visit_with(self);
+        n.default.visit_with(self);
+    }
+
+    fn visit_ts_property_signature(&mut self, n: &TsPropertySignature) {
+        if n.computed {
+            n.key.visit_with(self);
+        }
+
+        n.type_ann.visit_with(self);
+    }
+
+    fn visit_ts_getter_signature(&mut self, n: &TsGetterSignature) {
+        if n.computed {
+            n.key.visit_with(self);
+        }
+
+        n.type_ann.visit_with(self);
+    }
+
+    fn visit_ts_setter_signature(&mut self, n: &TsSetterSignature) {
+        if n.computed {
+            n.key.visit_with(self);
+        }
+
+        n.param.visit_with(self);
+    }
+
+    fn visit_ts_method_signature(&mut self, n: &TsMethodSignature) {
+        if n.computed {
+            n.key.visit_with(self);
+        }
+
+        n.params.visit_with(self);
+        n.type_ann.visit_with(self);
+        n.type_params.visit_with(self);
+    }
+
+    fn visit_ts_import_type(&mut self, n: &TsImportType) {
+        n.type_args.visit_with(self);
+    }
+
+    fn visit_ts_enum_member(&mut self, n: &TsEnumMember) {
+        n.init.visit_with(self);
+    }
+}
+
+/// Renames top-level identifiers of a module, except property names and
+/// names shadowed by declarations of namespaces or type parameters.
+struct Renamer<'a> {
+    names: &'a AHashMap<Atom, Atom>,
+    /// Names declared by the enclosing namespaces and type parameter lists.
+    scopes: Vec<Vec<Atom>>,
+}
+
+impl Renamer<'_> {
+    fn visit_mut_in_scope<N>(&mut self, names: Vec<Atom>, n: &mut N)
+    where
+        N: VisitMutWith<Self>,
+    {
+        self.scopes.push(names);
+        n.visit_mut_children_with(self);
+        self.scopes.pop();
+    }
+
+    fn visit_mut_signature<N>(
+        &mut self,
+        n: &mut N,
+        key: fn(&mut N) -> &mut Box<Expr>,
+        computed: bool,
+    ) where
+        N: VisitMutWith<Self>,
+    {
+        if computed {
+            n.visit_mut_children_with(self);
+        } else {
+            let name = key(n).take();
+
+            n.visit_mut_children_with(self);
+            *key(n) = name;
+        }
+    }
+}
+
+impl VisitMut for Renamer<'_> {
+    fn visit_mut_ident(&mut self, n: &mut Ident) {
+        if self.scopes.iter().any(|names| names.contains(&n.sym)) {
+            return;
+        }
+
+        if let Some(name) = self.names.get(&n.sym) {
+            n.sym = name.clone();
+        }
+    }
+
+    fn visit_mut_ts_module_block(&mut self, n: &mut TsModuleBlock) {
+        let names = n
+            .body
+            .iter()
+            .flat_map(|item| match item {
+                ModuleItem::Stmt(Stmt::Decl(decl))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                    decl_names(decl)
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)) => {
+                    vec![import.id.sym.clone()]
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => import
+                    .specifiers
+                    .iter()
+                    .map(|specifier| specifier.local().sym.clone())
+                    .collect(),
+                _ => vec![],
+            })
+            .collect();
+
+        self.visit_mut_in_scope(names, n);
+    }
+
+    fn visit_mut_ts_namespace_decl(&mut self, n: &mut TsNamespaceDecl) {
+        // `B` of `namespace A.B {}` is a member of `A`
+        n.body.visit_mut_with(self);
+    }
+
+    fn visit_mut_function(&mut self, n: &mut Function) {
+        self.visit_mut_in_scope(type_param_names(&n.type_params), n);
+    }
+
+    fn visit_mut_class(&mut self, n: &mut Class) {
+        self.visit_mut_in_scope(type_param_names(&n.type_params), n);
+    }
+
+    fn visit_mut_ts_interface_decl(&mut self, n: &mut TsInterfaceDecl) {
+        self.visit_mut_in_scope(type_param_names(&n.type_params), n);
+    }
+
+    fn visit_mut_ts_type_alias_decl(&mut self, n: &mut TsTypeAliasDecl) {
+        self.visit_mut_in_scope(type_param_names(&n.type_params), n);
+    }
+
+    fn visit_mut_ts_fn_type(&mut self, n: &mut TsFnType) {
+        self.visit_mut_in_scope(type_param_names(&n.type_params), n);
+    }
+
+    fn visit_mut_ts_constructor_type(&mut self, n: &mut TsConstructorType) {
+        self.visit_mut_in_scope(type_param_names(&n.type_params), n);
+    }
+
+    fn visit_mut_ts_call_signature_decl(&mut self, n: &mut TsCallSignatureDecl) {
+        self.visit_mut_in_scope(type_param_names(&n.type_params), n);
+    }
+
+    fn visit_mut_ts_construct_signature_decl(&mut self, n: &mut TsConstructSignatureDecl) {
+        self.visit_mut_in_scope(type_param_names(&n.type_params), n);
+    }
+
+    fn visit_mut_ts_mapped_type(&mut self, n: &mut TsMappedType) {
+        self.visit_mut_in_scope(vec![n.type_param.name.sym.clone()], n);
+    }
+
+    fn visit_mut_ts_conditional_type(&mut self, n: &mut TsConditionalType) {
+        let mut collector = InferTypeCollector::default();
+
+        n.extends_type.visit_with(&mut collector);
+
+        n.check_type.visit_mut_with(self);
+        n.false_type.visit_mut_with(self);
+
+        self.scopes.push(collector.names);
+        n.extends_type.visit_mut_with(self);
+        n.true_type.visit_mut_with(self);
+        self.scopes.pop();
+    }
+
+    fn visit_mut_ts_property_signature(&mut self, n: &mut TsPropertySignature) {
+        let computed = n.computed;
+
+        self.visit_mut_signature(n, |n| &mut n.key, computed);
+    }
+
+    fn visit_mut_ts_getter_signature(&mut self, n: &mut TsGetterSignature) {
+        let computed = n.computed;
+
+        self.visit_mut_signature(n, |n| &mut n.key, computed);
+    }
+
+    fn visit_mut_ts_setter_signature(&mut self, n: &mut TsSetterSignature) {
+        let computed = n.computed;
+
+        self.visit_mut_signature(n, |n| &mut n.key, computed);
+    }
+
+    fn visit_mut_ts_method_signature(&mut self, n: &mut TsMethodSignature) {
+        let computed = n.computed;
+
+        self.scopes.push(type_param_names(&n.type_params));
+        self.visit_mut_signature(n, |n| &mut n.key, computed);
+        self.scopes.pop();
+    }
+
+    fn visit_mut_ts_import_type(&mut self, n: &mut TsImportType) {
+        n.type_args.visit_mut_with(self);
+    }
+
+    fn visit_mut_ts_enum_member(&mut self, n: &mut TsEnumMember) {
+        n.init.visit_mut_with(self);
+    }
+}
+
+fn type_param_names(type_params: &Option<Box<TsTypeParamDecl>>) -> Vec<Atom> {
+    type_params
+        .iter()
+        .flat_map(|decl| &decl.params)
+        .map(|param| param.name.sym.clone())
+        .collect()
+}
+
+/// Collects names declared by `infer` types.
+#[derive(Default)]
+struct InferTypeCollector {
+    names: Vec<Atom>,
+}
+
+impl Visit for InferTypeCollector {
+    fn visit_ts_infer_type(&mut self, n: &TsInferType) {
+        self.names.push(n.type_param.name.sym.clone());
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_conditional_type(&mut self, n: &TsConditionalType) {
+        // `infer` types of nested conditional types are not in scope
+        n.check_type.visit_with(self);
+        n.false_type.visit_with(self);
+    }
+}
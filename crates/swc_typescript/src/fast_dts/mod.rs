@@ -4,11 +4,12 @@ use swc_atoms::Atom;
 use swc_common::{util::take::Take, FileName, Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::{
     BindingIdent, ClassMember, Decl, DefaultDecl, ExportDecl, ExportDefaultDecl, ExportDefaultExpr,
-    Expr, FnDecl, FnExpr, Ident, Lit, MethodKind, Module, ModuleDecl, ModuleItem, OptChainBase,
-    Pat, Prop, PropName, PropOrSpread, Stmt, TsEntityName, TsFnOrConstructorType, TsFnParam,
-    TsFnType, TsKeywordType, TsKeywordTypeKind, TsLit, TsLitType, TsNamespaceBody,
-    TsPropertySignature, TsTupleElement, TsTupleType, TsType, TsTypeAnn, TsTypeElement, TsTypeLit,
-    TsTypeOperator, TsTypeOperatorOp, TsTypeRef, VarDecl, VarDeclKind, VarDeclarator,
+    Expr, FnExpr, Ident, Lit, MethodKind, Module, ModuleDecl, ModuleItem, NamedExport,
+    OptChainBase, Pat, Prop, PropName, PropOrSpread, Stmt, TsEntityName, TsFnOrConstructorType,
+    TsFnParam, TsFnType, TsKeywordType, TsKeywordTypeKind, TsLit, TsLitType, TsModuleName,
+    TsNamespaceBody, TsPropertySignature, TsTupleElement, TsTupleType, TsType, TsTypeAnn,
+    TsTypeElement, TsTypeLit, TsTypeOperator, TsTypeOperatorOp, TsTypeRef, VarDecl, VarDeclKind,
+    VarDeclarator,
 };
 
 use crate::diagnostic::{DtsIssue, SourceRange};
@@ -84,27 +85,22 @@ impl FastDts {
         let orig_items = take(items);
         let mut new_items = Vec::with_capacity(orig_items.len());
 
-        let mut prev_is_overload = false;
+        // Name of the function if the previous item is an overload signature.
+        let mut prev_overload: Option<Atom> = None;
 
         for mut item in orig_items {
-            let is_overload = match &item {
-                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. }))
-                | ModuleItem::Stmt(Stmt::Decl(decl)) => match decl {
-                    Decl::Fn(FnDecl {
-                        function, declare, ..
-                    }) => !declare && function.body.is_none(),
-                    _ => false,
-                },
-
-                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
-                    decl,
-                    ..
-                })) => {
-                    matches!(decl, DefaultDecl::Fn(FnExpr { function, .. }) if function.body.is_none())
-                }
-
-                _ => false,
-            };
+            let signature = fn_signature(&item);
+
+            // The implementation of an overloaded function is not part of its
+            // declaration.
+            let is_overload_impl = matches!(
+                (&prev_overload, &signature),
+                (Some(prev), Some((name, false))) if prev == name
+            );
+            prev_overload = signature.and_then(|(name, is_overload)| is_overload.then_some(name));
+            if is_overload_impl {
+                continue;
+            }
 
             match &mut item {
                 // Keep all these
@@ -120,13 +116,6 @@ impl FastDts {
                 ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
                     span, decl, ..
                 })) => {
-                    let should_keep = prev_is_overload && !is_overload;
-
-                    if should_keep {
-                        prev_is_overload = is_overload;
-                        continue;
-                    }
-
                     if let Some(()) = self.decl_to_type_decl(decl) {
                         new_items.push(
                             ExportDecl {
@@ -153,24 +142,12 @@ impl FastDts {
                         DefaultDecl::TsInterfaceDecl(_) => {}
                     };
 
-                    let should_keep = prev_is_overload && !is_overload;
-                    prev_is_overload = is_overload;
-                    if should_keep {
-                        continue;
-                    }
-
                     new_items.push(item);
                 }
 
                 ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export)) => {
-                    let should_keep = prev_is_overload && !is_overload;
-                    prev_is_overload = is_overload;
-                    if should_keep {
-                        continue;
-                    }
-
                     let name = self.gen_unique_name();
-                    let name_ident = Ident::new_no_ctxt(name, DUMMY_SP);
+                    let name_ident = Ident::new_no_ctxt(name, export.expr.span());
                     let type_ann = self
                         .expr_to_ts_type(export.expr.clone(), false, true)
                         .map(type_ann);
@@ -178,11 +155,11 @@ impl FastDts {
                     if let Some(type_ann) = type_ann {
                         new_items.push(
                             VarDecl {
-                                span: DUMMY_SP,
+                                span: export.span,
                                 kind: VarDeclKind::Const,
                                 declare: true,
                                 decls: vec![VarDeclarator {
-                                    span: DUMMY_SP,
+                                    span: export.expr.span(),
                                     name: Pat::Ident(BindingIdent {
                                         id: name_ident.clone(),
 
@@ -234,8 +211,6 @@ impl FastDts {
 
                 ModuleItem::Stmt(..) => {}
             }
-
-            prev_is_overload = is_overload;
         }
 
         *items = new_items;
@@ -311,13 +286,14 @@ impl FastDts {
                                         }
                                     };
 
+                                    let span = key.span().with_hi(key_value.value.span_hi());
                                     let init_type = self
                                         .expr_to_ts_type(key_value.value, as_const, as_readonly)
                                         .map(type_ann);
 
                                     members.push(TsTypeElement::TsPropertySignature(
                                         TsPropertySignature {
-                                            span: DUMMY_SP,
+                                            span,
                                             readonly: as_readonly,
                                             key: Box::new(key),
                                             computed,
@@ -349,11 +325,14 @@ impl FastDts {
                 })))
             }
             Expr::Lit(lit) => {
-                if as_const {
+                let ts_type = if as_const {
                     maybe_lit_to_ts_type_const(&lit)
                 } else {
                     maybe_lit_to_ts_type(&lit)
-                }
+                };
+
+                // Declaration maps point inferred types to the literal
+                ts_type.map(|ts_type| with_span(ts_type, lit.span()))
             }
             Expr::TsConstAssertion(ts_const) => self.expr_to_ts_type(ts_const.expr, true, true),
             Expr::TsSatisfies(satisifies) => {
@@ -553,15 +532,26 @@ impl FastDts {
                 Some(())
             }
             Decl::TsModule(ts_module) => {
-                ts_module.declare = is_declare;
+                // `declare global`, `declare module "x"` and other ambient
+                // modules are declarations already, so only their `export`
+                // modifiers are adjusted.
+                let is_ambient = ts_module.declare
+                    || ts_module.global
+                    || matches!(ts_module.id, TsModuleName::Str(_));
 
-                if let Some(body) = ts_module.body.take() {
-                    ts_module.body = Some(self.transform_ts_ns_body(body));
+                ts_module.declare = is_declare;
 
-                    Some(())
-                } else {
-                    Some(())
+                if let Some(body) = &mut ts_module.body {
+                    if is_ambient {
+                        if !ts_module.global {
+                            fix_ambient_module_exports(body);
+                        }
+                    } else {
+                        self.transform_ts_ns_body(body);
+                    }
                 }
+
+                Some(())
             }
             Decl::TsInterface(_) | Decl::TsTypeAlias(_) => Some(()),
             Decl::Using(_) => {
@@ -573,18 +563,18 @@ impl FastDts {
         }
     }
 
-    fn transform_ts_ns_body(&mut self, ns: TsNamespaceBody) -> TsNamespaceBody {
+    fn transform_ts_ns_body(&mut self, ns: &mut TsNamespaceBody) {
         let original_is_top_level = self.is_top_level;
         self.is_top_level = false;
-        let body = match ns {
-            TsNamespaceBody::TsModuleBlock(mut ts_module_block) => {
+        match ns {
+            TsNamespaceBody::TsModuleBlock(ts_module_block) => {
                 self.transform_module_items(&mut ts_module_block.body);
-                TsNamespaceBody::TsModuleBlock(ts_module_block)
+                fix_namespace_exports(&mut ts_module_block.body, false);
             }
-            TsNamespaceBody::TsNamespaceDecl(ts_ns) => self.transform_ts_ns_body(*ts_ns.body),
-        };
+            // `namespace A.B.C {}`
+            TsNamespaceBody::TsNamespaceDecl(ts_ns) => self.transform_ts_ns_body(&mut ts_ns.body),
+        }
         self.is_top_level = original_is_top_level;
-        body
     }
 
     // Support for expressions is limited in enums,
@@ -864,6 +854,102 @@ impl FastDts {
     }
 }
 
+/// Returns the name of a function declaration and whether it is an overload
+/// signature.
+fn fn_signature(item: &ModuleItem) -> Option<(Atom, bool)> {
+    match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Fn(fn_decl),
+            ..
+        }))
+        | ModuleItem::Stmt(Stmt::Decl(Decl::Fn(fn_decl))) => {
+            (!fn_decl.declare).then(|| (fn_decl.ident.sym.clone(), fn_decl.function.body.is_none()))
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::Fn(FnExpr { function, .. }),
+            ..
+        })) => Some(("default".into(), function.body.is_none())),
+        _ => None,
+    }
+}
+
+/// Emits `export` modifiers in the body of a namespace like tsc does.
+///
+/// All declarations in the body of an ambient namespace are exported unless
+/// the body has an export declaration or an export assignment, so `export`
+/// modifiers are stripped if they are redundant, and `export {}` is added if
+/// the body has declarations which should not be exported.
+fn fix_namespace_exports(items: &mut Vec<ModuleItem>, is_ambient: bool) {
+    let has_scope_marker = items.iter().any(|item| {
+        matches!(
+            item,
+            ModuleItem::ModuleDecl(
+                ModuleDecl::ExportNamed(_)
+                    | ModuleDecl::ExportAll(_)
+                    | ModuleDecl::ExportDefaultExpr(_)
+                    | ModuleDecl::TsExportAssignment(_)
+            )
+        )
+    });
+    if has_scope_marker {
+        return;
+    }
+
+    let has_local_decl = !is_ambient
+        && items
+            .iter()
+            .any(|item| matches!(item, ModuleItem::Stmt(Stmt::Decl(_))));
+    if has_local_decl {
+        items.push(
+            NamedExport {
+                span: DUMMY_SP,
+                specifiers: vec![],
+                src: None,
+                type_only: false,
+                with: None,
+            }
+            .into(),
+        );
+        return;
+    }
+
+    for item in items.iter_mut() {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+                *item = Stmt::Decl(export.decl.take()).into();
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)) => {
+                import.is_export = false;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn fix_ambient_module_exports(body: &mut TsNamespaceBody) {
+    let block = match body {
+        TsNamespaceBody::TsModuleBlock(block) => block,
+        TsNamespaceBody::TsNamespaceDecl(ts_ns) => {
+            return fix_ambient_module_exports(&mut ts_ns.body)
+        }
+    };
+
+    fix_namespace_exports(&mut block.body, true);
+
+    for item in &mut block.body {
+        if let ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::TsModule(ts_module),
+            ..
+        }))
+        | ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(ts_module))) = item
+        {
+            if let (false, Some(body)) = (ts_module.global, &mut ts_module.body) {
+                fix_ambient_module_exports(body);
+            }
+        }
+    }
+}
+
 fn any_type_ann() -> Box<TsTypeAnn> {
     type_ann(ts_keyword_type(TsKeywordTypeKind::TsAnyKeyword))
 }
@@ -917,6 +1003,17 @@ fn regex_type() -> Box<TsType> {
     Box::new(TsType::TsTypeRef(type_ref("RegExp".into())))
 }
 
+fn with_span(mut ts_type: Box<TsType>, span: Span) -> Box<TsType> {
+    match &mut *ts_type {
+        TsType::TsKeywordType(ty) => ty.span = span,
+        TsType::TsLitType(ty) => ty.span = span,
+        TsType::TsTypeRef(ty) => ty.span = span,
+        _ => {}
+    }
+
+    ts_type
+}
+
 fn maybe_lit_to_ts_type_const(lit: &Lit) -> Option<Box<TsType>> {
     match lit {
         Lit::Str(lit_str) => Some(ts_lit_type(TsLit::Str(lit_str.clone()))),
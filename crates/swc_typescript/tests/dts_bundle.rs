@@ -0,0 +1,352 @@
+use swc_ecma_ast::EsVersion;
+use swc_ecma_codegen::to_code;
+use swc_ecma_parser::{parse_file_as_module, Syntax, TsSyntax};
+use swc_typescript::{diagnostic::DtsBundleError, dts_bundle::DtsBundler};
+
+fn bundle(modules: &[(&str, &str)], entry: &str) -> Result<String, DtsBundleError> {
+    let mut result = None;
+
+    testing::run_test(false, |cm, _| {
+        let mut bundler = DtsBundler::new();
+
+        for (id, source) in modules {
+            let fm = cm.new_source_file(
+                swc_common::FileName::Real(id.into()).into(),
+                source.to_string(),
+            );
+            let module = parse_file_as_module(
+                &fm,
+                Syntax::Typescript(TsSyntax {
+                    dts: true,
+                    ..Default::default()
+                }),
+                EsVersion::latest(),
+                None,
+                &mut vec![],
+            )
+            .unwrap();
+
+            bundler.add_module(*id, module);
+        }
+
+        result = Some(bundler.bundle(entry).map(|module| to_code(&module)));
+
+        Ok(())
+    })
+    .unwrap();
+
+    result.unwrap()
+}
+
+#[track_caller]
+fn bundle_test(modules: &[(&str, &str)], expected: &str) {
+    let code = bundle(modules, modules[0].0).unwrap();
+
+    assert_eq!(
+        code.trim(),
+        expected.trim(),
+        "Actual:\n{code}\nExpected:\n{expected}"
+    );
+}
+
+#[test]
+fn dts_bundle_relative_imports_test() {
+    bundle_test(
+        &[
+            (
+                "src/index.d.ts",
+                r#"import { Options } from "./options";
+import type { Readable } from "node:stream";
+export declare function run(options: Options): Readable;
+export { Level } from "./options.js";"#,
+            ),
+            (
+                "src/options.d.ts",
+                r#"interface Base {
+    name: string;
+}
+export interface Options extends Base {
+    level: Level;
+}
+export type Level = "low" | "high";
+export declare const unused: Base;"#,
+            ),
+        ],
+        r#"import type { Readable } from "node:stream";
+interface Base {
+    name: string;
+}
+interface Options extends Base {
+    level: Level;
+}
+type Level = "low" | "high";
+declare function run(options: Options): Readable;
+export { run, Level };"#,
+    );
+}
+
+#[test]
+fn dts_bundle_type_only_imports_test() {
+    bundle_test(
+        &[
+            (
+                "index.d.ts",
+                r#"import type { Readable } from "node:stream";
+import { type Writable, Duplex } from "node:stream";
+import type Emitter from "node:events";
+import type { EventMap } from "node:events";
+export { type Server } from "node:http";
+export * from "./stream";
+export declare function pipe(a: Readable, b: Writable, c: Duplex, d: Emitter<EventMap>): void;"#,
+            ),
+            (
+                "stream.d.ts",
+                r#"import { Writable } from "node:stream";
+export declare function sink(): Writable;"#,
+            ),
+        ],
+        r#"import type { Server } from "node:http";
+import type { Readable } from "node:stream";
+import { Writable, Duplex } from "node:stream";
+import type Emitter from "node:events";
+import type { EventMap } from "node:events";
+declare function sink(): Writable;
+declare function pipe(a: Readable, b: Writable, c: Duplex, d: Emitter<EventMap>): void;
+export { Server, pipe, sink };"#,
+    );
+}
+
+#[test]
+fn dts_bundle_rename_test() {
+    bundle_test(
+        &[
+            (
+                "index.d.ts",
+                r#"import { Config as ClientConfig } from "./client";
+export interface Config {
+    client: ClientConfig;
+}"#,
+            ),
+            (
+                "client/index.d.ts",
+                r#"import { Config as ServerConfig } from "../server";
+export interface Config {
+    server: ServerConfig;
+    name: Config;
+    [Config.key]: number;
+}
+export declare namespace Config {
+    const key: unique symbol;
+}"#,
+            ),
+            (
+                "server.d.ts",
+                r#"export interface Config {
+    Config: Promise<Config>;
+}"#,
+            ),
+        ],
+        r#"interface Config$2 {
+    Config: Promise<Config$2>;
+}
+interface Config$1 {
+    server: Config$2;
+    name: Config$1;
+    [Config$1.key]: number;
+}
+declare namespace Config$1 {
+    const key: unique symbol;
+}
+interface Config {
+    client: Config$1;
+}
+export { Config };"#,
+    );
+}
+
+#[test]
+fn dts_bundle_star_exports_test() {
+    bundle_test(
+        &[
+            (
+                "index.d.ts",
+                r#"export * from "./a";
+export * as b from "./b";
+export * from "external";
+import * as c from "./c";
+import * as external from "external-namespace";
+export type C = c.C | external.C;"#,
+            ),
+            (
+                "a.d.ts",
+                r#"export declare const a: number;
+export default a;"#,
+            ),
+            (
+                "b.d.ts",
+                r#"export declare const b: number;
+export { a as default } from "./a";"#,
+            ),
+            ("c.d.ts", r#"export type C = string;"#),
+        ],
+        r#"import * as external from "external-namespace";
+declare const a: number;
+declare const b$1: number;
+type C$1 = string;
+type C = c.C | external.C;
+declare namespace b {
+    export { b$1 as b, a as default };
+}
+declare namespace c {
+    export { C$1 as C };
+}
+export * from "external";
+export { b, C, a };"#,
+    );
+}
+
+#[test]
+fn dts_bundle_default_export_test() {
+    bundle_test(
+        &[
+            (
+                "index.d.ts",
+                r#"import Client, { type Options } from "./client";
+import Base from "base";
+export default Client;
+export declare function create(options?: Options): Client & Base;"#,
+            ),
+            (
+                "client.d.ts",
+                r#"export interface Options {
+}
+export default class {
+    constructor(options: Options);
+}"#,
+            ),
+        ],
+        r#"import Base from "base";
+interface Options {
+}
+declare class _default {
+    constructor(options: Options);
+}
+declare function create(options?: Options): _default & Base;
+export { _default as default, create };"#,
+    );
+}
+
+#[test]
+fn dts_bundle_missing_export_test() {
+    let err = bundle(
+        &[
+            ("index.d.ts", r#"export { Missing } from "./types";"#),
+            ("types.d.ts", r#"export type Existing = string;"#),
+        ],
+        "index.d.ts",
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "module `types.d.ts` has no export named `Missing`"
+    );
+}
+
+#[test]
+fn dts_bundle_side_effect_imports_test() {
+    bundle_test(
+        &[
+            (
+                "index.d.ts",
+                r#"import "external/augment";
+import "./globals";
+import "external";
+import { Options } from "external";
+export declare function run(options: Options): void;"#,
+            ),
+            (
+                "globals.d.ts",
+                r#"import "external/augment";
+declare global {
+    interface Window {
+        augmented: true;
+    }
+}"#,
+            ),
+        ],
+        r#"import "external/augment";
+import { Options } from "external";
+declare global {
+    interface Window {
+        augmented: true;
+    }
+}
+declare function run(options: Options): void;
+export { run };"#,
+    );
+}
+
+#[test]
+fn dts_bundle_rename_shadowed_test() {
+    bundle_test(
+        &[
+            (
+                "index.d.ts",
+                r#"import { Client } from "./client";
+export interface Options {
+    client: Client;
+}"#,
+            ),
+            (
+                "client.d.ts",
+                r#"interface Options {
+    name: string;
+}
+export interface Client {
+    options: Options;
+    with<Options>(options: Options): Wrap<Options>;
+    infer: Options extends { name: infer Options } ? Options : never;
+}
+type Wrap<Options> = {
+    [Options in keyof Wrap<string>]: Options;
+};
+export declare namespace Client {
+    interface Options {
+        nested: boolean;
+    }
+    const options: Options;
+    namespace Inner.Options {
+        const value: Options;
+    }
+}"#,
+            ),
+        ],
+        r#"interface Options$1 {
+    name: string;
+}
+interface Client {
+    options: Options$1;
+    with<Options>(options: Options): Wrap<Options>;
+    infer: Options$1 extends {
+        name: infer Options;
+    } ? Options : never;
+}
+type Wrap<Options> = {
+    [Options in keyof Wrap<string>]: Options;
+};
+declare namespace Client {
+    interface Options {
+        nested: boolean;
+    }
+    const options: Options;
+    namespace Inner.Options {
+        const value: Options;
+    }
+}
+interface Options {
+    client: Client;
+}
+export { Options };"#,
+    );
+}
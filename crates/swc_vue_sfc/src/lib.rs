@@ -0,0 +1,449 @@
+#![deny(clippy::all)]
+
+//! Parser of [Vue single-file components](https://vuejs.org/api/sfc-spec.html).
+//!
+//! The component is parsed as an HTML document fragment to split it into
+//! blocks. Spans of blocks and of the parsed scripts and stylesheets point to
+//! the original file, so diagnostics and source maps can use them directly.
+
+use anyhow::{bail, Error};
+use swc_atoms::JsWord;
+use swc_common::{
+    comments::SingleThreadedComments, errors::Handler, input::StringInput, BytePos, SourceFile,
+    Span, DUMMY_SP,
+};
+use swc_css_ast::Stylesheet;
+use swc_css_modules::{TransformConfig, TransformResult};
+use swc_ecma_ast::Module;
+use swc_ecma_parser::{Parser, Syntax, TsSyntax};
+use swc_html_ast::{Attribute, Child, DocumentFragment, DocumentMode, Element, Namespace};
+use swc_html_parser::parse_file_as_document_fragment;
+
+#[derive(Default)]
+pub struct SfcOptions<'a> {
+    /// Creates class names of `<style module>` blocks, which are not
+    /// transformed if [None].
+    pub css_modules: Option<&'a dyn TransformConfig>,
+    /// Keep comments of scripts.
+    pub comments: Option<&'a SingleThreadedComments>,
+}
+
+/// Blocks of a single-file component.
+#[derive(Debug, Default)]
+pub struct SfcDescriptor {
+    pub template: Option<SfcTemplateBlock>,
+    /// The normal `<script>` block.
+    pub script: Option<SfcScriptBlock>,
+    /// The `<script setup>` block.
+    pub script_setup: Option<SfcScriptBlock>,
+    pub styles: Vec<SfcStyleBlock>,
+    /// Top-level elements other than `template`, `script` and `style`, e.g.
+    /// `<i18n>`.
+    pub custom_blocks: Vec<SfcBlock>,
+}
+
+/// A top-level element of a single-file component.
+#[derive(Debug, Clone)]
+pub struct SfcBlock {
+    pub tag_name: JsWord,
+    /// The span of the element, including its tags.
+    pub span: Span,
+    /// The span of the content between the tags.
+    pub content_span: Span,
+    /// The source text of the content.
+    pub content: String,
+    pub attributes: Vec<Attribute>,
+}
+
+impl SfcBlock {
+    /// Returns the value of the attribute, an empty string for an attribute
+    /// without a value.
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+            .map(|attribute| attribute.value.as_deref().unwrap_or(""))
+    }
+
+    /// The `lang` attribute, e.g. `ts` or `scss`.
+    pub fn lang(&self) -> Option<&str> {
+        self.attribute("lang")
+    }
+
+    /// The `src` attribute, set when the content is in another file.
+    pub fn src(&self) -> Option<&str> {
+        self.attribute("src")
+    }
+}
+
+#[derive(Debug)]
+pub struct SfcTemplateBlock {
+    pub block: SfcBlock,
+    /// The content parsed as HTML, [None] for other languages (e.g. `pug`)
+    /// and external templates.
+    ///
+    /// Note that Vue templates are not HTML: for example, tag names are
+    /// lowercased and the self-closing syntax is ignored for components.
+    pub content: Option<DocumentFragment>,
+}
+
+#[derive(Debug)]
+pub struct SfcScriptBlock {
+    pub block: SfcBlock,
+    pub is_setup: bool,
+    /// `lang="ts"` or `lang="tsx"`.
+    pub is_typescript: bool,
+    /// The parsed content, [None] for external scripts.
+    pub module: Option<Module>,
+    /// The content with TypeScript syntax replaced by whitespace, so
+    /// positions are the same as in `block.content`.
+    pub code: String,
+}
+
+#[derive(Debug)]
+pub struct SfcStyleBlock {
+    pub block: SfcBlock,
+    pub is_scoped: bool,
+    /// The name of the injected CSS modules object, `$style` for a `module`
+    /// attribute without a value.
+    pub module: Option<JsWord>,
+    /// The parsed content, [None] for external stylesheets and preprocessor
+    /// languages (e.g. `scss`).
+    pub stylesheet: Option<Stylesheet>,
+    /// Class names of CSS modules, the stylesheet is transformed when set.
+    pub css_modules: Option<TransformResult>,
+}
+
+/// Parses `fm` as a single-file component. Errors are emitted to `handler`.
+pub fn parse_sfc(
+    fm: &SourceFile,
+    handler: &Handler,
+    options: &SfcOptions,
+) -> Result<SfcDescriptor, Error> {
+    let context_element = Element {
+        span: DUMMY_SP,
+        tag_name: "template".into(),
+        namespace: Namespace::HTML,
+        attributes: vec![],
+        children: vec![],
+        content: None,
+        is_self_closing: false,
+    };
+    // Recoverable errors are ignored, because templates are not HTML.
+    let mut errors = vec![];
+    let document_fragment = match parse_file_as_document_fragment(
+        fm,
+        &context_element,
+        DocumentMode::NoQuirks,
+        None,
+        Default::default(),
+        &mut errors,
+    ) {
+        Ok(document_fragment) => document_fragment,
+        Err(err) => {
+            err.to_diagnostics(handler).emit();
+
+            bail!("failed to parse single-file component");
+        }
+    };
+
+    let mut descriptor = SfcDescriptor::default();
+    let mut has_errors = false;
+
+    for child in document_fragment.children {
+        let Child::Element(element) = child else {
+            continue;
+        };
+
+        let (block, content) = create_block(fm, element);
+
+        match &*block.tag_name {
+            "template" => {
+                if descriptor.template.is_some() {
+                    has_errors = true;
+                    handler
+                        .struct_span_err(
+                            block.span,
+                            "A single-file component can contain only one `<template>` block",
+                        )
+                        .emit();
+
+                    continue;
+                }
+
+                let content = match block.lang() {
+                    Some(lang) if lang != "html" => None,
+                    _ if block.src().is_some() => None,
+                    _ => content,
+                };
+
+                descriptor.template = Some(SfcTemplateBlock { block, content });
+            }
+            "script" => {
+                let is_setup = block.attribute("setup").is_some();
+                let existing = if is_setup {
+                    &descriptor.script_setup
+                } else {
+                    &descriptor.script
+                };
+
+                if existing.is_some() {
+                    has_errors = true;
+                    handler
+                        .struct_span_err(
+                            block.span,
+                            if is_setup {
+                                "A single-file component can contain only one `<script setup>` \
+                                 block"
+                            } else {
+                                "A single-file component can contain only one `<script>` block"
+                            },
+                        )
+                        .emit();
+
+                    continue;
+                }
+
+                let Ok(script) = parse_script(handler, block, is_setup, options) else {
+                    has_errors = true;
+
+                    continue;
+                };
+
+                if is_setup {
+                    descriptor.script_setup = Some(script);
+                } else {
+                    descriptor.script = Some(script);
+                }
+            }
+            "style" => match parse_style(handler, block, options) {
+                Ok(style) => descriptor.styles.push(style),
+                Err(..) => has_errors = true,
+            },
+            _ => descriptor.custom_blocks.push(block),
+        }
+    }
+
+    if has_errors {
+        bail!("failed to parse single-file component");
+    }
+
+    Ok(descriptor)
+}
+
+fn create_block(fm: &SourceFile, element: Element) -> (SfcBlock, Option<DocumentFragment>) {
+    let start = (element.span.lo - fm.start_pos).0 as usize;
+    let end = (element.span.hi - fm.start_pos).0 as usize;
+    let source = &fm.src[start..end];
+
+    let content_start = start_tag_end(source);
+    // The end tag is missing at the end of the file
+    let content_end = source
+        .rfind("</")
+        .filter(|&index| {
+            index >= content_start
+                && source[index + 2..]
+                    .get(..element.tag_name.len())
+                    .map_or(false, |tag_name| {
+                        tag_name.eq_ignore_ascii_case(&element.tag_name)
+                    })
+        })
+        .unwrap_or(source.len());
+    let content_span = Span::new(
+        element.span.lo + BytePos(content_start as u32),
+        element.span.lo + BytePos(content_end as u32),
+    );
+
+    (
+        SfcBlock {
+            tag_name: element.tag_name,
+            span: element.span,
+            content_span,
+            content: source[content_start..content_end].to_string(),
+            attributes: element.attributes,
+        },
+        element.content,
+    )
+}
+
+/// Returns the end of the start tag at the beginning of `source`, skipping
+/// quoted attribute values.
+fn start_tag_end(source: &str) -> usize {
+    let mut quote = None;
+
+    for (index, c) in source.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '>') => return index + 1,
+            _ => {}
+        }
+    }
+
+    source.len()
+}
+
+fn parse_script(
+    handler: &Handler,
+    block: SfcBlock,
+    is_setup: bool,
+    options: &SfcOptions,
+) -> Result<SfcScriptBlock, Error> {
+    let lang = block.lang().unwrap_or("js");
+    let ts_syntax = TsSyntax {
+        tsx: lang == "tsx",
+        decorators: true,
+        ..Default::default()
+    };
+    let (syntax, is_typescript) = match lang {
+        "ts" | "tsx" => (Syntax::Typescript(ts_syntax), true),
+        "js" | "jsx" => (
+            Syntax::Es(swc_ecma_parser::EsSyntax {
+                jsx: lang == "jsx",
+                decorators: true,
+                ..Default::default()
+            }),
+            false,
+        ),
+        _ => {
+            handler
+                .struct_span_err(
+                    block.span,
+                    &format!("Unsupported language of `<script>` block: `{}`", lang),
+                )
+                .emit();
+
+            bail!("unsupported language");
+        }
+    };
+
+    if block.src().is_some() {
+        return Ok(SfcScriptBlock {
+            code: block.content.clone(),
+            block,
+            is_setup,
+            is_typescript,
+            module: None,
+        });
+    }
+
+    let input = StringInput::new(&block.content, block.content_span.lo, block.content_span.hi);
+    let mut parser = Parser::new(
+        syntax,
+        input,
+        options
+            .comments
+            .map(|comments| comments as &dyn swc_common::comments::Comments),
+    );
+    let module = parser.parse_module();
+    let errors = parser.take_errors();
+
+    let module = match module {
+        Ok(module) if errors.is_empty() => module,
+        result => {
+            if let Err(err) = result {
+                err.into_diagnostic(handler).emit();
+            }
+
+            for err in errors {
+                err.into_diagnostic(handler).emit();
+            }
+
+            bail!("failed to parse `<script>` block");
+        }
+    };
+
+    let code = if is_typescript {
+        swc_fast_ts_strip::operate(
+            // The stripper expects the code to be the first file of the source map, which is
+            // true for a new one. Syntax errors are already reported above.
+            &Default::default(),
+            handler,
+            block.content.clone(),
+            swc_fast_ts_strip::Options {
+                module: Some(true),
+                filename: None,
+                parser: ts_syntax,
+                mode: swc_fast_ts_strip::Mode::StripOnly,
+                transform: None,
+                source_map: false,
+                use_define_for_class_fields: false,
+            },
+        )?
+        .code
+    } else {
+        block.content.clone()
+    };
+
+    Ok(SfcScriptBlock {
+        block,
+        is_setup,
+        is_typescript,
+        module: Some(module),
+        code,
+    })
+}
+
+fn parse_style(
+    handler: &Handler,
+    block: SfcBlock,
+    options: &SfcOptions,
+) -> Result<SfcStyleBlock, Error> {
+    let is_scoped = block.attribute("scoped").is_some();
+    let module = block.attribute("module").map(|module| match module {
+        "" => "$style".into(),
+        _ => module.into(),
+    });
+
+    if block.src().is_some() || !matches!(block.lang(), None | Some("css")) {
+        return Ok(SfcStyleBlock {
+            block,
+            is_scoped,
+            module,
+            stylesheet: None,
+            css_modules: None,
+        });
+    }
+
+    let mut errors = vec![];
+    let input = StringInput::new(&block.content, block.content_span.lo, block.content_span.hi);
+    let mut stylesheet: Stylesheet =
+        match swc_css_parser::parse_string_input(input, None, Default::default(), &mut errors) {
+            Ok(stylesheet) if errors.is_empty() => stylesheet,
+            result => {
+                if let Err(err) = result {
+                    err.to_diagnostics(handler).emit();
+                }
+
+                for err in errors {
+                    err.to_diagnostics(handler).emit();
+                }
+
+                bail!("failed to parse `<style>` block");
+            }
+        };
+
+    let css_modules = match (&module, options.css_modules) {
+        (Some(..), Some(config)) => Some(swc_css_modules::compile(
+            &mut stylesheet,
+            CssModulesConfig(config),
+        )),
+        _ => None,
+    };
+
+    Ok(SfcStyleBlock {
+        block,
+        is_scoped,
+        module,
+        stylesheet: Some(stylesheet),
+        css_modules,
+    })
+}
+
+struct CssModulesConfig<'a>(&'a dyn TransformConfig);
+
+impl TransformConfig for CssModulesConfig<'_> {
+    fn new_name_for(&self, local: &JsWord) -> JsWord {
+        self.0.new_name_for(local)
+    }
+}
@@ -0,0 +1,148 @@
+use std::{fmt::Write, path::PathBuf};
+
+use swc_atoms::JsWord;
+use swc_common::{SourceMap, Span};
+use swc_css_modules::{CssClassName, TransformConfig};
+use swc_vue_sfc::{parse_sfc, SfcBlock, SfcOptions};
+use testing::NormalizedOutput;
+
+struct TestConfig;
+
+impl TransformConfig for TestConfig {
+    fn new_name_for(&self, local: &JsWord) -> JsWord {
+        format!("__{}", local).into()
+    }
+}
+
+fn loc(cm: &SourceMap, span: Span) -> String {
+    let lo = cm.lookup_char_pos(span.lo);
+    let hi = cm.lookup_char_pos(span.hi);
+
+    format!(
+        "{}:{}-{}:{}",
+        lo.line,
+        lo.col_display + 1,
+        hi.line,
+        hi.col_display + 1
+    )
+}
+
+fn print_block(cm: &SourceMap, output: &mut String, block: &SfcBlock) {
+    writeln!(output, "<{}>", block.tag_name).unwrap();
+    writeln!(output, "  span: {}", loc(cm, block.span)).unwrap();
+    writeln!(output, "  content span: {}", loc(cm, block.content_span)).unwrap();
+
+    for attribute in &block.attributes {
+        writeln!(
+            output,
+            "  attribute: {}={:?}",
+            attribute.name, attribute.value
+        )
+        .unwrap();
+    }
+
+    writeln!(output, "  content: {:?}", block.content).unwrap();
+}
+
+#[testing::fixture("tests/fixture/**/input.vue")]
+fn fixture(input: PathBuf) {
+    let output_file = input.parent().unwrap().join("output.txt");
+
+    testing::run_test(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+        let descriptor = parse_sfc(
+            &fm,
+            handler,
+            &SfcOptions {
+                css_modules: Some(&TestConfig),
+                ..Default::default()
+            },
+        )
+        .expect("should not return Err()");
+        let mut output = String::new();
+
+        if let Some(template) = &descriptor.template {
+            print_block(&cm, &mut output, &template.block);
+            writeln!(
+                output,
+                "  parsed: {}",
+                template
+                    .content
+                    .as_ref()
+                    .map_or(0, |content| content.children.len())
+            )
+            .unwrap();
+        }
+
+        for script in descriptor.script.iter().chain(&descriptor.script_setup) {
+            print_block(&cm, &mut output, &script.block);
+            writeln!(output, "  setup: {}", script.is_setup).unwrap();
+            writeln!(output, "  typescript: {}", script.is_typescript).unwrap();
+
+            if let Some(module) = &script.module {
+                for item in &module.body {
+                    writeln!(output, "  item: {}", loc(&cm, item_span(item))).unwrap();
+                }
+            }
+
+            writeln!(output, "  code: {:?}", script.code).unwrap();
+        }
+
+        for style in &descriptor.styles {
+            print_block(&cm, &mut output, &style.block);
+            writeln!(output, "  scoped: {}", style.is_scoped).unwrap();
+            writeln!(output, "  module: {:?}", style.module).unwrap();
+
+            if let Some(stylesheet) = &style.stylesheet {
+                writeln!(output, "  rules: {}", stylesheet.rules.len()).unwrap();
+            }
+
+            if let Some(css_modules) = &style.css_modules {
+                let mut renamed = css_modules.renamed.iter().collect::<Vec<_>>();
+
+                renamed.sort_by(|a, b| a.0.cmp(b.0));
+
+                for (local, names) in renamed {
+                    for name in names {
+                        if let CssClassName::Local { name } = name {
+                            writeln!(output, "  class: {} -> {}", local, name.value).unwrap();
+                        }
+                    }
+                }
+            }
+        }
+
+        for block in &descriptor.custom_blocks {
+            print_block(&cm, &mut output, block);
+        }
+
+        NormalizedOutput::new_raw(output)
+            .compare_to_file(&output_file)
+            .unwrap();
+
+        Ok(())
+    })
+    .expect("should not fail");
+}
+
+#[testing::fixture("tests/errors/**/input.vue")]
+fn error(input: PathBuf) {
+    let output_file = input.parent().unwrap().join("output.swc-stderr");
+
+    testing::run_test(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+
+        parse_sfc(&fm, handler, &SfcOptions::default()).expect_err("should return Err()");
+
+        Err::<(), _>(())
+    })
+    .expect_err("should fail")
+    .compare_to_file(output_file)
+    .unwrap();
+}
+
+fn item_span(item: &swc_ecma_ast::ModuleItem) -> Span {
+    use swc_common::Spanned;
+
+    item.span()
+}
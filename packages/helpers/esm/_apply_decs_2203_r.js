@@ -15,10 +15,12 @@
 
   STATIC = 5;
 
+  DECORATORS_HAVE_THIS = 16;
+
   CLASS = 10; // only used in assertValidReturnValue
 */
 
-export function _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentClass) {
+export function _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentClass, classDecsHaveThis) {
     function createAddInitializerMethod(initializers, decoratorFinishedRef) {
         return function addInitializer(initializer) {
             assertNotFinished(decoratorFinishedRef, "addInitializer");
@@ -27,7 +29,7 @@ export function _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentCla
         };
     }
 
-    function memberDec(dec, name, desc, initializers, kind, isStatic, isPrivate, metadata, value) {
+    function memberDec(dec, name, desc, initializers, kind, isStatic, isPrivate, metadata, value, decThis) {
         var kindStr;
 
         switch (kind) {
@@ -87,7 +89,7 @@ export function _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentCla
         ctx.access = get && set ? { get: get, set: set } : get ? { get: get } : { set: set };
 
         try {
-            return dec(value, ctx);
+            return dec.call(decThis, value, ctx);
         } finally {
             decoratorFinishedRef.v = true;
         }
@@ -134,7 +136,7 @@ export function _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentCla
         }
     }
 
-    function applyMemberDec(ret, base, decInfo, name, kind, isStatic, isPrivate, initializers, metadata) {
+    function applyMemberDec(ret, base, decInfo, name, kind, isStatic, isPrivate, initializers, metadata, decoratorsHaveThis) {
         var decs = decInfo[0];
 
         var desc, init, value;
@@ -184,10 +186,11 @@ export function _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentCla
                 }
             }
         } else {
-            for (var i = decs.length - 1; i >= 0; i--) {
+            for (var i = decs.length - 1; i >= 0; i -= decoratorsHaveThis ? 2 : 1) {
                 var dec = decs[i];
+                var decThis = decoratorsHaveThis ? decs[i - 1] : void 0;
 
-                newValue = memberDec(dec, name, desc, initializers, kind, isStatic, isPrivate, metadata, value);
+                newValue = memberDec(dec, name, desc, initializers, kind, isStatic, isPrivate, metadata, value, decThis);
 
                 if (newValue !== void 0) {
                     assertValidReturnValue(kind, newValue);
@@ -294,6 +297,8 @@ export function _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentCla
 
             var kind = decInfo[1];
             var name = decInfo[2];
+            var decoratorsHaveThis = kind & 16; /* DECORATORS_HAVE_THIS */
+            kind &= ~16; /* DECORATORS_HAVE_THIS */
             var isPrivate = decInfo.length > 3;
 
             var isStatic = kind >= 5; /* STATIC */
@@ -330,7 +335,7 @@ export function _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentCla
                 }
             }
 
-            applyMemberDec(ret, base, decInfo, name, kind, isStatic, isPrivate, initializers, metadata);
+            applyMemberDec(ret, base, decInfo, name, kind, isStatic, isPrivate, initializers, metadata, decoratorsHaveThis);
         }
 
         pushInitializers(ret, protoInitializers);
@@ -347,17 +352,17 @@ export function _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentCla
         }
     }
 
-    function applyClassDecs(targetClass, classDecs, metadata) {
+    function applyClassDecs(targetClass, classDecs, metadata, classDecsHaveThis) {
         if (classDecs.length > 0) {
             var initializers = [];
             var newClass = targetClass;
             var name = targetClass.name;
 
-            for (var i = classDecs.length - 1; i >= 0; i--) {
+            for (var i = classDecs.length - 1; i >= 0; i -= classDecsHaveThis ? 2 : 1) {
                 var decoratorFinishedRef = { v: false };
 
                 try {
-                    var nextNewClass = classDecs[i](newClass, { kind: "class", name: name, addInitializer: createAddInitializerMethod(initializers, decoratorFinishedRef), metadata });
+                    var nextNewClass = classDecs[i].call(classDecsHaveThis ? classDecs[i - 1] : void 0, newClass, { kind: "class", name: name, addInitializer: createAddInitializerMethod(initializers, decoratorFinishedRef), metadata });
                 } finally {
                     decoratorFinishedRef.v = true;
                 }
@@ -526,7 +531,7 @@ export function _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentCla
     initializeClass(Class);
    */
 
-    _apply_decs_2203_r = function(targetClass, memberDecs, classDecs, parentClass) {
+    _apply_decs_2203_r = function(targetClass, memberDecs, classDecs, parentClass, classDecsHaveThis) {
         if (parentClass !== void 0) {
             var parentMetadata = parentClass[Symbol.metadata || Symbol.for("Symbol.metadata")];
         }
@@ -537,12 +542,12 @@ export function _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentCla
             e: e,
             // Lazily apply class decorations so that member init locals can be properly bound.
             get c() {
-                return applyClassDecs(targetClass, classDecs, metadata);
+                return applyClassDecs(targetClass, classDecs, metadata, classDecsHaveThis);
             }
         };
     };
 
-    return _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentClass);
+    return _apply_decs_2203_r(targetClass, memberDecs, classDecs, parentClass, classDecsHaveThis);
 }
 
 export { _apply_decs_2203_r as _ };
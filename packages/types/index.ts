@@ -436,6 +436,16 @@ export interface Options extends Config {
      */
     swcrc?: boolean;
 
+    /**
+     * Path to a tsconfig.json file, relative to "cwd".
+     *
+     * `target`, `module`, `jsx*`, `experimentalDecorators`, `emitDecoratorMetadata`,
+     * `useDefineForClassFields`, `baseUrl`, `paths`, `importHelpers` and
+     * `verbatimModuleSyntax` of the file (and of the files it `extends`) are used.
+     * Options from .swcrc take precedence.
+     */
+    tsconfig?: string;
+
     /**
      * By default, Babel will only search for .babelrc files within the "root" package
      *  because otherwise Babel cannot know if a given .babelrc is meant to be loaded,
@@ -804,6 +814,13 @@ export interface TransformConfig {
     treatConstEnumAsEnum?: boolean;
 
     useDefineForClassFields?: boolean;
+
+    /**
+     * Reports code which can't be compiled one file at a time.
+     *
+     * https://www.typescriptlang.org/tsconfig#isolatedModules
+     */
+    isolatedModules?: boolean;
 }
 
 export interface ReactConfig {
@@ -850,7 +867,7 @@ export interface ReactConfig {
     /**
      * jsx runtime
      */
-    runtime?: "automatic" | "classic";
+    runtime?: "automatic" | "classic" | "preserve";
 
     /**
      * Declares the module specifier to be used for importing the `jsx` and `jsxs` factory functions when using `runtime` 'automatic'